@@ -12,7 +12,7 @@ use uv_once_map::OnceMap;
 use uv_redacted::DisplaySafeUrl;
 
 use crate::credentials::{Authentication, Username};
-use crate::{Credentials, Realm};
+use crate::{CredentialSource, Credentials, Realm};
 
 type FxOnceMap<K, V> = OnceMap<K, V, BuildHasherDefault<FxHasher>>;
 
@@ -65,6 +65,9 @@ impl CredentialsCache {
     pub fn store_credentials_from_url(&self, url: &DisplaySafeUrl) -> bool {
         if let Some(credentials) = Credentials::from_url(url) {
             trace!("Caching credentials for {url}");
+            if credentials.is_authenticated() {
+                CredentialSource::Url.select(url, credentials.username());
+            }
             self.insert(url, Arc::new(Authentication::from(credentials)));
             true
         } else {
@@ -72,11 +75,18 @@ impl CredentialsCache {
         }
     }
 
-    /// Populate the global authentication store with credentials on a URL, if there are any.
-    ///
-    /// Returns `true` if the store was updated.
-    pub fn store_credentials(&self, url: &DisplaySafeUrl, credentials: Credentials) {
+    /// Populate the global authentication store with credentials for a URL, read from the given
+    /// source.
+    pub fn store_credentials(
+        &self,
+        url: &DisplaySafeUrl,
+        credentials: Credentials,
+        source: CredentialSource,
+    ) {
         trace!("Caching credentials for {url}");
+        if credentials.is_authenticated() {
+            source.select(url, credentials.username());
+        }
         self.insert(url, Arc::new(Authentication::from(credentials)));
     }
 
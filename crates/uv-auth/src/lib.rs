@@ -9,6 +9,7 @@ pub use pyx::{
 };
 pub use realm::{Realm, RealmRef};
 pub use service::{Service, ServiceParseError};
+pub use source::CredentialSource;
 pub use store::{AuthBackend, AuthScheme, TextCredentialStore, TomlCredentialError};
 
 mod access_token;
@@ -21,4 +22,5 @@ mod providers;
 mod pyx;
 mod realm;
 mod service;
+mod source;
 mod store;
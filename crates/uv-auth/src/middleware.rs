@@ -17,7 +17,7 @@ use crate::credentials::Authentication;
 use crate::providers::{GcsEndpointProvider, HuggingFaceProvider, S3EndpointProvider};
 use crate::pyx::{DEFAULT_TOLERANCE_SECS, PyxTokenStore};
 use crate::{
-    AccessToken, CredentialsCache, KeyringProvider,
+    AccessToken, CredentialSource, CredentialsCache, KeyringProvider,
     cache::FetchUrl,
     credentials::{Credentials, Username},
     index::{AuthPolicy, Indexes},
@@ -544,15 +544,25 @@ impl AuthMiddleware {
         next: Next<'_>,
         auth_policy: AuthPolicy,
     ) -> reqwest_middleware::Result<Response> {
+        let url = DisplaySafeUrl::from_url(request.url().clone());
+        let is_authenticated = request
+            .headers()
+            .contains_key(reqwest::header::AUTHORIZATION);
         let Some(credentials) = credentials else {
             // Nothing to insert into the cache if we don't have credentials
-            return next.run(request, extensions).await;
+            let result = next.run(request, extensions).await;
+            if is_authenticated {
+                log_rejected_credentials(&url, &result);
+            }
+            return result;
         };
-        let url = DisplaySafeUrl::from_url(request.url().clone());
         if matches!(auth_policy, AuthPolicy::Always) && credentials.password().is_none() {
             return Err(Error::Middleware(format_err!("Missing password for {url}")));
         }
         let result = next.run(request, extensions).await;
+        if is_authenticated {
+            log_rejected_credentials(&url, &result);
+        }
 
         // Update the cache with new credentials on a successful request
         if result
@@ -698,6 +708,7 @@ impl AuthMiddleware {
             .map(Arc::new)
         {
             debug!("Found Hugging Face credentials for {url}");
+            CredentialSource::HuggingFace.select(url, credentials.username());
             self.cache().fetches.done(key, Some(credentials.clone()));
             return Some(credentials);
         }
@@ -719,6 +730,7 @@ impl AuthMiddleware {
 
             if let Some(credentials) = credentials {
                 debug!("Found S3 credentials for {url}");
+                CredentialSource::S3.select(url, None);
                 self.cache().fetches.done(key, Some(credentials.clone()));
                 return Some(credentials);
             }
@@ -741,6 +753,7 @@ impl AuthMiddleware {
 
             if let Some(credentials) = credentials {
                 debug!("Found GCS credentials for {url}");
+                CredentialSource::Gcs.select(url, None);
                 self.cache().fetches.done(key, Some(credentials.clone()));
                 return Some(credentials);
             }
@@ -775,6 +788,7 @@ impl AuthMiddleware {
 
                     let credentials = token.map(|token| {
                         trace!("Using credentials from token store for {url}");
+                        CredentialSource::Pyx.select(url, None);
                         Arc::new(Authentication::from(Credentials::from(token)))
                     });
 
@@ -789,31 +803,39 @@ impl AuthMiddleware {
         // Netrc support based on: <https://github.com/gribouille/netrc>.
         let credentials = if let Some(credentials) = self.netrc.get().and_then(|netrc| {
             debug!("Checking netrc for credentials for {url}");
-            Credentials::from_netrc(
+            let credentials = Credentials::from_netrc(
                 netrc,
                 url,
                 credentials
                     .as_ref()
                     .and_then(|credentials| credentials.username()),
-            )
+            );
+            if credentials.is_none() {
+                debug!("No credentials found in netrc file for {url}");
+            }
+            credentials
         }) {
             debug!("Found credentials in netrc file for {url}");
-            Some(credentials)
+            Some((credentials, CredentialSource::Netrc))
 
         // Text credential store support.
         } else if let Some(credentials) = self.text_store.get().await.and_then(|text_store| {
             debug!("Checking text store for credentials for {url}");
-            text_store
+            let credentials = text_store
                 .get_credentials(
                     url,
                     credentials
                         .as_ref()
                         .and_then(|credentials| credentials.username()),
                 )
-                .cloned()
+                .cloned();
+            if credentials.is_none() {
+                debug!("No credentials found in plaintext store for {url}");
+            }
+            credentials
         }) {
             debug!("Found credentials in plaintext store for {url}");
-            Some(credentials)
+            Some((credentials, CredentialSource::TextStore))
         } else if let Some(credentials) = {
             if self.preview.is_enabled(PreviewFeature::NativeAuth) {
                 let native_store = KeyringProvider::native();
@@ -823,7 +845,7 @@ impl AuthMiddleware {
                 } else {
                     String::new()
                 };
-                if let Some(index) = index {
+                let credentials = if let Some(index) = index {
                     // N.B. The native store performs an exact look up right now, so we use the root
                     // URL of the index instead of relying on prefix-matching.
                     debug!(
@@ -837,14 +859,18 @@ impl AuthMiddleware {
                         display_username, url
                     );
                     native_store.fetch(url, username).await
-                }
+                };
                 // TODO(zanieb): We should have a realm fallback here too
+                if credentials.is_none() {
+                    debug!("No credentials found in native store for {url}");
+                }
+                credentials
             } else {
                 None
             }
         } {
             debug!("Found credentials in native store for {url}");
-            Some(credentials)
+            Some((credentials, CredentialSource::NativeStore))
         // N.B. The keyring provider performs lookups for the exact URL then falls back to the host.
         //      But, in the absence of an index URL, we cache the result per realm. So in that case,
         //      if a keyring implementation returns different credentials for different URLs in the
@@ -854,21 +880,26 @@ impl AuthMiddleware {
                 // The subprocess keyring provider is _slow_ so we do not perform fetches for all
                 // URLs; instead, we fetch if there's a username or if the user has requested to
                 // always authenticate.
-                if let Some(username) = credentials.and_then(|credentials| credentials.username()) {
+                // The result of the lookup, or `None` if the keyring wasn't consulted.
+                let lookup = if let Some(username) =
+                    credentials.and_then(|credentials| credentials.username())
+                {
                     if let Some(index) = index {
                         debug!(
                             "Checking keyring for credentials for index URL {}@{}",
                             username, index.url
                         );
-                        keyring
-                            .fetch(DisplaySafeUrl::ref_cast(&index.url), Some(username))
-                            .await
+                        Some(
+                            keyring
+                                .fetch(DisplaySafeUrl::ref_cast(&index.url), Some(username))
+                                .await,
+                        )
                     } else {
                         debug!(
                             "Checking keyring for credentials for full URL {}@{}",
                             username, url
                         );
-                        keyring.fetch(url, Some(username)).await
+                        Some(keyring.fetch(url, Some(username)).await)
                     }
                 } else if matches!(auth_policy, AuthPolicy::Always) {
                     if let Some(index) = index {
@@ -876,9 +907,11 @@ impl AuthMiddleware {
                             "Checking keyring for credentials for index URL {} without username due to `authenticate = always`",
                             index.url
                         );
-                        keyring
-                            .fetch(DisplaySafeUrl::ref_cast(&index.url), None)
-                            .await
+                        Some(
+                            keyring
+                                .fetch(DisplaySafeUrl::ref_cast(&index.url), None)
+                                .await,
+                        )
                     } else {
                         None
                     }
@@ -887,17 +920,25 @@ impl AuthMiddleware {
                         "Skipping keyring fetch for {url} without username; use `authenticate = always` to force"
                     );
                     None
+                };
+                // Failures to run the keyring backend are logged by the provider itself.
+                if matches!(lookup, Some(None)) {
+                    debug!("Keyring lookup for {url} returned no credentials");
                 }
+                lookup.flatten()
             }
             None => None,
         } {
             debug!("Found credentials in keyring for {url}");
-            Some(credentials)
+            Some((credentials, CredentialSource::Keyring))
         } else {
             None
         };
 
-        let credentials = credentials.map(Authentication::from).map(Arc::new);
+        let credentials = credentials.map(|(credentials, source)| {
+            source.select(url, credentials.username());
+            Arc::new(Authentication::from(credentials))
+        });
 
         // Register the fetch for this key
         self.cache().fetches.done(key, credentials.clone());
@@ -906,6 +947,26 @@ impl AuthMiddleware {
     }
 }
 
+/// Log the source of the credentials used for a request that was rejected as unauthorized or
+/// forbidden.
+fn log_rejected_credentials(url: &DisplaySafeUrl, result: &reqwest_middleware::Result<Response>) {
+    let Ok(response) = result else {
+        return;
+    };
+    if !matches!(
+        response.status(),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+    ) {
+        return;
+    }
+    if let Some(source) = CredentialSource::reject(url) {
+        debug!(
+            "Request for {url} failed with {} using credentials from {source}",
+            response.status()
+        );
+    }
+}
+
 fn tracing_url(request: &Request, credentials: Option<&Authentication>) -> DisplaySafeUrl {
     let mut url = DisplaySafeUrl::from_url(request.url().clone());
     if let Some(Authentication::Credentials(creds)) = credentials {
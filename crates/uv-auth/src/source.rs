@@ -0,0 +1,123 @@
+//! The sources from which credentials are selected, logged at debug level for each realm.
+//!
+//! The selected source is recorded process-wide, such that errors for requests that are rejected
+//! despite being authenticated can name the source without threading the credentials cache
+//! through every layer that reports them. Secrets are never recorded; only the username is.
+
+use std::fmt::{Display, Formatter};
+use std::sync::{LazyLock, RwLock};
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+use url::Url;
+
+use uv_static::EnvVars;
+
+use crate::Realm;
+
+/// The credentials selected for each realm.
+static SELECTED: LazyLock<RwLock<FxHashMap<Realm, Selection>>> = LazyLock::new(RwLock::default);
+
+/// The source and username of the credentials selected for a realm.
+#[derive(Debug)]
+struct Selection {
+    source: CredentialSource,
+    username: Option<String>,
+    /// Whether a request authenticated with the credentials was rejected.
+    rejected: bool,
+}
+
+impl Display for Selection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(username) = &self.username {
+            write!(f, "{} (username: `{username}`)", self.source)
+        } else {
+            write!(f, "{}", self.source)
+        }
+    }
+}
+
+/// A source from which credentials can be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialSource {
+    /// Credentials embedded in the URL.
+    Url,
+    /// The `UV_INDEX_{name}_USERNAME` and `UV_INDEX_{name}_PASSWORD` environment variables for a
+    /// named index.
+    Environment(String),
+    /// A netrc file.
+    Netrc,
+    /// The plaintext credential store.
+    TextStore,
+    /// The native credential store.
+    NativeStore,
+    /// The keyring provider.
+    Keyring,
+    /// The `HF_TOKEN` environment variable.
+    HuggingFace,
+    /// The AWS credential chain, for S3 endpoints.
+    S3,
+    /// The Google Cloud credential chain, for GCS endpoints.
+    Gcs,
+    /// The pyx token store.
+    Pyx,
+}
+
+impl CredentialSource {
+    /// Record that credentials from this source were selected for the realm of the given URL.
+    pub(crate) fn select(self, url: &Url, username: Option<&str>) {
+        let realm = Realm::from(url);
+        let selection = Selection {
+            source: self,
+            username: username.map(ToString::to_string),
+            rejected: false,
+        };
+        let mut selected = SELECTED.write().unwrap();
+        if selected.get(&realm).is_some_and(|existing| {
+            existing.source == selection.source && existing.username == selection.username
+        }) {
+            return;
+        }
+        debug!("Using credentials from {selection} for {realm}");
+        selected.insert(realm, selection);
+    }
+
+    /// Record that a request authenticated with the credentials selected for the realm of the
+    /// given URL was rejected, returning a description of the credentials, if any were selected.
+    pub(crate) fn reject(url: &Url) -> Option<String> {
+        let mut selected = SELECTED.write().unwrap();
+        let selection = selected.get_mut(&Realm::from(url))?;
+        selection.rejected = true;
+        Some(selection.to_string())
+    }
+
+    /// Return a description of the credentials selected for the realm of the given URL, if a
+    /// request authenticated with them was rejected, e.g., "the netrc file (username: `public`)".
+    pub fn describe_rejected(url: &Url) -> Option<String> {
+        let selected = SELECTED.read().unwrap();
+        let selection = selected.get(&Realm::from(url))?;
+        selection.rejected.then(|| selection.to_string())
+    }
+}
+
+impl Display for CredentialSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url => write!(f, "the URL"),
+            Self::Environment(name) => write!(
+                f,
+                "the `{}` and `{}` environment variables",
+                EnvVars::index_username(name),
+                EnvVars::index_password(name)
+            ),
+            Self::Netrc => write!(f, "the netrc file"),
+            Self::TextStore => write!(f, "the plaintext credential store"),
+            Self::NativeStore => write!(f, "the native credential store"),
+            Self::Keyring => write!(f, "the keyring"),
+            Self::HuggingFace => write!(f, "the `{}` environment variable", EnvVars::HF_TOKEN),
+            Self::S3 => write!(f, "the AWS credential chain"),
+            Self::Gcs => write!(f, "the Google Cloud credential chain"),
+            Self::Pyx => write!(f, "the pyx token store"),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::str::FromStr;
 use criterion::{Criterion, criterion_group, criterion_main, measurement::WallTime};
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
-use uv_distribution_types::Requirement;
+use uv_distribution_types::{IndexMetadata, IndexUrl, Requirement, RequirementSource};
 use uv_python::PythonEnvironment;
 use uv_resolver::Manifest;
 
@@ -22,6 +22,69 @@ fn resolve_warm_jupyter_universal(c: &mut Criterion<WallTime>) {
     c.bench_function("resolve_warm_jupyter_universal", |b| b.iter(|| run(true)));
 }
 
+fn resolve_warm_jupyter_universal_pinned(c: &mut Criterion<WallTime>) {
+    // Pin `jupyter` and its direct dependencies to PyPI, such that every fork records (and
+    // re-confirms) the pinned index of each of them.
+    let index = IndexMetadata::from(IndexUrl::from_str("https://pypi.org/simple").unwrap());
+    let run = setup(Manifest::simple(
+        [
+            "jupyter==1.0.0",
+            "ipykernel",
+            "ipywidgets",
+            "jupyter-console",
+            "nbconvert",
+            "notebook",
+            "qtconsole",
+        ]
+        .into_iter()
+        .map(|requirement| {
+            let mut requirement =
+                Requirement::from(uv_pep508::Requirement::from_str(requirement).unwrap());
+            if let RequirementSource::Registry { index: pinned, .. } = &mut requirement.source {
+                *pinned = Some(index.clone());
+            }
+            requirement
+        })
+        .collect(),
+    ));
+    c.bench_function("resolve_warm_jupyter_universal_pinned", |b| {
+        b.iter(|| run(true));
+    });
+}
+
+fn resolve_warm_jupyter_universal_deep_forks(c: &mut Criterion<WallTime>) {
+    // Split the resolution on `sys_platform`, `python_version`, and `platform_machine` in turn,
+    // such that the forks form a deep tree, and pin every requirement to PyPI, such that each fork
+    // inherits the pinned indexes of the fork it was split from.
+    let index = IndexMetadata::from(IndexUrl::from_str("https://pypi.org/simple").unwrap());
+    let run = setup(Manifest::simple(
+        [
+            "jupyter==1.0.0",
+            "anyio==4.3.0 ; sys_platform == 'linux'",
+            "anyio==4.2.0 ; sys_platform == 'darwin'",
+            "anyio==4.1.0 ; sys_platform == 'win32'",
+            "idna==3.6 ; python_version < '3.11'",
+            "idna==3.7 ; python_version >= '3.11' and python_version < '3.13'",
+            "idna==3.4 ; python_version >= '3.13'",
+            "sniffio==1.3.1 ; platform_machine == 'x86_64'",
+            "sniffio==1.3.0 ; platform_machine != 'x86_64'",
+        ]
+        .into_iter()
+        .map(|requirement| {
+            let mut requirement =
+                Requirement::from(uv_pep508::Requirement::from_str(requirement).unwrap());
+            if let RequirementSource::Registry { index: pinned, .. } = &mut requirement.source {
+                *pinned = Some(index.clone());
+            }
+            requirement
+        })
+        .collect(),
+    ));
+    c.bench_function("resolve_warm_jupyter_universal_deep_forks", |b| {
+        b.iter(|| run(true));
+    });
+}
+
 fn resolve_warm_airflow(c: &mut Criterion<WallTime>) {
     let run = setup(Manifest::simple(vec![
         Requirement::from(uv_pep508::Requirement::from_str("apache-airflow[all]==2.9.3").unwrap()),
@@ -47,6 +110,8 @@ criterion_group!(
     uv,
     resolve_warm_jupyter,
     resolve_warm_jupyter_universal,
+    resolve_warm_jupyter_universal_pinned,
+    resolve_warm_jupyter_universal_deep_forks,
     resolve_warm_airflow
 );
 criterion_main!(uv);
@@ -258,7 +258,10 @@ async fn download_and_unpack_with_retry(
         .await;
 
         match result {
-            Ok(path) => return Ok(path),
+            Ok(path) => {
+                retry_state.succeeded();
+                return Ok(path);
+            }
             Err(err) => {
                 if let Some(backoff) = retry_state.should_retry(&err, err.retries()) {
                     retry_state.sleep_backoff(backoff).await;
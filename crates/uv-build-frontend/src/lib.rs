@@ -8,13 +8,14 @@ mod pipreqs;
 use std::borrow::Cow;
 use std::ffi::OsString;
 use std::fmt::Formatter;
-use std::fmt::Write;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::rc::Rc;
 use std::str::FromStr;
 use std::sync::LazyLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
 use std::{env, iter};
 
 use fs_err as fs;
@@ -59,6 +60,9 @@ static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend
     )],
 });
 
+/// The number of build backend scripts that are currently running, across all builds.
+static RUNNING_SCRIPTS: AtomicUsize = AtomicUsize::new(0);
+
 /// A `pyproject.toml` as specified in PEP 517.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "kebab-case")]
@@ -351,22 +355,36 @@ impl SourceBuild {
             .collect::<Result<Vec<_>, _>>()?;
 
         // Create a virtual environment, or install into the shared environment if requested.
+        let package = package_name
+            .as_ref()
+            .map(PackageName::as_str)
+            .or(version_id);
         let venv = if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
             venv.clone()
         } else {
-            uv_virtualenv::create_venv(
-                temp_dir.path(),
-                interpreter.clone(),
-                uv_virtualenv::Prompt::None,
-                false,
-                uv_virtualenv::OnExisting::Remove(
-                    uv_virtualenv::RemovalReason::TemporaryEnvironment,
-                ),
-                false,
-                false,
-                false,
-                preview,
-            )?
+            let start = Instant::now();
+            let span = info_span!(
+                "create_build_venv",
+                version_id = version_id,
+                package = package_name.as_ref().map(PackageName::as_str),
+            );
+            let venv = span.in_scope(|| {
+                uv_virtualenv::create_venv(
+                    temp_dir.path(),
+                    interpreter.clone(),
+                    uv_virtualenv::Prompt::None,
+                    false,
+                    uv_virtualenv::OnExisting::Remove(
+                        uv_virtualenv::RemovalReason::TemporaryEnvironment,
+                    ),
+                    false,
+                    false,
+                    false,
+                    preview,
+                )
+            })?;
+            log_step_duration("create_build_venv", package, start);
+            venv
         };
 
         // Set up the build environment. If build isolation is disabled, we assume the build
@@ -380,6 +398,12 @@ impl SourceBuild {
                 "`build-system.requires` and `extra-build-dependencies`"
             };
 
+            let start = Instant::now();
+            let span = info_span!(
+                "resolve_build_requirements",
+                version_id = version_id,
+                package = package_name.as_ref().map(PackageName::as_str),
+            );
             let resolved_requirements = Self::get_resolved_requirements(
                 build_context,
                 source_build_context,
@@ -387,12 +411,22 @@ impl SourceBuild {
                 extra_build_dependencies,
                 build_stack,
             )
+            .instrument(span)
             .await?;
-
+            log_step_duration("resolve_build_requirements", package, start);
+
+            let start = Instant::now();
+            let span = info_span!(
+                "install_build_requirements",
+                version_id = version_id,
+                package = package_name.as_ref().map(PackageName::as_str),
+            );
             build_context
                 .install(&resolved_requirements, &venv, build_stack)
+                .instrument(span)
                 .await
                 .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
+            log_step_duration("install_build_requirements", package, start);
         } else {
             debug!("Proceeding without build isolation");
         }
@@ -478,6 +512,14 @@ impl SourceBuild {
         })
     }
 
+    /// The tag used to attribute build backend output to this build.
+    fn output_tag(&self) -> Option<&str> {
+        self.package_name
+            .as_ref()
+            .map(PackageName::as_str)
+            .or(self.version_id.as_deref())
+    }
+
     /// Acquire a lock on the source tree, if necessary.
     async fn acquire_lock(&self) -> Result<Option<LockedFile>, Error> {
         // Depending on the command, setuptools puts `*.egg-info`, `build/`, and `dist/` in the
@@ -751,19 +793,23 @@ impl SourceBuild {
             self.config_settings.escape_for_python(),
             outfile.escape_for_python(),
         };
+        let hook = format!("prepare_metadata_for_build_{}", self.build_kind);
         let span = info_span!(
             "run_python_script",
-            script = format!("prepare_metadata_for_build_{}", self.build_kind),
+            script = hook.as_str(),
             version_id = self.version_id,
+            package = self.package_name.as_ref().map(PackageName::as_str),
         );
         let output = self
             .runner
             .run_script(
+                &hook,
                 &self.venv,
                 &script,
                 &self.source_tree,
                 &self.environment_variables,
                 &self.modified_path,
+                self.output_tag(),
             )
             .instrument(span)
             .await?;
@@ -873,19 +919,23 @@ impl SourceBuild {
             }
         };
 
+        let hook = format!("build_{}", self.build_kind);
         let span = info_span!(
             "run_python_script",
-            script = format!("build_{}", self.build_kind),
+            script = hook.as_str(),
             version_id = self.version_id,
+            package = self.package_name.as_ref().map(PackageName::as_str),
         );
         let output = self
             .runner
             .run_script(
+                &hook,
                 &self.venv,
                 &script,
                 &self.source_tree,
                 &self.environment_variables,
                 &self.modified_path,
+                self.output_tag(),
             )
             .instrument(span)
             .await?;
@@ -989,18 +1039,22 @@ async fn create_pep517_build_environment(
         config_settings.escape_for_python(),
         outfile.escape_for_python()
     };
+    let hook = format!("get_requires_for_build_{build_kind}");
     let span = info_span!(
         "run_python_script",
-        script = format!("get_requires_for_build_{}", build_kind),
+        script = hook.as_str(),
         version_id = version_id,
+        package = package_name.map(PackageName::as_str),
     );
     let output = runner
         .run_script(
+            &hook,
             venv,
             &script,
             source_tree,
             environment_variables,
             modified_path,
+            package_name.map(PackageName::as_str).or(version_id),
         )
         .instrument(span)
         .await?;
@@ -1076,24 +1130,55 @@ async fn create_pep517_build_environment(
             .cloned()
             .chain(extra_requires)
             .collect();
+        let package = package_name.map(PackageName::as_str).or(version_id);
+
+        let start = Instant::now();
+        let span = info_span!(
+            "resolve_build_requirements",
+            version_id = version_id,
+            package = package_name.map(PackageName::as_str),
+        );
         let resolution = build_context
             .resolve(&requirements, build_stack)
+            .instrument(span)
             .await
             .map_err(|err| {
                 Error::RequirementsResolve("`build-system.requires`", AnyErrorBuild::from(err))
             })?;
+        log_step_duration("resolve_build_requirements", package, start);
 
+        let start = Instant::now();
+        let span = info_span!(
+            "install_build_requirements",
+            version_id = version_id,
+            package = package_name.map(PackageName::as_str),
+        );
         build_context
             .install(&resolution, venv, build_stack)
+            .instrument(span)
             .await
             .map_err(|err| {
                 Error::RequirementsInstall("`build-system.requires`", AnyErrorBuild::from(err))
             })?;
+        log_step_duration("install_build_requirements", package, start);
     }
 
     Ok(())
 }
 
+/// Log the duration of a step of a build, such as creating the build environment or invoking a
+/// build backend hook.
+fn log_step_duration(step: &str, package: Option<&str>, start: Instant) {
+    if let Some(package) = package {
+        debug!(
+            "Finished `{step}` for `{package}` in {:.2?}",
+            start.elapsed()
+        );
+    } else {
+        debug!("Finished `{step}` in {:.2?}", start.elapsed());
+    }
+}
+
 /// A runner that manages the execution of external python processes with a
 /// concurrency limit.
 #[derive(Debug)]
@@ -1126,24 +1211,27 @@ impl PythonRunner {
     /// Note: It is the caller's responsibility to create an informative span.
     async fn run_script(
         &self,
+        hook: &str,
         venv: &PythonEnvironment,
         script: &str,
         source_tree: &Path,
         environment_variables: &FxHashMap<OsString, OsString>,
         modified_path: &OsString,
+        tag: Option<&str>,
     ) -> Result<PythonRunnerOutput, Error> {
         /// Read lines from a reader and store them in a buffer.
         async fn read_from(
             mut reader: tokio::io::Split<tokio::io::BufReader<impl tokio::io::AsyncRead + Unpin>>,
-            mut printer: Printer,
+            printer: Printer,
+            tag: Option<&str>,
             buffer: &mut Vec<String>,
         ) -> io::Result<()> {
             loop {
                 match reader.next_segment().await? {
                     Some(line_buf) => {
                         let line_buf = line_buf.strip_suffix(b"\r").unwrap_or(&line_buf);
-                        let line = String::from_utf8_lossy(line_buf).into();
-                        let _ = write!(printer, "{line}");
+                        let line = String::from_utf8_lossy(line_buf).into_owned();
+                        printer.write_line(tag, &line);
                         buffer.push(line);
                     }
                     None => return Ok(()),
@@ -1152,7 +1240,9 @@ impl PythonRunner {
         }
 
         let _permit = self.control.acquire().await.unwrap();
+        let _running = RunningScript::start();
 
+        let start = Instant::now();
         let mut child = Command::new(venv.python_executable())
             .args(["-c", script])
             .current_dir(source_tree.simplified())
@@ -1185,8 +1275,8 @@ impl PythonRunner {
         // Asynchronously read from the in-memory pipes.
         let printer = Printer::from(self.level);
         let result = tokio::join!(
-            read_from(stdout_reader, printer, &mut stdout_buf),
-            read_from(stderr_reader, printer, &mut stderr_buf),
+            read_from(stdout_reader, printer, tag, &mut stdout_buf),
+            read_from(stderr_reader, printer, tag, &mut stderr_buf),
         );
         match result {
             (Ok(()), Ok(())) => {}
@@ -1203,6 +1293,7 @@ impl PythonRunner {
             .wait()
             .await
             .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
+        log_step_duration(hook, tag, start);
 
         Ok(PythonRunnerOutput {
             stdout: stdout_buf,
@@ -1212,6 +1303,22 @@ impl PythonRunner {
     }
 }
 
+/// A build backend script that's running, counted in [`RUNNING_SCRIPTS`] until dropped.
+struct RunningScript;
+
+impl RunningScript {
+    fn start() -> Self {
+        RUNNING_SCRIPTS.fetch_add(1, Ordering::Relaxed);
+        Self
+    }
+}
+
+impl Drop for RunningScript {
+    fn drop(&mut self) {
+        RUNNING_SCRIPTS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Printer {
     /// Send the build backend output to `stderr`.
@@ -1232,17 +1339,26 @@ impl From<BuildOutput> for Printer {
     }
 }
 
-impl Write for Printer {
-    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+impl Printer {
+    /// Write a single line of build backend output.
+    ///
+    /// Lines are written whole while holding the console lock, such that output from concurrent
+    /// builds is never interleaved within a line. When writing to `stderr`, lines are only tagged
+    /// with the given package name while multiple builds are running at once.
+    fn write_line(self, tag: Option<&str>, line: &str) {
         match self {
             Self::Stderr => {
-                anstream::eprintln!("{s}");
+                let tag = tag.filter(|_| RUNNING_SCRIPTS.load(Ordering::Relaxed) > 1);
+                let _ = uv_logging::write_line(&mut anstream::stderr(), tag, line);
             }
             Self::Debug => {
-                debug!("{s}");
+                if let Some(tag) = tag {
+                    debug!("[{tag}] {line}");
+                } else {
+                    debug!("{line}");
+                }
             }
             Self::Quiet => {}
         }
-        Ok(())
     }
 }
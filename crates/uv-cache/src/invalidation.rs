@@ -0,0 +1,83 @@
+//! The reasons that existing cache entries go unused, logged under the `uv_cache::invalidation`
+//! target.
+//!
+//! A refresh forced via `--refresh`, `--reinstall`, or `--upgrade` (or their per-package variants)
+//! can invalidate several entries for the same package across buckets, so forced invalidations are
+//! only logged for the first entry of each package.
+
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::sync::{LazyLock, Mutex};
+
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+
+use crate::usage::bucket;
+
+/// The packages (or, for entries without a package, the paths) with a forced invalidation that
+/// was already logged.
+static FORCED: LazyLock<Mutex<FxHashSet<String>>> = LazyLock::new(Mutex::default);
+
+/// The reason an existing cache entry was not used.
+#[derive(Debug, Clone, Copy)]
+pub enum Invalidation<'a> {
+    /// The refresh policy requires the entries of the package (or all entries) to be revalidated.
+    Refresh(Option<&'a PackageName>),
+    /// The cached HTTP response has expired according to its cache headers.
+    Expired,
+    /// The entry lacks the hashes required by the hash-checking policy.
+    MissingHashes,
+    /// The unzipped archive that the entry points to no longer exists.
+    MissingArchive,
+    /// The source of the entry was modified after the entry was created.
+    Modified,
+}
+
+impl Invalidation<'_> {
+    /// Log that the existing cache entry at `path` is not used for this reason.
+    pub fn log(self, path: &Path) {
+        if let Self::Refresh(package) = self {
+            let key =
+                package.map_or_else(|| path.to_string_lossy().to_string(), ToString::to_string);
+            if !FORCED.lock().unwrap().insert(key) {
+                return;
+            }
+        }
+        if let Some((bucket, _)) = bucket(path) {
+            debug!(
+                target: "uv_cache::invalidation",
+                bucket = bucket.to_str(),
+                "Invalidated cache entry `{}` in `{bucket}`: {self}",
+                path.user_display()
+            );
+        } else {
+            debug!(
+                target: "uv_cache::invalidation",
+                "Invalidated cache entry `{}`: {self}",
+                path.user_display()
+            );
+        }
+    }
+}
+
+impl Display for Invalidation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Refresh(Some(package)) => write!(
+                f,
+                "refresh forced for `{package}` (via `--refresh`, `--reinstall`, or `--upgrade`)"
+            ),
+            Self::Refresh(None) => write!(
+                f,
+                "refresh forced (via `--refresh`, `--reinstall`, or `--upgrade`)"
+            ),
+            Self::Expired => write!(f, "the cached response expired per its HTTP cache headers"),
+            Self::MissingHashes => write!(f, "missing the required hashes"),
+            Self::MissingArchive => write!(f, "the unzipped archive was removed"),
+            Self::Modified => write!(f, "the source was modified since it was cached"),
+        }
+    }
+}
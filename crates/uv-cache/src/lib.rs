@@ -17,8 +17,10 @@ use uv_pypi_types::ResolutionMetadata;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::invalidation::Invalidation;
 use crate::removal::Remover;
 pub use crate::removal::{Removal, rm_rf};
+pub use crate::usage::CacheUsage;
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 pub use archive::ArchiveId;
@@ -27,7 +29,9 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod invalidation;
 mod removal;
+mod usage;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -226,7 +230,8 @@ impl Cache {
             LockedFileMode::Exclusive,
             root.simplified_display(),
         )
-        .await?;
+        .await?
+        .with_holder();
 
         Ok(Self {
             root,
@@ -256,7 +261,7 @@ impl Cache {
                 root,
                 refresh,
                 temp_dir,
-                lock_file: Some(Arc::new(lock_file)),
+                lock_file: Some(Arc::new(lock_file.with_holder())),
             }),
             None => Err(Self {
                 root,
@@ -337,7 +342,8 @@ impl Cache {
     /// Returns the [`Freshness`] for a cache entry, validating it against the [`Refresh`] policy.
     ///
     /// A cache entry is considered fresh if it was created after the cache itself was
-    /// initialized, or if the [`Refresh`] policy does not require revalidation.
+    /// initialized, or if the [`Refresh`] policy does not require revalidation. Stale entries are
+    /// logged as an [`Invalidation::Refresh`].
     pub fn freshness(
         &self,
         entry: &CacheEntry,
@@ -368,6 +374,7 @@ impl Cache {
                 if Timestamp::from_metadata(&metadata) >= *timestamp {
                     Ok(Freshness::Fresh)
                 } else {
+                    Invalidation::Refresh(package).log(entry.path());
                     Ok(Freshness::Stale)
                 }
             }
@@ -394,6 +401,7 @@ impl Cache {
         // Create a symlink to the directory store.
         fs_err::create_dir_all(path.as_ref().parent().expect("Cache entry to have parent"))?;
         self.create_link(&id, path.as_ref())?;
+        CacheUsage::write_dir(archive_entry.path());
 
         Ok(id)
     }
@@ -579,6 +587,7 @@ impl Cache {
                 || entry.file_name() == ".gitignore"
                 || entry.file_name() == ".git"
                 || entry.file_name() == ".lock"
+                || entry.file_name() == ".lock.holder"
             {
                 continue;
             }
@@ -802,8 +811,9 @@ impl Cache {
         }
 
         // Reconstruct the path.
-        let path = self.archive(&link.id);
-        path.canonicalize()
+        let path = self.archive(&link.id).canonicalize()?;
+        CacheUsage::read(&path);
+        Ok(path)
     }
 
     /// Create a link to a directory in the archive bucket.
@@ -839,7 +849,9 @@ impl Cache {
     /// Returns an error if the link target does not exist.
     #[cfg(unix)]
     pub fn resolve_link(&self, path: impl AsRef<Path>) -> io::Result<PathBuf> {
-        path.as_ref().canonicalize()
+        let path = path.as_ref().canonicalize()?;
+        CacheUsage::read(&path);
+        Ok(path)
     }
 }
 
@@ -0,0 +1,146 @@
+//! Counters of the cache entries read and written by a command, logged per bucket under the
+//! `uv_cache::usage` target once the command completes.
+//!
+//! The counters are process-wide, such that they can be incremented at the read and write sites
+//! without threading a handle through every layer that touches the cache.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+
+use crate::CacheBucket;
+
+/// The usage of each bucket, keyed by the name of the bucket.
+static USAGE: Mutex<BTreeMap<&'static str, BucketUsage>> = Mutex::new(BTreeMap::new());
+
+/// The usage of a single cache bucket.
+#[derive(Debug, Default)]
+struct BucketUsage {
+    /// The path to the bucket.
+    path: PathBuf,
+    /// The number of entries read from the bucket.
+    reads: u64,
+    /// The number of entries written to the bucket.
+    entries: u64,
+    /// The number of bytes written to the bucket.
+    bytes: u64,
+}
+
+/// The usage of the cache by the current command.
+///
+/// The usage is logged when the value returned by [`CacheUsage::track`] is dropped, i.e., once the
+/// command completes (or fails).
+#[derive(Debug)]
+pub struct CacheUsage(());
+
+impl CacheUsage {
+    /// Track the usage of the cache until the returned value is dropped.
+    #[must_use]
+    pub fn track() -> Self {
+        Self(())
+    }
+
+    /// Returns `true` if the usage of the cache is logged.
+    ///
+    /// Used to skip computing the size of entries that aren't known upfront, like directories.
+    pub fn enabled() -> bool {
+        tracing::enabled!(target: "uv_cache::usage", tracing::Level::DEBUG)
+    }
+
+    /// Record that the cache entry at `path` was read.
+    pub fn read(path: &Path) {
+        Self::update(path, |usage| usage.reads += 1);
+    }
+
+    /// Record that a cache entry of `bytes` bytes was written to `path`.
+    pub fn write(path: &Path, bytes: u64) {
+        Self::update(path, |usage| {
+            usage.entries += 1;
+            usage.bytes += bytes;
+        });
+    }
+
+    /// Record that the directory at `path` was written to the cache.
+    pub fn write_dir(path: &Path) {
+        if !Self::enabled() {
+            return;
+        }
+        let bytes = WalkDir::new(path)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter_map(|entry| entry.metadata().ok())
+            .filter(std::fs::Metadata::is_file)
+            .map(|metadata| metadata.len())
+            .sum();
+        Self::write(path, bytes);
+    }
+
+    fn update(path: &Path, f: impl FnOnce(&mut BucketUsage)) {
+        // Avoid locking the counters and searching for the bucket on every cache access unless
+        // the usage is logged.
+        if !Self::enabled() {
+            return;
+        }
+        let Some((bucket, bucket_path)) = bucket(path) else {
+            return;
+        };
+        let mut usage = USAGE.lock().unwrap_or_else(PoisonError::into_inner);
+        let usage = usage.entry(bucket.to_str()).or_default();
+        if usage.path.as_os_str().is_empty() {
+            usage.path = bucket_path.to_path_buf();
+        }
+        f(usage);
+    }
+}
+
+impl Drop for CacheUsage {
+    fn drop(&mut self) {
+        let usage = std::mem::take(&mut *USAGE.lock().unwrap_or_else(PoisonError::into_inner));
+        for (bucket, usage) in usage {
+            debug!(
+                target: "uv_cache::usage",
+                bucket,
+                reads = usage.reads,
+                entries = usage.entries,
+                bytes = usage.bytes,
+                "Used cache bucket `{}`",
+                usage.path.user_display()
+            );
+        }
+    }
+}
+
+/// Determine the bucket that contains `path`, along with the path to the bucket.
+pub(crate) fn bucket(path: &Path) -> Option<(CacheBucket, &Path)> {
+    path.ancestors().find_map(|ancestor| {
+        let name = ancestor.file_name()?;
+        let bucket = CacheBucket::iter().find(|bucket| name == bucket.to_str())?;
+        Some((bucket, ancestor))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::bucket;
+    use crate::CacheBucket;
+
+    #[test]
+    fn bucket_from_path() {
+        let root = Path::new("/home/ferris/.cache/uv");
+        let path = root
+            .join(CacheBucket::Wheels.to_str())
+            .join("pypi/iniconfig/2.0.0.msgpack");
+        let (found, found_path) = bucket(&path).unwrap();
+        assert_eq!(found, CacheBucket::Wheels);
+        assert_eq!(found_path, root.join(CacheBucket::Wheels.to_str()));
+
+        assert!(bucket(&root.join("unrelated/file")).is_none());
+    }
+}
@@ -1,13 +1,15 @@
 use std::ffi::OsString;
 use std::fmt::{self, Display, Formatter};
 use std::ops::{Deref, DerefMut};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{Result, anyhow};
 use clap::builder::styling::{AnsiColor, Effects, Style};
 use clap::builder::{PossibleValue, Styles, TypedValueParser, ValueParserFactory};
-use clap::error::ErrorKind;
+use clap::error::{ContextKind, ContextValue, ErrorKind};
+use clap::parser::ValueSource;
 use clap::{Args, Parser, Subcommand};
 use clap::{ValueEnum, ValueHint};
 
@@ -18,8 +20,8 @@ use uv_configuration::{
     ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
 };
 use uv_distribution_types::{
-    ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
-    PipFindLinks, PipIndex,
+    ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PackageIndexEntry,
+    PipExtraIndex, PipFindLinks, PipIndex,
 };
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, Requirement};
@@ -28,10 +30,10 @@ use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
-    AnnotationStyle, ExcludeNewerPackageEntry, ExcludeNewerValue, ForkStrategy, PrereleaseMode,
-    ResolutionMode,
+    AnnotationStyle, ExcludeNewerPackageEntry, ExcludeNewerValue, ForkStrategy,
+    IndexConflictStrategy, PrereleaseMode, ResolutionMode,
 };
-use uv_settings::PythonInstallMirrors;
+use uv_settings::{PythonInstallMirrors, SourcedIndexUrl};
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
 use uv_workspace::pyproject_mut::AddBoundsKind;
@@ -323,6 +325,118 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true)]
     pub show_settings: bool,
 
+    /// Write span durations to the given JSONL file [env: TRACING_DURATIONS_FILE=]
+    ///
+    /// A JSON summary of the durations of each span is written alongside it, with a
+    /// `.summary.json` extension, along with the formats selected by `--durations-format` (by
+    /// default, an SVG plot of uv's internal spans, with an `.svg` extension).
+    ///
+    /// This option is used for debugging and development purposes.
+    #[cfg(feature = "tracing-durations-export")]
+    #[expect(clippy::doc_markdown)]
+    #[arg(global = true, long, value_hint = ValueHint::FilePath)]
+    pub durations_file: Option<PathBuf>,
+
+    /// Write span durations to a generated location in the cache directory.
+    ///
+    /// The files are written to the `durations` directory of the cache, named after the time of
+    /// the run and the command, e.g., `20250101T120000123-lock.jsonl`. Only the files of the most
+    /// recent runs are retained.
+    ///
+    /// An explicit location, via `--durations-file` or `TRACING_DURATIONS_FILE`, takes
+    /// precedence.
+    #[cfg(feature = "tracing-durations-export")]
+    #[arg(global = true, long)]
+    pub durations: bool,
+
+    /// Omit spans with the given names from the durations plot, replacing the default set [env:
+    /// UV_DURATIONS_REMOVE=]
+    ///
+    /// Accepts a comma-separated list of span names.
+    #[cfg(feature = "tracing-durations-export")]
+    #[expect(clippy::doc_markdown)]
+    #[arg(
+        global = true,
+        long,
+        value_delimiter = ',',
+        conflicts_with = "durations_keep_all"
+    )]
+    pub durations_remove: Option<Vec<String>>,
+
+    /// Omit spans with the given names from the durations plot, in addition to the default set
+    /// [env: UV_DURATIONS_REMOVE_EXTRA=]
+    ///
+    /// Accepts a comma-separated list of span names.
+    #[cfg(feature = "tracing-durations-export")]
+    #[expect(clippy::doc_markdown)]
+    #[arg(
+        global = true,
+        long,
+        value_delimiter = ',',
+        conflicts_with = "durations_keep_all"
+    )]
+    pub durations_remove_extra: Option<Vec<String>>,
+
+    /// Include all spans in the durations plot, including those omitted by default.
+    #[cfg(feature = "tracing-durations-export")]
+    #[arg(global = true, long)]
+    pub durations_keep_all: bool,
+
+    /// The formats in which to write the span durations, in addition to the JSONL file [env:
+    /// UV_DURATIONS_FORMAT=]
+    ///
+    /// Accepts a comma-separated list. Defaults to `svg`.
+    #[cfg(feature = "tracing-durations-export")]
+    #[expect(clippy::doc_markdown)]
+    #[arg(global = true, long, value_delimiter = ',')]
+    pub durations_format: Option<Vec<DurationsFormat>>,
+
+    /// Include the span fields in the frame names of folded stacks, e.g., `build_sdist(numpy)`
+    /// [env: UV_DURATIONS_FOLD_FIELDS=]
+    #[cfg(feature = "tracing-durations-export")]
+    #[expect(clippy::doc_markdown)]
+    #[arg(global = true, long)]
+    pub durations_fold_fields: bool,
+
+    /// Log each recorded span as it closes, along with its fields and duration [env:
+    /// UV_DURATIONS_LOG=]
+    ///
+    /// The messages are logged at the `DEBUG` level with the `uv::durations` target, such that
+    /// they can be correlated with the rest of the log output, e.g., via `RUST_LOG`.
+    #[cfg(feature = "tracing-durations-export")]
+    #[expect(clippy::doc_markdown)]
+    #[arg(global = true, long)]
+    pub durations_log: bool,
+
+    /// Print a summary of the time spent in each phase of the command.
+    ///
+    /// The summary is printed to stderr once the command completes, even when `--quiet` is used.
+    ///
+    /// With `--timings=packages`, the summary is followed by the packages that took the longest to
+    /// download, build and install.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "phases",
+        value_name = "MODE"
+    )]
+    pub timings: Option<TimingsMode>,
+
+    /// Warn when an operation has been running for longer than the given duration, e.g., `30s`.
+    ///
+    /// The warning names the operation (e.g., the URL being fetched or the package being built)
+    /// and is repeated each time the operation exceeds a further multiple of the threshold.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_SLOW_OP_THRESHOLD,
+        value_parser = parse_slow_op_threshold,
+    )]
+    pub slow_op_threshold: Option<Duration>,
+
     /// Hide all progress outputs [env: UV_NO_PROGRESS=]
     ///
     /// For example, spinners or progress bars.
@@ -358,6 +472,34 @@ pub struct GlobalArgs {
     pub project: Option<PathBuf>,
 }
 
+#[cfg(feature = "tracing-durations-export")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum DurationsFormat {
+    /// Render an SVG plot of the spans, with an `.svg` extension.
+    Svg,
+
+    /// Write folded stacks, as consumed by `inferno` and `flamegraph.pl`, with a `.folded`
+    /// extension.
+    Folded,
+
+    /// Stream a Perfetto protobuf trace, as loaded by <https://ui.perfetto.dev>, with a `.pftrace`
+    /// extension.
+    Perfetto,
+
+    /// Write the spans as OpenTelemetry spans in the OTLP JSON encoding, as imported by tracing
+    /// backends, with an `.otlp.json` extension.
+    Otlp,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum TimingsMode {
+    /// Print the time spent in each phase of the command.
+    Phases,
+
+    /// Additionally print the time spent downloading, building and installing each package.
+    Packages,
+}
+
 #[derive(Debug, Copy, Clone, clap::ValueEnum)]
 pub enum ColorChoice {
     /// Enables colored output only when the output is going to a terminal or TTY with support.
@@ -565,6 +707,109 @@ pub enum Commands {
     Help(HelpArgs),
 }
 
+impl Commands {
+    /// Return the name of the subcommand as invoked on the command-line (e.g., `lock` or `pip`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Auth(_) => "auth",
+            Self::Project(command) => match **command {
+                ProjectCommand::Run(_) => "run",
+                ProjectCommand::Init(_) => "init",
+                ProjectCommand::Add(_) => "add",
+                ProjectCommand::Remove(_) => "remove",
+                ProjectCommand::Version(_) => "version",
+                ProjectCommand::Sync(_) => "sync",
+                ProjectCommand::Lock(_) => "lock",
+                ProjectCommand::Export(_) => "export",
+                ProjectCommand::Tree(_) => "tree",
+                ProjectCommand::Format(_) => "format",
+            },
+            Self::Tool(_) => "tool",
+            Self::Python(_) => "python",
+            Self::Pip(_) => "pip",
+            Self::Venv(_) => "venv",
+            Self::Build(_) => "build",
+            Self::Publish(_) => "publish",
+            Self::Workspace(_) => "workspace",
+            Self::BuildBackend { .. } => "build-backend",
+            Self::Cache(_) => "cache",
+            Self::Self_(_) => "self",
+            Self::Clean(_) => "clean",
+            Self::GenerateShellCompletion(_) => "generate-shell-completion",
+            Self::Help(_) => "help",
+        }
+    }
+
+    /// Return the index URLs passed to the subcommand, along with the flag or environment variable
+    /// that provided each of them.
+    ///
+    /// Returns `None` if the subcommand doesn't accept index options.
+    pub fn index_urls(&self) -> Option<Vec<SourcedIndexUrl>> {
+        let index_args = match self {
+            Self::Project(command) => match &**command {
+                ProjectCommand::Run(args) => &args.installer.index_args,
+                ProjectCommand::Add(args) => &args.installer.index_args,
+                ProjectCommand::Remove(args) => &args.installer.index_args,
+                ProjectCommand::Version(args) => &args.installer.index_args,
+                ProjectCommand::Sync(args) => &args.installer.index_args,
+                ProjectCommand::Lock(args) => &args.resolver.index_args,
+                ProjectCommand::Export(args) => &args.resolver.index_args,
+                ProjectCommand::Tree(args) => &args.resolver.index_args,
+                ProjectCommand::Init(_) | ProjectCommand::Format(_) => return None,
+            },
+            Self::Tool(ToolNamespace { command }) => match command {
+                ToolCommand::Run(args) | ToolCommand::Uvx(UvxArgs { tool_run: args, .. }) => {
+                    &args.installer.index_args
+                }
+                ToolCommand::Install(args) => &args.installer.index_args,
+                ToolCommand::Upgrade(args) => &args.index_args,
+                _ => return None,
+            },
+            Self::Pip(PipNamespace { command }) => match command {
+                PipCommand::Compile(args) => &args.resolver.index_args,
+                PipCommand::Sync(args) => &args.installer.index_args,
+                PipCommand::Install(args) => {
+                    let mut urls = args.installer.index_args.index_urls();
+                    urls.extend(args.package_index.iter().map(|entry| {
+                        SourcedIndexUrl::new(
+                            entry.index.clone(),
+                            Origin::Flag("package-index".to_string()),
+                        )
+                    }));
+                    return Some(urls);
+                }
+                PipCommand::List(args) => &args.fetch.index_args,
+                PipCommand::Tree(args) => &args.fetch.index_args,
+                _ => return None,
+            },
+            Self::Venv(args) => &args.index_args,
+            Self::Build(args) => &args.resolver.index_args,
+            _ => return None,
+        };
+        Some(index_args.index_urls())
+    }
+
+    /// Return the path to write the state of each fork to once the resolution completes, if the
+    /// subcommand resolves dependencies and `--resolver-dump` was provided.
+    pub fn resolver_dump(&self) -> Option<&Path> {
+        let resolver_dump = match self {
+            Self::Project(command) => match &**command {
+                ProjectCommand::Lock(args) => &args.resolver_dump,
+                ProjectCommand::Sync(args) => &args.resolver_dump,
+                _ => return None,
+            },
+            Self::Pip(PipNamespace { command }) => match command {
+                PipCommand::Compile(args) => &args.resolver_dump,
+                PipCommand::Sync(args) => &args.resolver_dump,
+                PipCommand::Install(args) => &args.resolver_dump,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        resolver_dump.as_deref()
+    }
+}
+
 #[derive(Args, Debug)]
 pub struct HelpArgs {
     /// Disable pager when printing help
@@ -1203,15 +1448,73 @@ impl<T> Maybe<T> {
     }
 }
 
+/// A value parser for an index argument, which records whether each index was provided via the
+/// flag itself or via its environment variable.
+///
+/// If a value read from the environment variable is invalid, the error names the variable.
+#[derive(Clone)]
+struct IndexValueParser<T>(fn(&str, &Origin) -> Result<T, String>);
+
+impl<T: Clone + Send + Sync + 'static> TypedValueParser for IndexValueParser<T> {
+    type Value = T;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        self.parse_ref_(cmd, arg, value, ValueSource::CommandLine)
+    }
+
+    fn parse_ref_(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+        source: ValueSource,
+    ) -> Result<Self::Value, clap::Error> {
+        let env = arg
+            .and_then(clap::Arg::get_env)
+            .filter(|_| source == ValueSource::EnvVariable)
+            .map(|env| env.to_string_lossy().into_owned());
+        let origin = if let Some(env) = &env {
+            Origin::EnvVar(env.clone())
+        } else {
+            Origin::Flag(
+                arg.and_then(clap::Arg::get_long)
+                    .unwrap_or_default()
+                    .to_string(),
+            )
+        };
+        let parse = self.0;
+        (move |input: &str| parse(input, &origin))
+            .parse_ref(cmd, arg, value)
+            .map_err(|mut err| {
+                if let Some(env) = env {
+                    let tip = format!(
+                        "`{}` was read from the `{env}` environment variable",
+                        value.to_string_lossy()
+                    );
+                    err.insert(
+                        ContextKind::Suggested,
+                        ContextValue::StyledStrs(vec![tip.into()]),
+                    );
+                }
+                err
+            })
+    }
+}
+
 /// Parse an `--index-url` argument into an [`PipIndex`], mapping the empty string to `None`.
-fn parse_index_url(input: &str) -> Result<Maybe<PipIndex>, String> {
+fn parse_index_url(input: &str, origin: &Origin) -> Result<Maybe<PipIndex>, String> {
     if input.is_empty() {
         Ok(Maybe::None)
     } else {
         IndexUrl::from_str(input)
             .map(Index::from_index_url)
             .map(|index| Index {
-                origin: Some(Origin::Cli),
+                origin: Some(origin.clone()),
                 ..index
             })
             .map(PipIndex::from)
@@ -1221,14 +1524,14 @@ fn parse_index_url(input: &str) -> Result<Maybe<PipIndex>, String> {
 }
 
 /// Parse an `--extra-index-url` argument into an [`PipExtraIndex`], mapping the empty string to `None`.
-fn parse_extra_index_url(input: &str) -> Result<Maybe<PipExtraIndex>, String> {
+fn parse_extra_index_url(input: &str, origin: &Origin) -> Result<Maybe<PipExtraIndex>, String> {
     if input.is_empty() {
         Ok(Maybe::None)
     } else {
         IndexUrl::from_str(input)
             .map(Index::from_extra_index_url)
             .map(|index| Index {
-                origin: Some(Origin::Cli),
+                origin: Some(origin.clone()),
                 ..index
             })
             .map(PipExtraIndex::from)
@@ -1238,14 +1541,14 @@ fn parse_extra_index_url(input: &str) -> Result<Maybe<PipExtraIndex>, String> {
 }
 
 /// Parse a `--find-links` argument into an [`PipFindLinks`], mapping the empty string to `None`.
-fn parse_find_links(input: &str) -> Result<Maybe<PipFindLinks>, String> {
+fn parse_find_links(input: &str, origin: &Origin) -> Result<Maybe<PipFindLinks>, String> {
     if input.is_empty() {
         Ok(Maybe::None)
     } else {
         IndexUrl::from_str(input)
             .map(Index::from_find_links)
             .map(|index| Index {
-                origin: Some(Origin::Cli),
+                origin: Some(origin.clone()),
                 ..index
             })
             .map(PipFindLinks::from)
@@ -1259,7 +1562,7 @@ fn parse_find_links(input: &str) -> Result<Maybe<PipFindLinks>, String> {
 /// This function splits the input on all whitespace characters rather than a single delimiter,
 /// which is necessary to parse environment variables like `PIP_EXTRA_INDEX_URL`.
 /// The standard `clap::Args` `value_delimiter` only supports single-character delimiters.
-fn parse_indices(input: &str) -> Result<Vec<Maybe<Index>>, String> {
+fn parse_indices(input: &str, origin: &Origin) -> Result<Vec<Maybe<Index>>, String> {
     if input.trim().is_empty() {
         return Ok(Vec::new());
     }
@@ -1268,7 +1571,7 @@ fn parse_indices(input: &str) -> Result<Vec<Maybe<Index>>, String> {
         match Index::from_str(token) {
             Ok(index) => indices.push(Maybe::Some(Index {
                 default: false,
-                origin: Some(Origin::Cli),
+                origin: Some(origin.clone()),
                 ..index
             })),
             Err(e) => return Err(e.to_string()),
@@ -1278,14 +1581,14 @@ fn parse_indices(input: &str) -> Result<Vec<Maybe<Index>>, String> {
 }
 
 /// Parse a `--default-index` argument into an [`Index`], mapping the empty string to `None`.
-fn parse_default_index(input: &str) -> Result<Maybe<Index>, String> {
+fn parse_default_index(input: &str, origin: &Origin) -> Result<Maybe<Index>, String> {
     if input.is_empty() {
         Ok(Maybe::None)
     } else {
         match Index::from_str(input) {
             Ok(index) => Ok(Maybe::Some(Index {
                 default: true,
-                origin: Some(Origin::Cli),
+                origin: Some(origin.clone()),
                 ..index
             })),
             Err(err) => Err(err.to_string()),
@@ -1329,6 +1632,15 @@ fn parse_maybe_file_path(input: &str) -> Result<Maybe<PathBuf>, String> {
     }
 }
 
+/// Parse a positive duration, e.g., `30s` or `1m 30s`.
+fn parse_slow_op_threshold(input: &str) -> Result<Duration, String> {
+    let duration = jiff::SignedDuration::from_str(input).map_err(|err| err.to_string())?;
+    match Duration::try_from(duration) {
+        Ok(duration) if !duration.is_zero() => Ok(duration),
+        _ => Err(format!("expected a positive duration, got `{input}`")),
+    }
+}
+
 // Parse a string, mapping the empty string to `None`.
 #[expect(clippy::unnecessary_wraps)]
 fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
@@ -1717,6 +2029,15 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("emit_index_annotation"), hide = true)]
     pub no_emit_index_annotation: bool,
 
+    /// Include comment annotations indicating the index used to resolve each package that wasn't
+    /// served by the default index, or that was pinned to an index (e.g., `# index: internal
+    /// (https://pypi.internal/simple)`).
+    #[arg(long, overrides_with("no_annotate_index"))]
+    pub annotate_index: bool,
+
+    #[arg(long, overrides_with("annotate_index"), hide = true)]
+    pub no_annotate_index: bool,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -1732,6 +2053,15 @@ pub struct PipCompileArgs {
     #[arg(long, value_enum, env = EnvVars::UV_TORCH_BACKEND)]
     pub torch_backend: Option<TorchMode>,
 
+    /// Write the state of each fork that was solved during the resolution to the given path as
+    /// JSON, for debugging.
+    ///
+    /// The state is written whether the resolution succeeded or failed. Each fork includes its
+    /// markers, the number of packages in its solution, whether it failed, and the indexes that
+    /// packages were pinned to. Credentials are omitted from index URLs.
+    #[arg(long, env = EnvVars::UV_RESOLVER_DUMP, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub resolver_dump: Option<PathBuf>,
+
     #[command(flatten)]
     pub compat_args: compat::PipCompileCompatArgs,
 }
@@ -2049,6 +2379,15 @@ pub struct PipSyncArgs {
 
     #[command(flatten)]
     pub compat_args: compat::PipSyncCompatArgs,
+
+    /// Write the state of each fork that was solved during the resolution to the given path as
+    /// JSON, for debugging.
+    ///
+    /// The state is written whether the resolution succeeded or failed. Each fork includes its
+    /// markers, the number of packages in its solution, whether it failed, and the indexes that
+    /// packages were pinned to. Credentials are omitted from index URLs.
+    #[arg(long, env = EnvVars::UV_RESOLVER_DUMP, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub resolver_dump: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -2429,8 +2768,27 @@ pub struct PipInstallArgs {
     #[arg(long, value_enum, env = EnvVars::UV_TORCH_BACKEND)]
     pub torch_backend: Option<TorchMode>,
 
+    /// Pin a package to an index, specified as a `PACKAGE=URL` pair.
+    ///
+    /// The package is only installed from the given index, as if it were pinned to the index via
+    /// `tool.uv.sources`. If a requirement pins the package to a different index, the resolution
+    /// fails.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE=URL", help_heading = "Index options")]
+    pub package_index: Vec<PackageIndexEntry>,
+
     #[command(flatten)]
     pub compat_args: compat::PipInstallCompatArgs,
+
+    /// Write the state of each fork that was solved during the resolution to the given path as
+    /// JSON, for debugging.
+    ///
+    /// The state is written whether the resolution succeeded or failed. Each fork includes its
+    /// markers, the number of packages in its solution, whether it failed, and the indexes that
+    /// packages were pinned to. Credentials are omitted from index URLs.
+    #[arg(long, env = EnvVars::UV_RESOLVER_DUMP, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub resolver_dump: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -4105,6 +4463,15 @@ pub struct SyncArgs {
 
     #[arg(long, overrides_with("check"), hide = true)]
     pub no_check: bool,
+
+    /// Write the state of each fork that was solved during the resolution to the given path as
+    /// JSON, for debugging.
+    ///
+    /// The state is written whether the resolution succeeded or failed. Each fork includes its
+    /// markers, the number of packages in its solution, whether it failed, and the indexes that
+    /// packages were pinned to. Credentials are omitted from index URLs.
+    #[arg(long, env = EnvVars::UV_RESOLVER_DUMP, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub resolver_dump: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -4180,6 +4547,15 @@ pub struct LockArgs {
         value_hint = ValueHint::Other,
     )]
     pub python: Option<Maybe<String>>,
+
+    /// Write the state of each fork that was solved during the resolution to the given path as
+    /// JSON, for debugging.
+    ///
+    /// The state is written whether the resolution succeeded or failed. Each fork includes its
+    /// markers, the number of packages in its solution, whether it failed, and the indexes that
+    /// packages were pinned to. Credentials are omitted from index URLs.
+    #[arg(long, env = EnvVars::UV_RESOLVER_DUMP, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub resolver_dump: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -5806,6 +6182,29 @@ pub struct ToolUpgradeArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The strategy to use when a package is pinned to different indexes for the same versions,
+    /// e.g., by requirements with overlapping markers.
+    ///
+    /// By default, uv will fail the resolution (`error`).
+    ///
+    /// Under `prefer-first`, uv will use the index that was defined first, and warn about the
+    /// index that was ignored.
+    ///
+    /// Under `fallback`, uv will use the other index if the index that the package was pinned to
+    /// doesn't offer any of the required versions, and warn about the fallback; otherwise, uv will
+    /// fail the resolution.
+    ///
+    /// Under `warn`, uv will use the index that the package was pinned to first, and warn about the
+    /// index that was ignored. Since that depends on the order in which requirements are resolved,
+    /// the lockfile may change when unrelated requirements change.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_INDEX_CONFLICT_STRATEGY,
+        help_heading = "Resolver options"
+    )]
+    pub index_conflict_strategy: Option<IndexConflictStrategy>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -6632,7 +7031,7 @@ pub struct IndexArgs {
     // The nested Vec structure (`Vec<Vec<Maybe<Index>>>`) is required for clap's
     // value parsing mechanism, which processes one value at a time, in order to handle
     // `UV_INDEX` the same way pip handles `PIP_EXTRA_INDEX_URL`.
-    #[arg(long, env = EnvVars::UV_INDEX, value_parser = parse_indices, help_heading = "Index options")]
+    #[arg(long, env = EnvVars::UV_INDEX, value_parser = IndexValueParser(parse_indices), help_heading = "Index options")]
     pub index: Option<Vec<Vec<Maybe<Index>>>>,
 
     /// The URL of the default package index (by default: <https://pypi.org/simple>).
@@ -6642,7 +7041,7 @@ pub struct IndexArgs {
     ///
     /// The index given by this flag is given lower priority than all other indexes specified via
     /// the `--index` flag.
-    #[arg(long, env = EnvVars::UV_DEFAULT_INDEX, value_parser = parse_default_index, help_heading = "Index options")]
+    #[arg(long, env = EnvVars::UV_DEFAULT_INDEX, value_parser = IndexValueParser(parse_default_index), help_heading = "Index options")]
     pub default_index: Option<Maybe<Index>>,
 
     /// (Deprecated: use `--default-index` instead) The URL of the Python package index (by default:
@@ -6653,7 +7052,7 @@ pub struct IndexArgs {
     ///
     /// The index given by this flag is given lower priority than all other indexes specified via
     /// the `--extra-index-url` flag.
-    #[arg(long, short, env = EnvVars::UV_INDEX_URL, value_parser = parse_index_url, help_heading = "Index options")]
+    #[arg(long, short, env = EnvVars::UV_INDEX_URL, value_parser = IndexValueParser(parse_index_url), help_heading = "Index options")]
     pub index_url: Option<Maybe<PipIndex>>,
 
     /// (Deprecated: use `--index` instead) Extra URLs of package indexes to use, in addition to
@@ -6665,7 +7064,7 @@ pub struct IndexArgs {
     /// All indexes provided via this flag take priority over the index specified by `--index-url`
     /// (which defaults to PyPI). When multiple `--extra-index-url` flags are provided, earlier
     /// values take priority.
-    #[arg(long, env = EnvVars::UV_EXTRA_INDEX_URL, value_delimiter = ' ', value_parser = parse_extra_index_url, help_heading = "Index options")]
+    #[arg(long, env = EnvVars::UV_EXTRA_INDEX_URL, value_delimiter = ' ', value_parser = IndexValueParser(parse_extra_index_url), help_heading = "Index options")]
     pub extra_index_url: Option<Vec<Maybe<PipExtraIndex>>>,
 
     /// Locations to search for candidate distributions, in addition to those found in the registry
@@ -6681,7 +7080,7 @@ pub struct IndexArgs {
         short,
         env = EnvVars::UV_FIND_LINKS,
         value_delimiter = ',',
-        value_parser = parse_find_links,
+        value_parser = IndexValueParser(parse_find_links),
         help_heading = "Index options"
     )]
     pub find_links: Option<Vec<Maybe<PipFindLinks>>>,
@@ -6692,6 +7091,47 @@ pub struct IndexArgs {
     pub no_index: bool,
 }
 
+impl IndexArgs {
+    /// Return the index URLs provided via these arguments, along with the flag or environment
+    /// variable that provided each of them, as recorded by the value parser.
+    pub fn index_urls(&self) -> Vec<SourcedIndexUrl> {
+        let index = self
+            .index
+            .iter()
+            .flatten()
+            .flatten()
+            .filter_map(|index| index.clone().into_option());
+        let default_index = self
+            .default_index
+            .iter()
+            .filter_map(|index| index.clone().into_option());
+        let index_url = self
+            .index_url
+            .iter()
+            .filter_map(|index| index.clone().into_option())
+            .map(Index::from);
+        let extra_index_url = self
+            .extra_index_url
+            .iter()
+            .flatten()
+            .filter_map(|index| index.clone().into_option())
+            .map(Index::from);
+        let find_links = self
+            .find_links
+            .iter()
+            .flatten()
+            .filter_map(|index| index.clone().into_option())
+            .map(Index::from);
+        index
+            .chain(default_index)
+            .chain(index_url)
+            .chain(extra_index_url)
+            .chain(find_links)
+            .filter_map(|index| Some(SourcedIndexUrl::new(index.url, index.origin?)))
+            .collect()
+    }
+}
+
 #[derive(Args)]
 pub struct RefreshArgs {
     /// Refresh all cached data.
@@ -7066,6 +7506,29 @@ pub struct ResolverArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The strategy to use when a package is pinned to different indexes for the same versions,
+    /// e.g., by requirements with overlapping markers.
+    ///
+    /// By default, uv will fail the resolution (`error`).
+    ///
+    /// Under `prefer-first`, uv will use the index that was defined first, and warn about the
+    /// index that was ignored.
+    ///
+    /// Under `fallback`, uv will use the other index if the index that the package was pinned to
+    /// doesn't offer any of the required versions, and warn about the fallback; otherwise, uv will
+    /// fail the resolution.
+    ///
+    /// Under `warn`, uv will use the index that the package was pinned to first, and warn about the
+    /// index that was ignored. Since that depends on the order in which requirements are resolved,
+    /// the lockfile may change when unrelated requirements change.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_INDEX_CONFLICT_STRATEGY,
+        help_heading = "Resolver options"
+    )]
+    pub index_conflict_strategy: Option<IndexConflictStrategy>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -7300,6 +7763,29 @@ pub struct ResolverInstallerArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The strategy to use when a package is pinned to different indexes for the same versions,
+    /// e.g., by requirements with overlapping markers.
+    ///
+    /// By default, uv will fail the resolution (`error`).
+    ///
+    /// Under `prefer-first`, uv will use the index that was defined first, and warn about the
+    /// index that was ignored.
+    ///
+    /// Under `fallback`, uv will use the other index if the index that the package was pinned to
+    /// doesn't offer any of the required versions, and warn about the fallback; otherwise, uv will
+    /// fail the resolution.
+    ///
+    /// Under `warn`, uv will use the index that the package was pinned to first, and warn about the
+    /// index that was ignored. Since that depends on the order in which requirements are resolved,
+    /// the lockfile may change when unrelated requirements change.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_INDEX_CONFLICT_STRATEGY,
+        help_heading = "Resolver options"
+    )]
+    pub index_conflict_strategy: Option<IndexConflictStrategy>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -208,6 +208,7 @@ impl From<ResolverArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            index_conflict_strategy,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -227,6 +228,7 @@ impl From<ResolverArgs> for PipOptions {
             keyring_provider,
             resolution,
             fork_strategy,
+            index_conflict_strategy,
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
@@ -313,6 +315,7 @@ impl From<ResolverInstallerArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            index_conflict_strategy,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -341,6 +344,7 @@ impl From<ResolverInstallerArgs> for PipOptions {
                 prerelease
             },
             fork_strategy,
+            index_conflict_strategy,
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             config_settings_package: config_settings_package.map(|config_settings| {
@@ -436,6 +440,7 @@ pub fn resolver_options(
         prerelease,
         pre,
         fork_strategy,
+        index_conflict_strategy,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -500,7 +505,9 @@ pub fn resolver_options(
             prerelease
         },
         fork_strategy,
+        index_conflict_strategy,
         dependency_metadata: None,
+        allowed_indexes: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
         config_settings_package: config_settings_package.map(|config_settings| {
@@ -548,6 +555,7 @@ pub fn resolver_installer_options(
         prerelease,
         pre,
         fork_strategy,
+        index_conflict_strategy,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -620,7 +628,9 @@ pub fn resolver_installer_options(
             prerelease
         },
         fork_strategy,
+        index_conflict_strategy,
         dependency_metadata: None,
+        allowed_indexes: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
         config_settings_package: config_settings_package.map(|config_settings| {
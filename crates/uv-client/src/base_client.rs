@@ -2,7 +2,7 @@ use std::error::Error;
 use std::fmt::Debug;
 use std::fmt::Write;
 use std::num::ParseIntError;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 use std::{env, io, iter};
@@ -20,15 +20,17 @@ use reqwest::{Client, ClientBuilder, IntoUrl, NoProxy, Proxy, Request, Response,
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{
-    RetryPolicy, RetryTransientMiddleware, Retryable, RetryableStrategy, default_on_request_error,
-    default_on_request_success,
+    RetryDecision, RetryPolicy, RetryTransientMiddleware, Retryable, RetryableStrategy,
+    default_on_request_error, default_on_request_success,
 };
 use thiserror::Error;
-use tracing::{debug, trace};
+use tracing::{debug, info, trace};
 use url::ParseError;
 use url::Url;
 
-use uv_auth::{AuthMiddleware, Credentials, CredentialsCache, Indexes, PyxTokenStore};
+use uv_auth::{
+    AuthMiddleware, CredentialSource, Credentials, CredentialsCache, Indexes, PyxTokenStore,
+};
 use uv_configuration::ProxyUrlKind;
 use uv_configuration::{KeyringProviderType, ProxyUrl, TrustedHost};
 use uv_fs::Simplified;
@@ -42,8 +44,9 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::middleware::{LoggingMiddleware, OfflineMiddleware, RetrySummaryMiddleware};
+use crate::proxy::ProxySettings;
+use crate::tls::{RootStore, log_root_store, log_skipped, read_identity};
 use crate::{Connectivity, WrappedReqwestError};
 
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -324,8 +327,14 @@ impl<'a> BaseClientBuilder<'a> {
     }
 
     /// See [`CredentialsCache::store_credentials`].
-    pub fn store_credentials(&self, url: &DisplaySafeUrl, credentials: Credentials) {
-        self.credentials_cache.store_credentials(url, credentials);
+    pub fn store_credentials(
+        &self,
+        url: &DisplaySafeUrl,
+        credentials: Credentials,
+        source: CredentialSource,
+    ) {
+        self.credentials_cache
+            .store_credentials(url, credentials, source);
     }
 
     pub fn is_native_tls(&self) -> bool {
@@ -517,9 +526,30 @@ impl<'a> BaseClientBuilder<'a> {
             self.redirect_policy,
         );
 
+        log_root_store(&self.root_store(ssl_cert_file_exists, ssl_cert_dir_exists));
+
         (raw_client, raw_dangerous_client)
     }
 
+    /// Determine the [`RootStore`] that server certificates are verified against.
+    fn root_store(&self, ssl_cert_file_exists: bool, ssl_cert_dir_exists: bool) -> RootStore {
+        if ssl_cert_file_exists || ssl_cert_dir_exists {
+            // If either is set, `rustls-native-certs` loads them instead of the native store.
+            let file = env::var_os(EnvVars::SSL_CERT_FILE)
+                .filter(|_| ssl_cert_file_exists)
+                .map(PathBuf::from);
+            let dirs = env::var_os(EnvVars::SSL_CERT_DIR)
+                .filter(|_| ssl_cert_dir_exists)
+                .map(|dirs| env::split_paths(&dirs).filter(|dir| dir.exists()).collect())
+                .unwrap_or_default();
+            RootStore::Custom { file, dirs }
+        } else if self.native_tls {
+            RootStore::Native
+        } else {
+            RootStore::Webpki
+        }
+    }
+
     fn create_client(
         &self,
         user_agent: &str,
@@ -564,6 +594,11 @@ impl<'a> BaseClientBuilder<'a> {
         };
 
         // apply proxies
+        ProxySettings::init(
+            self.http_proxy.as_ref(),
+            self.https_proxy.as_ref(),
+            self.no_proxy.as_deref(),
+        );
         let mut client_builder = client_builder;
         for p in &self.proxies {
             client_builder = client_builder.proxy(p.clone());
@@ -617,6 +652,9 @@ impl<'a> BaseClientBuilder<'a> {
                         }
                     }
 
+                    // Log each request last, to capture every retry and the final headers.
+                    client = client.with(LoggingMiddleware);
+
                     client.build()
                 };
 
@@ -624,9 +662,12 @@ impl<'a> BaseClientBuilder<'a> {
 
                 // Avoid uncloneable errors with a streaming body during publish.
                 if self.retries > 0 {
+                    // Log a summary of the retries, across all attempts.
+                    client = client.with(RetrySummaryMiddleware);
+
                     // Initialize the retry strategy.
                     let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                        self.retry_policy(),
+                        LoggingRetryPolicy::new(self.retry_policy(), self.retries),
                         UvRetryableStrategy,
                     );
                     client = client.with(retry_strategy);
@@ -671,6 +712,9 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Log each request last, to capture every retry and the final headers.
+                client = client.with(LoggingMiddleware);
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
@@ -714,7 +758,8 @@ enum Security {
 impl BaseClient {
     /// Selects the appropriate client based on the host's trustworthiness.
     pub fn for_host(&self, url: &DisplaySafeUrl) -> &RedirectClientWithMiddleware {
-        if self.disable_ssl(url) {
+        if let Some(entry) = self.insecure_host(url) {
+            log_skipped(url, entry);
             &self.dangerous_client
         } else {
             &self.client
@@ -729,9 +774,14 @@ impl BaseClient {
 
     /// Returns `true` if the host is trusted to use the insecure client.
     pub fn disable_ssl(&self, url: &DisplaySafeUrl) -> bool {
+        self.insecure_host(url).is_some()
+    }
+
+    /// Returns the `allow-insecure-host` entry that matches the host, if any.
+    fn insecure_host(&self, url: &DisplaySafeUrl) -> Option<&TrustedHost> {
         self.allow_insecure_host
             .iter()
-            .any(|allow_insecure_host| allow_insecure_host.matches(url))
+            .find(|allow_insecure_host| allow_insecure_host.matches(url))
     }
 
     /// The configured client timeout, in seconds.
@@ -1094,16 +1144,27 @@ impl RetryableStrategy for UvRetryableStrategy {
         if retryable == Some(Retryable::Transient) {
             match res {
                 Ok(response) => {
-                    debug!("Transient request failure for: {}", response.url());
+                    debug!(
+                        target: "uv_client::retry",
+                        "Transient failure for {}: HTTP {}",
+                        DisplaySafeUrl::from_url(response.url().clone()),
+                        response.status()
+                    );
                 }
                 Err(err) => {
+                    debug!(
+                        target: "uv_client::retry",
+                        "Transient failure for {}: {}",
+                        err.url().map_or_else(
+                            || "unknown URL".to_string(),
+                            |url| DisplaySafeUrl::from_url(url.clone()).to_string()
+                        ),
+                        failure_reason(err)
+                    );
                     let context = iter::successors(err.source(), |&err| err.source())
                         .map(|err| format!("  Caused by: {err}"))
                         .join("\n");
-                    debug!(
-                        "Transient request failure for {}, retrying: {err}\n{context}",
-                        err.url().map(Url::as_str).unwrap_or("unknown URL")
-                    );
+                    trace!(target: "uv_client::retry", "{err}\n{context}");
                 }
             }
         }
@@ -1111,6 +1172,92 @@ impl RetryableStrategy for UvRetryableStrategy {
     }
 }
 
+/// A [`RetryPolicy`] that logs the backoff chosen before each retry of a request under the
+/// `uv_client::retry` target.
+struct LoggingRetryPolicy {
+    policy: ExponentialBackoff,
+    max_retries: u32,
+}
+
+impl LoggingRetryPolicy {
+    fn new(policy: ExponentialBackoff, max_retries: u32) -> Self {
+        Self {
+            policy,
+            max_retries,
+        }
+    }
+}
+
+impl RetryPolicy for LoggingRetryPolicy {
+    fn should_retry(&self, request_start_time: SystemTime, n_past_retries: u32) -> RetryDecision {
+        let decision = self.policy.should_retry(request_start_time, n_past_retries);
+        match &decision {
+            RetryDecision::Retry { execute_after } => {
+                let backoff = execute_after
+                    .duration_since(SystemTime::now())
+                    .unwrap_or_default();
+                debug!(
+                    target: "uv_client::retry",
+                    "Retrying (attempt {} of {}) after {:.2}s",
+                    n_past_retries + 1,
+                    self.max_retries,
+                    backoff.as_secs_f32()
+                );
+            }
+            RetryDecision::DoNotRetry => {
+                debug!(
+                    target: "uv_client::retry",
+                    "Giving up after {n_past_retries} {}",
+                    if n_past_retries == 1 { "retry" } else { "retries" }
+                );
+            }
+        }
+        decision
+    }
+}
+
+/// A short description of why a request failed, for logging retries, e.g., `HTTP 503 Service
+/// Unavailable`, `timeout`, or `connection reset`.
+///
+/// Falls back to the innermost error in the chain.
+fn failure_reason(err: &(dyn Error + 'static)) -> String {
+    let mut innermost = err;
+    let mut current_source = Some(err);
+    while let Some(source) = current_source {
+        let reqwest_err = if let Some(reqwest_err) = source.downcast_ref::<reqwest::Error>() {
+            Some(reqwest_err)
+        } else if let Some(reqwest_middleware::Error::Reqwest(reqwest_err)) =
+            source.downcast_ref::<reqwest_middleware::Error>()
+        {
+            Some(reqwest_err)
+        } else {
+            source
+                .downcast_ref::<WrappedReqwestError>()
+                .and_then(WrappedReqwestError::inner)
+        };
+        if let Some(reqwest_err) = reqwest_err {
+            if let Some(status) = reqwest_err.status() {
+                return format!("HTTP {status}");
+            }
+            if reqwest_err.is_timeout() {
+                return "timeout".to_string();
+            }
+        }
+        if let Some(io_err) = source.downcast_ref::<io::Error>() {
+            match io_err.kind() {
+                io::ErrorKind::TimedOut => return "timeout".to_string(),
+                io::ErrorKind::ConnectionReset => return "connection reset".to_string(),
+                io::ErrorKind::ConnectionAborted => return "connection aborted".to_string(),
+                io::ErrorKind::BrokenPipe => return "broken pipe".to_string(),
+                _ => {}
+            }
+        }
+        innermost = source;
+        current_source = source.source();
+    }
+    innermost.to_string()
+}
+
 /// Whether the error looks like a network error that should be retried.
 ///
 /// This is an extension over [`reqwest_middleware::default_on_request_failure`], which is missing
@@ -1212,6 +1359,8 @@ pub struct RetryState {
     start_time: SystemTime,
     total_retries: u32,
     url: DisplaySafeUrl,
+    /// Why the last attempt failed, if it's retried.
+    reason: Option<String>,
 }
 
 impl RetryState {
@@ -1222,6 +1371,7 @@ impl RetryState {
             start_time: SystemTime::now(),
             total_retries: 0,
             url: url.into(),
+            reason: None,
         }
     }
 
@@ -1257,6 +1407,7 @@ impl RetryState {
                         .unwrap_or_else(|_| Duration::default());
 
                     self.total_retries += 1;
+                    self.reason = Some(failure_reason(err));
                     return Some(duration);
                 }
 
@@ -1266,12 +1417,33 @@ impl RetryState {
         }
     }
 
+    /// Log that the request succeeded, summarizing the retries if it was retried.
+    pub fn succeeded(&self) {
+        if self.total_retries > 0 {
+            info!(
+                target: "uv_client::retry",
+                "Request for {} succeeded after {} {}, {:.2?} total",
+                self.url,
+                self.total_retries,
+                if self.total_retries == 1 {
+                    "retry"
+                } else {
+                    "retries"
+                },
+                self.start_time.elapsed().unwrap_or_default()
+            );
+        }
+    }
+
     /// Wait before retrying the request.
     pub async fn sleep_backoff(&self, duration: Duration) {
         debug!(
-            "Transient failure while handling response from {}; retrying after {:.1}s...",
+            target: "uv_client::retry",
+            "Transient failure while handling response from {} ({}); retrying after {:.2}s (attempt {})",
             self.url,
+            self.reason.as_deref().unwrap_or("unknown reason"),
             duration.as_secs_f32(),
+            self.total_retries,
         );
         // TODO(konsti): Should we show a spinner plus a message in the CLI while
         // waiting?
@@ -7,7 +7,7 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 
-use uv_cache::{CacheEntry, Freshness};
+use uv_cache::{CacheEntry, CacheUsage, Freshness, Invalidation};
 use uv_fs::write_atomic;
 use uv_redacted::DisplaySafeUrl;
 
@@ -20,6 +20,18 @@ use crate::{
     rkyvutil::OwnedArchive,
 };
 
+/// Log a request that was served from the HTTP cache without a network request, alongside the
+/// requests logged by the [`LoggingMiddleware`](crate::middleware::LoggingMiddleware).
+fn log_cache_hit(req: &Request, cached: &DataWithCachePolicy) {
+    debug!(
+        target: "uv_client::http",
+        "{} {} -> cached ({} bytes, served from cache)",
+        req.method(),
+        DisplaySafeUrl::from_url(req.url().clone()),
+        cached.data.len()
+    );
+}
+
 /// Extract problem details from an HTTP response if it has the correct content type
 ///
 /// Note: This consumes the response body, so it should only be called when there's an error status.
@@ -281,7 +293,7 @@ impl CachedClient {
     ) -> Result<Payload::Target, CachedClientError<CallBackError>> {
         let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
         let cached_response = if let Some(cached) = Self::read_cache(cache_entry).await {
-            self.send_cached(req, cache_control, cached)
+            self.send_cached(req, cache_entry, cache_control, cached)
                 .boxed_local()
                 .await?
         } else {
@@ -439,20 +451,24 @@ impl CachedClient {
                 .map_err(ErrorKind::CacheWrite)?;
             let data_with_cache_policy_bytes =
                 DataWithCachePolicy::serialize(&cache_policy, &data.to_bytes()?)?;
+            let bytes = data_with_cache_policy_bytes.len() as u64;
             write_atomic(cache_entry.path(), data_with_cache_policy_bytes)
                 .await
                 .map_err(ErrorKind::CacheWrite)?;
+            CacheUsage::write(cache_entry.path(), bytes);
             Ok(data.into_target())
         }
         .instrument(new_cache)
         .await
     }
 
-    #[instrument(name = "read_and_parse_cache", skip_all, fields(file = %cache_entry.path().display()
-    ))]
+    #[instrument(name = "read_and_parse_cache", skip_all, fields(file = %cache_entry.path().display(), kind = "disk"))]
     async fn read_cache(cache_entry: &CacheEntry) -> Option<DataWithCachePolicy> {
         match DataWithCachePolicy::from_path_async(cache_entry.path()).await {
-            Ok(data) => Some(data),
+            Ok(data) => {
+                CacheUsage::read(cache_entry.path());
+                Some(data)
+            }
             Err(err) => {
                 // When we know the cache entry doesn't exist, then things are
                 // normal and we shouldn't emit a WARN.
@@ -477,6 +493,7 @@ impl CachedClient {
     async fn send_cached(
         &self,
         mut req: Request,
+        cache_entry: &CacheEntry,
         cache_control: CacheControl<'_>,
         cached: DataWithCachePolicy,
     ) -> Result<CachedResponse, Error> {
@@ -493,11 +510,16 @@ impl CachedClient {
         Ok(match cached.cache_policy.before_request(&mut req) {
             BeforeRequest::Fresh => {
                 debug!("Found fresh response for: {}", req.url());
+                log_cache_hit(&req, &cached);
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
                 CacheControl::None | CacheControl::MustRevalidate | CacheControl::Override(_) => {
                     debug!("Found stale response for: {}", req.url());
+                    // Forced revalidations are logged when determining the freshness of the entry.
+                    if !matches!(cache_control, CacheControl::MustRevalidate) {
+                        Invalidation::Expired.log(cache_entry.path());
+                    }
                     self.send_cached_handle_stale(
                         req,
                         cache_control,
@@ -508,6 +530,7 @@ impl CachedClient {
                 }
                 CacheControl::AllowStale => {
                     debug!("Found stale (but allowed) response for: {}", req.url());
+                    log_cache_hit(&req, &cached);
                     CachedResponse::FreshCache(cached)
                 }
             },
@@ -538,7 +561,11 @@ impl CachedClient {
         let mut response = self
             .0
             .execute(req)
-            .instrument(info_span!("revalidation_request", url = url.as_str()))
+            .instrument(info_span!(
+                "revalidation_request",
+                url = url.as_str(),
+                kind = "network"
+            ))
             .await
             .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?;
         trace!(
@@ -602,7 +629,7 @@ impl CachedClient {
         }
     }
 
-    #[instrument(skip_all, fields(url = req.url().as_str()))]
+    #[instrument(skip_all, fields(url = req.url().as_str(), kind = "network"))]
     async fn fresh_request(
         &self,
         req: Request,
@@ -708,7 +735,10 @@ impl CachedClient {
                 .await;
 
             match result {
-                Ok(ok) => return Ok(ok),
+                Ok(ok) => {
+                    retry_state.succeeded();
+                    return Ok(ok);
+                }
                 Err(err) => {
                     if let Some(backoff) = retry_state.should_retry(err.error(), err.retries()) {
                         retry_state.sleep_backoff(backoff).await;
@@ -742,7 +772,10 @@ impl CachedClient {
                 .await;
 
             match result {
-                Ok(ok) => return Ok(ok),
+                Ok(ok) => {
+                    retry_state.succeeded();
+                    return Ok(ok);
+                }
                 Err(err) => {
                     if let Some(backoff) = retry_state.should_retry(err.error(), err.retries()) {
                         retry_state.sleep_backoff(backoff).await;
@@ -12,6 +12,7 @@ pub use registry_client::{
     SimpleDetailMetadatum, SimpleIndexMetadata, VersionFiles,
 };
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use tls::InsecureHosts;
 
 mod base_client;
 mod cached_client;
@@ -21,6 +22,7 @@ mod html;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod proxy;
 mod registry_client;
 mod remote_metadata;
 mod rkyvutil;
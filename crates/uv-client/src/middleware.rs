@@ -1,7 +1,12 @@
-use http::Extensions;
-use std::fmt::Debug;
+use http::header::{AUTHORIZATION, PROXY_AUTHORIZATION};
+use http::{Extensions, HeaderMap, StatusCode};
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Instant;
+use tracing::{debug, info, trace};
 use uv_redacted::DisplaySafeUrl;
 
+use crate::proxy::ProxySettings;
+
 use reqwest::{Request, Response};
 use reqwest_middleware::{Middleware, Next};
 
@@ -49,3 +54,147 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// The number of times a request has been sent, shared across retries via the request extensions.
+#[derive(Debug, Clone, Copy)]
+struct Attempt(u32);
+
+/// A middleware that logs a summary under the `uv_client::retry` target once a request succeeds
+/// after being retried.
+///
+/// Must be added before the retry middleware, such that it observes the outcome of all attempts.
+pub(crate) struct RetrySummaryMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for RetrySummaryMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let url = DisplaySafeUrl::from_url(req.url().clone());
+        let start = Instant::now();
+        let result = next.run(req, extensions).await;
+
+        let retries = extensions
+            .get::<Attempt>()
+            .map_or(0, |Attempt(attempt)| attempt.saturating_sub(1));
+        if retries > 0
+            && let Ok(response) = &result
+            && !(response.status().is_client_error() || response.status().is_server_error())
+        {
+            info!(
+                target: "uv_client::retry",
+                "Request for {url} succeeded after {retries} {}, {:.2?} total",
+                if retries == 1 { "retry" } else { "retries" },
+                start.elapsed()
+            );
+        }
+
+        result
+    }
+}
+
+/// A middleware that logs a summary of each HTTP request under the `uv_client::http` target.
+///
+/// At the debug level, each completed request is logged with its method, URL, status, size,
+/// whether it was served from the HTTP cache, and elapsed time. At the trace level, each attempt
+/// is also logged with its request headers, omitting any credentials.
+pub(crate) struct LoggingMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for LoggingMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let method = req.method().clone();
+        let url = DisplaySafeUrl::from_url(req.url().clone());
+
+        let attempt = extensions.get::<Attempt>().map_or(1, |Attempt(n)| n + 1);
+        extensions.insert(Attempt(attempt));
+        ProxySettings::trace_bypass(req.url());
+        trace!(
+            target: "uv_client::http",
+            "{method} {url} (attempt {attempt}) with headers: {}",
+            RedactedHeaders(req.headers())
+        );
+
+        let start = Instant::now();
+        let result = next.run(req, extensions).await;
+        let elapsed = start.elapsed();
+
+        match &result {
+            Ok(response) => {
+                let size = response.content_length().map_or_else(
+                    || "unknown size".to_string(),
+                    |bytes| format!("{bytes} bytes"),
+                );
+                // A `304 Not Modified` revalidates the cached response, which is then used as-is.
+                let cache = if response.status() == StatusCode::NOT_MODIFIED {
+                    "revalidated from cache"
+                } else {
+                    "not cached"
+                };
+                debug!(
+                    target: "uv_client::http",
+                    "{method} {url} -> {} ({size}, {cache}, {elapsed:.2?})",
+                    response.status()
+                );
+            }
+            Err(_) => {
+                debug!(
+                    target: "uv_client::http",
+                    "{method} {url} -> failed ({elapsed:.2?})"
+                );
+            }
+        }
+
+        result
+    }
+}
+
+/// Display the headers of a request, omitting the values of any credentials.
+struct RedactedHeaders<'a>(&'a HeaderMap);
+
+impl Display for RedactedHeaders<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("{")?;
+        for (i, (name, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            if name == AUTHORIZATION || name == PROXY_AUTHORIZATION || value.is_sensitive() {
+                write!(f, "{name}: ****")?;
+            } else {
+                write!(f, "{name}: {}", String::from_utf8_lossy(value.as_bytes()))?;
+            }
+        }
+        f.write_str("}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{ACCEPT, AUTHORIZATION};
+    use http::{HeaderMap, HeaderValue};
+
+    use super::RedactedHeaders;
+
+    #[test]
+    fn redacted_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("text/html"));
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_static("Basic dXNlcjpwYXNz"),
+        );
+        assert_eq!(
+            RedactedHeaders(&headers).to_string(),
+            "{accept: text/html, authorization: ****}"
+        );
+    }
+}
@@ -15,7 +15,7 @@ use tokio::sync::{Mutex, Semaphore};
 use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 use url::Url;
 
-use uv_auth::{CredentialsCache, Indexes, PyxTokenStore};
+use uv_auth::{CredentialsCache, Indexes, PyxTokenStore, Realm};
 use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
 use uv_configuration::IndexStrategy;
 use uv_configuration::KeyringProviderType;
@@ -152,7 +152,7 @@ impl<'a> RegistryClientBuilder<'a> {
     /// Add all authenticated sources to the cache.
     pub fn cache_index_credentials(&mut self) {
         for index in self.index_locations.known_indexes() {
-            if let Some(credentials) = index.credentials() {
+            if let Some((credentials, source)) = index.credentials() {
                 trace!(
                     "Read credentials for index {}",
                     index
@@ -162,16 +162,20 @@ impl<'a> RegistryClientBuilder<'a> {
                         .unwrap_or_else(|| index.url.to_string())
                 );
                 if let Some(root_url) = index.root_url() {
-                    self.base_client_builder
-                        .store_credentials(&root_url, credentials.clone());
+                    self.base_client_builder.store_credentials(
+                        &root_url,
+                        credentials.clone(),
+                        source.clone(),
+                    );
                 }
                 self.base_client_builder
-                    .store_credentials(index.raw_url(), credentials);
+                    .store_credentials(index.raw_url(), credentials, source);
             }
         }
     }
 
     pub fn build(mut self) -> RegistryClient {
+        self.index_locations.log_effective();
         self.cache_index_credentials();
         let index_urls = self.index_locations.index_urls();
 
@@ -204,6 +208,7 @@ impl<'a> RegistryClientBuilder<'a> {
 
     /// Share the underlying client between two different middleware configurations.
     pub fn wrap_existing(mut self, existing: &BaseClient) -> RegistryClient {
+        self.index_locations.log_effective();
         self.cache_index_credentials();
         let index_urls = self.index_locations.index_urls();
 
@@ -497,6 +502,7 @@ impl RegistryClient {
     ///
     /// The index can either be a PEP 503-compatible remote repository, or a local directory laid
     /// out in the same format.
+    #[instrument(skip_all, fields(package = %package_name, host = %Realm::from(index.url())))]
     async fn simple_detail_single_index(
         &self,
         package_name: &PackageName,
@@ -1167,7 +1173,11 @@ impl RegistryClient {
                     })
                 }
                 .boxed_local()
-                .instrument(info_span!("read_metadata_range_request", wheel = %filename))
+                .instrument(info_span!(
+                    "read_metadata_range_request",
+                    wheel = %filename,
+                    kind = "network"
+                ))
             };
 
             let result = self
@@ -1226,7 +1236,11 @@ impl RegistryClient {
                     .await
                     .map_err(|err| ErrorKind::Metadata(url.to_string(), err))
             }
-            .instrument(info_span!("read_metadata_stream", wheel = %filename))
+            .instrument(info_span!(
+                "read_metadata_stream",
+                wheel = %filename,
+                kind = "network"
+            ))
         };
 
         self.cached_client()
@@ -1,6 +1,27 @@
-use reqwest::Identity;
+//! The TLS configuration of the clients, and the certificate verification decisions made for each
+//! host, logged under the `uv_client::tls` target.
+//!
+//! The hosts that skip certificate verification are tracked process-wide, such that each host is
+//! logged once per command, even if it's contacted by multiple clients.
+
+use std::collections::BTreeSet;
 use std::ffi::OsStr;
+use std::fmt::{self, Display};
 use std::io::Read;
+use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError};
+
+use itertools::Itertools;
+use reqwest::Identity;
+use tracing::debug;
+
+use uv_configuration::TrustedHost;
+use uv_fs::Simplified;
+use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user;
+
+/// The hosts, keyed by host and port, that were contacted without verifying their certificates.
+static INSECURE_HOSTS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum CertificateError {
@@ -19,3 +40,110 @@ pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, Certifi
         CertificateError::Reqwest(tls_err)
     })
 }
+
+/// The root certificates that server certificates are verified against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RootStore {
+    /// The Mozilla root certificates bundled via `webpki-roots`.
+    Webpki,
+    /// The platform's native certificate store.
+    Native,
+    /// The certificates provided via `SSL_CERT_FILE` and `SSL_CERT_DIR`.
+    Custom {
+        file: Option<PathBuf>,
+        dirs: Vec<PathBuf>,
+    },
+}
+
+impl Display for RootStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Webpki => write!(f, "the bundled webpki roots"),
+            Self::Native => write!(f, "the native certificate store"),
+            Self::Custom { file, dirs } => {
+                if let Some(file) = file {
+                    write!(f, "the bundle at `{}`", file.user_display())?;
+                    if !dirs.is_empty() {
+                        write!(f, " and ")?;
+                    }
+                }
+                if !dirs.is_empty() {
+                    write!(
+                        f,
+                        "the certificates in {}",
+                        dirs.iter()
+                            .map(|dir| format!("`{}`", dir.user_display()))
+                            .join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Log the [`RootStore`] that the certificates of all hosts are verified against, unless the host
+/// is allowed to be insecure.
+pub(crate) fn log_root_store(root_store: &RootStore) {
+    debug!("Verifying certificates against {root_store}");
+}
+
+/// Log that certificate verification is skipped for `url` due to the `allow-insecure-host` entry,
+/// if that wasn't logged for its host yet.
+pub(crate) fn log_skipped(url: &DisplaySafeUrl, entry: &TrustedHost) {
+    if url.scheme() != "https" {
+        return;
+    }
+    let host = match (url.host_str(), url.port()) {
+        (Some(host), Some(port)) => format!("{host}:{port}"),
+        (Some(host), None) => host.to_string(),
+        (None, _) => return,
+    };
+    if !INSECURE_HOSTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(host.clone())
+    {
+        return;
+    }
+    debug!(
+        "Skipping certificate verification for `{host}` due to the `allow-insecure-host` entry `{entry}`"
+    );
+}
+
+/// The hosts that were contacted by the current command without verifying their certificates.
+///
+/// The hosts are reported in a single warning when the value returned by [`InsecureHosts::track`]
+/// is dropped, i.e., once the command completes (or fails).
+#[derive(Debug)]
+pub struct InsecureHosts(());
+
+impl InsecureHosts {
+    /// Track the hosts that skip certificate verification until the returned value is dropped.
+    #[must_use]
+    pub fn track() -> Self {
+        Self(())
+    }
+}
+
+impl Drop for InsecureHosts {
+    fn drop(&mut self) {
+        let hosts = std::mem::take(
+            &mut *INSECURE_HOSTS
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        if hosts.is_empty() {
+            return;
+        }
+        warn_user!(
+            "Certificate verification was skipped for {} due to `allow-insecure-host`: {}",
+            if hosts.len() == 1 {
+                "1 host".to_string()
+            } else {
+                format!("{} hosts", hosts.len())
+            },
+            hosts.iter().map(|host| format!("`{host}`")).join(", ")
+        );
+    }
+}
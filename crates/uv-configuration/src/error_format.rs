@@ -0,0 +1,25 @@
+use std::str::FromStr;
+
+/// The format in which errors are reported, e.g., via `UV_ERROR_FORMAT`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// Report errors as human-readable messages.
+    #[default]
+    Text,
+    /// Report errors with a stable shape as JSON objects.
+    Json,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("text") {
+            Ok(Self::Text)
+        } else if s.eq_ignore_ascii_case("json") {
+            Ok(Self::Json)
+        } else {
+            Err(format!("expected `text` or `json`, found `{s}`"))
+        }
+    }
+}
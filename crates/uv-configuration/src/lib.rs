@@ -6,6 +6,7 @@ pub use dependency_groups::*;
 pub use dry_run::*;
 pub use editable::*;
 pub use env_file::*;
+pub use error_format::*;
 pub use excludes::*;
 pub use export_format::*;
 pub use extras::*;
@@ -32,6 +33,7 @@ mod dependency_groups;
 mod dry_run;
 mod editable;
 mod env_file;
+mod error_format;
 mod excludes;
 mod export_format;
 mod extras;
@@ -1,3 +1,7 @@
+use std::fmt::{Display, Formatter};
+
+use tracing::{debug, trace};
+
 use uv_pypi_types::{HashAlgorithm, HashDigest};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -84,6 +88,19 @@ pub trait Hashed {
         }
     }
 
+    /// Returns `true` if the archive satisfies the given hash policy, logging the outcome of the
+    /// check for the given distribution under the `uv_distribution::hashes` target.
+    ///
+    /// Digests are truncated at the debug level, and logged in full at the trace level.
+    fn verify(&self, distribution: &str, hashes: HashPolicy) -> bool {
+        if let HashPolicy::Validate(expected) = hashes {
+            let check = HashCheck::new(distribution, expected, self.hashes());
+            debug!(target: "uv_distribution::hashes", "{check}");
+            trace!(target: "uv_distribution::hashes", "{check:#}");
+        }
+        self.satisfies(hashes)
+    }
+
     /// Returns `true` if the archive includes a hash for at least one of the given algorithms.
     fn has_digests(&self, hashes: HashPolicy) -> bool {
         match hashes {
@@ -99,3 +116,198 @@ pub trait Hashed {
         }
     }
 }
+
+/// The outcome of checking the computed hashes of an archive against the expected hashes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashCheckResult {
+    /// A computed hash matches one of the expected hashes.
+    Matched,
+    /// A hash was computed for an expected algorithm, but it doesn't match.
+    Mismatched,
+    /// No hashes were expected.
+    MissingExpected,
+    /// No hash was computed for any of the expected algorithms.
+    MissingComputed,
+}
+
+/// The check of an archive's hashes, for use in diagnostics.
+///
+/// Displays the digests truncated by default, and in full with the alternate flag (`{:#}`).
+#[derive(Debug, Clone, Copy)]
+pub struct HashCheck<'a> {
+    distribution: &'a str,
+    expected: Option<&'a HashDigest>,
+    computed: Option<&'a HashDigest>,
+    result: HashCheckResult,
+}
+
+impl<'a> HashCheck<'a> {
+    /// The number of characters of each digest to display when truncated.
+    const PREFIX: usize = 12;
+
+    /// Check the `computed` hashes of the given distribution against the `expected` hashes.
+    ///
+    /// The check is reported for a single algorithm: that of the matching hash, if any; otherwise,
+    /// that of the first expected hash that was also computed.
+    pub fn new(
+        distribution: &'a str,
+        expected: &'a [HashDigest],
+        computed: &'a [HashDigest],
+    ) -> Self {
+        let computed_for = |algorithm: HashAlgorithm| {
+            computed
+                .iter()
+                .find(|computed| computed.algorithm == algorithm)
+        };
+
+        if let Some(matched) = computed.iter().find(|hash| expected.contains(hash)) {
+            return Self {
+                distribution,
+                expected: Some(matched),
+                computed: Some(matched),
+                result: HashCheckResult::Matched,
+            };
+        }
+        if expected.is_empty() {
+            return Self {
+                distribution,
+                expected: None,
+                computed: computed.first(),
+                result: HashCheckResult::MissingExpected,
+            };
+        }
+        if let Some((expected, computed)) = expected
+            .iter()
+            .find_map(|expected| Some((expected, computed_for(expected.algorithm)?)))
+        {
+            return Self {
+                distribution,
+                expected: Some(expected),
+                computed: Some(computed),
+                result: HashCheckResult::Mismatched,
+            };
+        }
+        Self {
+            distribution,
+            expected: expected.first(),
+            computed: None,
+            result: HashCheckResult::MissingComputed,
+        }
+    }
+
+    /// Returns the outcome of the check.
+    pub fn result(&self) -> HashCheckResult {
+        self.result
+    }
+}
+
+impl Display for HashCheck<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let full = f.alternate();
+        let digest = |hash: Option<&HashDigest>| match hash {
+            Some(hash) if full => hash.digest.to_string(),
+            Some(hash) if hash.digest.len() > Self::PREFIX => {
+                format!("{}...", &hash.digest[..Self::PREFIX])
+            }
+            Some(hash) => hash.digest.to_string(),
+            None => "none".to_string(),
+        };
+
+        match self.result {
+            HashCheckResult::Matched => write!(f, "Hash match for `{}`", self.distribution)?,
+            HashCheckResult::Mismatched => write!(f, "Hash mismatch for `{}`", self.distribution)?,
+            HashCheckResult::MissingExpected => {
+                write!(f, "Missing expected hash for `{}`", self.distribution)?;
+            }
+            HashCheckResult::MissingComputed => {
+                write!(f, "Missing computed hash for `{}`", self.distribution)?;
+            }
+        }
+        if let Some(algorithm) = self.expected.or(self.computed).map(HashDigest::algorithm) {
+            write!(f, " ({algorithm})")?;
+        }
+        write!(
+            f,
+            ": expected {}, computed {}",
+            digest(self.expected),
+            digest(self.computed)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pypi_types::HashDigest;
+
+    use super::{HashCheck, HashCheckResult};
+
+    fn digest(hash: &str) -> HashDigest {
+        HashDigest::from_str(hash).unwrap()
+    }
+
+    #[test]
+    fn hash_check_matched() {
+        let expected = [
+            digest("sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f"),
+            digest("sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a"),
+        ];
+        let computed = [digest(
+            "sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a",
+        )];
+        let check = HashCheck::new("anyio==4.0.0", &expected, &computed);
+        assert_eq!(check.result(), HashCheckResult::Matched);
+        assert_eq!(
+            check.to_string(),
+            "Hash match for `anyio==4.0.0` (sha256): expected a7ed51751b2c..., computed a7ed51751b2c..."
+        );
+    }
+
+    #[test]
+    fn hash_check_mismatched() {
+        let expected = [digest(
+            "sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+        )];
+        let computed = [digest(
+            "sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+        )];
+        let check = HashCheck::new("anyio==4.0.0", &expected, &computed);
+        assert_eq!(check.result(), HashCheckResult::Mismatched);
+        assert_eq!(
+            check.to_string(),
+            "Hash mismatch for `anyio==4.0.0` (sha256): expected afdb2b588b9f..., computed cfdb2b588b9f..."
+        );
+        assert_eq!(
+            format!("{check:#}"),
+            "Hash mismatch for `anyio==4.0.0` (sha256): expected afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f, computed cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f"
+        );
+    }
+
+    #[test]
+    fn hash_check_missing_expected() {
+        let computed = [digest(
+            "sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+        )];
+        let check = HashCheck::new("anyio==4.0.0", &[], &computed);
+        assert_eq!(check.result(), HashCheckResult::MissingExpected);
+        assert_eq!(
+            check.to_string(),
+            "Missing expected hash for `anyio==4.0.0` (sha256): expected none, computed cfdb2b588b9f..."
+        );
+    }
+
+    #[test]
+    fn hash_check_missing_computed() {
+        let expected = [digest("md5:4f3b6c0f3d2d27d4d8e3b1e4c7b1c2a9")];
+        let computed = [digest(
+            "sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+        )];
+        let check = HashCheck::new("anyio==4.0.0", &expected, &computed);
+        assert_eq!(check.result(), HashCheckResult::MissingComputed);
+        assert_eq!(
+            check.to_string(),
+            "Missing computed hash for `anyio==4.0.0` (md5): expected 4f3b6c0f3d2d..., computed none"
+        );
+    }
+}
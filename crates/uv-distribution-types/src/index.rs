@@ -1,11 +1,14 @@
 use std::path::Path;
 use std::str::FromStr;
+use std::sync::{LazyLock, Mutex};
 
+use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tracing::debug;
 use url::Url;
 
-use uv_auth::{AuthPolicy, Credentials};
+use uv_auth::{AuthPolicy, CredentialSource, Credentials};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
@@ -100,7 +103,8 @@ pub struct Index {
     /// is given the highest priority when resolving packages.
     #[serde(default)]
     pub default: bool,
-    /// The origin of the index (e.g., a CLI flag, a user-level configuration file, etc.).
+    /// The origin of the index (e.g., a CLI flag, or a key in a configuration file), for use in
+    /// diagnostics.
     #[serde(skip)]
     pub origin: Option<Origin>,
     /// The format used by the index.
@@ -157,6 +161,20 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub cache_control: Option<IndexCacheControl>,
+    /// Alternative URLs that serve the same packages as this index (e.g., a mirror).
+    ///
+    /// A package pinned to this index and to one of its aliases (e.g., by a dependency that
+    /// declares the mirror) is not considered to be pinned to conflicting indexes. Instead, the
+    /// package is fetched from whichever of the two was defined first.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.example.com/simple"
+    /// aliases = ["https://mirror.example.com/simple"]
+    /// ```
+    #[serde(default)]
+    pub aliases: Option<Vec<IndexUrl>>,
 }
 
 impl PartialEq for Index {
@@ -172,6 +190,7 @@ impl PartialEq for Index {
             authenticate,
             ignore_error_codes,
             cache_control,
+            aliases,
         } = self;
         *url == other.url
             && *name == other.name
@@ -182,6 +201,7 @@ impl PartialEq for Index {
             && *authenticate == other.authenticate
             && *ignore_error_codes == other.ignore_error_codes
             && *cache_control == other.cache_control
+            && *aliases == other.aliases
     }
 }
 
@@ -206,6 +226,7 @@ impl Ord for Index {
             authenticate,
             ignore_error_codes,
             cache_control,
+            aliases,
         } = self;
         url.cmp(&other.url)
             .then_with(|| name.cmp(&other.name))
@@ -216,6 +237,7 @@ impl Ord for Index {
             .then_with(|| authenticate.cmp(&other.authenticate))
             .then_with(|| ignore_error_codes.cmp(&other.ignore_error_codes))
             .then_with(|| cache_control.cmp(&other.cache_control))
+            .then_with(|| aliases.cmp(&other.aliases))
     }
 }
 
@@ -232,6 +254,7 @@ impl std::hash::Hash for Index {
             authenticate,
             ignore_error_codes,
             cache_control,
+            aliases,
         } = self;
         url.hash(state);
         name.hash(state);
@@ -242,6 +265,7 @@ impl std::hash::Hash for Index {
         authenticate.hash(state);
         ignore_error_codes.hash(state);
         cache_control.hash(state);
+        aliases.hash(state);
     }
 }
 
@@ -282,6 +306,7 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            aliases: None,
         }
     }
 
@@ -298,6 +323,7 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            aliases: None,
         }
     }
 
@@ -314,6 +340,7 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            aliases: None,
         }
     }
 
@@ -347,17 +374,26 @@ impl Index {
         self.url.root()
     }
 
-    /// Retrieve the credentials for the index, either from the environment, or from the URL itself.
-    pub fn credentials(&self) -> Option<Credentials> {
+    /// Retrieve the credentials for the index, either from the environment, or from the URL itself,
+    /// along with their source.
+    pub fn credentials(&self) -> Option<(Credentials, CredentialSource)> {
+        /// The indexes for which the environment was found to be empty, to log them only once.
+        static EMPTY: LazyLock<Mutex<FxHashSet<String>>> = LazyLock::new(Mutex::default);
+
         // If the index is named, and credentials are provided via the environment, prefer those.
         if let Some(name) = self.name.as_ref() {
+            let source = CredentialSource::Environment(name.to_env_var());
             if let Some(credentials) = Credentials::from_env(name.to_env_var()) {
-                return Some(credentials);
+                return Some((credentials, source));
+            }
+            if EMPTY.lock().unwrap().insert(name.to_string()) {
+                debug!("No credentials found in {source} for index `{name}`");
             }
         }
 
         // Otherwise, extract the credentials from the URL.
         Credentials::from_url(self.url.url())
+            .map(|credentials| (credentials, CredentialSource::Url))
     }
 
     /// Resolve the index relative to the given root directory.
@@ -367,6 +403,13 @@ impl Index {
                 self.url = IndexUrl::parse(given, Some(root_dir))?;
             }
         }
+        for alias in self.aliases.iter_mut().flatten() {
+            if let IndexUrl::Path(ref url) = *alias {
+                if let Some(given) = url.given() {
+                    *alias = IndexUrl::parse(given, Some(root_dir))?;
+                }
+            }
+        }
         Ok(self)
     }
 
@@ -419,6 +462,7 @@ impl From<IndexUrl> for Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            aliases: None,
         }
     }
 }
@@ -443,6 +487,7 @@ impl FromStr for Index {
                     authenticate: AuthPolicy::default(),
                     ignore_error_codes: None,
                     cache_control: None,
+                    aliases: None,
                 });
             }
         }
@@ -460,6 +505,7 @@ impl FromStr for Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            aliases: None,
         })
     }
 }
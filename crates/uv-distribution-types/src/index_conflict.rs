@@ -0,0 +1,312 @@
+use std::fmt::{Display, Formatter};
+
+use thiserror::Error;
+
+use crate::{Index, IndexName, Origin, Verbatim};
+
+/// Two definitions of the same index that can't be reconciled.
+#[derive(Debug, Error)]
+pub enum IndexConflictError {
+    #[error(
+        "Index `{name}` is defined with two different URLs: `{}` ({first}) and `{}` ({second})",
+        first.url,
+        second.url
+    )]
+    DuplicateName {
+        name: IndexName,
+        first: Box<IndexDefinition>,
+        second: Box<IndexDefinition>,
+    },
+    #[error("Index `{name}` is defined twice with different settings ({first}; {second})")]
+    DuplicateSettings {
+        name: IndexName,
+        first: Box<IndexDefinition>,
+        second: Box<IndexDefinition>,
+    },
+    #[error(
+        "Index URL `{url}` is defined under two different names: `{first_name}` ({first}) and `{second_name}` ({second})"
+    )]
+    DuplicateUrl {
+        url: String,
+        first_name: IndexName,
+        second_name: IndexName,
+        first: Box<IndexDefinition>,
+        second: Box<IndexDefinition>,
+    },
+    #[error("Index URL `{url}` is defined as both explicit ({first}) and not explicit ({second})")]
+    ConflictingExplicit {
+        url: String,
+        first: Box<IndexDefinition>,
+        second: Box<IndexDefinition>,
+    },
+}
+
+impl IndexConflictError {
+    /// Record the line of each conflicting definition, given the lines on which each could be
+    /// defined, as returned by `locate`.
+    ///
+    /// The second definition follows the first, so if both match the same lines (as when an index
+    /// is repeated verbatim in a single file), the first is assigned the earliest line and the
+    /// second the latest.
+    #[must_use]
+    pub fn with_lines(mut self, locate: impl Fn(&IndexDefinition) -> Vec<usize>) -> Self {
+        let (Self::DuplicateName { first, second, .. }
+        | Self::DuplicateSettings { first, second, .. }
+        | Self::DuplicateUrl { first, second, .. }
+        | Self::ConflictingExplicit { first, second, .. }) = &mut self;
+        first.line = locate(first).first().copied();
+        second.line = locate(second).last().copied();
+        self
+    }
+}
+
+/// A definition of an index, as reported in an [`IndexConflictError`].
+#[derive(Debug, Clone)]
+pub struct IndexDefinition {
+    /// The URL of the index, without credentials.
+    pub url: String,
+    /// The name of the index, if any.
+    pub name: Option<IndexName>,
+    /// The URL of the index, as written in the configuration.
+    pub given: String,
+    /// Where the index was configured, if known.
+    pub origin: Option<Origin>,
+    /// The (one-based) line on which the index is defined, if known.
+    pub line: Option<usize>,
+}
+
+impl From<&Index> for IndexDefinition {
+    fn from(index: &Index) -> Self {
+        Self {
+            url: index.url.without_credentials().to_string(),
+            name: index.name.clone(),
+            given: index.url.verbatim().to_string(),
+            origin: index.origin.clone(),
+            line: None,
+        }
+    }
+}
+
+impl IndexDefinition {
+    /// Return the (one-based) lines on which this index may be defined in `content`, the TOML
+    /// document it was read from, under the dotted `key` (e.g., `tool.uv.index`).
+    ///
+    /// Definitions are matched on both their name and URL, falling back to either alone.
+    pub fn find_lines(&self, content: &str, key: &str) -> Vec<usize> {
+        let Ok(document) = toml_edit::Document::parse(content) else {
+            return Vec::new();
+        };
+        let mut item = document.as_item();
+        for segment in key.split('.') {
+            let Some(next) = item.get(segment) else {
+                return Vec::new();
+            };
+            item = next;
+        }
+
+        // Collect the name, URL, and location of each definition under the key.
+        let mut definitions = Vec::new();
+        if let Some(tables) = item.as_array_of_tables() {
+            for table in tables {
+                let name = table.get("name").and_then(toml_edit::Item::as_str);
+                let url = table.get("url").and_then(toml_edit::Item::as_str);
+                definitions.push((name, url, table.span()));
+            }
+        } else if let Some(array) = item.as_array() {
+            for value in array {
+                if let Some(table) = value.as_inline_table() {
+                    let name = table.get("name").and_then(toml_edit::Value::as_str);
+                    let url = table.get("url").and_then(toml_edit::Value::as_str);
+                    definitions.push((name, url, value.span()));
+                } else {
+                    definitions.push((None, value.as_str(), value.span()));
+                }
+            }
+        } else {
+            definitions.push((None, item.as_str(), item.span()));
+        }
+
+        let name = self.name.as_ref().map(AsRef::as_ref);
+        let url = Some(self.given.as_str());
+        let lines = |matches: &dyn Fn(Option<&str>, Option<&str>) -> bool| {
+            definitions
+                .iter()
+                .filter(|(name, url, _)| matches(*name, *url))
+                .filter_map(|(.., span)| span.as_ref())
+                .map(|span| content[..span.start].matches('\n').count() + 1)
+                .collect::<Vec<_>>()
+        };
+        let exact = lines(&|other_name, other_url| other_name == name && other_url == url);
+        if !exact.is_empty() {
+            return exact;
+        }
+        if name.is_some() {
+            lines(&|other_name, _| other_name == name)
+        } else {
+            lines(&|_, other_url| other_url == url)
+        }
+    }
+}
+
+impl Display for IndexDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.origin, self.line) {
+            (Some(origin), Some(line)) => write!(f, "{origin}, line {line}"),
+            (Some(origin), None) => write!(f, "{origin}"),
+            (None, Some(line)) => write!(f, "line {line}"),
+            (None, None) => f.write_str("unknown location"),
+        }
+    }
+}
+
+/// Deduplicate the given indexes, in order.
+///
+/// Indexes are compared by their canonical URL. An index that's defined twice with the same name,
+/// URL, and settings (e.g., `authenticate` or `cache-control`) is deduplicated, even if the two
+/// definitions come from different configuration files. Otherwise, an error is returned if an
+/// index name is reused, or if the same URL is defined under two different names or with
+/// conflicting `explicit` flags.
+pub fn dedup_indexes(indexes: Vec<Index>) -> Result<Vec<Index>, IndexConflictError> {
+    let mut unique: Vec<Index> = Vec::with_capacity(indexes.len());
+    'indexes: for index in indexes {
+        for existing in &unique {
+            let same_url = existing.url.is_same_index(&index.url);
+            // Only exact duplicates are deduplicated: every user-settable field must match, with
+            // the URLs compared by their canonical form.
+            if same_url
+                && *existing
+                    == (Index {
+                        url: existing.url.clone(),
+                        ..index.clone()
+                    })
+            {
+                continue 'indexes;
+            }
+            if let Some(name) = index.name.as_ref()
+                && existing.name.as_ref() == Some(name)
+            {
+                let name = name.clone();
+                let first = Box::new(IndexDefinition::from(existing));
+                let second = Box::new(IndexDefinition::from(&index));
+                return Err(if same_url {
+                    IndexConflictError::DuplicateSettings {
+                        name,
+                        first,
+                        second,
+                    }
+                } else {
+                    IndexConflictError::DuplicateName {
+                        name,
+                        first,
+                        second,
+                    }
+                });
+            }
+            if !same_url {
+                continue;
+            }
+            if let (Some(first_name), Some(second_name)) =
+                (existing.name.as_ref(), index.name.as_ref())
+            {
+                return Err(IndexConflictError::DuplicateUrl {
+                    url: index.url.without_credentials().to_string(),
+                    first_name: first_name.clone(),
+                    second_name: second_name.clone(),
+                    first: Box::new(IndexDefinition::from(existing)),
+                    second: Box::new(IndexDefinition::from(&index)),
+                });
+            }
+            if existing.explicit != index.explicit {
+                let (first, second) = if existing.explicit {
+                    (existing, &index)
+                } else {
+                    (&index, existing)
+                };
+                return Err(IndexConflictError::ConflictingExplicit {
+                    url: index.url.without_credentials().to_string(),
+                    first: Box::new(IndexDefinition::from(first)),
+                    second: Box::new(IndexDefinition::from(second)),
+                });
+            }
+        }
+        unique.push(index);
+    }
+    Ok(unique)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{Index, Origin};
+
+    use super::{IndexConflictError, dedup_indexes};
+
+    fn index(name: &str, url: &str, key: &str) -> Index {
+        let mut index = Index::from_extra_index_url(url.parse().unwrap());
+        index.name = Some(name.parse().unwrap());
+        index.origin = Some(Origin::Project {
+            path: PathBuf::from(if key == "index" {
+                "uv.toml"
+            } else {
+                "pyproject.toml"
+            }),
+            key: key.to_string(),
+        });
+        index
+    }
+
+    #[test]
+    fn exact_duplicates_across_files() {
+        let indexes = dedup_indexes(vec![
+            index("proxy", "https://example.com/simple", "tool.uv.index"),
+            index("proxy", "https://example.com/simple/", "index"),
+        ])
+        .unwrap();
+        assert_eq!(indexes.len(), 1);
+    }
+
+    #[test]
+    fn duplicate_name_across_files() {
+        let pyproject_toml = "[project]\nname = \"project\"\n\n[[tool.uv.index]]\nname = \"proxy\"\nurl = \"https://example.com/simple\"\n";
+        let uv_toml = "[[index]]\nname = \"other\"\nurl = \"https://other.com/simple\"\n\n[[index]]\nname = \"proxy\"\nurl = \"https://mirror.com/simple\"\n";
+
+        let err = dedup_indexes(vec![
+            index("proxy", "https://example.com/simple", "tool.uv.index"),
+            index("proxy", "https://mirror.com/simple", "index"),
+        ])
+        .unwrap_err()
+        .with_lines(|definition| {
+            let Some(Origin::Project { path, key }) = &definition.origin else {
+                return Vec::new();
+            };
+            let content = if path.ends_with("uv.toml") {
+                uv_toml
+            } else {
+                pyproject_toml
+            };
+            definition.find_lines(content, key)
+        });
+        assert!(matches!(err, IndexConflictError::DuplicateName { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Index `proxy` is defined with two different URLs: `https://example.com/simple` (`tool.uv.index` in `pyproject.toml`, line 4) and `https://mirror.com/simple` (`index` in `uv.toml`, line 5)"
+        );
+    }
+
+    #[test]
+    fn duplicate_url_inline() {
+        let uv_toml = "index = [\n  { name = \"a\", url = \"https://example.com/simple\" },\n  { name = \"b\", url = \"https://example.com/simple/\" },\n]\n";
+
+        let err = dedup_indexes(vec![
+            index("a", "https://example.com/simple", "index"),
+            index("b", "https://example.com/simple/", "index"),
+        ])
+        .unwrap_err()
+        .with_lines(|definition| definition.find_lines(uv_toml, "index"));
+        assert_eq!(
+            err.to_string(),
+            "Index URL `https://example.com/simple/` is defined under two different names: `a` (`index` in `uv.toml`, line 2) and `b` (`index` in `uv.toml`, line 3)"
+        );
+    }
+}
@@ -67,6 +67,7 @@ pub use crate::file::*;
 pub use crate::hash::*;
 pub use crate::id::*;
 pub use crate::index::*;
+pub use crate::index_conflict::*;
 pub use crate::index_name::*;
 pub use crate::index_url::*;
 pub use crate::installed::*;
@@ -98,6 +99,7 @@ mod file;
 mod hash;
 mod id;
 mod index;
+mod index_conflict;
 mod index_name;
 mod index_url;
 mod installed;
@@ -1,12 +1,83 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use uv_fs::Simplified;
+
 /// The origin of a piece of configuration.
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Origin {
-    /// The setting was provided via the CLI.
-    Cli,
-    /// The setting was provided via a user-level configuration file.
-    User,
-    /// The setting was provided via a project-level configuration file.
-    Project,
+    /// The setting was provided via a command-line flag, e.g., `--index-url`.
+    Flag(String),
+    /// The setting was provided via the environment variable of a command-line flag, e.g.,
+    /// `UV_INDEX_URL`.
+    EnvVar(String),
+    /// The setting was provided via a key in a user-level (or system-level) configuration file,
+    /// e.g., `index-url` in `~/.config/uv/uv.toml`.
+    User { path: PathBuf, key: String },
+    /// The setting was provided via a key in a project-level configuration file, e.g.,
+    /// `tool.uv.index` in a `pyproject.toml`.
+    Project { path: PathBuf, key: String },
     /// The setting was provided via a `requirements.txt` file.
     RequirementsTxt,
+    /// The setting is the built-in default, e.g., PyPI when no default index is configured.
+    Default,
+}
+
+impl Origin {
+    /// Returns `true` if the setting was provided via the CLI, either as a flag or via its
+    /// environment variable.
+    pub fn is_cli(&self) -> bool {
+        matches!(self, Self::Flag(_) | Self::EnvVar(_))
+    }
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Flag(flag) => write!(f, "`--{flag}`"),
+            Self::EnvVar(var) => write!(f, "`{var}`"),
+            Self::User { path, key } | Self::Project { path, key } => {
+                write!(f, "`{key}` in `{}`", path.user_display())
+            }
+            Self::RequirementsTxt => f.write_str("a `requirements.txt` file"),
+            Self::Default => f.write_str("the built-in default"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::Origin;
+
+    #[test]
+    fn display_origin() {
+        assert_eq!(Origin::Flag("index".to_string()).to_string(), "`--index`");
+        assert_eq!(
+            Origin::EnvVar("UV_INDEX".to_string()).to_string(),
+            "`UV_INDEX`"
+        );
+        assert_eq!(
+            Origin::User {
+                path: PathBuf::from("uv.toml"),
+                key: "index".to_string(),
+            }
+            .to_string(),
+            "`index` in `uv.toml`"
+        );
+        assert_eq!(
+            Origin::Project {
+                path: PathBuf::from("pyproject.toml"),
+                key: "tool.uv.index".to_string(),
+            }
+            .to_string(),
+            "`tool.uv.index` in `pyproject.toml`"
+        );
+        assert_eq!(
+            Origin::RequirementsTxt.to_string(),
+            "a `requirements.txt` file"
+        );
+        assert_eq!(Origin::Default.to_string(), "the built-in default");
+    }
 }
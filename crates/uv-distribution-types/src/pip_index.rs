@@ -20,6 +20,12 @@ macro_rules! impl_index {
             }
         }
 
+        impl AsMut<Index> for $name {
+            fn as_mut(&mut self) -> &mut Index {
+                &mut self.0
+            }
+        }
+
         impl From<$name> for Index {
             fn from(value: $name) -> Self {
                 value.0
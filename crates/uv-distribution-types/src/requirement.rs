@@ -3,7 +3,9 @@ use std::io;
 use std::path::Path;
 use std::str::FromStr;
 
+use itertools::Itertools;
 use thiserror::Error;
+use tracing::trace;
 use uv_cache_key::{CacheKey, CacheKeyHasher};
 use uv_distribution_filename::DistExtension;
 use uv_fs::{CWD, PortablePath, PortablePathBuf, relative_to};
@@ -69,8 +71,24 @@ impl Requirement {
     /// When `env` is `None`, this specifically evaluates all marker
     /// expressions based on the environment to `true`. That is, this provides
     /// environment independent marker evaluation.
+    ///
+    /// Each evaluation is logged at trace level under the `uv_pep508::eval` target, along with
+    /// the environment values the markers were evaluated against.
     pub fn evaluate_markers(&self, env: Option<&MarkerEnvironment>, extras: &[ExtraName]) -> bool {
-        self.marker.evaluate_optional_environment(env, extras)
+        let result = self.marker.evaluate_optional_environment(env, extras);
+        if let Some(contents) = self.marker.contents() {
+            trace!(
+                target: "uv_pep508::eval",
+                "Evaluated `{contents}` for `{}` to {result} ({})",
+                self.name,
+                MarkerContext {
+                    marker: self.marker,
+                    env,
+                    extras,
+                }
+            );
+        }
+        result
     }
 
     /// Returns `true` if the requirement is editable.
@@ -476,6 +494,34 @@ impl CacheKey for Requirement {
     }
 }
 
+/// The environment a requirement's markers were evaluated against, for logging.
+struct MarkerContext<'a> {
+    marker: MarkerTree,
+    env: Option<&'a MarkerEnvironment>,
+    extras: &'a [ExtraName],
+}
+
+impl Display for MarkerContext<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.env {
+            Some(env) => {
+                let values = self.marker.environment_values(env);
+                if values.is_empty() {
+                    write!(f, "no environment markers")?;
+                } else {
+                    write!(f, "{}", values.join(", "))?;
+                }
+            }
+            None => write!(f, "environment-independent")?,
+        }
+        if self.extras.is_empty() {
+            write!(f, "; no extras")
+        } else {
+            write!(f, "; extras: {}", self.extras.iter().join(", "))
+        }
+    }
+}
+
 /// The different locations with can install a distribution from: Version specifier (from an index),
 /// HTTP(S) URL, git repository, and path.
 ///
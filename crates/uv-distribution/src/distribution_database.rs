@@ -13,7 +13,8 @@ use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{Instrument, info_span, instrument, warn};
 use url::Url;
 
-use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
+use uv_auth::Realm;
+use uv_cache::{ArchiveId, CacheBucket, CacheEntry, Invalidation, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
@@ -32,6 +33,7 @@ use uv_types::{BuildContext, BuildStack};
 
 use crate::archive::Archive;
 use crate::metadata::{ArchiveMetadata, Metadata};
+use crate::progress::{DownloadProgress, ProgressLogReader};
 use crate::source::SourceDistributionBuilder;
 use crate::{Error, LocalWheel, Reporter, RequiresDist};
 
@@ -605,6 +607,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         // Create an entry for the HTTP cache.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
 
+        // Attribute the download to the host it's served from.
+        let host = Realm::from(&url);
         let download = |response: reqwest::Response| {
             async {
                 let size = size.or_else(|| content_length(&response));
@@ -618,11 +622,13 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .bytes_stream()
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
+                let download_progress = DownloadProgress::start(filename, size);
+                let reader = ProgressLogReader::new(reader.compat(), &download_progress);
 
                 // Create a hasher for each hash algorithm.
                 let algorithms = hashes.algorithms();
                 let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
-                let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+                let mut hasher = uv_extract::hash::HashReader::new(reader, &mut hashers);
 
                 // Download and unzip the wheel to a temporary directory.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
@@ -662,6 +668,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 if !hashes.is_none() {
                     hasher.finish().await.map_err(Error::HashExhaustion)?;
                 }
+                download_progress.complete();
 
                 // Persist the temporary directory to the directory store.
                 let id = self
@@ -681,7 +688,13 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     filename.clone(),
                 ))
             }
-            .instrument(info_span!("wheel", wheel = %dist))
+            .instrument(info_span!(
+                "wheel",
+                wheel = %dist,
+                package = %dist.name(),
+                host = %host,
+                kind = "network"
+            ))
         };
 
         // Fetch the archive from the cache, or download it if necessary.
@@ -725,9 +738,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             })?;
 
         // If the archive is missing the required hashes, or has since been removed, force a refresh.
-        let archive = Some(archive)
-            .filter(|archive| archive.has_digests(hashes))
-            .filter(|archive| archive.exists(self.build_context.cache()));
+        let archive = if !archive.has_digests(hashes) {
+            Invalidation::MissingHashes.log(http_entry.path());
+            None
+        } else if !archive.exists(self.build_context.cache()) {
+            Invalidation::MissingArchive.log(http_entry.path());
+            None
+        } else {
+            Some(archive)
+        };
 
         let archive = if let Some(archive) = archive {
             archive
@@ -776,6 +795,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         // Create an entry for the HTTP cache.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
 
+        // Attribute the download to the host it's served from.
+        let host = Realm::from(&url);
         let download = |response: reqwest::Response| {
             async {
                 let size = size.or_else(|| content_length(&response));
@@ -789,6 +810,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .bytes_stream()
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
+                let download_progress = DownloadProgress::start(filename, size);
+                let mut reader = ProgressLogReader::new(reader.compat(), &download_progress);
 
                 // Download the wheel to a temporary file.
                 let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
@@ -803,19 +826,19 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         // Wrap the reader in a progress reporter. This will report 100% progress
                         // after the download is complete, even if we still have to unzip and hash
                         // part of the file.
-                        let mut reader =
-                            ProgressReader::new(reader.compat(), progress, &**reporter);
+                        let mut reader = ProgressReader::new(reader, progress, &**reporter);
 
                         tokio::io::copy(&mut reader, &mut writer)
                             .await
                             .map_err(Error::CacheWrite)?;
                     }
                     None => {
-                        tokio::io::copy(&mut reader.compat(), &mut writer)
+                        tokio::io::copy(&mut reader, &mut writer)
                             .await
                             .map_err(Error::CacheWrite)?;
                     }
                 }
+                download_progress.complete();
 
                 // Unzip the wheel to a temporary directory.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
@@ -886,7 +909,13 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
                 Ok(Archive::new(id, hashes, filename.clone()))
             }
-            .instrument(info_span!("wheel", wheel = %dist))
+            .instrument(info_span!(
+                "wheel",
+                wheel = %dist,
+                package = %dist.name(),
+                host = %host,
+                kind = "network"
+            ))
         };
 
         // Fetch the archive from the cache, or download it if necessary.
@@ -930,9 +959,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             })?;
 
         // If the archive is missing the required hashes, or has since been removed, force a refresh.
-        let archive = Some(archive)
-            .filter(|archive| archive.has_digests(hashes))
-            .filter(|archive| archive.exists(self.build_context.cache()));
+        let archive = if !archive.has_digests(hashes) {
+            Invalidation::MissingHashes.log(http_entry.path());
+            None
+        } else if !archive.exists(self.build_context.cache()) {
+            Invalidation::MissingArchive.log(http_entry.path());
+            None
+        } else {
+            Some(archive)
+        };
 
         let archive = if let Some(archive) = archive {
             archive
@@ -983,10 +1018,18 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let pointer = LocalArchivePointer::read_from(&pointer_entry)?;
 
         // Extract the archive from the pointer.
-        let archive = pointer
-            .filter(|pointer| pointer.is_up_to_date(modified))
-            .map(LocalArchivePointer::into_archive)
-            .filter(|archive| archive.has_digests(hashes));
+        let archive = pointer.and_then(|pointer| {
+            if !pointer.is_up_to_date(modified) {
+                Invalidation::Modified.log(pointer_entry.path());
+                return None;
+            }
+            let archive = pointer.into_archive();
+            if !archive.has_digests(hashes) {
+                Invalidation::MissingHashes.log(pointer_entry.path());
+                return None;
+            }
+            Some(archive)
+        });
 
         // If the file is already unzipped, and the cache is up-to-date, return it.
         if let Some(archive) = archive {
@@ -1095,6 +1138,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     }
 
     /// Unzip a wheel into the cache, returning the path to the unzipped directory.
+    #[instrument(skip_all, fields(kind = "compute"))]
     async fn unzip_wheel(&self, path: &Path, target: &Path) -> Result<ArchiveId, Error> {
         let temp_dir = tokio::task::spawn_blocking({
             let path = path.to_owned();
@@ -219,7 +219,7 @@ impl LoweredRequirement {
                             // in that order.
                             let Some(index) = locations
                                 .indexes()
-                                .filter(|index| matches!(index.origin, Some(Origin::Cli)))
+                                .filter(|index| index.origin.as_ref().is_some_and(Origin::is_cli))
                                 .chain(project_indexes.iter())
                                 .chain(workspace.indexes().iter())
                                 .find(|Index { name, .. }| {
@@ -231,8 +231,12 @@ impl LoweredRequirement {
                                     index,
                                 ));
                             };
-                            if let Some(credentials) = index.credentials() {
-                                credentials_cache.store_credentials(index.raw_url(), credentials);
+                            if let Some((credentials, source)) = index.credentials() {
+                                credentials_cache.store_credentials(
+                                    index.raw_url(),
+                                    credentials,
+                                    source,
+                                );
                             }
                             let index = IndexMetadata {
                                 url: index.url.clone(),
@@ -456,7 +460,7 @@ impl LoweredRequirement {
                         Source::Registry { index, marker, .. } => {
                             let Some(index) = locations
                                 .indexes()
-                                .filter(|index| matches!(index.origin, Some(Origin::Cli)))
+                                .filter(|index| index.origin.as_ref().is_some_and(Origin::is_cli))
                                 .chain(indexes.iter())
                                 .find(|Index { name, .. }| {
                                     name.as_ref().is_some_and(|name| *name == index)
@@ -467,8 +471,12 @@ impl LoweredRequirement {
                                     index,
                                 ));
                             };
-                            if let Some(credentials) = index.credentials() {
-                                credentials_cache.store_credentials(index.raw_url(), credentials);
+                            if let Some((credentials, source)) = index.credentials() {
+                                credentials_cache.store_credentials(
+                                    index.raw_url(),
+                                    credentials,
+                                    source,
+                                );
                             }
                             let index = IndexMetadata {
                                 url: index.url.clone(),
@@ -0,0 +1,187 @@
+//! Progress milestones for downloads, logged under the `uv_distribution::progress` target.
+//!
+//! Unlike the progress bars, which are only rendered to the terminal, these events are recorded
+//! by the logging subscriber. The number of events per download is bounded: a download with a
+//! known size logs at most one event per milestone, and a download with an unknown size logs at
+//! most one event per interval.
+
+use std::fmt::Display;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Mutex, PoisonError};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, ReadBuf};
+use tracing::debug;
+
+/// The percentages at which progress is logged for downloads with a known size.
+const MILESTONES: [u64; 4] = [25, 50, 75, 100];
+
+/// The interval at which progress is logged for downloads with an unknown size.
+const UNSIZED_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The progress of a single download.
+#[derive(Debug)]
+pub(crate) struct DownloadProgress {
+    name: String,
+    total: Option<u64>,
+    start: Instant,
+    state: Mutex<ProgressState>,
+}
+
+#[derive(Debug)]
+struct ProgressState {
+    /// The number of bytes read so far.
+    bytes: u64,
+    /// The index of the next milestone to log, for downloads with a known size.
+    milestone: usize,
+    /// The time at which progress was last logged, for downloads with an unknown size.
+    logged_at: Instant,
+}
+
+impl DownloadProgress {
+    /// Log the start of a download.
+    pub(crate) fn start(name: impl Display, total: Option<u64>) -> Self {
+        let name = name.to_string();
+        if let Some(total) = total {
+            debug!("Downloading {name} ({total} bytes)");
+        } else {
+            debug!("Downloading {name} (unknown size)");
+        }
+        let start = Instant::now();
+        Self {
+            name,
+            total,
+            start,
+            state: Mutex::new(ProgressState {
+                bytes: 0,
+                milestone: 0,
+                logged_at: start,
+            }),
+        }
+    }
+
+    /// Record that `bytes` more bytes were read, logging any milestone that was reached.
+    fn record(&self, bytes: u64) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.bytes += bytes;
+
+        match self.total {
+            Some(total) if total > 0 => {
+                // If a single read crosses several milestones, only log the last of them.
+                let percent = state.bytes.saturating_mul(100) / total;
+                let mut reached = None;
+                while let Some(&milestone) = MILESTONES.get(state.milestone) {
+                    if percent < milestone {
+                        break;
+                    }
+                    reached = Some(milestone);
+                    state.milestone += 1;
+                }
+                if let Some(milestone) = reached {
+                    debug!(
+                        "Downloaded {milestone}% of {}: {} of {total} bytes ({})",
+                        self.name,
+                        state.bytes,
+                        throughput(state.bytes, self.start.elapsed())
+                    );
+                }
+            }
+            _ => {
+                let now = Instant::now();
+                if now.duration_since(state.logged_at) >= UNSIZED_INTERVAL {
+                    state.logged_at = now;
+                    debug!(
+                        "Downloaded {} bytes of {} so far ({})",
+                        state.bytes,
+                        self.name,
+                        throughput(state.bytes, self.start.elapsed())
+                    );
+                }
+            }
+        }
+    }
+
+    /// Log the completion of the download.
+    pub(crate) fn complete(&self) {
+        let bytes = self
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .bytes;
+        let elapsed = self.start.elapsed();
+        debug!(
+            "Finished downloading {}: {bytes} bytes in {elapsed:.2?} ({})",
+            self.name,
+            throughput(bytes, elapsed)
+        );
+    }
+}
+
+/// Format the average throughput of a download.
+#[expect(clippy::cast_precision_loss)]
+fn throughput(bytes: u64, elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs_f64();
+    if seconds > 0.0 {
+        format!("{:.2} MiB/s", bytes as f64 / (1024.0 * 1024.0) / seconds)
+    } else {
+        "unknown throughput".to_string()
+    }
+}
+
+/// An asynchronous reader that records the [`DownloadProgress`] as bytes are read.
+pub(crate) struct ProgressLogReader<'a, R> {
+    reader: R,
+    progress: &'a DownloadProgress,
+}
+
+impl<'a, R> ProgressLogReader<'a, R> {
+    /// Create a new [`ProgressLogReader`] that wraps another reader.
+    pub(crate) fn new(reader: R, progress: &'a DownloadProgress) -> Self {
+        Self { reader, progress }
+    }
+}
+
+impl<R> AsyncRead for ProgressLogReader<'_, R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before = buf.filled().len();
+        Pin::new(&mut self.as_mut().reader)
+            .poll_read(cx, buf)
+            .map_ok(|()| {
+                self.progress.record((buf.filled().len() - before) as u64);
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn milestones() {
+        let progress = DownloadProgress::start("example.whl", Some(1000));
+
+        progress.record(100);
+        assert_eq!(progress.state.lock().unwrap().milestone, 0);
+
+        // Crossing 25% and 50% in a single read advances past both milestones.
+        progress.record(500);
+        assert_eq!(progress.state.lock().unwrap().milestone, 2);
+
+        progress.record(400);
+        assert_eq!(progress.state.lock().unwrap().milestone, MILESTONES.len());
+
+        // Reads past the expected size don't log further events.
+        progress.record(100);
+        assert_eq!(progress.state.lock().unwrap().milestone, MILESTONES.len());
+        assert_eq!(progress.state.lock().unwrap().bytes, 1100);
+    }
+}
@@ -0,0 +1,131 @@
+//! Inconsistencies between the metadata of a source distribution that was available before a
+//! build, e.g., as advertised by the index or as read from `PKG-INFO`, and the metadata of the
+//! wheel that was built from it.
+//!
+//! The resolver may have relied on the former, while the latter describes what's installed, so
+//! any disagreement is logged at `WARN`. The built wheel's metadata is trusted, and replaces any
+//! cached metadata.
+
+use std::path::Path;
+
+use tracing::warn;
+
+use uv_cache::CacheEntry;
+use uv_distribution_types::{BuildableSource, SourceDist};
+use uv_pep440::VersionSpecifiers;
+use uv_pypi_types::ResolutionMetadata;
+
+use crate::source::{CachedMetadata, read_pkg_info};
+
+/// Warn about any fields of the built metadata that disagree with the metadata that was available
+/// before the build.
+///
+/// The metadata that was prepared via `prepare_metadata_for_build_wheel` is read from the cache
+/// entry, if it exists. Otherwise, the static `PKG-INFO` metadata is used, except for source trees,
+/// where it could be out-of-date.
+pub(super) async fn warn_inconsistencies(
+    source: &BuildableSource<'_>,
+    source_root: &Path,
+    subdirectory: Option<&Path>,
+    metadata_entry: &CacheEntry,
+    built: &ResolutionMetadata,
+) {
+    // The index may advertise the `Requires-Python` of a source distribution.
+    if let BuildableSource::Dist(SourceDist::Registry(dist)) = source
+        && let Some(requires_python) = &dist.file.requires_python
+    {
+        warn_field(
+            source,
+            "Requires-Python",
+            "index",
+            &specifiers(Some(requires_python)),
+            &specifiers(built.requires_python.as_ref()),
+        );
+    }
+
+    if let Ok(Some(prepared)) = CachedMetadata::read(metadata_entry).await {
+        warn_metadata(
+            source,
+            "prepared metadata",
+            &ResolutionMetadata::from(prepared),
+            built,
+        );
+    } else if !source.is_source_tree()
+        && let Ok(pkg_info) = read_pkg_info(source_root, subdirectory).await
+    {
+        warn_metadata(source, "`PKG-INFO`", &pkg_info, built);
+    }
+}
+
+/// Warn about each field that differs between the `expected` and the `built` metadata.
+fn warn_metadata(
+    source: &BuildableSource<'_>,
+    origin: &str,
+    expected: &ResolutionMetadata,
+    built: &ResolutionMetadata,
+) {
+    warn_field(
+        source,
+        "Version",
+        origin,
+        &expected.version.to_string(),
+        &built.version.to_string(),
+    );
+    warn_field(
+        source,
+        "Requires-Dist",
+        origin,
+        &requirements(expected),
+        &requirements(built),
+    );
+    warn_field(
+        source,
+        "Requires-Python",
+        origin,
+        &specifiers(expected.requires_python.as_ref()),
+        &specifiers(built.requires_python.as_ref()),
+    );
+}
+
+fn warn_field(
+    source: &BuildableSource<'_>,
+    field: &str,
+    origin: &str,
+    expected: &str,
+    built: &str,
+) {
+    if expected == built {
+        return;
+    }
+    warn!(
+        "`{field}` of `{source}` differs between the {origin} (`{expected}`) and the built wheel (`{built}`); using the built wheel"
+    );
+}
+
+/// Format the `Requires-Dist` of the metadata, independent of the order of the requirements.
+fn requirements(metadata: &ResolutionMetadata) -> String {
+    let mut requirements = metadata
+        .requires_dist
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    if requirements.is_empty() {
+        return "none".to_string();
+    }
+    requirements.sort();
+    requirements.join(", ")
+}
+
+/// Format a `Requires-Python`, independent of the order of the specifiers.
+fn specifiers(specifiers: Option<&VersionSpecifiers>) -> String {
+    let mut specifiers = specifiers
+        .into_iter()
+        .flat_map(|specifiers| specifiers.iter())
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    if specifiers.is_empty() {
+        return "none".to_string();
+    }
+    specifiers.sort();
+    specifiers.join(", ")
+}
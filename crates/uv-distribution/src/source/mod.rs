@@ -13,6 +13,7 @@ use std::ops::Bound;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Instant;
 
 use fs_err::tokio as fs;
 use futures::{FutureExt, TryStreamExt};
@@ -22,8 +23,10 @@ use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 use zip::ZipArchive;
 
-use uv_auth::CredentialsCache;
-use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
+use uv_auth::{CredentialsCache, Realm};
+use uv_cache::{
+    Cache, CacheBucket, CacheEntry, CacheShard, CacheUsage, Invalidation, Removal, WheelCache,
+};
 use uv_cache_info::CacheInfo;
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
@@ -51,11 +54,13 @@ use uv_workspace::pyproject::ToolUvSources;
 use crate::distribution_database::ManagedClient;
 use crate::error::Error;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
+use crate::progress::{DownloadProgress, ProgressLogReader};
 use crate::source::built_wheel_metadata::{BuiltWheelFile, BuiltWheelMetadata};
 use crate::source::revision::Revision;
 use crate::{Reporter, RequiresDist};
 
 mod built_wheel_metadata;
+mod consistency;
 mod revision;
 
 /// Fetch and build a source distribution from a remote source, or from a local cache.
@@ -465,7 +470,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await?;
 
         // Before running the build, check that the hashes match.
-        if !revision.satisfies(hashes) {
+        if !revision.verify(&source.to_string(), hashes) {
             return Err(Error::hash_mismatch(
                 source.to_string(),
                 hashes.digests(),
@@ -595,7 +600,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await?;
 
         // Before running the build, check that the hashes match.
-        if !revision.satisfies(hashes) {
+        if !revision.verify(&source.to_string(), hashes) {
             return Err(Error::hash_mismatch(
                 source.to_string(),
                 hashes.digests(),
@@ -808,7 +813,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 Ok(revision.with_hashes(HashDigests::from(hashes)))
             }
             .boxed_local()
-            .instrument(info_span!("download", source_dist = %source))
+            .instrument(info_span!(
+                "download",
+                source_dist = %source,
+                package = source.name().map(PackageName::as_str),
+                host = %Realm::from(url),
+                kind = "network"
+            ))
         };
         let req = Self::request(url.clone(), client.unmanaged)?;
         let revision = client
@@ -830,6 +841,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         if revision.has_digests(hashes) {
             Ok(revision)
         } else {
+            Invalidation::MissingHashes.log(cache_entry.path());
             client
                 .managed(async |client| {
                     client
@@ -870,7 +882,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await?;
 
         // Before running the build, check that the hashes match.
-        if !revision.satisfies(hashes) {
+        if !revision.verify(&source.to_string(), hashes) {
             return Err(Error::hash_mismatch(
                 source.to_string(),
                 hashes.digests(),
@@ -972,7 +984,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await?;
 
         // Before running the build, check that the hashes match.
-        if !revision.satisfies(hashes) {
+        if !revision.verify(&source.to_string(), hashes) {
             return Err(Error::hash_mismatch(
                 source.to_string(),
                 hashes.digests(),
@@ -1130,10 +1142,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // If the revision already exists, return it. There's no need to check for freshness, since
         // we use an exact timestamp.
         if let Some(pointer) = LocalRevisionPointer::read_from(&revision_entry)? {
-            if *pointer.cache_info() == cache_info {
-                if pointer.revision().has_digests(hashes) {
-                    return Ok(pointer);
-                }
+            if *pointer.cache_info() != cache_info {
+                Invalidation::Modified.log(revision_entry.path());
+            } else if !pointer.revision().has_digests(hashes) {
+                Invalidation::MissingHashes.log(revision_entry.path());
+            } else {
+                return Ok(pointer);
             }
         }
 
@@ -2265,7 +2279,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 Ok(revision.clone().with_hashes(HashDigests::from(hashes)))
             }
             .boxed_local()
-            .instrument(info_span!("download", source_dist = %source))
+            .instrument(info_span!(
+                "download",
+                source_dist = %source,
+                package = source.name().map(PackageName::as_str),
+                host = %Realm::from(url),
+                kind = "network"
+            ))
         };
         client
             .managed(async |client| {
@@ -2301,10 +2321,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .bucket(CacheBucket::SourceDistributions),
         )
         .map_err(Error::CacheWrite)?;
+        let size = response.content_length();
         let reader = response
             .bytes_stream()
             .map_err(std::io::Error::other)
             .into_async_read();
+        let download_progress = DownloadProgress::start(source, size);
+        let reader = ProgressLogReader::new(reader.compat(), &download_progress);
 
         // Create a hasher for each hash algorithm.
         let mut hashers = algorithms
@@ -2312,7 +2335,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .copied()
             .map(Hasher::from)
             .collect::<Vec<_>>();
-        let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+        let mut hasher = uv_extract::hash::HashReader::new(reader, &mut hashers);
 
         // Download and unzip the source distribution into a temporary directory.
         let span = info_span!("download_source_dist", source_dist = %source);
@@ -2325,6 +2348,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         if !algorithms.is_empty() {
             hasher.finish().await.map_err(Error::HashExhaustion)?;
         }
+        download_progress.complete();
 
         let hashes = hashers.into_iter().map(HashDigest::from).collect();
 
@@ -2427,7 +2451,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     /// Build a source distribution, storing the built wheel in the cache.
     ///
     /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
-    #[instrument(skip_all, fields(dist = %source))]
+    #[instrument(skip_all, fields(dist = %source, package = source.name().map(PackageName::as_str)))]
     async fn build_distribution(
         &self,
         source: &BuildableSource<'_>,
@@ -2567,13 +2591,37 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         validate_metadata(source, &metadata)?;
         validate_filename(&filename, &metadata)?;
 
+        // Warn if the metadata disagrees with the metadata that was available before the build.
+        consistency::warn_inconsistencies(
+            source,
+            source_root,
+            subdirectory,
+            &cache_shard.entry(METADATA),
+            &metadata,
+        )
+        .await;
+
         // Move the wheel to the cache.
+        let start = Instant::now();
+        let span = info_span!(
+            "archive_built_wheel",
+            dist = %source,
+            package = source.name().map(PackageName::as_str),
+        );
         rename_with_retry(
             temp_dir.path().join(&disk_filename),
             cache_shard.join(&disk_filename),
         )
+        .instrument(span)
         .await
         .map_err(Error::CacheWrite)?;
+        debug!(
+            "Finished `archive_built_wheel` for `{source}` in {:.2?}",
+            start.elapsed()
+        );
+        if let Ok(metadata) = fs::metadata(cache_shard.join(&disk_filename)).await {
+            CacheUsage::write(&cache_shard.join(&disk_filename), metadata.len());
+        }
 
         debug!("Built `{source}` into `{disk_filename}`");
         Ok((disk_filename, filename, metadata))
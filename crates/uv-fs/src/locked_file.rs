@@ -6,7 +6,9 @@ use std::time::Duration;
 use std::{env, io};
 
 use thiserror::Error;
-use tracing::{debug, error, info, trace, warn};
+#[cfg(feature = "tokio")]
+use tracing::{Instrument, info_span};
+use tracing::{debug, error, trace, warn};
 
 use uv_static::EnvVars;
 
@@ -34,6 +36,14 @@ static LOCK_TIMEOUT: LazyLock<Duration> = LazyLock::new(|| {
     }
 });
 
+/// How long to wait for a contended lock before telling the user about it.
+#[cfg(feature = "tokio")]
+const LOCK_WAIT_NOTICE: Duration = Duration::from_secs(1);
+
+/// How often to remind the user that we're still waiting for a contended lock.
+#[cfg(feature = "tokio")]
+const LOCK_WAIT_INTERVAL: Duration = Duration::from_secs(5);
+
 #[derive(Debug, Error)]
 pub enum LockedFileError {
     #[error(
@@ -129,7 +139,11 @@ impl Display for LockedFileMode {
 #[cfg(feature = "tokio")]
 #[derive(Debug)]
 #[must_use]
-pub struct LockedFile(fs_err::File);
+pub struct LockedFile {
+    file: fs_err::File,
+    /// The metadata file naming this process as the holder of the lock, if written.
+    holder: Option<PathBuf>,
+}
 
 #[cfg(feature = "tokio")]
 impl LockedFile {
@@ -148,7 +162,7 @@ impl LockedFile {
         let file = match try_lock_exclusive.await? {
             (Ok(()), file) => {
                 debug!("Acquired {mode} lock for `{resource}`");
-                return Ok(Self(file));
+                return Ok(Self::new(file));
             }
             (Err(err), file) => {
                 // Log error code and enum kind to help debugging more exotic failures.
@@ -159,14 +173,60 @@ impl LockedFile {
             }
         };
 
-        // If there's lock contention, wait and break deadlocks with a timeout if necessary.
-        info!(
+        // If there's lock contention, wait and break deadlocks with a timeout if necessary. The wait
+        // is recorded as a dedicated span, as the process otherwise appears to be idle.
+        let holder = LockHolder::find(&file);
+        let span = info_span!(
+            "acquire_lock",
+            resource,
+            %mode,
+            holder_pid = tracing::field::Empty
+        );
+        if let Some(holder) = &holder {
+            span.record("holder_pid", holder.pid);
+        }
+        Self::wait_for_lock(file, mode, resource, holder.as_ref())
+            .instrument(span)
+            .await
+    }
+
+    /// Wait for a lock that is held by another process.
+    async fn wait_for_lock(
+        file: fs_err::File,
+        mode: LockedFileMode,
+        resource: &str,
+        holder: Option<&LockHolder>,
+    ) -> Result<Self, LockedFileError> {
+        debug!(
             "Waiting to acquire {mode} lock for `{resource}` at `{}`",
             file.path().user_display(),
         );
         let path = file.path().to_path_buf();
-        let lock_exclusive = tokio::task::spawn_blocking(move || (mode.lock(&file), file));
-        let (result, file) = tokio::time::timeout(*LOCK_TIMEOUT, lock_exclusive)
+        let start = tokio::time::Instant::now();
+        let mut lock_exclusive = tokio::task::spawn_blocking(move || (mode.lock(&file), file));
+        let lock = async {
+            // Tell the user who we're waiting on after a second, and then periodically, such that
+            // uv doesn't appear to be frozen.
+            let mut notices = 0;
+            loop {
+                let elapsed = if notices == 0 {
+                    LOCK_WAIT_NOTICE
+                } else {
+                    LOCK_WAIT_INTERVAL * notices
+                };
+                if let Ok(result) =
+                    tokio::time::timeout_at(start + elapsed, &mut lock_exclusive).await
+                {
+                    return result;
+                }
+                notices += 1;
+                // Don't announce a wait that's about to time out.
+                if elapsed < *LOCK_TIMEOUT {
+                    report_wait(resource, holder, elapsed);
+                }
+            }
+        };
+        let (result, file) = tokio::time::timeout(*LOCK_TIMEOUT, lock)
             .await
             .map_err(|_| LockedFileError::Timeout {
                 timeout: *LOCK_TIMEOUT,
@@ -180,8 +240,11 @@ impl LockedFile {
             source: err,
         })?;
 
-        debug!("Acquired {mode} lock for `{resource}`");
-        Ok(Self(file))
+        debug!(
+            "Acquired {mode} lock for `{resource}` after waiting {:.2}s",
+            start.elapsed().as_secs_f64()
+        );
+        Ok(Self::new(file))
     }
 
     /// Inner implementation for [`LockedFile::acquire_no_wait`].
@@ -193,7 +256,7 @@ impl LockedFile {
         match mode.try_lock(&file) {
             Ok(()) => {
                 debug!("Acquired {mode} lock for `{resource}`");
-                Some(Self(file))
+                Some(Self::new(file))
             }
             Err(err) => {
                 // Log error code and enum kind to help debugging more exotic failures.
@@ -206,6 +269,28 @@ impl LockedFile {
         }
     }
 
+    fn new(file: fs_err::File) -> Self {
+        Self { file, holder: None }
+    }
+
+    /// Record the current process as the holder of the lock, such that processes waiting on the
+    /// lock can name it.
+    ///
+    /// Only exclusive locks should be recorded, as a shared lock may have many holders. The
+    /// metadata is written next to the lock file on a best-effort basis, and removed when the lock
+    /// is released.
+    pub fn with_holder(mut self) -> Self {
+        let path = LockHolder::path(self.file.path());
+        match LockHolder::write(&path) {
+            Ok(()) => self.holder = Some(path),
+            Err(err) => debug!(
+                "Failed to write lock holder to `{}`: {err}",
+                path.user_display()
+            ),
+        }
+        self
+    }
+
     /// Acquire a cross-process lock for a resource using a file at the provided path.
     pub async fn acquire(
         path: impl AsRef<Path>,
@@ -337,17 +422,233 @@ impl LockedFile {
     }
 }
 
+/// Tell the user that we're still waiting for a lock, unless warnings are disabled.
+#[cfg(feature = "tokio")]
+fn report_wait(resource: &str, holder: Option<&LockHolder>, elapsed: Duration) {
+    if let Some(holder) = holder {
+        uv_warnings::warn_user!(
+            "Waiting for lock on `{resource}` held by {holder}, {}s...",
+            elapsed.as_secs()
+        );
+    } else {
+        uv_warnings::warn_user!(
+            "Waiting for lock on `{resource}` held by another process, {}s...",
+            elapsed.as_secs()
+        );
+    }
+}
+
+/// The process holding a lock, as recorded in the metadata file next to the lock file.
+///
+/// The metadata is advisory: it's written by the holder after acquiring the lock, is replaced
+/// atomically, and may be missing or stale, so readers must tolerate its absence.
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+struct LockHolder {
+    pid: u32,
+    /// The command the holder is running, e.g., `uv sync`.
+    command: Option<String>,
+}
+
+#[cfg(feature = "tokio")]
+impl LockHolder {
+    /// Return the path to the metadata file for a lock file, e.g., `.lock.holder` for `.lock`.
+    fn path(lock: &Path) -> PathBuf {
+        let mut path = lock.as_os_str().to_owned();
+        path.push(".holder");
+        PathBuf::from(path)
+    }
+
+    /// Determine the process that holds a lock on the file.
+    ///
+    /// If the OS reports a holder, the metadata is only used if it names the same process, as it
+    /// may have been left behind by a process that exited without releasing the lock cleanly.
+    fn find(file: &fs_err::File) -> Option<Self> {
+        let metadata = Self::read(&Self::path(file.path()));
+        match (lock_holder(file), metadata) {
+            (Some(pid), Some(holder)) if holder.pid == pid => Some(holder),
+            (Some(pid), _) => Some(Self { pid, command: None }),
+            (None, metadata) => metadata,
+        }
+    }
+
+    /// Read the holder from a metadata file, e.g., `1234\nuv sync\n`.
+    fn read(path: &Path) -> Option<Self> {
+        let contents = fs_err::read_to_string(path).ok()?;
+        let mut lines = contents.lines();
+        let pid = lines.next()?.parse().ok()?;
+        let command = lines
+            .next()
+            .filter(|command| !command.is_empty())
+            .map(ToString::to_string);
+        Some(Self { pid, command })
+    }
+
+    /// Write the current process to a metadata file.
+    ///
+    /// The file is written to a temporary file and then renamed into place, such that concurrent
+    /// readers never observe a partially written file.
+    fn write(path: &Path) -> io::Result<()> {
+        use std::io::Write;
+
+        let mut file = tempfile::NamedTempFile::new_in(path.parent().unwrap_or(Path::new(".")))?;
+        writeln!(file, "{}", std::process::id())?;
+        writeln!(file, "{}", current_command())?;
+        file.persist(path).map_err(|err| err.error)?;
+        Ok(())
+    }
+
+    /// Remove the metadata file, if it still names the current process.
+    fn remove(path: &Path) {
+        if Self::read(path).is_some_and(|holder| holder.pid == std::process::id())
+            && let Err(err) = fs_err::remove_file(path)
+        {
+            trace!("Failed to remove lock holder: {err}");
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Display for LockHolder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(command) = &self.command {
+            write!(f, "PID {} (`{command}`)", self.pid)
+        } else {
+            write!(f, "PID {}", self.pid)
+        }
+    }
+}
+
+/// Describe the command the current process is running, e.g., `uv sync` or `uv pip install`.
+///
+/// Only the program name and the leading subcommands are included, as the remaining arguments may
+/// contain credentials.
+#[cfg(feature = "tokio")]
+fn current_command() -> String {
+    let mut args = env::args_os();
+    let program = args
+        .next()
+        .and_then(|program| {
+            Path::new(&program)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })
+        .unwrap_or_default();
+    let subcommands = args
+        .map_while(|arg| arg.into_string().ok())
+        .take_while(|arg| {
+            !arg.is_empty()
+                && arg
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                && !arg.starts_with('-')
+        })
+        .take(2);
+    std::iter::once(program)
+        .chain(subcommands)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Determine the ID of a process that holds a lock on the file, if possible.
+///
+/// This is only supported on Linux, where the holders of `flock` locks are listed in `/proc/locks`.
+#[cfg(all(feature = "tokio", target_os = "linux"))]
+fn lock_holder(file: &fs_err::File) -> Option<u32> {
+    use std::os::linux::fs::MetadataExt;
+
+    let metadata = file.metadata().ok()?;
+    let id = format!(
+        "{:02x}:{:02x}:{}",
+        rustix::fs::major(metadata.st_dev()),
+        rustix::fs::minor(metadata.st_dev()),
+        metadata.st_ino()
+    );
+    let locks = fs_err::read_to_string("/proc/locks").ok()?;
+    parse_lock_holder(&locks, &id)
+}
+
+#[cfg(all(feature = "tokio", not(target_os = "linux")))]
+fn lock_holder(_file: &fs_err::File) -> Option<u32> {
+    None
+}
+
+/// Find the process holding a lock on the file with the given `major:minor:inode` ID in the
+/// contents of `/proc/locks`, e.g., `1: FLOCK  ADVISORY  WRITE 4242 fd:01:1234 0 EOF`.
+#[cfg(any(test, all(feature = "tokio", target_os = "linux")))]
+fn parse_lock_holder(locks: &str, id: &str) -> Option<u32> {
+    locks.lines().find_map(|line| {
+        let mut fields = line.split_whitespace().skip(1);
+        // Skip processes that are waiting on a lock, rather than holding it.
+        if fields.next()? == "->" {
+            return None;
+        }
+        // Open file description locks aren't associated with a process, and have a PID of `-1`.
+        let pid = fields.nth(2)?.parse().ok()?;
+        (fields.next()? == id).then_some(pid)
+    })
+}
+
 #[cfg(feature = "tokio")]
 impl Drop for LockedFile {
     /// Unlock the file.
     fn drop(&mut self) {
-        if let Err(err) = self.0.unlock() {
+        // Remove the metadata before unlocking, such that it can't name us once another process
+        // holds the lock.
+        if let Some(holder) = &self.holder {
+            LockHolder::remove(holder);
+        }
+        if let Err(err) = self.file.unlock() {
             error!(
                 "Failed to unlock resource at `{}`; program may be stuck: {err}",
-                self.0.path().display()
+                self.file.path().display()
             );
         } else {
-            debug!("Released lock at `{}`", self.0.path().display());
+            debug!("Released lock at `{}`", self.file.path().display());
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_lock_holder;
+    #[cfg(feature = "tokio")]
+    use super::{LockHolder, LockedFile, LockedFileMode};
+
+    /// An exclusive lock records the current process as its holder until it's released.
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn lock_holder_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        let lock = dir.path().join(".lock");
+        let holder = LockHolder::path(&lock);
+
+        let locked = LockedFile::acquire_no_wait(&lock, LockedFileMode::Exclusive, "cache")
+            .unwrap()
+            .with_holder();
+        let found = LockHolder::find(&locked.file).unwrap();
+        assert_eq!(found.pid, std::process::id());
+        assert!(
+            found
+                .to_string()
+                .starts_with(&format!("PID {}", std::process::id()))
+        );
+
+        drop(locked);
+        assert!(!holder.exists());
+    }
+
+    #[test]
+    fn lock_holder() {
+        let locks = "\
+1: POSIX  ADVISORY  WRITE 1001 00:1a:77 0 EOF
+2: FLOCK  ADVISORY  WRITE 1002 fd:01:1234 0 EOF
+2: -> FLOCK  ADVISORY  WRITE 1003 fd:01:1234 0 EOF
+3: OFDLCK ADVISORY  READ  -1 fd:01:5678 0 EOF
+";
+        assert_eq!(parse_lock_holder(locks, "fd:01:1234"), Some(1002));
+        assert_eq!(parse_lock_holder(locks, "00:1a:77"), Some(1001));
+        assert_eq!(parse_lock_holder(locks, "fd:01:5678"), None);
+        assert_eq!(parse_lock_holder(locks, "fd:01:9999"), None);
+    }
+}
@@ -25,7 +25,7 @@ use crate::{Error, Layout};
 /// <https://packaging.python.org/en/latest/specifications/binary-distribution-format/#installing-a-wheel-distribution-1-0-py32-none-any-whl>
 ///
 /// Wheel 1.0: <https://www.python.org/dev/peps/pep-0427/>
-#[instrument(skip_all, fields(wheel = %filename))]
+#[instrument(skip_all, fields(wheel = %filename, package = %filename.name))]
 pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     layout: &Layout,
     relocatable: bool,
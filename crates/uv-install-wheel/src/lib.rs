@@ -12,11 +12,13 @@ use uv_pep440::Version;
 use uv_pypi_types::Scheme;
 
 pub use install::install_wheel;
+pub use link_usage::LinkUsage;
 pub use linker::{LinkMode, Locks};
 pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
 pub use wheel::{LibKind, WheelFile, read_record_file};
 
 mod install;
+mod link_usage;
 mod linker;
 mod record;
 mod script;
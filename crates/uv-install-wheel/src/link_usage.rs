@@ -0,0 +1,246 @@
+//! The link mode used to install each wheel, logged per package and counted per mode under the
+//! `uv_install_wheel::link_usage` target once the command completes.
+//!
+//! Installing from the cache is much faster when files can be hardlinked or cloned rather than
+//! copied, so each fallback to copying is logged along with the reason and the filesystems
+//! involved. The counts are process-wide, like the usage of the cache.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use tracing::debug;
+
+use uv_distribution_filename::WheelFilename;
+
+use crate::LinkMode;
+
+/// The number of wheels installed with each outcome, keyed by its description, e.g.,
+/// `copy (user requested)`.
+static USAGE: Mutex<BTreeMap<String, u64>> = Mutex::new(BTreeMap::new());
+
+/// Whether the link mode was configured explicitly, e.g., via `--link-mode`.
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// The usage of each link mode by the current command.
+///
+/// The counts are logged when the value returned by [`LinkUsage::track`] is dropped, i.e., once
+/// the command completes (or fails).
+#[derive(Debug)]
+pub struct LinkUsage(());
+
+impl LinkUsage {
+    /// Track the usage of each link mode until the returned value is dropped.
+    #[must_use]
+    pub fn track() -> Self {
+        Self(())
+    }
+
+    /// Resolve the configured link mode, falling back to the platform default.
+    ///
+    /// A configured link mode is reported as user requested, even if it matches the default.
+    pub fn resolve(link_mode: Option<LinkMode>) -> LinkMode {
+        if link_mode.is_some() {
+            REQUESTED.store(true, Ordering::Relaxed);
+        }
+        link_mode.unwrap_or_default()
+    }
+}
+
+impl Drop for LinkUsage {
+    fn drop(&mut self) {
+        let usage = std::mem::take(&mut *USAGE.lock().unwrap_or_else(PoisonError::into_inner));
+        for (outcome, packages) in usage {
+            debug!(
+                target: "uv_install_wheel::link_usage",
+                outcome = outcome.as_str(),
+                packages,
+                "Linked {packages} {} via {outcome}",
+                if packages == 1 { "package" } else { "packages" }
+            );
+        }
+    }
+}
+
+/// The reason a link failed, such that the remaining files were copied instead.
+#[derive(Debug)]
+enum FallbackReason {
+    /// The cache and the target directory are on different filesystems.
+    CrossDevice,
+    /// The filesystem doesn't support the link mode, e.g., reflinks on ext4.
+    Unsupported,
+    /// The link was denied, e.g., due to the permissions of the source files.
+    PermissionDenied,
+    /// Any other error.
+    Other(String),
+}
+
+impl From<&io::Error> for FallbackReason {
+    fn from(err: &io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::CrossesDevices => Self::CrossDevice,
+            io::ErrorKind::Unsupported => Self::Unsupported,
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied,
+            _ => Self::Other(err.to_string()),
+        }
+    }
+}
+
+/// The link mode that was used to install a wheel, and why a faster mode wasn't used, if any.
+#[derive(Debug)]
+pub(crate) struct LinkOutcome {
+    mode: LinkMode,
+    /// Whether the link mode was configured explicitly.
+    requested: bool,
+    fallback: Option<FallbackReason>,
+}
+
+impl LinkOutcome {
+    pub(crate) fn new(mode: LinkMode) -> Self {
+        Self {
+            mode,
+            requested: REQUESTED.load(Ordering::Relaxed),
+            fallback: None,
+        }
+    }
+
+    /// Record that linking a file failed with the given error, such that the files are copied.
+    pub(crate) fn fall_back(&mut self, err: &io::Error) {
+        if self.fallback.is_none() {
+            self.fallback = Some(FallbackReason::from(err));
+        }
+    }
+
+    /// Log the outcome for a wheel linked from `wheel` into `site_packages`, and count it towards
+    /// the [`LinkUsage`].
+    pub(crate) fn finish(self, filename: &WheelFilename, wheel: &Path, site_packages: &Path) {
+        let outcome = self.to_string();
+        if self.fallback.is_some()
+            && let (Some(from), Some(to)) = (filesystem(wheel), filesystem(site_packages))
+        {
+            debug!("Linked {filename} via {outcome}, from {from} to {to}");
+        } else {
+            debug!("Linked {filename} via {outcome}");
+        }
+        *USAGE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(outcome)
+            .or_default() += 1;
+    }
+}
+
+impl Display for LinkOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(reason) = &self.fallback else {
+            // Only the default link mode can be used without being requested.
+            return if self.requested || self.mode != LinkMode::default() {
+                write!(f, "{} (user requested)", self.mode)
+            } else {
+                write!(f, "{}", self.mode)
+            };
+        };
+        let mode = self.mode;
+        match reason {
+            FallbackReason::CrossDevice => write!(f, "copy ({mode} failed: cross-device)"),
+            FallbackReason::Unsupported => {
+                let link = if mode == LinkMode::Clone {
+                    "reflink".to_string()
+                } else {
+                    mode.to_string()
+                };
+                write!(f, "copy ({mode} failed: filesystem without {link} support)")
+            }
+            FallbackReason::PermissionDenied => {
+                write!(f, "copy ({mode} failed: permission error)")
+            }
+            FallbackReason::Other(err) => write!(f, "copy ({mode} failed: {err})"),
+        }
+    }
+}
+
+/// Describe the filesystem that contains the given path, e.g., ``ext4 at `/home` ``.
+#[cfg(target_os = "linux")]
+fn filesystem(path: &Path) -> Option<String> {
+    let path = fs_err::canonicalize(path).ok()?;
+    let mounts = fs_err::read_to_string("/proc/self/mounts").ok()?;
+    // Later entries shadow earlier ones mounted at the same path.
+    let (mount, fstype) = mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let _device = fields.next()?;
+            let mount = unescape_mount(fields.next()?);
+            let fstype = fields.next()?;
+            Some((mount, fstype))
+        })
+        .filter(|(mount, _)| path.starts_with(mount))
+        .max_by_key(|(mount, _)| mount.len())?;
+    Some(format!("{fstype} at `{mount}`"))
+}
+
+/// The filesystem type isn't determined on other platforms.
+#[cfg(not(target_os = "linux"))]
+fn filesystem(_path: &Path) -> Option<String> {
+    None
+}
+
+/// Unescape a mount point in `/proc/self/mounts`, which encodes whitespace and backslashes as
+/// octal escapes.
+#[cfg(target_os = "linux")]
+fn unescape_mount(mount: &str) -> String {
+    mount
+        .replace("\\040", " ")
+        .replace("\\011", "\t")
+        .replace("\\012", "\n")
+        .replace("\\134", "\\")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::LinkOutcome;
+    use crate::LinkMode;
+
+    #[test]
+    fn describe_outcome() {
+        assert_eq!(
+            LinkOutcome::new(LinkMode::Copy).to_string(),
+            "copy (user requested)"
+        );
+        assert_eq!(
+            LinkOutcome::new(LinkMode::default()).to_string(),
+            LinkMode::default().to_string()
+        );
+        assert_eq!(
+            LinkOutcome::new(LinkMode::Symlink).to_string(),
+            "symlink (user requested)"
+        );
+        // An explicit link mode is requested, even if it matches the default.
+        let outcome = LinkOutcome {
+            requested: true,
+            ..LinkOutcome::new(LinkMode::default())
+        };
+        assert_eq!(
+            outcome.to_string(),
+            format!("{} (user requested)", LinkMode::default())
+        );
+
+        let mut outcome = LinkOutcome::new(LinkMode::Hardlink);
+        outcome.fall_back(&io::Error::from(io::ErrorKind::CrossesDevices));
+        // Only the first failure is reported.
+        outcome.fall_back(&io::Error::from(io::ErrorKind::PermissionDenied));
+        assert_eq!(outcome.to_string(), "copy (hardlink failed: cross-device)");
+
+        let mut outcome = LinkOutcome::new(LinkMode::Clone);
+        outcome.fall_back(&io::Error::from(io::ErrorKind::Unsupported));
+        assert_eq!(
+            outcome.to_string(),
+            "copy (clone failed: filesystem without reflink support)"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
 use std::io;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
@@ -20,6 +21,7 @@ use uv_preview::{Preview, PreviewFeature};
 use uv_warnings::{warn_user, warn_user_once};
 
 use crate::Error;
+use crate::link_usage::LinkOutcome;
 
 /// Avoid and track conflicts between packages.
 #[expect(clippy::struct_field_names)]
@@ -270,8 +272,22 @@ impl Default for LinkMode {
     }
 }
 
+impl Display for LinkMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Clone => write!(f, "clone"),
+            Self::Copy => write!(f, "copy"),
+            Self::Hardlink => write!(f, "hardlink"),
+            Self::Symlink => write!(f, "symlink"),
+        }
+    }
+}
+
 impl LinkMode {
     /// Extract a wheel by linking all of its files into site packages.
+    ///
+    /// The link mode that was used, and the reason for any fallback to copying, is logged for
+    /// each wheel.
     #[instrument(skip_all)]
     pub fn link_wheel_files(
         self,
@@ -280,12 +296,21 @@ impl LinkMode {
         locks: &Locks,
         filename: &WheelFilename,
     ) -> Result<usize, Error> {
-        match self {
-            Self::Clone => clone_wheel_files(site_packages, wheel, locks, filename),
+        let site_packages = site_packages.as_ref();
+        let wheel = wheel.as_ref();
+        let mut outcome = LinkOutcome::new(self);
+        let count = match self {
+            Self::Clone => clone_wheel_files(site_packages, wheel, locks, filename, &mut outcome),
             Self::Copy => copy_wheel_files(site_packages, wheel, locks, filename),
-            Self::Hardlink => hardlink_wheel_files(site_packages, wheel, locks, filename),
-            Self::Symlink => symlink_wheel_files(site_packages, wheel, locks, filename),
-        }
+            Self::Hardlink => {
+                hardlink_wheel_files(site_packages, wheel, locks, filename, &mut outcome)
+            }
+            Self::Symlink => {
+                symlink_wheel_files(site_packages, wheel, locks, filename, &mut outcome)
+            }
+        }?;
+        outcome.finish(filename, wheel, site_packages);
+        Ok(count)
     }
 
     /// Returns `true` if the link mode is [`LinkMode::Symlink`].
@@ -305,6 +330,7 @@ fn clone_wheel_files(
     wheel: impl AsRef<Path>,
     locks: &Locks,
     filename: &WheelFilename,
+    outcome: &mut LinkOutcome,
 ) -> Result<usize, Error> {
     let wheel = wheel.as_ref();
     let mut count = 0usize;
@@ -320,7 +346,14 @@ fn clone_wheel_files(
             &entry.path(),
             filename,
         );
-        clone_recursive(site_packages.as_ref(), wheel, locks, &entry, &mut attempt)?;
+        clone_recursive(
+            site_packages.as_ref(),
+            wheel,
+            locks,
+            &entry,
+            &mut attempt,
+            outcome,
+        )?;
         count += 1;
     }
 
@@ -384,6 +417,7 @@ fn clone_recursive(
     locks: &Locks,
     entry: &DirEntry,
     attempt: &mut Attempt,
+    outcome: &mut LinkOutcome,
 ) -> Result<(), Error> {
     // Determine the existing and destination paths.
     let from = entry.path();
@@ -397,7 +431,7 @@ fn clone_recursive(
     if (cfg!(windows) || cfg!(target_os = "linux")) && from.is_dir() {
         fs::create_dir_all(&to)?;
         for entry in fs::read_dir(from)? {
-            clone_recursive(site_packages, wheel, locks, &entry?, attempt)?;
+            clone_recursive(site_packages, wheel, locks, &entry?, attempt, outcome)?;
         }
         return Ok(());
     }
@@ -410,22 +444,30 @@ fn clone_recursive(
                     // merged recursively.
                     if entry.file_type()?.is_dir() {
                         for entry in fs::read_dir(from)? {
-                            clone_recursive(site_packages, wheel, locks, &entry?, attempt)?;
+                            clone_recursive(
+                                site_packages,
+                                wheel,
+                                locks,
+                                &entry?,
+                                attempt,
+                                outcome,
+                            )?;
                         }
                     } else {
                         // If file already exists, overwrite it.
                         let tempdir = tempdir_in(site_packages)?;
                         let tempfile = tempdir.path().join(from.file_name().unwrap());
-                        if reflink::reflink(&from, &tempfile).is_ok() {
-                            fs::rename(&tempfile, to)?;
-                        } else {
+                        if let Err(err) = reflink::reflink(&from, &tempfile) {
                             debug!(
                                 "Failed to clone `{}` to temporary location `{}`, attempting to copy files as a fallback",
                                 from.display(),
                                 tempfile.display(),
                             );
+                            outcome.fall_back(&err);
                             *attempt = Attempt::UseCopyFallback;
                             synchronized_copy(&from, &to, locks)?;
+                        } else {
+                            fs::rename(&tempfile, to)?;
                         }
                     }
                 } else {
@@ -435,8 +477,9 @@ fn clone_recursive(
                         to.display()
                     );
                     // Fallback to copying
+                    outcome.fall_back(&err);
                     *attempt = Attempt::UseCopyFallback;
-                    clone_recursive(site_packages, wheel, locks, entry, attempt)?;
+                    clone_recursive(site_packages, wheel, locks, entry, attempt, outcome)?;
                 }
             }
         }
@@ -446,7 +489,14 @@ fn clone_recursive(
                     // If cloning/copying fails and the directory exists already, it must be merged recursively.
                     if entry.file_type()?.is_dir() {
                         for entry in fs::read_dir(from)? {
-                            clone_recursive(site_packages, wheel, locks, &entry?, attempt)?;
+                            clone_recursive(
+                                site_packages,
+                                wheel,
+                                locks,
+                                &entry?,
+                                attempt,
+                                outcome,
+                            )?;
                         }
                     } else {
                         // If file already exists, overwrite it.
@@ -464,7 +514,7 @@ fn clone_recursive(
             if entry.file_type()?.is_dir() {
                 fs::create_dir_all(&to)?;
                 for entry in fs::read_dir(from)? {
-                    clone_recursive(site_packages, wheel, locks, &entry?, attempt)?;
+                    clone_recursive(site_packages, wheel, locks, &entry?, attempt, outcome)?;
                 }
             } else {
                 synchronized_copy(&from, &to, locks)?;
@@ -517,6 +567,7 @@ fn hardlink_wheel_files(
     wheel: impl AsRef<Path>,
     locks: &Locks,
     filename: &WheelFilename,
+    outcome: &mut LinkOutcome,
 ) -> Result<usize, Error> {
     let mut attempt = Attempt::default();
     let mut count = 0usize;
@@ -557,16 +608,17 @@ fn hardlink_wheel_files(
                         // Removing and recreating would lead to race conditions.
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
-                        if fs::hard_link(path, &tempfile).is_ok() {
-                            fs_err::rename(&tempfile, &out_path)?;
-                        } else {
+                        if let Err(err) = fs::hard_link(path, &tempfile) {
                             debug!(
                                 "Failed to hardlink `{}` to `{}`, attempting to copy files as a fallback",
                                 out_path.display(),
                                 path.display()
                             );
+                            outcome.fall_back(&err);
                             synchronized_copy(path, &out_path, locks)?;
                             attempt = Attempt::UseCopyFallback;
+                        } else {
+                            fs_err::rename(&tempfile, &out_path)?;
                         }
                     } else {
                         debug!(
@@ -574,6 +626,7 @@ fn hardlink_wheel_files(
                             out_path.display(),
                             path.display()
                         );
+                        outcome.fall_back(&err);
                         synchronized_copy(path, &out_path, locks)?;
                         attempt = Attempt::UseCopyFallback;
                     }
@@ -617,6 +670,7 @@ fn symlink_wheel_files(
     wheel: impl AsRef<Path>,
     locks: &Locks,
     filename: &WheelFilename,
+    outcome: &mut LinkOutcome,
 ) -> Result<usize, Error> {
     let mut attempt = Attempt::default();
     let mut count = 0usize;
@@ -657,16 +711,17 @@ fn symlink_wheel_files(
                         // Removing and recreating would lead to race conditions.
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
-                        if create_symlink(path, &tempfile).is_ok() {
-                            fs::rename(&tempfile, &out_path)?;
-                        } else {
+                        if let Err(err) = create_symlink(path, &tempfile) {
                             debug!(
                                 "Failed to symlink `{}` to `{}`, attempting to copy files as a fallback",
                                 out_path.display(),
                                 path.display()
                             );
+                            outcome.fall_back(&err);
                             synchronized_copy(path, &out_path, locks)?;
                             attempt = Attempt::UseCopyFallback;
+                        } else {
+                            fs::rename(&tempfile, &out_path)?;
                         }
                     } else {
                         debug!(
@@ -674,6 +729,7 @@ fn symlink_wheel_files(
                             out_path.display(),
                             path.display()
                         );
+                        outcome.fall_back(&err);
                         synchronized_copy(path, &out_path, locks)?;
                         attempt = Attempt::UseCopyFallback;
                     }
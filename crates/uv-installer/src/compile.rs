@@ -1,7 +1,8 @@
+use std::collections::BTreeMap;
 use std::panic::AssertUnwindSafe;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{env, io, panic};
 
 use async_channel::{Receiver, SendError};
@@ -10,7 +11,7 @@ use thiserror::Error;
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{Child, ChildStderr, ChildStdin, ChildStdout, Command};
 use tokio::sync::oneshot;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, trace};
 use walkdir::WalkDir;
 
 use uv_configuration::Concurrency;
@@ -21,6 +22,8 @@ use uv_warnings::warn_user;
 const COMPILEALL_SCRIPT: &str = include_str!("pip_compileall.py");
 /// This is longer than any compilation should ever take.
 const DEFAULT_COMPILE_TIMEOUT: Duration = Duration::from_secs(60);
+/// The number of failures logged with their error message.
+const MAX_LOGGED_FAILURES: usize = 3;
 
 #[derive(Debug, Error)]
 pub enum CompileError {
@@ -59,11 +62,112 @@ pub enum CompileError {
     EnvironmentError { var: &'static str, message: String },
 }
 
+/// The outcome of compiling the files in a tree, summed across workers.
+#[derive(Debug, Default)]
+struct CompileStats {
+    /// The number of files that were compiled.
+    compiled: usize,
+    /// The number of files with an up-to-date `.pyc` file, which weren't recompiled.
+    skipped: usize,
+    /// The number of files that failed to compile.
+    failed: usize,
+    /// The first few files that failed to compile, along with the error message.
+    failures: Vec<(String, String)>,
+    /// The number of compiled, skipped, and failed files for each top-level package or module.
+    packages: BTreeMap<String, [usize; 3]>,
+}
+
+impl CompileStats {
+    /// Record the outcome reported by the compile script for the file at `source_file`.
+    fn record(&mut self, dir: &Path, source_file: &str, outcome: &str) {
+        let index = match outcome
+            .split_once(' ')
+            .map_or(outcome, |(status, _)| status)
+        {
+            "compiled" => {
+                self.compiled += 1;
+                0
+            }
+            "skipped" => {
+                self.skipped += 1;
+                1
+            }
+            _ => {
+                self.failed += 1;
+                if self.failures.len() < MAX_LOGGED_FAILURES {
+                    let message = outcome.strip_prefix("failed ").unwrap_or(outcome);
+                    self.failures
+                        .push((source_file.to_string(), message.to_string()));
+                }
+                2
+            }
+        };
+        let package = Path::new(source_file)
+            .strip_prefix(dir)
+            .ok()
+            .and_then(|relative| relative.components().next())
+            .map(|component| {
+                let component = component.as_os_str().to_string_lossy();
+                component
+                    .strip_suffix(".py")
+                    .unwrap_or(&component)
+                    .to_string()
+            })
+            .unwrap_or_default();
+        self.packages.entry(package).or_default()[index] += 1;
+    }
+
+    /// Add the outcomes of another worker.
+    fn merge(&mut self, other: Self) {
+        self.compiled += other.compiled;
+        self.skipped += other.skipped;
+        self.failed += other.failed;
+        self.failures.extend(other.failures);
+        self.failures.truncate(MAX_LOGGED_FAILURES);
+        for (package, counts) in other.packages {
+            let entry = self.packages.entry(package).or_default();
+            for (total, count) in entry.iter_mut().zip(counts) {
+                *total += count;
+            }
+        }
+    }
+
+    /// Log the totals at debug level, along with the first few failures, and the outcomes for
+    /// each top-level package at trace level.
+    fn log(&self, workers: usize, elapsed: Duration) {
+        debug!(
+            workers,
+            compiled = self.compiled,
+            skipped = self.skipped,
+            failed = self.failed,
+            "Bytecode compilation with {workers} workers: {} compiled, {} skipped (up to date), {} failed in {}ms",
+            self.compiled,
+            self.skipped,
+            self.failed,
+            elapsed.as_millis()
+        );
+        for (source_file, message) in &self.failures {
+            debug!("Failed to compile `{source_file}`: {message}");
+        }
+        if self.failed > self.failures.len() {
+            debug!(
+                "...and {} more files that failed to compile",
+                self.failed - self.failures.len()
+            );
+        }
+        for (package, [compiled, skipped, failed]) in &self.packages {
+            trace!(
+                "Bytecode compilation of `{package}`: {compiled} compiled, {skipped} skipped, {failed} failed"
+            );
+        }
+    }
+}
+
 /// Bytecode compile all file in `dir` using a pool of Python interpreters running a Python script
 /// that calls `compileall.compile_file`.
 ///
-/// All compilation errors are muted (like pip). There is a 60s timeout for each file to handle
-/// a broken `python`.
+/// All compilation errors are muted (like pip), but counted and logged along with the number of
+/// compiled and skipped files. There is a 60s timeout for each file to handle a broken `python`.
 ///
 /// We only compile all files, but we don't update the RECORD, relying on PEP 491:
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
@@ -81,6 +185,7 @@ pub async fn compile_tree(
         "compileall doesn't work with relative paths: `{}`",
         dir.display()
     );
+    let start = Instant::now();
     let worker_count = concurrency.installs;
 
     // A larger buffer is significantly faster than just 1 or the worker count.
@@ -192,13 +297,14 @@ pub async fn compile_tree(
     drop(sender);
 
     // Make sure all workers exit regularly, avoid hiding errors.
+    let mut stats = CompileStats::default();
     for result in futures::future::join_all(worker_handles).await {
         match result {
             // There spawning earlier errored due to a panic in a task.
             Err(_) => return Err(CompileError::Join),
             // The worker reports an error.
             Ok(Err(compile_error)) => return Err(compile_error),
-            Ok(Ok(())) => {}
+            Ok(Ok(worker_stats)) => stats.merge(worker_stats),
         }
     }
 
@@ -208,6 +314,8 @@ pub async fn compile_tree(
         return Err(CompileError::WorkerDisappeared(send_error));
     }
 
+    stats.log(worker_count, start.elapsed());
+
     Ok(source_files)
 }
 
@@ -217,7 +325,7 @@ async fn worker(
     pip_compileall_py: PathBuf,
     receiver: Receiver<PathBuf>,
     timeout: Option<Duration>,
-) -> Result<(), CompileError> {
+) -> Result<CompileStats, CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
         .await
         .map_err(CompileError::TempFile)?;
@@ -256,7 +364,7 @@ async fn worker(
         Ok(child_stderr_collected)
     });
 
-    let result = worker_main_loop(receiver, child_stdin, &mut child_stdout, timeout).await;
+    let result = worker_main_loop(&dir, receiver, child_stdin, &mut child_stdout, timeout).await;
     // Reap the process to avoid zombies.
     let _ = bytecode_compiler.kill().await;
 
@@ -274,13 +382,13 @@ async fn worker(
     } else {
         let stderr = String::from_utf8_lossy(&child_stderr_collected);
         match result {
-            Ok(()) => {
+            Ok(stats) => {
                 debug!(
                     "Bytecode compilation `python` at {} stderr:\n{}\n---",
                     interpreter.user_display(),
                     stderr
                 );
-                Ok(())
+                Ok(stats)
             }
             Err(err) => Err(CompileError::ErrorWithStderr {
                 stderr: stderr.trim().to_string(),
@@ -289,7 +397,10 @@ async fn worker(
         }
     };
 
-    debug!("Bytecode compilation worker exiting: {:?}", result);
+    debug!(
+        "Bytecode compilation worker exiting: {:?}",
+        result.as_ref().map(|_| ())
+    );
 
     result
 }
@@ -367,15 +478,19 @@ async fn launch_bytecode_compiler(
 }
 
 /// We use stdin/stdout as a sort of bounded channel. We write one path to stdin, then wait until
-/// we get the same path back from stdout. This way we ensure one worker is only working on one
-/// piece of work at the same time.
+/// we get the same path back from stdout, followed by the outcome of compiling it (`compiled`,
+/// `skipped`, or `failed <message>`). This way we ensure one worker is only working on one piece of
+/// work at the same time.
 async fn worker_main_loop(
+    dir: &Path,
     receiver: Receiver<PathBuf>,
     mut child_stdin: ChildStdin,
     child_stdout: &mut BufReader<ChildStdout>,
     timeout: Option<Duration>,
-) -> Result<(), CompileError> {
+) -> Result<CompileStats, CompileError> {
+    let mut stats = CompileStats::default();
     let mut out_line = String::new();
+    let mut outcome_line = String::new();
     while let Ok(source_file) = receiver.recv().await {
         let source_file = source_file.display().to_string();
         if source_file.contains(['\r', '\n']) {
@@ -401,6 +516,14 @@ async fn worker_main_loop(
                     err,
                 }
             })?;
+            outcome_line.clear();
+            child_stdout
+                .read_line(&mut outcome_line)
+                .await
+                .map_err(|err| CompileError::ChildStdio {
+                    device: "stdout",
+                    err,
+                })?;
             Ok::<(), CompileError>(())
         };
 
@@ -423,6 +546,11 @@ async fn worker_main_loop(
         if actual != source_file {
             return Err(CompileError::WrongPath(source_file, actual.to_string()));
         }
+        stats.record(
+            dir,
+            &source_file,
+            outcome_line.trim_end_matches(['\n', '\r']),
+        );
     }
-    Ok(())
+    Ok(stats)
 }
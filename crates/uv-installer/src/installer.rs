@@ -155,7 +155,7 @@ impl<'a> Installer<'a> {
 }
 
 /// Install a set of wheels into a Python virtual environment synchronously.
-#[instrument(skip_all, fields(num_wheels = %wheels.len()))]
+#[instrument(skip_all, fields(num_wheels = %wheels.len(), kind = "disk"))]
 fn install(
     wheels: Vec<CachedDist>,
     layout: &Layout,
@@ -9,11 +9,31 @@ which contains some vendored Python 2 code which fails to compile.
 """
 
 import compileall
+import importlib.util
 import os
 import py_compile
 import sys
 import warnings
 
+
+def cached_mtime(path):
+    """Return the modification time of the `.pyc` file for `path`, if it exists."""
+    try:
+        return os.stat(importlib.util.cache_from_source(path)).st_mtime_ns
+    except (NotImplementedError, OSError):
+        return None
+
+
+def failure(path):
+    """Return the error message for a file that failed to compile, on a single line."""
+    try:
+        with open(path, "rb") as f:
+            compile(f.read(), path, "exec", dont_inherit=True)
+    except Exception as err:
+        return " ".join(f"{type(err).__name__}: {err}".splitlines())
+    return "unknown error"
+
+
 with warnings.catch_warnings():
     warnings.filterwarnings("ignore")
 
@@ -60,8 +80,16 @@ with warnings.catch_warnings():
         # Unlike pip, we set quiet=2, so we don't have to capture stdout.
         # We'd like to show those errors, but given that pip thinks that's totally fine,
         # we can't really change that.
+        before = cached_mtime(path)
         success = compileall.compile_file(
             path, invalidation_mode=invalidation_mode, force=force, quiet=2
         )
-        # We're ready for the next file.
+        if not success:
+            outcome = f"failed {failure(path)}"
+        elif before is not None and before == cached_mtime(path):
+            outcome = "skipped"
+        else:
+            outcome = "compiled"
+        # We're ready for the next file, report the outcome along with the path.
         print(path)
+        print(outcome)
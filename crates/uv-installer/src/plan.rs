@@ -1,3 +1,4 @@
+use std::fmt::Display;
 use std::sync::Arc;
 
 use anyhow::{Result, bail};
@@ -91,6 +92,9 @@ impl<'a> Planner<'a> {
         let mut reinstalls = vec![];
         let mut extraneous = vec![];
 
+        // The reason for each planned change, for the `uv_installer::plan` event.
+        let mut reasons = PlanReasons::default();
+
         // TODO(charlie): There are a few assumptions here that are hard to spot:
         //
         // 1. Apparently, we never return direct URL distributions as [`ResolvedDist::Installed`].
@@ -116,6 +120,9 @@ impl<'a> Planner<'a> {
             // Determine whether the distribution is already installed.
             let installed_dists = site_packages.remove_packages(dist.name());
             if reinstall {
+                for installed in &installed_dists {
+                    reasons.reinstall(installed, "reinstall requested");
+                }
                 reinstalls.extend(installed_dists);
             } else {
                 match installed_dists.as_slice() {
@@ -138,13 +145,22 @@ impl<'a> Planner<'a> {
                                 debug!(
                                     "Requirement installed, but mismatched:\n  Installed: {installed:?}\n  Requested: {source:?}"
                                 );
+                                let reason = match dist.version() {
+                                    Some(version) if version != installed.version() => {
+                                        format!("version change to {version}")
+                                    }
+                                    _ => "installed source doesn't match".to_string(),
+                                };
+                                reasons.reinstall(installed, &reason);
                             }
                             RequirementSatisfaction::Satisfied => {
                                 debug!("Requirement already installed: {installed}");
+                                reasons.unchanged(installed, "already installed");
                                 continue;
                             }
                             RequirementSatisfaction::OutOfDate => {
                                 debug!("Requirement installed, but not fresh: {installed}");
+                                reasons.reinstall(installed, "out of date");
 
                                 // If we made it here, something went wrong in the resolver, because it returned an
                                 // already-installed distribution that we "shouldn't" use. Typically, this means the
@@ -161,11 +177,13 @@ impl<'a> Planner<'a> {
                                     warn!(
                                         "Installed distribution was considered out-of-date, but returned by the resolver: {dist}"
                                     );
+                                    reasons.unchanged(installed, "selected by the resolver");
                                     continue;
                                 }
                             }
                             RequirementSatisfaction::CacheInvalid => {
                                 // Already logged
+                                reasons.reinstall(installed, "cache entry invalidated");
                             }
                         }
                         reinstalls.push(installed.clone());
@@ -173,7 +191,12 @@ impl<'a> Planner<'a> {
                     // We reinstall installed distributions with multiple versions because
                     // we do not want to keep multiple incompatible versions but removing
                     // one version is likely to break another.
-                    _ => reinstalls.extend(installed_dists),
+                    _ => {
+                        for installed in &installed_dists {
+                            reasons.reinstall(installed, "multiple versions installed");
+                        }
+                        reinstalls.extend(installed_dists);
+                    }
                 }
             }
 
@@ -187,6 +210,7 @@ impl<'a> Planner<'a> {
                     .is_some_and(|source_tree| cache.must_revalidate_path(source_tree))
             {
                 debug!("Must revalidate requirement: {}", dist.name());
+                reasons.install(dist, "must revalidate");
                 remote.push(dist.clone());
                 continue;
             }
@@ -496,8 +520,12 @@ impl<'a> Planner<'a> {
             }
 
             debug!("Identified uncached distribution: {dist}");
+            reasons.install(dist, "not cached");
             remote.push(dist.clone());
         }
+        for dist in &cached {
+            reasons.install(dist, "cached");
+        }
 
         // Remove any unnecessary packages.
         if site_packages.any() {
@@ -507,14 +535,18 @@ impl<'a> Planner<'a> {
             for dist_info in site_packages {
                 if seed_packages && is_seed_package(&dist_info, venv) {
                     debug!("Preserving seed package: {dist_info}");
+                    reasons.unchanged(&dist_info, "seed package");
                     continue;
                 }
 
                 debug!("Unnecessary package: {dist_info}");
+                reasons.remove(&dist_info, "not required");
                 extraneous.push(dist_info);
             }
         }
 
+        reasons.log();
+
         Ok(Plan {
             cached,
             remote,
@@ -524,6 +556,53 @@ impl<'a> Planner<'a> {
     }
 }
 
+/// The reason for each change in a [`Plan`], logged as a single event under the
+/// `uv_installer::plan` target before the plan is executed.
+#[derive(Debug, Default)]
+struct PlanReasons {
+    install: Vec<String>,
+    reinstall: Vec<String>,
+    remove: Vec<String>,
+    unchanged: Vec<String>,
+}
+
+impl PlanReasons {
+    fn install(&mut self, dist: impl Display, reason: &str) {
+        self.install.push(format!("{dist} ({reason})"));
+    }
+
+    fn reinstall(&mut self, dist: &InstalledDist, reason: &str) {
+        self.reinstall.push(format!("{dist} ({reason})"));
+    }
+
+    fn remove(&mut self, dist: &InstalledDist, reason: &str) {
+        self.remove.push(format!("{dist} ({reason})"));
+    }
+
+    fn unchanged(&mut self, dist: &InstalledDist, reason: &str) {
+        self.unchanged.push(format!("{dist} ({reason})"));
+    }
+
+    fn log(mut self) {
+        for entries in [
+            &mut self.install,
+            &mut self.reinstall,
+            &mut self.remove,
+            &mut self.unchanged,
+        ] {
+            entries.sort_unstable();
+        }
+        debug!(
+            target: "uv_installer::plan",
+            install = %self.install.join(", "),
+            reinstall = %self.reinstall.join(", "),
+            remove = %self.remove.join(", "),
+            unchanged = %self.unchanged.join(", "),
+            "Planned installation"
+        );
+    }
+}
+
 /// Returns `true` if the given distribution is a seed package.
 fn is_seed_package(dist_info: &InstalledDist, venv: &PythonEnvironment) -> bool {
     if venv.interpreter().python_tuple() >= (3, 12) {
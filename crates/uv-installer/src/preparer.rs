@@ -143,7 +143,7 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
                 .map_err(|err| Error::from_dist(dist.clone(), err, resolution))
                 .await
                 .and_then(|wheel: LocalWheel| {
-                    if wheel.satisfies(policy) {
+                    if wheel.verify(&dist.to_string(), policy) {
                         Ok(wheel)
                     } else {
                         let err = uv_distribution::Error::hash_mismatch(
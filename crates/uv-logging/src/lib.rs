@@ -1,12 +1,84 @@
 use std::fmt;
+use std::io;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 use jiff::Timestamp;
 use owo_colors::OwoColorize;
 use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::fmt::format::Writer;
 use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
 use tracing_subscriber::registry::LookupSpan;
 
+/// A process-wide lock serializing writes to the console.
+///
+/// The console logging layer holds this lock while writing each event. Any other source that
+/// writes to `stderr` concurrently (e.g., build backend output) should hold it while writing a
+/// line, such that no two sources interleave within a line.
+static CONSOLE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Acquire the process-wide console lock.
+fn lock_console() -> MutexGuard<'static, ()> {
+    CONSOLE_LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Write a single line to the given stream while holding the console lock, optionally prefixed
+/// with a tag (e.g., the name of the package being built).
+///
+/// The line is written with a single call, such that it can't be interleaved with console log
+/// messages or with lines written by other threads.
+pub fn write_line(stream: &mut impl io::Write, tag: Option<&str>, line: &str) -> io::Result<()> {
+    let buffer = match tag {
+        Some(tag) => format!("[{tag}] {line}\n"),
+        None => format!("{line}\n"),
+    };
+    let _console = lock_console();
+    stream.write_all(buffer.as_bytes())?;
+    stream.flush()
+}
+
+/// A [`MakeWriter`] that writes each event to the wrapped stream while holding the console lock.
+pub struct ConsoleWriter<W> {
+    stream: Mutex<W>,
+}
+
+impl<W> ConsoleWriter<W> {
+    pub fn new(stream: W) -> Self {
+        Self {
+            stream: Mutex::new(stream),
+        }
+    }
+}
+
+impl<'a, W: io::Write + 'a> MakeWriter<'a> for ConsoleWriter<W> {
+    type Writer = ConsoleGuard<'a, W>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        let console = lock_console();
+        let stream = self.stream.lock().unwrap_or_else(PoisonError::into_inner);
+        ConsoleGuard {
+            stream,
+            _console: console,
+        }
+    }
+}
+
+/// A writer that holds the console lock until dropped.
+pub struct ConsoleGuard<'a, W> {
+    stream: MutexGuard<'a, W>,
+    _console: MutexGuard<'static, ()>,
+}
+
+impl<W: io::Write> io::Write for ConsoleGuard<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
 /// The style of a uv logging line.
 pub struct UvFormat {
     pub display_timestamp: bool,
@@ -93,3 +165,80 @@ where
         writeln!(writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+    use std::sync::{Arc, Mutex};
+
+    use tracing_subscriber::fmt::MakeWriter;
+
+    use super::{ConsoleWriter, write_line};
+
+    /// A stream that records every write, like `stderr` without any locking across calls.
+    #[derive(Clone, Default)]
+    struct SharedStream(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedStream {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn concurrent_lines_are_not_interleaved() {
+        const LINES: usize = 500;
+
+        let stream = SharedStream::default();
+        let console = Arc::new(ConsoleWriter::new(stream.clone()));
+
+        std::thread::scope(|scope| {
+            // Two chatty "builds" writing tagged lines.
+            for tag in ["foo", "bar"] {
+                let mut stream = stream.clone();
+                scope.spawn(move || {
+                    for i in 0..LINES {
+                        write_line(&mut stream, Some(tag), &format!("output line {i}")).unwrap();
+                    }
+                });
+            }
+            // The console layer, which writes each event in several pieces.
+            let console = console.clone();
+            scope.spawn(move || {
+                for i in 0..LINES {
+                    let mut writer = console.make_writer();
+                    writer.write_all(b"DEBUG ").unwrap();
+                    writer.write_all(b"console ").unwrap();
+                    writer.write_all(format!("line {i}\n").as_bytes()).unwrap();
+                }
+            });
+        });
+
+        let output = String::from_utf8(stream.0.lock().unwrap().clone()).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3 * LINES);
+        for tag in ["foo", "bar"] {
+            let tagged = lines
+                .iter()
+                .filter(|line| line.starts_with(&format!("[{tag}] ")))
+                .collect::<Vec<_>>();
+            assert_eq!(tagged.len(), LINES);
+            for (i, line) in tagged.iter().enumerate() {
+                assert_eq!(**line, format!("[{tag}] output line {i}"));
+            }
+        }
+        let console = lines
+            .iter()
+            .filter(|line| line.starts_with("DEBUG "))
+            .collect::<Vec<_>>();
+        assert_eq!(console.len(), LINES);
+        for (i, line) in console.iter().enumerate() {
+            assert_eq!(**line, format!("DEBUG console line {i}"));
+        }
+    }
+}
@@ -1129,6 +1129,68 @@ impl MarkerTree {
         false
     }
 
+    /// Returns the value of each environment marker referenced by this tree in the given
+    /// environment, e.g., `sys_platform = 'linux'`, in the order they're first encountered.
+    ///
+    /// Extras and dependency groups are not included, as they aren't part of the environment.
+    pub fn environment_values(self, env: &MarkerEnvironment) -> Vec<String> {
+        fn collect(tree: MarkerTree, env: &MarkerEnvironment, values: &mut Vec<String>) {
+            let (value, children): (_, Vec<MarkerTree>) = match tree.kind() {
+                MarkerTreeKind::True | MarkerTreeKind::False => return,
+                MarkerTreeKind::Version(marker) => (
+                    Some(format!(
+                        "{} = '{}'",
+                        marker.key(),
+                        env.get_version(marker.key())
+                    )),
+                    marker.edges().map(|(_, tree)| tree).collect(),
+                ),
+                MarkerTreeKind::String(marker) => (
+                    Some(format!(
+                        "{} = '{}'",
+                        marker.key(),
+                        env.get_string(marker.key())
+                    )),
+                    marker.children().map(|(_, tree)| tree).collect(),
+                ),
+                MarkerTreeKind::In(marker) => (
+                    Some(format!(
+                        "{} = '{}'",
+                        marker.key(),
+                        env.get_string(marker.key())
+                    )),
+                    marker.children().map(|(_, tree)| tree).collect(),
+                ),
+                MarkerTreeKind::Contains(marker) => (
+                    Some(format!(
+                        "{} = '{}'",
+                        marker.key(),
+                        env.get_string(marker.key())
+                    )),
+                    marker.children().map(|(_, tree)| tree).collect(),
+                ),
+                MarkerTreeKind::List(marker) => {
+                    (None, marker.children().map(|(_, tree)| tree).collect())
+                }
+                MarkerTreeKind::Extra(marker) => {
+                    (None, marker.children().map(|(_, tree)| tree).collect())
+                }
+            };
+            if let Some(value) = value {
+                if !values.contains(&value) {
+                    values.push(value);
+                }
+            }
+            for child in children {
+                collect(child, env, values);
+            }
+        }
+
+        let mut values = Vec::new();
+        collect(self, env, &mut values);
+        values
+    }
+
     /// Checks if the requirement should be activated with the given set of active extras without evaluating
     /// the remaining environment markers, i.e. if there is potentially an environment that could activate this
     /// requirement.
@@ -2157,6 +2219,29 @@ mod test {
         assert!(!marker3.evaluate(&env37, &[]));
     }
 
+    #[test]
+    fn test_environment_values() {
+        let env37 = env37();
+
+        let marker = m("sys_platform == 'win32'");
+        assert_eq!(
+            marker.environment_values(&env37),
+            ["sys_platform = 'linux'"]
+        );
+
+        let mut values =
+            m("sys_platform == 'win32' or (python_version >= '3.8' and extra == 'dev')")
+                .environment_values(&env37);
+        values.sort();
+        assert_eq!(
+            values,
+            ["python_full_version = '3.7'", "sys_platform = 'linux'"]
+        );
+
+        assert!(m("extra == 'dev'").environment_values(&env37).is_empty());
+        assert!(MarkerTree::TRUE.environment_values(&env37).is_empty());
+    }
+
     #[test]
     fn test_version_in_evaluation() {
         let env27 = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
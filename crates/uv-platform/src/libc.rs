@@ -14,7 +14,7 @@ use std::process::{Command, Stdio};
 use std::str::FromStr;
 use std::sync::LazyLock;
 use std::{env, fmt};
-use tracing::trace;
+use tracing::{debug, trace};
 use uv_fs::Simplified;
 use uv_static::EnvVars;
 
@@ -54,6 +54,15 @@ pub enum LibcVersion {
     Musllinux { major: u32, minor: u32 },
 }
 
+impl Display for LibcVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Manylinux { major, minor } => write!(f, "glibc {major}.{minor}"),
+            Self::Musllinux { major, minor } => write!(f, "musl {major}.{minor}"),
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub enum Libc {
     Some(target_lexicon::Environment),
@@ -66,6 +75,7 @@ impl Libc {
             "linux" => {
                 if let Ok(libc) = env::var(EnvVars::UV_LIBC) {
                     if !libc.is_empty() {
+                        debug!("Using libc `{libc}` from `{}`", EnvVars::UV_LIBC);
                         return Self::from_str(&libc);
                     }
                 }
@@ -145,13 +155,25 @@ pub(crate) fn detect_linux_libc() -> Result<LibcVersion, LibcDetectionError> {
     trace!("Found `ld` path: {}", ld_path.user_display());
 
     match detect_musl_version(&ld_path) {
-        Ok(os) => return Ok(os),
+        Ok(os) => {
+            debug!(
+                "Detected {os} from the output of `{}`",
+                ld_path.user_display()
+            );
+            return Ok(os);
+        }
         Err(err) => {
             trace!("Tried to find musl version by running `{ld_path:?}`, but failed: {err}");
         }
     }
     match detect_linux_libc_from_ld_symlink(&ld_path) {
-        Ok(os) => return Ok(os),
+        Ok(os) => {
+            debug!(
+                "Detected {os} from the `ld` symlink at `{}`",
+                ld_path.user_display()
+            );
+            return Ok(os);
+        }
         Err(err) => {
             trace!(
                 "Tried to find libc version from possible symlink at {ld_path:?}, but failed: {err}"
@@ -159,7 +181,13 @@ pub(crate) fn detect_linux_libc() -> Result<LibcVersion, LibcDetectionError> {
         }
     }
     match detect_glibc_version_from_ld(&ld_path) {
-        Ok(os_version) => return Ok(os_version),
+        Ok(os_version) => {
+            debug!(
+                "Detected {os_version} from the output of `{} --version`",
+                ld_path.user_display()
+            );
+            return Ok(os_version);
+        }
         Err(err) => {
             trace!(
                 "Tried to find glibc version from `{} --version`, but failed: {}",
@@ -581,6 +581,7 @@ pub async fn upload(
                     continue;
                 }
                 reporter.on_upload_complete(idx);
+                retry_state.succeeded();
                 response
             }
             Err(err) => {
@@ -827,6 +828,7 @@ pub async fn upload_two_phase(
             let response = match result {
                 Ok(response) => {
                     reporter.on_upload_complete(idx);
+                    retry_state.succeeded();
                     response
                 }
                 Err(err) => {
@@ -7,6 +7,7 @@ use same_file::is_same_file;
 use std::borrow::Cow;
 use std::env::consts::EXE_SUFFIX;
 use std::fmt::{self, Debug, Formatter};
+use std::time::Instant;
 use std::{env, io, iter};
 use std::{path::Path, path::PathBuf, str::FromStr};
 use thiserror::Error;
@@ -761,8 +762,9 @@ fn python_interpreters<'a>(
             true
         } else {
             debug!(
-                "Skipping interpreter at `{}` from {source}: does not satisfy request `{request}`",
-                interpreter.sys_executable().user_display()
+                "Skipping interpreter at `{}` from {source}: `{}` does not satisfy request `{request}`",
+                interpreter.sys_executable().user_display(),
+                interpreter.key()
             );
             false
         }
@@ -792,17 +794,22 @@ fn python_interpreters_from_executables<'a>(
     cache: &'a Cache,
 ) -> impl Iterator<Item = Result<(PythonSource, Interpreter), Error>> + 'a {
     executables.map(|result| match result {
-        Ok((source, path)) => Interpreter::query(&path, cache)
-            .map(|interpreter| (source, interpreter))
-            .inspect(|(source, interpreter)| {
-                debug!(
-                    "Found `{}` at `{}` ({source})",
-                    interpreter.key(),
-                    path.display()
-                );
-            })
-            .map_err(|err| Error::Query(Box::new(err), path, source))
-            .inspect_err(|err| debug!("{err}")),
+        Ok((source, path)) => {
+            let start = Instant::now();
+            let result = Interpreter::query(&path, cache);
+            let elapsed = start.elapsed();
+            result
+                .map(|interpreter| (source, interpreter))
+                .inspect(|(source, interpreter)| {
+                    debug!(
+                        "Found `{}` at `{}` ({source}) in {elapsed:.2?}",
+                        interpreter.key(),
+                        path.display()
+                    );
+                })
+                .map_err(|err| Error::Query(Box::new(err), path, source))
+                .inspect_err(|err| debug!("{err} (after {elapsed:.2?})"))
+        }
         Err(err) => Err(err),
     })
 }
@@ -1393,6 +1400,12 @@ pub(crate) fn find_python_installation(
         }
 
         // If we didn't skip it, this is the installation to use
+        debug!(
+            "Selected {} at `{}` ({}): first installation to satisfy {request}",
+            installation.key(),
+            installation.interpreter().sys_executable().user_display(),
+            installation.source
+        );
         return result;
     }
 
@@ -1122,7 +1122,10 @@ impl ManagedPythonDownload {
                 )
                 .await;
             match result {
-                Ok(download_result) => return Ok(download_result),
+                Ok(download_result) => {
+                    retry_state.succeeded();
+                    return Ok(download_result);
+                }
                 Err(err) => {
                     if let Some(backoff) = retry_state.should_retry(&err, err.retries()) {
                         retry_state.sleep_backoff(backoff).await;
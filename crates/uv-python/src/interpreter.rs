@@ -4,18 +4,19 @@ use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus};
 use std::str::FromStr;
-use std::sync::OnceLock;
+use std::sync::{LazyLock, Mutex, OnceLock};
 use std::{env, io};
 
 use configparser::ini::Ini;
 use fs_err as fs;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use same_file::is_same_file;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, trace, warn};
 
-use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness};
+use uv_cache::{Cache, CacheBucket, CacheUsage, CachedByTimestamp, Freshness};
 use uv_cache_info::Timestamp;
 use uv_cache_key::cache_digest;
 use uv_fs::{
@@ -257,11 +258,121 @@ impl Interpreter {
                 self.gil_disabled,
                 false,
             )?;
+            self.log_tags(&tags);
             self.tags.set(tags).expect("tags should not be set");
         }
         Ok(self.tags.get().expect("tags should be set"))
     }
 
+    /// Log the inputs to the computation of the compatible [`Tags`], along with the
+    /// highest-priority tags, under the `uv_python::tags` target.
+    ///
+    /// Each distinct set of inputs is logged once per process, as build environments share the
+    /// platform of their base interpreter.
+    fn log_tags(&self, tags: &Tags) {
+        const MAX_LOGGED_TAGS: usize = 5;
+
+        static LOGGED: LazyLock<Mutex<FxHashSet<String>>> = LazyLock::new(Mutex::default);
+
+        if !tracing::enabled!(target: "uv_python::tags", tracing::Level::DEBUG) {
+            return;
+        }
+
+        let arch = self.platform().arch();
+        let (os, libc) = match self.platform().os() {
+            uv_platform_tags::Os::Manylinux { major, minor } => {
+                ("linux".to_string(), format!("glibc {major}.{minor}"))
+            }
+            uv_platform_tags::Os::Musllinux { major, minor } => {
+                ("linux".to_string(), format!("musl {major}.{minor}"))
+            }
+            uv_platform_tags::Os::Macos { major, minor } => {
+                (format!("macos {major}.{minor}"), "none".to_string())
+            }
+            uv_platform_tags::Os::Ios {
+                major,
+                minor,
+                simulator,
+            } => {
+                let os = if *simulator { "ios-simulator" } else { "ios" };
+                (format!("{os} {major}.{minor}"), "none".to_string())
+            }
+            uv_platform_tags::Os::Pyodide { major, minor } => {
+                (format!("pyodide {major}.{minor}"), "none".to_string())
+            }
+            uv_platform_tags::Os::Android { api_level } => {
+                (format!("android {api_level}"), "bionic".to_string())
+            }
+            uv_platform_tags::Os::FreeBsd { release }
+            | uv_platform_tags::Os::NetBsd { release }
+            | uv_platform_tags::Os::OpenBsd { release }
+            | uv_platform_tags::Os::Dragonfly { release }
+            | uv_platform_tags::Os::Illumos { release, .. }
+            | uv_platform_tags::Os::Haiku { release } => (
+                format!("{} {release}", self.platform().os()),
+                "none".to_string(),
+            ),
+            uv_platform_tags::Os::Windows => ("windows".to_string(), "none".to_string()),
+        };
+        let key = format!(
+            "{os} {arch} {libc} {} {} {} {}",
+            self.implementation_name(),
+            self.python_version(),
+            self.manylinux_compatible,
+            self.gil_disabled
+        );
+        if !LOGGED.lock().unwrap().insert(key) {
+            return;
+        }
+
+        // Explain the edge cases in which fewer platform tags apply than the libc would suggest.
+        match self.platform().os() {
+            uv_platform_tags::Os::Manylinux { minor, .. } => {
+                if !self.manylinux_compatible {
+                    debug!(
+                        target: "uv_python::tags",
+                        "Excluding `manylinux` tags, as the interpreter's `_manylinux` module declares them incompatible"
+                    );
+                } else if arch
+                    .get_minimum_manylinux_minor()
+                    .is_some_and(|minimum| *minor < minimum)
+                {
+                    debug!(
+                        target: "uv_python::tags",
+                        "Excluding `manylinux` tags, as {libc} predates the oldest `manylinux` tag for {arch}"
+                    );
+                }
+            }
+            uv_platform_tags::Os::Musllinux { .. } => {
+                debug!(
+                    target: "uv_python::tags",
+                    "Using `musllinux` tags, as the interpreter is linked against {libc}"
+                );
+            }
+            _ => {}
+        }
+
+        let tags = tags.to_string();
+        let count = tags.lines().count();
+        let first = tags
+            .lines()
+            .take(MAX_LOGGED_TAGS)
+            .collect::<Vec<_>>()
+            .join(", ");
+        debug!(
+            target: "uv_python::tags",
+            os = %os,
+            arch = %arch,
+            libc = %libc,
+            implementation = self.implementation_name(),
+            python_version = %self.python_version(),
+            gil_disabled = self.gil_disabled,
+            "Computed {count} compatible tags for `{}`: {first}{}",
+            self.sys_executable().user_display(),
+            if count > MAX_LOGGED_TAGS { ", …" } else { "" }
+        );
+    }
+
     /// Returns `true` if the environment is a PEP 405-compliant virtual environment.
     ///
     /// See: <https://github.com/pypa/pip/blob/0ad4c94be74cc24874c6feb5bb3c2152c398a18e/src/pip/_internal/utils/virtualenv.py#L14>
@@ -1173,13 +1284,13 @@ impl InterpreterInfo {
         // python executable at another path, we're not allowed to cache the interpreter info.
         if is_same_file(executable, &info.sys_executable).unwrap_or(false) {
             fs::create_dir_all(cache_entry.dir())?;
-            write_atomic_sync(
-                cache_entry.path(),
-                rmp_serde::to_vec(&CachedByTimestamp {
-                    timestamp: modified,
-                    data: info.clone(),
-                })?,
-            )?;
+            let contents = rmp_serde::to_vec(&CachedByTimestamp {
+                timestamp: modified,
+                data: info.clone(),
+            })?;
+            let bytes = contents.len() as u64;
+            write_atomic_sync(cache_entry.path(), contents)?;
+            CacheUsage::write(cache_entry.path(), bytes);
         }
 
         Ok(info)
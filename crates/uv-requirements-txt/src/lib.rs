@@ -41,7 +41,7 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use rustc_hash::{FxHashMap, FxHashSet};
-use tracing::instrument;
+use tracing::{debug, instrument};
 use unscanny::{Pattern, Scanner};
 use url::Url;
 
@@ -84,9 +84,15 @@ enum RequirementsTxtStatement {
         end: usize,
     },
     /// PEP 508 requirement plus metadata
-    RequirementEntry(RequirementEntry),
+    RequirementEntry {
+        entry: RequirementEntry,
+        start: usize,
+    },
     /// `-e`
-    EditableRequirementEntry(RequirementEntry),
+    EditableRequirementEntry {
+        entry: RequirementEntry,
+        start: usize,
+    },
     /// `--index-url`
     IndexUrl(VerbatimUrl),
     /// `--extra-index-url`
@@ -504,11 +510,17 @@ impl RequirementsTxt {
                         data.constraints.push(constraint);
                     }
                 }
-                RequirementsTxtStatement::RequirementEntry(requirement_entry) => {
-                    data.requirements.push(requirement_entry);
+                RequirementsTxtStatement::RequirementEntry { entry, start } => {
+                    let kind = match visited {
+                        VisitedFiles::Requirements { .. } => None,
+                        VisitedFiles::Constraints { .. } => Some("constraint"),
+                    };
+                    log_entry(kind, &entry, requirements_txt, content, start);
+                    data.requirements.push(entry);
                 }
-                RequirementsTxtStatement::EditableRequirementEntry(editable) => {
-                    data.editables.push(editable);
+                RequirementsTxtStatement::EditableRequirementEntry { entry, start } => {
+                    log_entry(Some("editable"), &entry, requirements_txt, content, start);
+                    data.editables.push(entry);
                 }
                 RequirementsTxtStatement::IndexUrl(url) => {
                     if data.index_url.is_some() {
@@ -652,6 +664,31 @@ impl Display for UnsupportedOption {
     }
 }
 
+/// Log an entry collected from a `requirements.txt` file, along with the line it was declared on.
+fn log_entry(
+    kind: Option<&str>,
+    entry: &RequirementEntry,
+    requirements_txt: &Path,
+    content: &str,
+    start: usize,
+) {
+    // Computing the line requires a scan of the file up to the entry.
+    if !tracing::enabled!(tracing::Level::DEBUG) {
+        return;
+    }
+    let (line, _) = calculate_row_column(content, start);
+    let origin = if requirements_txt == Path::new("-") {
+        format!("stdin:{line}")
+    } else {
+        format!("`{}:{line}`", requirements_txt.user_display())
+    };
+    if let Some(kind) = kind {
+        debug!("Collected {kind} `{}` from {origin}", entry.requirement);
+    } else {
+        debug!("Collected `{}` from {origin}", entry.requirement);
+    }
+}
+
 /// Returns `true` if the character is a newline or a comment character.
 const fn is_terminal(c: char) -> bool {
     matches!(c, '\n' | '\r' | '#')
@@ -731,10 +768,13 @@ fn parse_entry(
                     start,
                     end: s.cursor(),
                 })?;
-        RequirementsTxtStatement::EditableRequirementEntry(RequirementEntry {
-            requirement,
-            hashes,
-        })
+        RequirementsTxtStatement::EditableRequirementEntry {
+            entry: RequirementEntry {
+                requirement,
+                hashes,
+            },
+            start,
+        }
     } else if s.eat_if("-i") || s.eat_if("--index-url") {
         let given = parse_value("--index-url", content, s, |c: char| !is_terminal(c))?;
         let given = unquote(given)
@@ -871,10 +911,13 @@ fn parse_entry(
 
         let (requirement, hashes) =
             parse_requirement_and_hashes(s, content, source, working_dir, false)?;
-        RequirementsTxtStatement::RequirementEntry(RequirementEntry {
-            requirement,
-            hashes,
-        })
+        RequirementsTxtStatement::RequirementEntry {
+            entry: RequirementEntry {
+                requirement,
+                hashes,
+            },
+            start,
+        }
     } else if let Some(char) = s.peek() {
         // Identify an unsupported option, like `--trusted-host`.
         if let Some(option) = UnsupportedOption::iter().find(|option| s.eat_if(option.name())) {
@@ -1009,6 +1052,7 @@ fn parse_requirement_and_hashes(
             source: err,
             start,
             end,
+            line: calculate_row_column(content, start).0,
         })?;
 
     let hashes = if has_hashes {
@@ -1178,6 +1222,7 @@ pub enum RequirementsTxtParserError {
         source: Box<Pep508Error<VerbatimParsedUrl>>,
         start: usize,
         end: usize,
+        line: usize,
     },
     ParsedUrl {
         source: Box<Pep508Error<VerbatimParsedUrl>>,
@@ -1245,8 +1290,8 @@ impl Display for RequirementsTxtParserError {
             Self::UnsupportedRequirement { start, end, .. } => {
                 write!(f, "Unsupported requirement in position {start} to {end}")
             }
-            Self::Pep508 { start, .. } => {
-                write!(f, "Couldn't parse requirement at position {start}")
+            Self::Pep508 { line, .. } => {
+                write!(f, "Couldn't parse requirement on line {line}")
             }
             Self::ParsedUrl { start, .. } => {
                 write!(f, "Couldn't URL at position {start}")
@@ -1401,10 +1446,10 @@ impl Display for RequirementsTxtFileError {
                     self.file.user_display(),
                 )
             }
-            RequirementsTxtParserError::Pep508 { start, .. } => {
+            RequirementsTxtParserError::Pep508 { line, .. } => {
                 write!(
                     f,
-                    "Couldn't parse requirement in `{}` at position {start}",
+                    "Couldn't parse requirement in `{}` on line {line}",
                     self.file.user_display(),
                 )
             }
@@ -1764,7 +1809,7 @@ mod test {
             filters => filters
         }, {
             insta::assert_snapshot!(errors, @"
-            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 0
+            Couldn't parse requirement in `<REQUIREMENTS_TXT>` on line 1
             Expected an alphanumeric character starting the extra name, found `ö`
             numpy[ö]==1.29
                   ^
@@ -1793,7 +1838,7 @@ mod test {
             filters => filters
         }, {
             insta::assert_snapshot!(errors, @"
-            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 0
+            Couldn't parse requirement in `<REQUIREMENTS_TXT>` on line 1
             empty host
             numpy @ https:///
                     ^^^^^^^^^
@@ -1822,7 +1867,7 @@ mod test {
             filters => filters
         }, {
             insta::assert_snapshot!(errors, @"
-            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 3
+            Couldn't parse requirement in `<REQUIREMENTS_TXT>` on line 1
             Expected direct URL (`https://localhost:8080/`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
             https://localhost:8080/
             ^^^^^^^^^^^^^^^^^^^^^^^
@@ -1878,7 +1923,7 @@ mod test {
             filters => filters
         }, {
             insta::assert_snapshot!(errors, @"
-            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 3
+            Couldn't parse requirement in `<REQUIREMENTS_TXT>` on line 1
             Expected either alphanumerical character (starting the extra name) or `]` (ending the extras section), found `,`
             black[,abcdef]
                   ^
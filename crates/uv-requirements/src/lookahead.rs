@@ -206,6 +206,7 @@ impl<'a, Context: BuildContext> LookaheadResolver<'a, Context> {
 
         // Return the requirements from the metadata.
         Ok(Some(RequestedRequirements::new(
+            requirement.name,
             requirement.extras,
             requires_dist,
             direct,
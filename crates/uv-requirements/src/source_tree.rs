@@ -5,6 +5,7 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use futures::TryStreamExt;
 use futures::stream::FuturesOrdered;
+use tracing::debug;
 use url::Url;
 
 use uv_configuration::ExtrasSpecification;
@@ -137,6 +138,13 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
                 }),
         );
 
+        for requirement in &requirements {
+            debug!(
+                "Collected `{requirement}` from `{}`",
+                source_tree.path().user_display()
+            );
+        }
+
         let requirements = requirements.into_boxed_slice();
         let project = metadata.name;
         let extras = metadata.provides_extra;
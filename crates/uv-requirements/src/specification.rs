@@ -32,7 +32,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use rustc_hash::FxHashSet;
-use tracing::instrument;
+use tracing::{debug, instrument};
 use url::Url;
 
 use uv_cache_key::CanonicalUrl;
@@ -241,18 +241,24 @@ impl RequirementsSpecification {
         cache: &mut SourceCache,
     ) -> Result<Self> {
         Ok(match source {
-            RequirementsSource::Package(requirement) => Self {
-                requirements: vec![UnresolvedRequirementSpecification::from(
-                    requirement.clone(),
-                )],
-                ..Self::default()
-            },
-            RequirementsSource::Editable(requirement) => Self {
-                requirements: vec![UnresolvedRequirementSpecification::from(
-                    requirement.clone().into_editable()?,
-                )],
-                ..Self::default()
-            },
+            RequirementsSource::Package(requirement) => {
+                debug!("Collected `{requirement}` from command line");
+                Self {
+                    requirements: vec![UnresolvedRequirementSpecification::from(
+                        requirement.clone(),
+                    )],
+                    ..Self::default()
+                }
+            }
+            RequirementsSource::Editable(requirement) => {
+                debug!("Collected editable `{requirement}` from command line");
+                Self {
+                    requirements: vec![UnresolvedRequirementSpecification::from(
+                        requirement.clone().into_editable()?,
+                    )],
+                    ..Self::default()
+                }
+            }
             RequirementsSource::RequirementsTxt(path) => {
                 if !(path.starts_with("http://") || path.starts_with("https://") || path.exists()) {
                     return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
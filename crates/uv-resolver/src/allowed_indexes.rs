@@ -0,0 +1,169 @@
+use uv_distribution_types::{AllowedIndex, IndexLocations, IndexUrl};
+use uv_normalize::PackageName;
+use uv_pypi_types::{ParsedUrl, VerbatimParsedUrl};
+use uv_redacted::DisplaySafeUrl;
+
+use crate::ResolveError;
+
+/// The indexes that packages may be installed from, as configured via `allowed-indexes`.
+///
+/// Entries that name an index are resolved to the URL of that index, along with its aliases. A
+/// direct URL requirement is allowed if it's hosted on the same host as one of the allowed
+/// indexes; local paths and directories are always allowed.
+///
+/// The allowlist is enforced both during resolution and when an existing lockfile is read (see
+/// [`crate::Lock::check_allowed_indexes`]).
+#[derive(Debug, Clone)]
+pub(crate) struct AllowedIndexes(Vec<IndexUrl>);
+
+impl AllowedIndexes {
+    /// Resolve the entries of `allowed-indexes` against the configured indexes.
+    pub(crate) fn from_entries(
+        entries: &[AllowedIndex],
+        locations: &IndexLocations,
+    ) -> Result<Self, ResolveError> {
+        let mut urls = Vec::with_capacity(entries.len());
+        for entry in entries {
+            match entry {
+                AllowedIndex::Url(url) => urls.push(url.clone()),
+                AllowedIndex::Name(name) => {
+                    let Some(index) = locations
+                        .simple_indexes()
+                        .chain(locations.flat_indexes())
+                        .find(|index| index.name.as_ref() == Some(name))
+                    else {
+                        return Err(ResolveError::UnknownAllowedIndex(name.clone()));
+                    };
+                    urls.push(index.url.clone());
+                    urls.extend(index.aliases.iter().flatten().cloned());
+                }
+            }
+        }
+        Ok(Self(urls))
+    }
+
+    /// Returns `true` if packages may be installed from the given index.
+    pub(crate) fn allows_index(&self, url: &IndexUrl) -> bool {
+        self.0.iter().any(|allowed| allowed.is_same_index(url))
+    }
+
+    /// Returns `true` if packages may be installed from the given direct URL.
+    pub(crate) fn allows_url(&self, url: &VerbatimParsedUrl) -> bool {
+        match &url.parsed_url {
+            ParsedUrl::Path(_) | ParsedUrl::Directory(_) => true,
+            ParsedUrl::Git(_) | ParsedUrl::Archive(_) => self.allows_host(url.verbatim.raw()),
+        }
+    }
+
+    /// Returns `true` if the given remote URL is hosted on the same host as one of the allowed
+    /// indexes.
+    pub(crate) fn allows_host(&self, url: &DisplaySafeUrl) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        self.0
+            .iter()
+            .any(|allowed| allowed.url().host_str() == Some(host))
+    }
+
+    /// Return the error for a package that would be installed from the given URL, which isn't
+    /// allowed.
+    pub(crate) fn not_allowed(&self, package_name: &PackageName, url: String) -> ResolveError {
+        ResolveError::IndexNotAllowed {
+            package_name: package_name.clone(),
+            url,
+            allowed: self
+                .0
+                .iter()
+                .map(|url| url.without_credentials().to_string())
+                .collect(),
+        }
+    }
+
+    /// Return the error for a package that would be installed from the given direct URL, which
+    /// isn't allowed.
+    pub(crate) fn url_not_allowed(
+        &self,
+        package_name: &PackageName,
+        url: &VerbatimParsedUrl,
+    ) -> ResolveError {
+        self.not_allowed(
+            package_name,
+            DisplaySafeUrl::from(url.parsed_url.clone()).to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_distribution_types::{AllowedIndex, Index, IndexLocations, IndexName, IndexUrl};
+    use uv_pep508::VerbatimUrl;
+    use uv_pypi_types::{ParsedUrl, VerbatimParsedUrl};
+
+    use super::AllowedIndexes;
+    use crate::ResolveError;
+
+    fn locations() -> IndexLocations {
+        let mut internal =
+            Index::from_index_url(IndexUrl::from_str("https://pypi.example.com/simple").unwrap());
+        internal.name = Some(IndexName::from_str("internal").unwrap());
+        internal.aliases = Some(vec![
+            IndexUrl::from_str("https://mirror.example.com/simple").unwrap(),
+        ]);
+        IndexLocations::new(vec![internal], vec![], false)
+    }
+
+    fn allowed(entries: &[&str]) -> Result<AllowedIndexes, ResolveError> {
+        let entries = entries
+            .iter()
+            .map(|entry| AllowedIndex::from_str(entry).unwrap())
+            .collect::<Vec<_>>();
+        AllowedIndexes::from_entries(&entries, &locations())
+    }
+
+    fn url(url: &str) -> VerbatimParsedUrl {
+        let verbatim = VerbatimUrl::parse_url(url).unwrap();
+        VerbatimParsedUrl {
+            parsed_url: ParsedUrl::try_from(verbatim.to_url()).unwrap(),
+            verbatim,
+        }
+    }
+
+    /// A named index allows its URL and its aliases, but no other index.
+    #[test]
+    fn named() {
+        let allowed = allowed(&["internal"]).unwrap();
+        assert!(
+            allowed.allows_index(&IndexUrl::from_str("https://pypi.example.com/simple/").unwrap())
+        );
+        assert!(
+            allowed.allows_index(&IndexUrl::from_str("https://mirror.example.com/simple").unwrap())
+        );
+        assert!(!allowed.allows_index(&IndexUrl::from_str("https://pypi.org/simple").unwrap()));
+    }
+
+    #[test]
+    fn unknown_name() {
+        let err = allowed(&["missing"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`allowed-indexes` includes `missing`, but no index with that name is defined"
+        );
+    }
+
+    /// Direct URLs are allowed on the hosts of the allowed indexes; local paths are always allowed.
+    #[test]
+    fn direct_urls() {
+        let allowed = allowed(&["https://pypi.example.com/simple"]).unwrap();
+        assert!(allowed.allows_url(&url(
+            "https://pypi.example.com/files/iniconfig-2.0.0-py3-none-any.whl"
+        )));
+        assert!(!allowed.allows_url(&url(
+            "https://files.pythonhosted.org/packages/iniconfig-2.0.0-py3-none-any.whl"
+        )));
+        assert!(!allowed.allows_url(&url("git+https://github.com/pallets/flask")));
+        assert!(allowed.allows_url(&url("file:///tmp/iniconfig-2.0.0-py3-none-any.whl")));
+    }
+}
@@ -18,6 +18,8 @@ use uv_platform_tags::{TagCompatibility, Tags};
 use uv_pypi_types::HashDigest;
 use uv_types::HashStrategy;
 
+use crate::version_map::{trace_source_dist, trace_wheel};
+
 /// A set of [`PrioritizedDist`] from a `--find-links` entry, indexed by [`PackageName`]
 /// and [`Version`].
 #[derive(Debug, Clone, Default)]
@@ -131,6 +133,7 @@ impl FlatDistributions {
                     hasher,
                     build_options,
                 );
+                trace_wheel(&filename, &compatibility);
                 let dist = RegistryBuiltWheel {
                     filename,
                     file: Box::new(file),
@@ -152,6 +155,7 @@ impl FlatDistributions {
                     hasher,
                     build_options,
                 );
+                trace_source_dist(&filename, &compatibility);
                 let dist = RegistrySourceDist {
                     name: filename.name.clone(),
                     version: filename.version.clone(),
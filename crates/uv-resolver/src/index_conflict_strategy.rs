@@ -0,0 +1,30 @@
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum IndexConflictStrategy {
+    /// Fail the resolution if a package is pinned to different indexes for the same versions.
+    #[default]
+    Error,
+    /// Resolve the conflict by using the index with the highest priority, i.e., the index that
+    /// was defined first, and warn about the index that was ignored.
+    PreferFirst,
+    /// Resolve the conflict by falling back to the other index if the index that the package was
+    /// pinned to doesn't offer any of the versions that are required, and warn about the fallback.
+    /// Otherwise, fail the resolution.
+    Fallback,
+    /// Resolve the conflict by using the index that the package was pinned to first, and warn
+    /// about the index that was ignored.
+    Warn,
+}
+
+impl std::fmt::Display for IndexConflictStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::PreferFirst => write!(f, "prefer-first"),
+            Self::Fallback => write!(f, "fallback"),
+            Self::Warn => write!(f, "warn"),
+        }
+    }
+}
@@ -1,5 +1,8 @@
 pub use dependency_mode::DependencyMode;
-pub use error::{ErrorTree, NoSolutionError, NoSolutionHeader, ResolveError, SentinelRange};
+pub use error::{
+    ConflictingIndexReport, ErrorTree, NoSolutionError, NoSolutionHeader, ResolveError,
+    ResolveErrorDetails, ResolveErrorReport, SentinelRange,
+};
 pub use exclude_newer::{
     ExcludeNewer, ExcludeNewerChange, ExcludeNewerPackage, ExcludeNewerPackageChange,
     ExcludeNewerPackageEntry, ExcludeNewerValue, ExcludeNewerValueChange, PackageExcludeNewer,
@@ -7,7 +10,10 @@ pub use exclude_newer::{
 };
 pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
+pub use fork_graph::ForkGraph;
+pub use fork_indexes::PinnedIndexes;
 pub use fork_strategy::ForkStrategy;
+pub use index_conflict_strategy::IndexConflictStrategy;
 pub use lock::{
     Installable, Lock, LockError, LockVersion, Package, PackageMap, PylockToml,
     PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest, SatisfiesResult, TreeDisplay,
@@ -40,6 +46,7 @@ type FxHashbrownSet<T> = hashbrown::HashSet<T, rustc_hash::FxBuildHasher>;
 
 type FxHashbrownMap<K, V> = hashbrown::HashMap<K, V, rustc_hash::FxBuildHasher>;
 
+mod allowed_indexes;
 mod candidate_selector;
 mod dependency_mode;
 mod dependency_provider;
@@ -47,10 +54,12 @@ mod error;
 mod exclude_newer;
 mod exclusions;
 mod flat_index;
+mod fork_graph;
 mod fork_indexes;
 mod fork_strategy;
 mod fork_urls;
 mod graph_ops;
+mod index_conflict_strategy;
 mod lock;
 mod manifest;
 mod marker;
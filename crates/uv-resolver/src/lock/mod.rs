@@ -25,11 +25,11 @@ use uv_distribution_filename::{
     BuildTag, DistExtension, ExtensionError, SourceDistExtension, WheelFilename,
 };
 use uv_distribution_types::{
-    BuiltDist, DependencyMetadata, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist,
-    Dist, DistributionMetadata, FileLocation, GitSourceDist, IndexLocations, IndexMetadata,
-    IndexUrl, Name, PathBuiltDist, PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel,
-    RegistrySourceDist, RemoteSource, Requirement, RequirementSource, RequiresPython, ResolvedDist,
-    SimplifiedMarkerTree, StaticMetadata, ToUrlError, UrlString,
+    AllowedIndex, BuiltDist, DependencyMetadata, DirectUrlBuiltDist, DirectUrlSourceDist,
+    DirectorySourceDist, Dist, DistributionMetadata, FileLocation, GitSourceDist, IndexLocations,
+    IndexMetadata, IndexUrl, Name, PathBuiltDist, PathSourceDist, RegistryBuiltDist,
+    RegistryBuiltWheel, RegistrySourceDist, RemoteSource, Requirement, RequirementSource,
+    RequiresPython, ResolvedDist, SimplifiedMarkerTree, StaticMetadata, ToUrlError, UrlString,
 };
 use uv_fs::{PortablePath, PortablePathBuf, relative_to};
 use uv_git::{RepositoryReference, ResolvedRepositoryReference};
@@ -49,6 +49,7 @@ use uv_small_str::SmallString;
 use uv_types::{BuildContext, HashStrategy};
 use uv_workspace::{Editability, WorkspaceMember};
 
+use crate::allowed_indexes::AllowedIndexes;
 use crate::exclude_newer::ExcludeNewerSpan;
 use crate::fork_strategy::ForkStrategy;
 pub(crate) use crate::lock::export::PylockTomlPackage;
@@ -61,7 +62,7 @@ use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
     ExcludeNewer, ExcludeNewerPackage, ExcludeNewerValue, InMemoryIndex, MetadataResponse,
-    PackageExcludeNewer, PrereleaseMode, ResolutionMode, ResolverOutput,
+    PackageExcludeNewer, PrereleaseMode, ResolutionMode, ResolveError, ResolverOutput,
 };
 
 mod export;
@@ -1497,6 +1498,51 @@ impl Lock {
         Ok(found_dist)
     }
 
+    /// Returns an error if any package in the lockfile would be installed from a source that isn't
+    /// allowed by the `allowed-indexes` setting.
+    ///
+    /// As during resolution, registry packages must come from one of the allowed indexes, and Git
+    /// and direct URL packages must be hosted on the same host as one of them. Packages from local
+    /// paths and directories (including workspace members) are always allowed.
+    pub fn check_allowed_indexes(
+        &self,
+        root: &Path,
+        entries: &[AllowedIndex],
+        locations: &IndexLocations,
+    ) -> Result<(), ResolveError> {
+        let allowed_indexes = AllowedIndexes::from_entries(entries, locations)?;
+        for package in &self.packages {
+            let (allowed, url) = match &package.id.source {
+                Source::Registry(RegistrySource::Url(url)) => (
+                    url.to_url().is_ok_and(|url| {
+                        allowed_indexes.allows_index(&IndexUrl::from(VerbatimUrl::from_url(url)))
+                    }),
+                    url.to_string(),
+                ),
+                Source::Registry(RegistrySource::Path(path)) => (
+                    VerbatimUrl::from_absolute_path(root.join(path))
+                        .is_ok_and(|url| allowed_indexes.allows_index(&IndexUrl::from(url))),
+                    path.display().to_string(),
+                ),
+                Source::Git(url, _) | Source::Direct(url, _) => (
+                    url.to_url()
+                        .is_ok_and(|url| allowed_indexes.allows_host(&url)),
+                    url.to_string(),
+                ),
+                Source::Path(_)
+                | Source::Directory(_)
+                | Source::Editable(_)
+                | Source::Virtual(_) => {
+                    continue;
+                }
+            };
+            if !allowed {
+                return Err(allowed_indexes.not_allowed(&package.id.name, url));
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the package with the given name.
     ///
     /// If there are multiple matching packages, returns the package that
@@ -6669,4 +6715,61 @@ source = { editable = "path/to/dir" }
         let result: Result<Lock, _> = toml::from_str(data);
         insta::assert_debug_snapshot!(result);
     }
+
+    /// A lockfile read from disk is checked against `allowed-indexes`: registry packages must come
+    /// from an allowed index and direct URL packages from the host of one, while packages from
+    /// local paths and directories are always allowed.
+    #[test]
+    fn check_allowed_indexes() {
+        let data = r#"
+version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://example.com", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+
+[[package]]
+name = "iniconfig"
+version = "2.0.0"
+source = { url = "https://files.pythonhosted.org/packages/iniconfig-2.0.0-py3-none-any.whl" }
+wheels = [{ url = "https://files.pythonhosted.org/packages/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3" }]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+
+[[package]]
+name = "sniffio"
+version = "1.3.1"
+source = { directory = "path/to/sniffio" }
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+        let locations = IndexLocations::default();
+        let allowed = |entries: &[&str]| {
+            let entries = entries
+                .iter()
+                .map(|entry| AllowedIndex::from_str(entry).unwrap())
+                .collect::<Vec<_>>();
+            lock.check_allowed_indexes(Path::new("/"), &entries, &locations)
+        };
+
+        allowed(&[
+            "https://pypi.org/simple",
+            "https://files.pythonhosted.org/simple",
+        ])
+        .unwrap();
+
+        assert_stripped_snapshot!(
+            allowed(&["https://pypi.example.com/simple"]).unwrap_err(),
+            @"Package `anyio` would be installed from https://pypi.org/simple, which is not one of the allowed indexes: https://pypi.example.com/simple"
+        );
+        assert_stripped_snapshot!(
+            allowed(&["https://pypi.org/simple"]).unwrap_err(),
+            @"Package `iniconfig` would be installed from https://files.pythonhosted.org/packages/iniconfig-2.0.0-py3-none-any.whl, which is not one of the allowed indexes: https://pypi.org/simple"
+        );
+    }
 }
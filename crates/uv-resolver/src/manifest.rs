@@ -114,12 +114,36 @@ impl Manifest {
             .chain(self.overrides(env, mode))
     }
 
+    /// Like [`Self::requirements`], but along with the name of the package that requested each
+    /// requirement, or `None` for the direct requirements, constraints, and overrides.
+    pub fn requirements_with_parents<'a>(
+        &'a self,
+        env: &'a ResolverEnvironment,
+        mode: DependencyMode,
+    ) -> impl Iterator<Item = (Option<&'a PackageName>, Cow<'a, Requirement>)> + 'a {
+        self.requirements_no_overrides_with_parents(env, mode)
+            .chain(
+                self.overrides(env, mode)
+                    .map(|requirement| (None, requirement)),
+            )
+    }
+
     /// Like [`Self::requirements`], but without the overrides.
     pub fn requirements_no_overrides<'a>(
         &'a self,
         env: &'a ResolverEnvironment,
         mode: DependencyMode,
     ) -> impl Iterator<Item = Cow<'a, Requirement>> + 'a {
+        self.requirements_no_overrides_with_parents(env, mode)
+            .map(|(_, requirement)| requirement)
+    }
+
+    /// Like [`Self::requirements_with_parents`], but without the overrides.
+    fn requirements_no_overrides_with_parents<'a>(
+        &'a self,
+        env: &'a ResolverEnvironment,
+        mode: DependencyMode,
+    ) -> impl Iterator<Item = (Option<&'a PackageName>, Cow<'a, Requirement>)> + 'a {
         match mode {
             // Include all direct and transitive requirements, with constraints and overrides applied.
             DependencyMode::Transitive => Either::Left(
@@ -133,6 +157,7 @@ impl Manifest {
                                 requirement
                                     .evaluate_markers(env.marker_environment(), lookahead.extras())
                             })
+                            .map(move |requirement| (Some(lookahead.name()), requirement))
                     })
                     .chain(
                         self.overrides
@@ -140,7 +165,8 @@ impl Manifest {
                             .filter(|requirement| !self.excludes.contains(&requirement.name))
                             .filter(move |requirement| {
                                 requirement.evaluate_markers(env.marker_environment(), &[])
-                            }),
+                            })
+                            .map(|requirement| (None, requirement)),
                     )
                     .chain(
                         self.constraints
@@ -149,7 +175,7 @@ impl Manifest {
                             .filter(move |requirement| {
                                 requirement.evaluate_markers(env.marker_environment(), &[])
                             })
-                            .map(Cow::Borrowed),
+                            .map(|requirement| (None, Cow::Borrowed(requirement))),
                     ),
             ),
             // Include direct requirements, with constraints and overrides applied.
@@ -160,7 +186,8 @@ impl Manifest {
                     .filter(|requirement| !self.excludes.contains(&requirement.name))
                     .filter(move |requirement| {
                         requirement.evaluate_markers(env.marker_environment(), &[])
-                    }),
+                    })
+                    .map(|requirement| (None, requirement)),
             ),
         }
     }
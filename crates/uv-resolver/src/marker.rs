@@ -1,9 +1,14 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Bound;
+
 use pubgrub::Ranges;
 use smallvec::SmallVec;
-use std::ops::Bound;
 
 use uv_pep440::{LowerBound, UpperBound, Version};
-use uv_pep508::{CanonicalMarkerValueVersion, MarkerTree, MarkerTreeKind};
+use uv_pep508::{
+    CanonicalMarkerValueVersion, MarkerEnvironment, MarkerEnvironmentBuilder, MarkerTree,
+    MarkerTreeKind,
+};
 
 use uv_distribution_types::RequiresPythonRange;
 
@@ -94,6 +99,128 @@ pub(crate) fn requires_python(tree: MarkerTree) -> Option<RequiresPythonRange> {
     ))
 }
 
+/// A concrete environment that satisfies a [`MarkerTree`], used to illustrate the environments
+/// that the markers describe, e.g., "Python 3.9 on Windows (AMD64)".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ExampleEnvironment {
+    python_version: &'static str,
+    platform: &'static ExamplePlatform,
+    machine: &'static str,
+}
+
+/// A platform that example environments are drawn from.
+#[derive(Debug, PartialEq, Eq)]
+struct ExamplePlatform {
+    name: &'static str,
+    sys_platform: &'static str,
+    platform_system: &'static str,
+    os_name: &'static str,
+    machines: &'static [&'static str],
+}
+
+impl ExampleEnvironment {
+    /// The Python versions that examples are drawn from, with the most recent first.
+    const PYTHON_VERSIONS: &[&str] = &["3.14", "3.13", "3.12", "3.11", "3.10", "3.9", "3.8"];
+
+    /// The platforms that examples are drawn from, in order of preference.
+    const PLATFORMS: &[ExamplePlatform] = &[
+        ExamplePlatform {
+            name: "Linux",
+            sys_platform: "linux",
+            platform_system: "Linux",
+            os_name: "posix",
+            machines: &["x86_64", "aarch64"],
+        },
+        ExamplePlatform {
+            name: "Windows",
+            sys_platform: "win32",
+            platform_system: "Windows",
+            os_name: "nt",
+            machines: &["AMD64", "ARM64"],
+        },
+        ExamplePlatform {
+            name: "macOS",
+            sys_platform: "darwin",
+            platform_system: "Darwin",
+            os_name: "posix",
+            machines: &["arm64", "x86_64"],
+        },
+    ];
+
+    /// Return up to two environments that satisfy the markers, preferring examples that differ
+    /// in their platform, and then in their Python version.
+    ///
+    /// Extras are ignored, as they aren't a property of the environment. Returns no examples if
+    /// the markers don't constrain the environment, or if none of the candidate environments
+    /// satisfy them (e.g., for markers on the `platform_release`).
+    pub(crate) fn for_markers(markers: MarkerTree) -> Vec<Self> {
+        let markers = markers.without_extras();
+        if markers.is_true() {
+            return Vec::new();
+        }
+        let mut candidates = Self::candidates().filter(|candidate| {
+            candidate
+                .marker_environment()
+                .is_some_and(|env| markers.evaluate(&env, &[]))
+        });
+        let Some(first) = candidates.next() else {
+            return Vec::new();
+        };
+        let candidates = candidates.collect::<Vec<_>>();
+        let second = candidates
+            .iter()
+            .find(|candidate| candidate.platform != first.platform)
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .find(|candidate| candidate.python_version != first.python_version)
+            });
+        std::iter::once(first).chain(second.copied()).collect()
+    }
+
+    /// Iterate over the candidate environments, in order of preference.
+    fn candidates() -> impl Iterator<Item = Self> {
+        Self::PYTHON_VERSIONS.iter().flat_map(|python_version| {
+            Self::PLATFORMS.iter().flat_map(move |platform| {
+                platform.machines.iter().map(move |machine| Self {
+                    python_version,
+                    platform,
+                    machine,
+                })
+            })
+        })
+    }
+
+    /// Construct the [`MarkerEnvironment`] of a CPython interpreter in this environment.
+    fn marker_environment(&self) -> Option<MarkerEnvironment> {
+        let python_full_version = format!("{}.0", self.python_version);
+        MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+            implementation_name: "cpython",
+            implementation_version: &python_full_version,
+            os_name: self.platform.os_name,
+            platform_machine: self.machine,
+            platform_python_implementation: "CPython",
+            platform_release: "",
+            platform_system: self.platform.platform_system,
+            platform_version: "",
+            python_full_version: &python_full_version,
+            python_version: self.python_version,
+            sys_platform: self.platform.sys_platform,
+        })
+        .ok()
+    }
+}
+
+impl Display for ExampleEnvironment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Python {} on {} ({})",
+            self.python_version, self.platform.name, self.machine
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,4 +313,89 @@ mod tests {
                 .unwrap();
         assert_eq!(requires_python(tree), None);
     }
+
+    /// Render the examples for the given markers, verifying that each satisfies them.
+    fn examples(markers: &str) -> Vec<String> {
+        let markers = MarkerTree::from_str(markers).unwrap();
+        ExampleEnvironment::for_markers(markers)
+            .into_iter()
+            .map(|example| {
+                let env = example.marker_environment().unwrap();
+                assert!(
+                    markers.evaluate(&env, &[]),
+                    "{example} doesn't satisfy {markers:?}"
+                );
+                example.to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn example_environments_python_version() {
+        assert_eq!(
+            examples("python_full_version < '3.10'"),
+            [
+                "Python 3.9 on Linux (x86_64)",
+                "Python 3.9 on Windows (AMD64)"
+            ]
+        );
+        assert_eq!(
+            examples("python_version >= '3.12'"),
+            [
+                "Python 3.14 on Linux (x86_64)",
+                "Python 3.14 on Windows (AMD64)"
+            ]
+        );
+    }
+
+    #[test]
+    fn example_environments_sys_platform() {
+        assert_eq!(
+            examples("sys_platform == 'win32'"),
+            [
+                "Python 3.14 on Windows (AMD64)",
+                "Python 3.13 on Windows (AMD64)"
+            ]
+        );
+        assert_eq!(
+            examples("sys_platform != 'linux'"),
+            [
+                "Python 3.14 on Windows (AMD64)",
+                "Python 3.14 on macOS (arm64)"
+            ]
+        );
+        assert_eq!(
+            examples("sys_platform == 'darwin' and platform_machine == 'x86_64'"),
+            [
+                "Python 3.14 on macOS (x86_64)",
+                "Python 3.13 on macOS (x86_64)"
+            ]
+        );
+    }
+
+    #[test]
+    fn example_environments_conjunction() {
+        assert_eq!(
+            examples("python_full_version == '3.9.*' and sys_platform == 'win32'"),
+            ["Python 3.9 on Windows (AMD64)"]
+        );
+        assert_eq!(
+            examples(
+                "python_full_version >= '3.11' and python_full_version < '3.13' and sys_platform == 'darwin'"
+            ),
+            [
+                "Python 3.12 on macOS (arm64)",
+                "Python 3.11 on macOS (arm64)"
+            ]
+        );
+    }
+
+    /// Markers that don't constrain the environment, or that no candidate satisfies, have no
+    /// examples.
+    #[test]
+    fn example_environments_none() {
+        assert!(examples("extra == 'cu124'").is_empty());
+        assert!(examples("sys_platform == 'emscripten'").is_empty());
+        assert!(examples("python_full_version < '3.8'").is_empty());
+    }
 }
@@ -1,8 +1,10 @@
 use uv_configuration::{BuildOptions, IndexStrategy};
+use uv_distribution_types::{AllowedIndex, PackageIndexEntry};
 use uv_pypi_types::SupportedEnvironments;
 use uv_torch::TorchStrategy;
 
 use crate::fork_strategy::ForkStrategy;
+use crate::index_conflict_strategy::IndexConflictStrategy;
 use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
@@ -18,6 +20,10 @@ pub struct Options {
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
     pub torch_backend: Option<TorchStrategy>,
+    pub allow_index_divergence: bool,
+    pub index_conflict_strategy: IndexConflictStrategy,
+    pub package_indexes: Vec<PackageIndexEntry>,
+    pub allowed_indexes: Option<Vec<AllowedIndex>>,
 }
 
 /// Builder for [`Options`].
@@ -33,6 +39,10 @@ pub struct OptionsBuilder {
     flexibility: Flexibility,
     build_options: BuildOptions,
     torch_backend: Option<TorchStrategy>,
+    allow_index_divergence: bool,
+    index_conflict_strategy: IndexConflictStrategy,
+    package_indexes: Vec<PackageIndexEntry>,
+    allowed_indexes: Option<Vec<AllowedIndex>>,
 }
 
 impl OptionsBuilder {
@@ -111,6 +121,39 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether a package may be pinned to different indexes under disjoint markers, in which
+    /// case the resolution forks such that each fork uses its own index.
+    #[must_use]
+    pub fn allow_index_divergence(mut self, allow_index_divergence: bool) -> Self {
+        self.allow_index_divergence = allow_index_divergence;
+        self
+    }
+
+    /// Sets the [`IndexConflictStrategy`].
+    #[must_use]
+    pub fn index_conflict_strategy(
+        mut self,
+        index_conflict_strategy: IndexConflictStrategy,
+    ) -> Self {
+        self.index_conflict_strategy = index_conflict_strategy;
+        self
+    }
+
+    /// Sets the indexes that packages are pinned to before the resolution starts, as with
+    /// `--package-index`.
+    #[must_use]
+    pub fn package_indexes(mut self, package_indexes: Vec<PackageIndexEntry>) -> Self {
+        self.package_indexes = package_indexes;
+        self
+    }
+
+    /// Sets the indexes that packages may be installed from, as with `allowed-indexes`.
+    #[must_use]
+    pub fn allowed_indexes(mut self, allowed_indexes: Option<Vec<AllowedIndex>>) -> Self {
+        self.allowed_indexes = allowed_indexes;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -124,6 +167,10 @@ impl OptionsBuilder {
             flexibility: self.flexibility,
             build_options: self.build_options,
             torch_backend: self.torch_backend,
+            allow_index_divergence: self.allow_index_divergence,
+            index_conflict_strategy: self.index_conflict_strategy,
+            package_indexes: self.package_indexes,
+            allowed_indexes: self.allowed_indexes,
         }
     }
 }
@@ -8,6 +8,7 @@ use owo_colors::OwoColorize;
 use pubgrub::{DerivationTree, Derived, External, Map, Range, ReportFormatter, Term};
 use rustc_hash::FxHashMap;
 
+use uv_auth::CredentialSource;
 use uv_configuration::{IndexStrategy, NoBinary, NoBuild};
 use uv_distribution_types::{
     IncompatibleDist, IncompatibleSource, IncompatibleWheel, Index, IndexCapabilities,
@@ -26,10 +27,12 @@ use crate::prerelease::AllowPrerelease;
 use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubPython};
 use crate::python_requirement::{PythonRequirement, PythonRequirementSource};
 use crate::resolver::{
-    MetadataUnavailable, UnavailableErrorChain, UnavailablePackage, UnavailableReason,
-    UnavailableVersion,
+    ExcludedVersions, MetadataUnavailable, UnavailableErrorChain, UnavailablePackage,
+    UnavailableReason, UnavailableVersion, resolving_for,
+};
+use crate::{
+    Flexibility, InMemoryIndex, Options, ResolverEnvironment, VersionMap, VersionsResponse,
 };
-use crate::{Flexibility, InMemoryIndex, Options, ResolverEnvironment, VersionsResponse};
 
 #[derive(Debug)]
 pub(crate) struct PubGrubReportFormatter<'a> {
@@ -581,6 +584,13 @@ impl PubGrubReportFormatter<'_> {
                         output_hints,
                     );
 
+                    // Check for a pinned index that lacks the package, when another index has it.
+                    if let Some(hint) =
+                        Self::pinned_index_hint(name, set, index, index_locations, fork_indexes)
+                    {
+                        output_hints.insert(hint);
+                    }
+
                     if let UnavailableReason::Version(UnavailableVersion::IncompatibleDist(
                         incompatibility,
                     )) = reason
@@ -638,6 +648,13 @@ impl PubGrubReportFormatter<'_> {
                         incomplete_packages,
                         output_hints,
                     );
+
+                    // Check for a pinned index that lacks the package, when another index has it.
+                    if let Some(hint) =
+                        Self::pinned_index_hint(name, set, index, index_locations, fork_indexes)
+                    {
+                        output_hints.insert(hint);
+                    }
                 }
             }
             DerivationTree::External(External::FromDependencyOf(
@@ -731,6 +748,22 @@ impl PubGrubReportFormatter<'_> {
         }
     }
 
+    /// Generate a [`PubGrubHint`] for each package whose newest versions were excluded due to their
+    /// `requires-python`.
+    pub(crate) fn python_exclusion_hints(
+        &self,
+        python_exclusions: &FxHashMap<PackageName, ExcludedVersions>,
+        output_hints: &mut IndexSet<PubGrubHint>,
+    ) {
+        for (name, versions) in python_exclusions.iter().sorted_by_key(|(name, _)| *name) {
+            output_hints.insert(PubGrubHint::ExcludedByRequiresPython {
+                package: name.clone(),
+                versions: versions.clone(),
+                python: resolving_for(self.python_requirement),
+            });
+        }
+    }
+
     /// Generate a [`PubGrubHint`] for a package that doesn't have any wheels matching the current
     /// Python version, ABI, or platform.
     fn tag_hint(
@@ -829,6 +862,56 @@ impl PubGrubReportFormatter<'_> {
         }
     }
 
+    /// Generate a [`PubGrubHint`] for a package that is pinned to an index, when a compatible
+    /// version is available on another configured index.
+    ///
+    /// Only considers metadata that was already fetched during the resolution (e.g., in another
+    /// fork), rather than querying the remaining indexes.
+    fn pinned_index_hint(
+        name: &PackageName,
+        set: &Range<Version>,
+        index: &InMemoryIndex,
+        index_locations: &IndexLocations,
+        fork_indexes: &ForkIndexes,
+    ) -> Option<PubGrubHint> {
+        let pinned = fork_indexes.get(name).map(IndexMetadata::url)?;
+
+        // Determine whether the given version maps include a compatible version from `url`.
+        let available = |url: &IndexUrl, version_maps: &[VersionMap]| {
+            version_maps.iter().any(|version_map| {
+                version_map.index().is_none_or(|index| index == url)
+                    && version_map.versions().any(|version| set.contains(version))
+            })
+        };
+
+        let indexes = index_locations.allowed_indexes();
+        let pinned_name = indexes
+            .iter()
+            .find(|candidate| candidate.url == *pinned)
+            .and_then(|candidate| candidate.name.as_ref());
+
+        let found = indexes
+            .into_iter()
+            .filter(|candidate| candidate.url != *pinned)
+            .find(|candidate| {
+                let explicit = index
+                    .explicit()
+                    .get(&(name.clone(), candidate.url.clone()));
+                let implicit = index.implicit().get(name);
+                [explicit, implicit].into_iter().flatten().any(|response| {
+                    matches!(&*response, VersionsResponse::Found(version_maps) if available(&candidate.url, version_maps))
+                })
+            })?;
+
+        Some(PubGrubHint::AvailableOnOtherIndex {
+            package: name.clone(),
+            pinned: pinned.clone(),
+            pinned_name: pinned_name.map(ToString::to_string),
+            index: found.url.clone(),
+            index_name: found.name.as_ref().map(ToString::to_string),
+        })
+    }
+
     fn index_hints(
         name: &PackageName,
         set: &Range<Version>,
@@ -946,11 +1029,13 @@ impl PubGrubReportFormatter<'_> {
             if index_capabilities.unauthorized(&index.url) {
                 hints.insert(PubGrubHint::UnauthorizedIndex {
                     index: index.url.clone(),
+                    source: CredentialSource::describe_rejected(index.url.url()),
                 });
             }
             if index_capabilities.forbidden(&index.url) {
                 hints.insert(PubGrubHint::ForbiddenIndex {
                     index: index.url.clone(),
+                    source: CredentialSource::describe_rejected(index.url.url()),
                 });
             }
         }
@@ -1157,6 +1242,19 @@ pub(crate) enum PubGrubHint {
         // excluded from `PartialEq` and `Hash`
         next_index: IndexUrl,
     },
+    /// A package is pinned to an index that doesn't provide a compatible version, but a
+    /// compatible version was found on another configured index.
+    AvailableOnOtherIndex {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        pinned: IndexUrl,
+        // excluded from `PartialEq` and `Hash`
+        pinned_name: Option<String>,
+        // excluded from `PartialEq` and `Hash`
+        index: IndexUrl,
+        // excluded from `PartialEq` and `Hash`
+        index_name: Option<String>,
+    },
     /// No wheels are available for a package, and using source distributions was disabled.
     NoBuild {
         package: PackageName,
@@ -1170,9 +1268,17 @@ pub(crate) enum PubGrubHint {
         option: NoBinary,
     },
     /// An index returned an Unauthorized (401) response.
-    UnauthorizedIndex { index: IndexUrl },
+    UnauthorizedIndex {
+        index: IndexUrl,
+        // excluded from `PartialEq` and `Hash`
+        source: Option<String>,
+    },
     /// An index returned a Forbidden (403) response.
-    ForbiddenIndex { index: IndexUrl },
+    ForbiddenIndex {
+        index: IndexUrl,
+        // excluded from `PartialEq` and `Hash`
+        source: Option<String>,
+    },
     /// None of the available wheels for a package have a compatible Python language tag (e.g.,
     /// `cp310` in `cp310-abi3-manylinux_2_17_x86_64.whl`).
     LanguageTags {
@@ -1204,6 +1310,15 @@ pub(crate) enum PubGrubHint {
         // excluded from `PartialEq` and `Hash`
         tags: BTreeSet<PlatformTag>,
     },
+    /// The newest versions of a package were excluded, as they require a newer Python version
+    /// than the one being resolved for.
+    ExcludedByRequiresPython {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        versions: ExcludedVersions,
+        // excluded from `PartialEq` and `Hash`
+        python: String,
+    },
     /// The resolution failed for a Python version that is different from the current Python version.
     DisjointPythonVersion {
         // excluded from `PartialEq` and `Hash`
@@ -1266,6 +1381,9 @@ enum PubGrubHintCore {
     UncheckedIndex {
         package: PackageName,
     },
+    AvailableOnOtherIndex {
+        package: PackageName,
+    },
     UnauthorizedIndex {
         index: IndexUrl,
     },
@@ -1287,6 +1405,9 @@ enum PubGrubHintCore {
     PlatformTags {
         package: PackageName,
     },
+    ExcludedByRequiresPython {
+        package: PackageName,
+    },
     DisjointPythonVersion,
     DisjointEnvironment,
 }
@@ -1348,13 +1469,19 @@ impl From<PubGrubHint> for PubGrubHintCore {
                 Self::DependsOnItself { package, workspace }
             }
             PubGrubHint::UncheckedIndex { name: package, .. } => Self::UncheckedIndex { package },
-            PubGrubHint::UnauthorizedIndex { index } => Self::UnauthorizedIndex { index },
-            PubGrubHint::ForbiddenIndex { index } => Self::ForbiddenIndex { index },
+            PubGrubHint::AvailableOnOtherIndex { package, .. } => {
+                Self::AvailableOnOtherIndex { package }
+            }
+            PubGrubHint::UnauthorizedIndex { index, .. } => Self::UnauthorizedIndex { index },
+            PubGrubHint::ForbiddenIndex { index, .. } => Self::ForbiddenIndex { index },
             PubGrubHint::NoBuild { package, .. } => Self::NoBuild { package },
             PubGrubHint::NoBinary { package, .. } => Self::NoBinary { package },
             PubGrubHint::LanguageTags { package, .. } => Self::LanguageTags { package },
             PubGrubHint::AbiTags { package, .. } => Self::AbiTags { package },
             PubGrubHint::PlatformTags { package, .. } => Self::PlatformTags { package },
+            PubGrubHint::ExcludedByRequiresPython { package, .. } => {
+                Self::ExcludedByRequiresPython { package }
+            }
             PubGrubHint::DisjointPythonVersion { .. } => Self::DisjointPythonVersion,
             PubGrubHint::DisjointEnvironment => Self::DisjointEnvironment,
         }
@@ -1637,7 +1764,35 @@ impl std::fmt::Display for PubGrubHint {
                     "--index-strategy unsafe-best-match".green(),
                 )
             }
-            Self::UnauthorizedIndex { index } => {
+            Self::AvailableOnOtherIndex {
+                package,
+                pinned,
+                pinned_name,
+                index,
+                index_name,
+            } => {
+                let describe = |url: &IndexUrl, name: Option<&String>| {
+                    if let Some(name) = name {
+                        format!(
+                            "the index `{}` ({})",
+                            name.cyan(),
+                            url.without_credentials().cyan()
+                        )
+                    } else {
+                        format!("{}", url.without_credentials().cyan())
+                    }
+                };
+                write!(
+                    f,
+                    "{}{} `{}` is pinned to {}, which doesn't provide a compatible version, but it is available on {}",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.cyan(),
+                    describe(pinned, pinned_name.as_ref()),
+                    describe(index, index_name.as_ref()),
+                )
+            }
+            Self::UnauthorizedIndex { index, source } => {
                 write!(
                     f,
                     "{}{} An index URL ({}) could not be queried due to a lack of valid authentication credentials ({}).",
@@ -1645,9 +1800,13 @@ impl std::fmt::Display for PubGrubHint {
                     ":".bold(),
                     index.without_credentials().cyan(),
                     "401 Unauthorized".red(),
-                )
+                )?;
+                if let Some(source) = source {
+                    write!(f, " The credentials were read from {source}.")?;
+                }
+                Ok(())
             }
-            Self::ForbiddenIndex { index } => {
+            Self::ForbiddenIndex { index, source } => {
                 write!(
                     f,
                     "{}{} An index URL ({}) returned a {} error. This could indicate lack of valid authentication credentials, or the package may not exist on this index.",
@@ -1655,7 +1814,11 @@ impl std::fmt::Display for PubGrubHint {
                     ":".bold(),
                     index.without_credentials().cyan(),
                     "403 Forbidden".red(),
-                )
+                )?;
+                if let Some(source) = source {
+                    write!(f, " The credentials were read from {source}.")?;
+                }
+                Ok(())
             }
             Self::NoBuild { package, option } => {
                 let option = match option {
@@ -1787,6 +1950,33 @@ impl std::fmt::Display for PubGrubHint {
                         .join(", "),
                 )
             }
+            Self::ExcludedByRequiresPython {
+                package,
+                versions,
+                python,
+            } => {
+                write!(
+                    f,
+                    "{}{} {} newer {} of `{}` ({}) {} Python {}, but you're resolving for Python {}",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    versions.len(),
+                    if versions.len() == 1 {
+                        "version"
+                    } else {
+                        "versions"
+                    },
+                    package.cyan(),
+                    versions.cyan(),
+                    if versions.len() == 1 {
+                        "requires"
+                    } else {
+                        "require"
+                    },
+                    versions.requires_python().cyan(),
+                    python.cyan(),
+                )
+            }
             Self::DisjointPythonVersion { python_version } => {
                 write!(
                     f,
@@ -1869,7 +2059,7 @@ enum PackageRangeKind {
 
 /// A [`Range`] and [`PubGrubPackage`] combination for display.
 #[derive(Debug)]
-struct PackageRange<'a> {
+pub(crate) struct PackageRange<'a> {
     package: &'a PubGrubPackage,
     range: &'a Range<Version>,
     kind: PackageRangeKind,
@@ -1890,7 +2080,7 @@ impl PackageRange<'_> {
         }
     }
 
-    fn dependency<'a>(
+    pub(crate) fn dependency<'a>(
         package: &'a PubGrubPackage,
         range: &'a Range<Version>,
         formatter: Option<&'a PubGrubReportFormatter<'a>>,
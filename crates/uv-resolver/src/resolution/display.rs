@@ -5,7 +5,9 @@ use petgraph::visit::EdgeRef;
 use petgraph::{Directed, Direction, Graph};
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use uv_distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
+use uv_distribution_types::{
+    DistributionMetadata, IndexLocations, Name, SourceAnnotation, SourceAnnotations,
+};
 use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
 
@@ -32,6 +34,9 @@ pub struct DisplayResolutionGraph<'a> {
     include_annotations: bool,
     /// Whether to include indexes in the output, to indicate which index was used for each package.
     include_index_annotation: bool,
+    /// The index locations, if packages that weren't served by the default index should be
+    /// annotated with the index that was used (e.g., `# index: internal (https://...)`).
+    annotate_index: Option<&'a IndexLocations>,
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
@@ -60,6 +65,7 @@ impl<'a> DisplayResolutionGraph<'a> {
         include_markers: bool,
         include_annotations: bool,
         include_index_annotation: bool,
+        annotate_index: Option<&'a IndexLocations>,
         annotation_style: AnnotationStyle,
     ) -> Self {
         for fork_marker in &underlying.fork_markers {
@@ -78,6 +84,7 @@ impl<'a> DisplayResolutionGraph<'a> {
             include_markers,
             include_annotations,
             include_index_annotation,
+            annotate_index,
             annotation_style,
         }
     }
@@ -285,9 +292,34 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 writeln!(f, "{line}")?;
             }
 
-            // If enabled, include indexes to indicate which index was used for each package (e.g.,
-            // `# from https://pypi.org/simple`).
-            if self.include_index_annotation {
+            // If enabled, include the index that was used for each package that wasn't served by
+            // the default index, or that was pinned to an index, along with its name, if any (e.g.,
+            // `# index: internal (https://pypi.internal/simple)`).
+            let index_comment = self.annotate_index.and_then(|locations| {
+                let index = node.dist.index()?;
+                if locations
+                    .default_index()
+                    .is_some_and(|default| default.url.is_same_index(index))
+                    && !self.resolution.pinned_indexes.contains_key(node.name())
+                {
+                    return None;
+                }
+                let url = index.without_credentials();
+                let name = locations
+                    .simple_indexes()
+                    .find(|candidate| candidate.url.is_same_index(index))
+                    .and_then(|candidate| candidate.name.as_ref());
+                Some(if let Some(name) = name {
+                    format!("    # index: {name} ({url})")
+                } else {
+                    format!("    # index: {url}")
+                })
+            });
+            if let Some(comment) = index_comment {
+                writeln!(f, "{}", comment.green())?;
+            } else if self.include_index_annotation {
+                // If enabled, include indexes to indicate which index was used for each package
+                // (e.g., `# from https://pypi.org/simple`).
                 if let Some(index) = node.dist.index() {
                     let url = index.without_credentials();
                     writeln!(f, "{}", format!("    # from {url}").green())?;
@@ -8,6 +8,7 @@ use petgraph::{
     graph::{Graph, NodeIndex},
 };
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use tracing::debug;
 
 use uv_configuration::{Constraints, Overrides};
 use uv_distribution::Metadata;
@@ -21,6 +22,8 @@ use uv_pep440::{Version, VersionSpecifier};
 use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind};
 use uv_pypi_types::{Conflicts, HashDigests, ParsedUrlError, VerbatimParsedUrl, Yanked};
 
+use crate::fork_graph::ForkGraph;
+use crate::fork_indexes::PinnedIndexes;
 use crate::graph_ops::{marker_reachability, simplify_conflict_markers};
 use crate::pins::FilePins;
 use crate::preferences::Preferences;
@@ -56,6 +59,13 @@ pub struct ResolverOutput {
     pub(crate) overrides: Overrides,
     /// The options that were used to build the graph.
     pub(crate) options: Options,
+    /// The indexes that packages were pinned to (e.g., via `tool.uv.sources`), merged across
+    /// forks.
+    pub(crate) pinned_indexes: PinnedIndexes,
+    /// The tree of forks that were solved to produce the graph.
+    pub(crate) fork_graph: ForkGraph,
+    /// The number of packages that weren't moved to their latest version during an upgrade.
+    pub(crate) held_back: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -134,6 +144,7 @@ impl ResolverOutput {
         conflicts: &Conflicts,
         resolution_strategy: &ResolutionStrategy,
         options: Options,
+        fork_graph: ForkGraph,
     ) -> Result<Self, ResolveError> {
         let size_guess = resolutions[0].nodes.len();
         let mut graph: Graph<ResolutionGraphNode, UniversalMarker, Directed> =
@@ -228,6 +239,38 @@ impl ResolverOutput {
             report_missing_lower_bounds(&graph, &mut diagnostics, constraints, overrides);
         }
 
+        // Merge the indexes that packages were pinned to across forks, and log the index that
+        // each pinned package was resolved from.
+        let pinned_indexes = PinnedIndexes::merge(resolutions.iter().map(|resolution| {
+            (
+                &resolution.indexes,
+                resolution.env.fork_markers().unwrap_or(MarkerTree::TRUE),
+            )
+        }));
+        for (package_name, entries) in pinned_indexes.iter() {
+            if entries.len() > 1 {
+                debug!(
+                    "`{package_name}` was pinned to {} indexes across forks",
+                    entries.len()
+                );
+            }
+        }
+        for node in graph.node_weights() {
+            if let ResolutionGraphNode::Dist(dist) = node
+                && dist.extra.is_none()
+                && dist.group.is_none()
+                && pinned_indexes.contains_key(&dist.name)
+                && let Some(index) = dist.index()
+            {
+                debug!(
+                    "Resolved `{}=={}` from pinned index {}",
+                    dist.name,
+                    dist.version,
+                    index.without_credentials()
+                );
+            }
+        }
+
         let output = Self {
             graph,
             requires_python,
@@ -237,6 +280,9 @@ impl ResolverOutput {
             overrides: overrides.clone(),
             options,
             fork_markers,
+            pinned_indexes,
+            fork_graph,
+            held_back: 0,
         };
 
         // We only do conflicting distribution detection when no
@@ -618,6 +664,29 @@ impl ResolverOutput {
         &self.diagnostics
     }
 
+    /// Return the indexes that packages were pinned to, merged across forks.
+    pub fn pinned_indexes(&self) -> &PinnedIndexes {
+        &self.pinned_indexes
+    }
+
+    /// Return the tree of forks that were solved to produce the graph.
+    pub fn fork_graph(&self) -> &ForkGraph {
+        &self.fork_graph
+    }
+
+    /// Set the number of packages that were held back during an upgrade.
+    #[must_use]
+    pub(crate) fn with_held_back(mut self, held_back: usize) -> Self {
+        self.held_back = held_back;
+        self
+    }
+
+    /// Return the number of packages that weren't moved to their latest version during an
+    /// upgrade.
+    pub fn held_back(&self) -> usize {
+        self.held_back
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
@@ -0,0 +1,101 @@
+//! Backtracking decisions, logged under the `uv_resolver::backtrack` target.
+//!
+//! A resolution with heavy backtracking can run into thousands of conflicts, mostly between the
+//! same few packages. To keep the log readable, only the first conflicts for each affected
+//! package are logged in full; the remainder are counted and summarized once the resolution
+//! completes (or fails), along with a ranking of the packages involved in the most conflicts.
+
+use std::fmt::Display;
+use std::hash::BuildHasherDefault;
+
+use indexmap::IndexMap;
+use itertools::Itertools;
+use pubgrub::{Ranges, Term};
+use rustc_hash::FxHasher;
+use tracing::debug;
+use uv_pep440::Version;
+
+use crate::pubgrub::PubGrubPackage;
+
+/// The number of conflicts logged in full for each affected package.
+const MAX_LOGGED_CONFLICTS: usize = 10;
+
+/// The number of packages listed in the summary of conflicts.
+const MAX_SUMMARIZED_PACKAGES: usize = 10;
+
+/// The log of the conflicts encountered during a resolution, across all forks.
+#[derive(Debug, Default)]
+pub(crate) struct BacktrackLog {
+    /// The number of conflicts per affected package, in the order they were first encountered.
+    conflicts: IndexMap<String, usize, BuildHasherDefault<FxHasher>>,
+}
+
+impl BacktrackLog {
+    /// Returns `true` if backtracking decisions are logged.
+    pub(crate) fn enabled() -> bool {
+        tracing::enabled!(target: "uv_resolver::backtrack", tracing::Level::DEBUG)
+    }
+
+    /// Log a conflict on the `affected` package, which led pubgrub to learn the `learned`
+    /// incompatibility and to retract the `retracted` decisions.
+    pub(crate) fn conflict(
+        &mut self,
+        affected: &PubGrubPackage,
+        learned: impl Display,
+        retracted: &[String],
+    ) {
+        let package = affected.to_string();
+        let count = self.conflicts.entry(package.clone()).or_default();
+        *count += 1;
+        if *count > MAX_LOGGED_CONFLICTS {
+            return;
+        }
+
+        let retracted = if retracted.is_empty() {
+            "no decisions".to_string()
+        } else {
+            retracted.join(", ")
+        };
+        debug!(
+            target: "uv_resolver::backtrack",
+            "Conflict on {package}: learned ({learned}), retracting {retracted}"
+        );
+    }
+}
+
+impl Drop for BacktrackLog {
+    fn drop(&mut self) {
+        if self.conflicts.is_empty() {
+            return;
+        }
+        for (package, count) in &self.conflicts {
+            if *count > MAX_LOGGED_CONFLICTS {
+                debug!(
+                    target: "uv_resolver::backtrack",
+                    "…and {} more conflicts involving {package}",
+                    count - MAX_LOGGED_CONFLICTS
+                );
+            }
+        }
+        let ranking = self
+            .conflicts
+            .iter()
+            .sorted_by(|(_, a), (_, b)| b.cmp(a))
+            .take(MAX_SUMMARIZED_PACKAGES)
+            .map(|(package, count)| format!("{package} ({count})"))
+            .join(", ");
+        debug!(target: "uv_resolver::backtrack", "Conflicts by package: {ranking}");
+    }
+}
+
+/// Format the terms of an incompatibility, e.g., `foo >=2, not bar <1`.
+pub(crate) fn format_incompatibility<'a>(
+    terms: impl Iterator<Item = (&'a PubGrubPackage, &'a Term<Ranges<Version>>)>,
+) -> String {
+    terms
+        .map(|(package, term)| match term {
+            Term::Positive(range) => format!("{package} {range}"),
+            Term::Negative(range) => format!("not {package} {range}"),
+        })
+        .join(", ")
+}
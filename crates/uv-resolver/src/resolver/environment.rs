@@ -159,6 +159,63 @@ impl ResolverEnvironment {
         Self { kind }
     }
 
+    /// Split the initial forks of a universal resolution by the given pairwise
+    /// disjoint markers.
+    ///
+    /// Each initial fork (or, if there are none, the entire marker space) is
+    /// intersected with each of the markers, and with the remainder of the
+    /// marker space that isn't covered by any of them. Empty forks are
+    /// dropped.
+    ///
+    /// This is used to resolve a package that is pinned to a different index
+    /// under each marker in a distinct fork. For a resolution fixed to a
+    /// specific marker environment, this is a no-op.
+    pub(crate) fn split_initial_forks(self, markers: &[MarkerTree]) -> Self {
+        let Kind::Universal {
+            ref initial_forks,
+            markers: fork_markers,
+            ref include,
+            ref exclude,
+        } = self.kind
+        else {
+            return self;
+        };
+        if markers.is_empty() {
+            return self;
+        }
+
+        let mut remainder = MarkerTree::TRUE;
+        for marker in markers {
+            remainder.and(marker.negate());
+        }
+        let forks = if initial_forks.is_empty() {
+            &[MarkerTree::TRUE][..]
+        } else {
+            &initial_forks[..]
+        };
+        let initial_forks = forks
+            .iter()
+            .flat_map(|fork| {
+                markers
+                    .iter()
+                    .chain(std::iter::once(&remainder))
+                    .filter_map(|marker| {
+                        let mut fork = *fork;
+                        fork.and(*marker);
+                        (!fork.is_false()).then_some(fork)
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        let kind = Kind::Universal {
+            initial_forks: initial_forks.into(),
+            markers: fork_markers,
+            include: Arc::clone(include),
+            exclude: Arc::clone(exclude),
+        };
+        Self { kind }
+    }
+
     /// Returns the marker environment corresponding to this resolver
     /// environment.
     ///
@@ -180,6 +237,16 @@ impl ResolverEnvironment {
         }
     }
 
+    /// Returns `true` if every environment in this resolver environment
+    /// satisfies the given marker, i.e., a requirement with the marker
+    /// applies throughout the fork.
+    pub(crate) fn implies_marker(&self, marker: MarkerTree) -> bool {
+        match self.kind {
+            Kind::Specific { ref marker_env } => marker.evaluate(marker_env, &[]),
+            Kind::Universal { ref markers, .. } => markers.is_disjoint(marker.negate()),
+        }
+    }
+
     /// Returns true if the dependency represented by this forker may be
     /// included in the given resolver environment.
     pub(crate) fn included_by_group(&self, group: ConflictItemRef<'_>) -> bool {
@@ -221,7 +288,7 @@ impl ResolverEnvironment {
     ///
     /// This panics if the resolver environment corresponds to one and only one
     /// specific marker environment. i.e., "pip"-style resolution.
-    fn narrow_environment(&self, rhs: MarkerTree) -> Self {
+    pub(crate) fn narrow_environment(&self, rhs: MarkerTree) -> Self {
         match self.kind {
             Kind::Specific { .. } => {
                 unreachable!("environment narrowing only happens in universal resolution")
@@ -245,6 +312,34 @@ impl ResolverEnvironment {
         }
     }
 
+    /// Returns a copy of this resolver environment with its markers
+    /// simplified for display to end users.
+    ///
+    /// Bounds on the Python version that are implied by the given
+    /// `requires-python` are removed, since they're redundant in the context
+    /// of the resolution. This is display-only: the simplified markers are no
+    /// longer exact, so the resolver itself should keep using the original
+    /// environment.
+    pub(crate) fn simplify_markers_for_display(&self, requires_python: &RequiresPython) -> Self {
+        match self.kind {
+            Kind::Specific { .. } => self.clone(),
+            Kind::Universal {
+                ref initial_forks,
+                ref markers,
+                ref include,
+                ref exclude,
+            } => {
+                let kind = Kind::Universal {
+                    initial_forks: Arc::clone(initial_forks),
+                    markers: requires_python.simplify_markers(*markers),
+                    include: Arc::clone(include),
+                    exclude: Arc::clone(exclude),
+                };
+                Self { kind }
+            }
+        }
+    }
+
     /// Returns a new resolver environment with the given groups included or
     /// excluded from it. An `Ok` variant indicates an include rule while an
     /// `Err` variant indicates en exclude rule.
@@ -790,6 +885,59 @@ mod tests {
         );
     }
 
+    /// Tests that Python bounds implied by `requires-python` are dropped from
+    /// the markers displayed for a fork, while other terms are preserved.
+    #[test]
+    fn simplify_markers_for_display() {
+        let requires_python = requires_python_lower("3.9");
+        let display = |raw: &str| {
+            ResolverEnvironment::universal(vec![])
+                .narrow_environment(marker(raw))
+                .simplify_markers_for_display(&requires_python)
+                .end_user_fork_display()
+        };
+
+        assert_eq!(
+            display(
+                "python_version >= '3.9' and python_version >= '3.8' and sys_platform == 'linux'"
+            )
+            .as_deref(),
+            Some("split (markers: sys_platform == 'linux')"),
+        );
+        assert_eq!(
+            display("sys_platform == 'linux' and python_full_version < '3.12' and python_full_version >= '3.9'")
+                .as_deref(),
+            Some("split (markers: python_full_version < '3.12' and sys_platform == 'linux')"),
+        );
+        assert_eq!(
+            display("os_name == 'posix' and sys_platform == 'linux' and os_name == 'posix'")
+                .as_deref(),
+            Some("split (markers: os_name == 'posix' and sys_platform == 'linux')"),
+        );
+        assert_eq!(display("python_version >= '3.8'"), None);
+    }
+
+    /// Tests that simplifying markers for display leaves the original
+    /// environment untouched.
+    #[test]
+    fn simplify_markers_for_display_is_display_only() {
+        let requires_python = requires_python_lower("3.9");
+        let env = ResolverEnvironment::universal(vec![]).narrow_environment(marker(
+            "python_version >= '3.9' and sys_platform == 'linux'",
+        ));
+        let simplified = env.simplify_markers_for_display(&requires_python);
+        assert_eq!(
+            env.fork_markers(),
+            Some(marker(
+                "python_full_version >= '3.9' and sys_platform == 'linux'"
+            )),
+        );
+        assert_eq!(
+            simplified.fork_markers(),
+            Some(marker("sys_platform == 'linux'"))
+        );
+    }
+
     /// In this test, we narrow a stricter requirement compared to the marker
     /// for the current fork. This in turn results in a requirement that
     /// remains unchanged.
@@ -803,4 +951,27 @@ mod tests {
             Some(python_requirement("3.11")),
         );
     }
+
+    /// A fork implies the markers that hold throughout it, but not those that only hold for some
+    /// of its environments.
+    #[test]
+    fn implies_marker() {
+        let universal = ResolverEnvironment::universal(vec![]);
+        assert!(universal.implies_marker(MarkerTree::TRUE));
+        assert!(!universal.implies_marker(marker("sys_platform == 'linux'")));
+
+        let fork = universal.narrow_environment(marker(
+            "sys_platform == 'linux' and platform_machine == 'x86_64'",
+        ));
+        assert!(fork.implies_marker(MarkerTree::TRUE));
+        assert!(fork.implies_marker(marker("sys_platform == 'linux'")));
+        assert!(fork.implies_marker(marker("sys_platform != 'win32'")));
+        assert!(!fork.implies_marker(marker("python_version >= '3.12'")));
+        assert!(!fork.implies_marker(marker("sys_platform == 'darwin'")));
+
+        let specific =
+            ResolverEnvironment::specific(ResolverMarkerEnvironment::from(MARKER_ENV.clone()));
+        assert!(specific.implies_marker(marker("sys_platform == 'darwin'")));
+        assert!(!specific.implies_marker(marker("sys_platform == 'linux'")));
+    }
 }
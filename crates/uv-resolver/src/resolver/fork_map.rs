@@ -51,6 +51,19 @@ impl<T> ForkMap<T> {
         self.0.contains_key(package_name)
     }
 
+    /// Returns an iterator over the packages in the map, along with their values and the markers
+    /// of the requirements that contained them.
+    pub(crate) fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&PackageName, impl Iterator<Item = (&T, MarkerTree)>)> {
+        self.0.iter().map(|(package_name, entries)| {
+            (
+                package_name,
+                entries.iter().map(|entry| (&entry.value, entry.marker)),
+            )
+        })
+    }
+
     /// Returns a list of values associated with a package that are compatible with the given fork.
     ///
     /// Compatibility implies that the markers on the requirement that contained this value
@@ -0,0 +1,267 @@
+//! Packages that weren't moved to their latest version during an upgrade, summarized once the
+//! resolution completes.
+//!
+//! When upgrading, a package may remain on an older version because another package requires an
+//! older version, because the newer versions require a newer Python, or because the newer
+//! versions can't be used at all. The constraints are read from the incompatibilities that the
+//! solver learned for the package, rather than being recomputed.
+
+use std::collections::BTreeSet;
+
+use dashmap::DashMap;
+use pubgrub::{Kind, Ranges};
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_distribution_types::{IncompatibleSource, IncompatibleWheel, PrioritizedDist};
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+
+use crate::error::SentinelRange;
+use crate::exclusions::Exclusions;
+use crate::preferences::Preferences;
+use crate::pubgrub::PubGrubPackageInner;
+use crate::resolver::{ForkState, InMemoryIndex, UnavailableReason, VersionsResponse};
+
+/// The packages that were held back during an upgrade, across all forks.
+#[derive(Debug, Default)]
+pub(crate) struct HeldBack(DashMap<PackageName, HeldBackPackage>);
+
+/// A package that was held back, along with the constraints that held it back.
+#[derive(Debug)]
+struct HeldBackPackage {
+    /// The version that was selected.
+    version: Version,
+    /// The latest available version.
+    latest: Version,
+    /// The constraints that exclude the latest version, e.g., ``flask==3.0.0` requires
+    /// `werkzeug>=3.0.0``.
+    reasons: BTreeSet<String>,
+}
+
+impl HeldBack {
+    /// Record the packages that were held back in a fork that was solved successfully.
+    ///
+    /// Only packages that are being upgraded are considered. If a package is held back in
+    /// multiple forks, the first fork is recorded.
+    pub(crate) fn record(
+        &self,
+        state: &ForkState,
+        index: &InMemoryIndex,
+        exclusions: &Exclusions,
+        preferences: &Preferences,
+    ) {
+        let solution: FxHashMap<_, _> = state.pubgrub.partial_solution.extract_solution().collect();
+
+        for (id, version) in &solution {
+            let PubGrubPackageInner::Package {
+                name,
+                extra: None,
+                group: None,
+                ..
+            } = &*state.pubgrub.package_store[*id]
+            else {
+                continue;
+            };
+            if !exclusions.upgrade(name) || self.0.contains_key(name) {
+                continue;
+            }
+            let Some(latest) = latest_version(state, index, name, version) else {
+                continue;
+            };
+            if latest <= *version {
+                continue;
+            }
+
+            let mut reasons = BTreeSet::new();
+            for package in solution.keys() {
+                if state.pubgrub.package_store[*package].name() != Some(name) {
+                    continue;
+                }
+                for incompatibility in &state.pubgrub.incompatibilities[package] {
+                    match &state.pubgrub.incompatibility_store[*incompatibility].kind {
+                        // Another package requires a range that excludes the latest version.
+                        Kind::FromDependencyOf(
+                            requirer,
+                            requirer_range,
+                            dependency,
+                            dependency_range,
+                        ) if dependency == package && !dependency_range.contains(&latest) => {
+                            let Some(requirer_version) = solution.get(requirer) else {
+                                continue;
+                            };
+                            if !requirer_range.contains(requirer_version) {
+                                continue;
+                            }
+                            let requirer = &state.pubgrub.package_store[*requirer];
+                            if let PubGrubPackageInner::Root(_) = &**requirer {
+                                reasons.insert(format!(
+                                    "the requirements include `{name}{}`",
+                                    specifiers(dependency_range)
+                                ));
+                            } else {
+                                let Some(requirer_name) = requirer.name_no_root() else {
+                                    continue;
+                                };
+                                if requirer_name == name {
+                                    continue;
+                                }
+                                // The requirer was kept at its preferred version, e.g., the
+                                // version in the lockfile.
+                                let pinned = !exclusions.upgrade(requirer_name)
+                                    && preferences
+                                        .get(requirer_name)
+                                        .iter()
+                                        .any(|entry| entry.pin().version() == requirer_version);
+                                reasons.insert(format!(
+                                    "`{requirer_name}=={requirer_version}`{} requires `{name}{}`",
+                                    if pinned { " (pinned)" } else { "" },
+                                    specifiers(dependency_range)
+                                ));
+                            }
+                        }
+                        // The latest version has a dependency that can't be satisfied alongside
+                        // the rest of the solution.
+                        Kind::FromDependencyOf(
+                            dependent,
+                            dependent_range,
+                            dependency,
+                            dependency_range,
+                        ) if dependent == package && dependent_range.contains(&latest) => {
+                            let dependency_package = &state.pubgrub.package_store[*dependency];
+                            if let PubGrubPackageInner::Python(_) = &**dependency_package {
+                                reasons.insert(format!(
+                                    "`{name}=={latest}` requires Python {}",
+                                    specifiers(dependency_range)
+                                ));
+                            } else {
+                                let Some(dependency_name) = dependency_package.name_no_root()
+                                else {
+                                    continue;
+                                };
+                                if dependency_name == name {
+                                    continue;
+                                }
+                                if solution
+                                    .get(dependency)
+                                    .is_none_or(|version| dependency_range.contains(version))
+                                {
+                                    continue;
+                                }
+                                reasons.insert(format!(
+                                    "`{name}=={latest}` requires `{dependency_name}{}`",
+                                    specifiers(dependency_range)
+                                ));
+                            }
+                        }
+                        // The latest version can't be used, e.g., as it has no compatible wheels.
+                        Kind::Custom(_, range, UnavailableReason::Version(reason))
+                            if range.contains(&latest) =>
+                        {
+                            reasons.insert(format!(
+                                "`{name}=={latest}` {}",
+                                reason.singular_message()
+                            ));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            if reasons.is_empty() {
+                continue;
+            }
+            self.0.insert(
+                name.clone(),
+                HeldBackPackage {
+                    version: version.clone(),
+                    latest,
+                    reasons,
+                },
+            );
+        }
+    }
+
+    /// Returns the number of packages that were held back.
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Log the constraints that held back each package.
+    pub(crate) fn log(&self) {
+        let mut packages = self.0.iter().collect::<Vec<_>>();
+        packages.sort_by(|a, b| a.key().cmp(b.key()));
+        for entry in packages {
+            let (name, package) = entry.pair();
+            debug!(
+                "Held back `{name}` at {} (latest: {}): {}",
+                package.version,
+                package.latest,
+                package
+                    .reasons
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            );
+        }
+    }
+}
+
+/// Return the latest available version of a package in the index that it was resolved from.
+///
+/// Pre-releases are only considered if the selected version is a pre-release, and versions that
+/// were published after the `exclude-newer` cutoff or yanked are skipped.
+fn latest_version(
+    state: &ForkState,
+    index: &InMemoryIndex,
+    name: &PackageName,
+    selected: &Version,
+) -> Option<Version> {
+    let response = if let Some(index_metadata) = state.fork_indexes.get(name) {
+        index
+            .explicit()
+            .get(&(name.clone(), index_metadata.url().clone()))
+    } else {
+        index.implicit().get(name)
+    }?;
+    let VersionsResponse::Found(version_maps) = &*response else {
+        return None;
+    };
+    version_maps
+        .iter()
+        .filter_map(|version_map| {
+            version_map.versions().rev().find(|version| {
+                (selected.any_prerelease() || !version.any_prerelease())
+                    && version_map.get(version).is_some_and(is_available)
+            })
+        })
+        .max()
+        .cloned()
+}
+
+/// Returns `true` if the distribution could be selected, ignoring the constraints of the
+/// resolution itself.
+fn is_available(dist: &PrioritizedDist) -> bool {
+    if dist.get().is_some() {
+        return true;
+    }
+    let wheel = dist.incompatible_wheel().is_some_and(|incompatibility| {
+        !matches!(
+            incompatibility,
+            IncompatibleWheel::ExcludeNewer(_) | IncompatibleWheel::Yanked(_)
+        )
+    });
+    let source = dist.incompatible_source().is_some_and(|incompatibility| {
+        !matches!(
+            incompatibility,
+            IncompatibleSource::ExcludeNewer(_) | IncompatibleSource::Yanked(_)
+        )
+    });
+    wheel || source
+}
+
+/// Format a range of versions as specifiers, e.g., `>=1.0, <2.0`.
+fn specifiers(range: &Ranges<Version>) -> VersionSpecifiers {
+    VersionSpecifiers::from_release_only_bounds(SentinelRange::from(range).strip().iter())
+}
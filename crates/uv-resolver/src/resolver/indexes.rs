@@ -1,9 +1,20 @@
-use uv_distribution_types::{IndexMetadata, RequirementSource};
+use std::fmt::Write;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use pubgrub::Range;
+
+use uv_distribution_types::{
+    IndexLocations, IndexMetadata, IndexUrl, Requirement, RequirementSource,
+};
 use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::MarkerTree;
 use uv_pypi_types::ConflictItem;
 
-use crate::resolver::ForkMap;
-use crate::{DependencyMode, Manifest, ResolverEnvironment};
+use crate::fork_indexes::{IndexInterner, IndexPriority, IndexProvenance, IndexRole, index_origin};
+use crate::resolver::{ForkMap, InMemoryIndex};
+use crate::{DependencyMode, IndexConflictStrategy, Manifest, ResolverEnvironment};
 
 /// A map of package names to their explicit index.
 ///
@@ -18,13 +29,22 @@ use crate::{DependencyMode, Manifest, ResolverEnvironment};
 /// ```
 ///
 /// [`Indexes`] would contain a single entry mapping `torch` to `https://download.pytorch.org/whl/cu121`.
+///
+/// Alongside, [`Indexes`] tracks the priority of each index, to resolve a package being pinned to
+/// different indexes for the same versions.
 #[derive(Debug, Default, Clone)]
-pub(crate) struct Indexes(ForkMap<Entry>);
+pub(crate) struct Indexes {
+    pins: ForkMap<Entry>,
+    priority: IndexPriority,
+}
 
 #[derive(Debug, Clone)]
 struct Entry {
-    index: IndexMetadata,
+    index: Arc<IndexMetadata>,
+    /// The versions of the package that the requirement allows.
+    versions: Range<Version>,
     conflict: Option<ConflictItem>,
+    provenance: IndexProvenance,
 }
 
 impl Indexes {
@@ -33,34 +53,70 @@ impl Indexes {
         manifest: &Manifest,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
+        locations: &IndexLocations,
+        index_conflict_strategy: IndexConflictStrategy,
+        metadata: &InMemoryIndex,
     ) -> Self {
         let mut indexes = ForkMap::default();
+        let mut interner = IndexInterner::default();
 
-        for requirement in manifest.requirements(env, dependencies) {
+        for (parent, requirement) in manifest.requirements_with_parents(env, dependencies) {
             let RequirementSource::Registry {
+                specifier,
                 index: Some(index),
                 conflict,
-                ..
             } = &requirement.source
             else {
                 continue;
             };
-            let index = index.clone();
+            let index_name = locations
+                .simple_indexes()
+                .find(|location| location.url == index.url)
+                .and_then(|location| location.name.clone());
+            let role = IndexRole::of(&index.url, locations);
+            let origin = index_origin(&index.url, locations);
+            let index = interner.intern(index);
             let conflict = conflict.clone();
-            indexes.add(&requirement, Entry { index, conflict });
+            let provenance = IndexProvenance {
+                requirement: pinning_requirement(&requirement, specifier),
+                parent: parent.cloned(),
+                index_name,
+                marker: requirement.marker,
+                explicit: parent.is_none_or(|parent| manifest.workspace_members.contains(parent)),
+                role,
+                origin,
+                offered: None,
+            };
+            indexes.add(
+                &requirement,
+                Entry {
+                    index,
+                    versions: Range::from(specifier.clone()),
+                    conflict,
+                    provenance,
+                },
+            );
         }
 
-        Self(indexes)
+        Self {
+            pins: indexes,
+            priority: IndexPriority::new(index_conflict_strategy, locations, metadata),
+        }
     }
 
     /// Returns `true` if the map contains any indexes for a package.
     pub(crate) fn contains_key(&self, name: &PackageName) -> bool {
-        self.0.contains_key(name)
+        self.pins.contains_key(name)
     }
 
-    /// Return the explicit index used for a package in the given fork.
-    pub(crate) fn get(&self, name: &PackageName, env: &ResolverEnvironment) -> Vec<&IndexMetadata> {
-        let entries = self.0.get(name, env);
+    /// Return the explicit indexes used for a package in the given fork, along with the versions
+    /// and provenance of each.
+    pub(crate) fn get(
+        &self,
+        name: &PackageName,
+        env: &ResolverEnvironment,
+    ) -> Vec<(&Arc<IndexMetadata>, &Range<Version>, &IndexProvenance)> {
+        let entries = self.pins.get(name, env);
         entries
             .iter()
             .filter(|entry| {
@@ -69,7 +125,118 @@ impl Indexes {
                     .as_ref()
                     .is_none_or(|conflict| env.included_by_group(conflict.as_ref()))
             })
-            .map(|entry| &entry.index)
+            .map(|entry| (&entry.index, &entry.versions, &entry.provenance))
             .collect()
     }
+
+    /// Return the pins that the project itself declares (e.g., in its `tool.uv.sources`) and that
+    /// apply throughout the given fork, along with the versions and provenance of each.
+    ///
+    /// Pins that only apply to some environments within the fork, or to an extra or a dependency
+    /// group, are omitted, as they only apply once the requirement is encountered. The pins are
+    /// sorted by package name, and then by the package that declared them (starting with the
+    /// project), such that the order doesn't depend on the order of the requirements.
+    pub(crate) fn static_pins(
+        &self,
+        env: &ResolverEnvironment,
+    ) -> Vec<(
+        &PackageName,
+        &Arc<IndexMetadata>,
+        &Range<Version>,
+        &IndexProvenance,
+    )> {
+        let mut pins = self
+            .pins
+            .iter()
+            .flat_map(|(name, entries)| {
+                entries
+                    .filter(|(entry, marker)| {
+                        entry.conflict.is_none()
+                            && entry.provenance.explicit
+                            && env.implies_marker(*marker)
+                    })
+                    .map(move |(entry, _)| (name, &entry.index, &entry.versions, &entry.provenance))
+            })
+            .collect::<Vec<_>>();
+        pins.sort_by(
+            |(a, a_index, _, a_provenance), (b, b_index, _, b_provenance)| {
+                (
+                    a,
+                    a_provenance.parent.is_some(),
+                    &a_provenance.parent,
+                    &a_provenance.requirement,
+                    &a_index.url,
+                )
+                    .cmp(&(
+                        b,
+                        b_provenance.parent.is_some(),
+                        &b_provenance.parent,
+                        &b_provenance.requirement,
+                        &b_index.url,
+                    ))
+            },
+        );
+        pins
+    }
+
+    /// Return the priority of the indexes.
+    pub(crate) fn priority(&self) -> &IndexPriority {
+        &self.priority
+    }
+
+    /// Return the markers under which each package is pinned to its indexes, for the packages
+    /// that are pinned to different indexes under pairwise disjoint markers.
+    ///
+    /// For example, given `torch` pinned to one index on Linux and another on macOS, returns
+    /// `sys_platform == 'linux'` and `sys_platform == 'darwin'` for `torch`. Pins that only apply
+    /// to an extra or a dependency group are not considered.
+    pub(crate) fn divergent_markers(&self) -> Vec<(PackageName, Vec<MarkerTree>)> {
+        let mut divergent = Vec::new();
+        for (name, entries) in self.pins.iter() {
+            // Combine the markers of the pins to the same index.
+            let mut markers: Vec<(&IndexUrl, MarkerTree)> = Vec::new();
+            for (entry, marker) in entries {
+                if entry.conflict.is_some() {
+                    continue;
+                }
+                if let Some((_, existing)) = markers
+                    .iter_mut()
+                    .find(|(url, _)| url.is_same_index(&entry.index.url))
+                {
+                    existing.or(marker);
+                } else {
+                    markers.push((&entry.index.url, marker));
+                }
+            }
+            if markers.len() < 2 {
+                continue;
+            }
+            let disjoint = markers
+                .iter()
+                .tuple_combinations()
+                .all(|((_, a), (_, b))| a.is_disjoint(*b));
+            if disjoint {
+                divergent.push((
+                    name.clone(),
+                    markers.into_iter().map(|(_, marker)| marker).collect(),
+                ));
+            }
+        }
+        divergent.sort_by(|(a, _), (b, _)| a.cmp(b));
+        divergent
+    }
+}
+
+/// Render a requirement that pins a package to an index, without the index itself, e.g.,
+/// `torch>=2 ; sys_platform == 'linux'`.
+fn pinning_requirement(requirement: &Requirement, specifier: &VersionSpecifiers) -> String {
+    let mut rendered = requirement.name.to_string();
+    if !requirement.extras.is_empty() {
+        let _ = write!(rendered, "[{}]", requirement.extras.iter().join(","));
+    }
+    let _ = write!(rendered, "{specifier}");
+    if let Some(marker) = requirement.marker.contents() {
+        let _ = write!(rendered, " ; {marker}");
+    }
+    rendered
 }
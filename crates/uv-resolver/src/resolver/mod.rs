@@ -6,7 +6,7 @@ use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::{Display, Formatter, Write};
 use std::ops::Bound;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::{iter, slice, thread};
 
 use dashmap::DashMap;
@@ -18,15 +18,17 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use tokio::sync::mpsc::{self, Receiver, Sender};
 use tokio::sync::oneshot;
 use tokio_stream::wrappers::ReceiverStream;
-use tracing::{Level, debug, info, instrument, trace, warn};
+use tracing::field::Empty;
+use tracing::{Level, Span, debug, info, info_span, instrument, trace, warn};
 
 use uv_configuration::{Constraints, Excludes, Overrides};
 use uv_distribution::{ArchiveMetadata, DistributionDatabase};
 use uv_distribution_types::{
     BuiltDist, CompatibleDist, DerivationChain, Dist, DistErrorKind, DistributionMetadata,
     IncompatibleDist, IncompatibleSource, IncompatibleWheel, IndexCapabilities, IndexLocations,
-    IndexMetadata, IndexUrl, InstalledDist, Name, PythonRequirementKind, RemoteSource, Requirement,
-    ResolvedDist, ResolvedDistRef, SourceDist, VersionOrUrlRef, implied_markers,
+    IndexMetadata, IndexUrl, InstalledDist, Name, PackageIndexEntry, PythonRequirementKind,
+    RemoteSource, Requirement, ResolvedDist, ResolvedDistRef, SourceDist, VersionOrUrlRef,
+    implied_markers,
 };
 use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -40,26 +42,29 @@ use uv_torch::TorchStrategy;
 use uv_types::{BuildContext, HashStrategy, InstalledPackagesProvider};
 use uv_warnings::warn_user_once;
 
+use crate::allowed_indexes::AllowedIndexes;
 use crate::candidate_selector::{Candidate, CandidateDist, CandidateSelector};
 use crate::dependency_provider::UvDependencyProvider;
-use crate::error::{NoSolutionError, ResolveError};
-use crate::fork_indexes::ForkIndexes;
+use crate::error::{NoSolutionError, ResolveError, SentinelRange};
+use crate::fork_graph::{ForkEdge, ForkGraph};
+use crate::fork_indexes::{ForkIndexes, IndexProvenance, IndexRole, index_origin};
 use crate::fork_strategy::ForkStrategy;
 use crate::fork_urls::ForkUrls;
 use crate::manifest::Manifest;
 use crate::pins::FilePins;
 use crate::preferences::{PreferenceSource, Preferences};
 use crate::pubgrub::{
-    PubGrubDependency, PubGrubDistribution, PubGrubPackage, PubGrubPackageInner, PubGrubPriorities,
-    PubGrubPython,
+    PackageRange, PubGrubDependency, PubGrubDistribution, PubGrubPackage, PubGrubPackageInner,
+    PubGrubPriorities, PubGrubPython,
 };
-use crate::python_requirement::PythonRequirement;
+use crate::python_requirement::{PythonRequirement, PythonRequirementSource};
 use crate::resolution::ResolverOutput;
 use crate::resolution_mode::ResolutionStrategy;
 pub(crate) use crate::resolver::availability::{
     ResolverVersion, UnavailableErrorChain, UnavailablePackage, UnavailableReason,
     UnavailableVersion,
 };
+use crate::resolver::backtrack::{BacktrackLog, format_incompatibility};
 use crate::resolver::batch_prefetch::BatchPrefetcher;
 pub use crate::resolver::derivation::DerivationChainBuilder;
 pub use crate::resolver::environment::ResolverEnvironment;
@@ -67,12 +72,15 @@ use crate::resolver::environment::{
     ForkingPossibility, fork_version_by_marker, fork_version_by_python_requirement,
 };
 pub(crate) use crate::resolver::fork_map::{ForkMap, ForkSet};
+use crate::resolver::held_back::HeldBack;
 pub use crate::resolver::index::InMemoryIndex;
 use crate::resolver::indexes::Indexes;
 pub use crate::resolver::provider::{
     DefaultResolverProvider, MetadataResponse, PackageVersionsResult, ResolverProvider,
     VersionsResponse, WheelMetadataResult,
 };
+use crate::resolver::python_exclusions::PythonExclusions;
+pub(crate) use crate::resolver::python_exclusions::{ExcludedVersions, resolving_for};
 pub use crate::resolver::reporter::{BuildId, Reporter};
 use crate::resolver::system::SystemDependency;
 pub(crate) use crate::resolver::urls::Urls;
@@ -85,13 +93,16 @@ use crate::{
 pub(crate) use provider::MetadataUnavailable;
 
 mod availability;
+mod backtrack;
 mod batch_prefetch;
 mod derivation;
 mod environment;
 mod fork_map;
+mod held_back;
 mod index;
 mod indexes;
 mod provider;
+mod python_exclusions;
 mod reporter;
 mod system;
 mod urls;
@@ -99,6 +110,9 @@ mod urls;
 /// The number of conflicts a package may accumulate before we re-prioritize and backtrack.
 const CONFLICT_THRESHOLD: usize = 5;
 
+/// The number of packages diverging from the base fork that are listed in the summary of a fork.
+const MAX_LOGGED_DIVERGING: usize = 10;
+
 pub struct Resolver<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider> {
     state: ResolverState<InstalledPackages>,
     provider: Provider,
@@ -119,6 +133,8 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     exclusions: Exclusions,
     urls: Urls,
     indexes: Indexes,
+    /// The indexes that packages may be installed from, if restricted.
+    allowed_indexes: Option<AllowedIndexes>,
     dependency_mode: DependencyMode,
     hasher: HashStrategy,
     env: ResolverEnvironment,
@@ -135,6 +151,10 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     unavailable_packages: DashMap<PackageName, UnavailablePackage>,
     /// Incompatibilities for packages that are unavailable at specific versions.
     incomplete_packages: DashMap<PackageName, DashMap<Version, MetadataUnavailable>>,
+    /// Versions that were rejected due to their `requires-python`, across all forks.
+    python_exclusions: PythonExclusions,
+    /// Packages that weren't moved to their latest version during an upgrade, across all forks.
+    held_back: HeldBack,
     /// The options that were used to configure this resolver.
     options: Options,
     /// The reporter to use for this resolver.
@@ -228,6 +248,34 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         provider: Provider,
         installed_packages: InstalledPackages,
     ) -> Result<Self, ResolveError> {
+        let indexes = Indexes::from_manifest(
+            &manifest,
+            &env,
+            options.dependency_mode,
+            locations,
+            options.index_conflict_strategy,
+            index,
+        );
+
+        let allowed_indexes = options
+            .allowed_indexes
+            .as_deref()
+            .map(|entries| AllowedIndexes::from_entries(entries, locations))
+            .transpose()?;
+
+        // If a package is pinned to different indexes under disjoint markers, resolve each of
+        // them in its own fork, such that no fork sees more than one index for the package.
+        let mut env = env;
+        if options.allow_index_divergence {
+            for (package_name, markers) in indexes.divergent_markers() {
+                debug!(
+                    "Splitting forks for `{package_name}`, which is pinned to {} indexes",
+                    markers.len()
+                );
+                env = env.split_initial_forks(&markers);
+            }
+        }
+
         let state = ResolverState {
             index: index.clone(),
             git: git.clone(),
@@ -235,7 +283,8 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             selector: CandidateSelector::for_resolution(&options, &manifest, &env),
             dependency_mode: options.dependency_mode,
             urls: Urls::from_manifest(&manifest, &env, git, options.dependency_mode),
-            indexes: Indexes::from_manifest(&manifest, &env, options.dependency_mode),
+            indexes,
+            allowed_indexes,
             project: manifest.project,
             workspace_members: manifest.workspace_members,
             requirements: manifest.requirements,
@@ -254,6 +303,8 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             installed_packages,
             unavailable_packages: DashMap::default(),
             incomplete_packages: DashMap::default(),
+            python_exclusions: PythonExclusions::default(),
+            held_back: HeldBack::default(),
             options,
             reporter: None,
         };
@@ -276,6 +327,17 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
 
     /// Resolve a set of requirements into a set of pinned versions.
     pub async fn resolve(self) -> Result<ResolverOutput, ResolveError> {
+        self.resolve_with_fork_graph().await.0
+    }
+
+    /// Resolve a set of requirements into a set of pinned versions, along with the tree of forks
+    /// that were solved, which is returned even if the resolution failed.
+    ///
+    /// The tree is empty if the resolution failed outside of the solver, e.g., when fetching
+    /// metadata.
+    pub async fn resolve_with_fork_graph(
+        self,
+    ) -> (Result<ResolverOutput, ResolveError>, ForkGraph) {
         let state = Arc::new(self.state);
         let provider = Arc::new(self.provider);
 
@@ -293,28 +355,112 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         thread::Builder::new()
             .name("uv-resolver".into())
             .spawn(move || {
-                let result = solver.solve(&request_sink);
+                let mut fork_graph = ForkGraph::default();
+                let result = solver.solve(&request_sink, &mut fork_graph);
 
                 // This may fail if the main thread returned early due to an error.
-                let _ = tx.send(result);
+                let _ = tx.send((result, fork_graph));
             })
             .unwrap();
 
         let resolve_fut = async move { rx.await.map_err(|_| ResolveError::ChannelClosed) };
 
         // Wait for both to complete.
-        let ((), resolution) = tokio::try_join!(requests_fut, resolve_fut)?;
+        let (resolution, fork_graph) = match tokio::try_join!(requests_fut, resolve_fut) {
+            Ok(((), (resolution, fork_graph))) => (resolution, fork_graph),
+            Err(err) => return (Err(err), ForkGraph::default()),
+        };
 
+        state.python_exclusions.log(&state.python_requirement);
+        if resolution.is_ok() {
+            state.held_back.log();
+        }
         state.on_complete();
-        resolution
+        (
+            resolution
+                .map(|resolution| resolution.with_held_back(state.held_back.len()))
+                .map_err(|err| err.simplify_markers_for_display(state.python_requirement.target())),
+            fork_graph,
+        )
     }
 }
 
 impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackages> {
-    #[instrument(skip_all)]
+    /// The packages that were served by an index other than the highest-priority index that was
+    /// consulted for them, in any fork, along with the index that served them.
+    ///
+    /// A package that's pinned to an index only counts if it fell back from the index it was
+    /// pinned to. Otherwise, the package counts if the indexes were searched for it and it wasn't
+    /// served by the first one.
+    fn fallbacks<'a>(
+        &'a self,
+        resolutions: &'a [Resolution],
+    ) -> BTreeSet<(&'a PackageName, &'a IndexUrl)> {
+        let mut fallbacks = BTreeSet::new();
+        for resolution in resolutions {
+            for package in resolution.nodes.keys() {
+                let Some(url) = package.index.as_ref() else {
+                    continue;
+                };
+                let fallback = resolution
+                    .indexes
+                    .is_fallback(&package.name, url)
+                    .unwrap_or_else(|| {
+                        let mut consulted = self.consulted_indexes(&package.name);
+                        consulted
+                            .next()
+                            .is_some_and(|primary| !primary.is_same_index(url))
+                            && consulted.any(|index| index.is_same_index(url))
+                    });
+                if fallback {
+                    fallbacks.insert((&package.name, url));
+                }
+            }
+        }
+        fallbacks
+    }
+
+    /// The indexes that are searched for a package that isn't pinned to an index, in order of
+    /// priority.
+    fn consulted_indexes(&self, name: &PackageName) -> impl Iterator<Item = &IndexUrl> {
+        if let Some(torch_backend) = &self.options.torch_backend
+            && torch_backend.applies_to(name)
+        {
+            Either::Left(torch_backend.index_urls())
+        } else {
+            Either::Right(self.locations.indexes().map(|index| &index.url))
+        }
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            kind = "compute",
+            conflicts = Empty,
+            retracted_decisions = Empty,
+            conflict_resolution = Empty,
+            fallbacks = Empty,
+            index_conflicts = Empty
+        )
+    )]
     fn solve(
         self: Arc<Self>,
         request_sink: &Sender<Request>,
+        fork_graph: &mut ForkGraph,
+    ) -> Result<ResolverOutput, ResolveError> {
+        let mut stats = BacktrackingStats::new(Span::current());
+        let result = self.solve_forks(request_sink, fork_graph, &mut stats);
+        // Report the statistics once the resolution ends, whether or not it succeeded.
+        stats.report();
+        result
+    }
+
+    /// Solve each fork in turn, collecting the [`BacktrackingStats`] across all forks.
+    fn solve_forks(
+        &self,
+        request_sink: &Sender<Request>,
+        fork_graph: &mut ForkGraph,
+        stats: &mut BacktrackingStats,
     ) -> Result<ResolverOutput, ResolveError> {
         debug!(
             "Solving with installed Python version: {}",
@@ -334,21 +480,78 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             self.index.clone(),
             request_sink.clone(),
         );
-        let state = ForkState::new(
+        let mut state = ForkState::new(
             pubgrub,
             self.env.clone(),
             self.python_requirement.clone(),
             prefetcher,
         );
+        // Pin the packages that were pinned to an index on the command line before any other
+        // package is added, such that any conflicting pin is reported against them.
+        for PackageIndexEntry { package, index } in &self.options.package_indexes {
+            let provenance = IndexProvenance {
+                requirement: format!("--package-index {package}={index}"),
+                parent: None,
+                index_name: None,
+                marker: MarkerTree::TRUE,
+                explicit: true,
+                role: IndexRole::of(index, &self.locations),
+                origin: index_origin(index, &self.locations),
+                offered: None,
+            };
+            state.fork_indexes.insert(
+                package,
+                &Arc::new(IndexMetadata::from(index.clone())),
+                &Range::full(),
+                &provenance,
+                &self.env,
+                self.indexes.priority(),
+            );
+        }
         let mut preferences = self.preferences.clone();
         let mut forked_states = self.env.initial_forked_states(state)?;
         let mut resolutions = vec![];
+        let mut backtrack_log = BacktrackLog::default();
 
         'FORK: while let Some(mut state) = forked_states.pop() {
+            // Solve each fork within its own span, such that its events are attributed to it, and
+            // record the time spent on the fork until it's either resolved or split further.
+            //
+            // The markers are simplified against the root `requires-python`, rather than the
+            // fork's, which would drop the Python bounds that distinguish the fork.
+            let markers = state
+                .env
+                .simplify_markers_for_display(self.python_requirement.target())
+                .fork_markers()
+                .and_then(MarkerTree::try_to_string);
+            let span = info_span!(
+                "solve_fork",
+                markers = markers.as_deref(),
+                resolved = tracing::field::Empty,
+                pinned = tracing::field::Empty,
+                divergent = tracing::field::Empty
+            );
+            let _enter = span.enter();
+            state.fork_id = fork_graph.add(state.fork_edge.take(), markers);
             if let Some(split) = state.env.end_user_fork_display() {
                 let requires_python = state.python_requirement.target();
                 debug!("Solving {split} (requires-python: {requires_python:?})");
             }
+
+            // Pin the packages that the project pins to an index throughout the fork before any
+            // package is visited, such that the pins don't depend on the order in which the
+            // requirements are traversed.
+            for (name, index, versions, provenance) in self.indexes.static_pins(&state.env) {
+                state.fork_indexes.insert(
+                    name,
+                    index,
+                    versions,
+                    provenance,
+                    &state.env,
+                    self.indexes.priority(),
+                );
+            }
+
             let start = Instant::now();
             loop {
                 let highest_priority_pkg =
@@ -358,11 +561,38 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         // the fork.
                         initial
                     } else {
-                        // Run unit propagation.
+                        // Run unit propagation, which resolves any conflicts by backtracking.
+                        let decisions = stats.enabled.then(|| decision_level(&state));
+                        // Capture the decisions before propagation, to log those that are
+                        // retracted by backtracking.
+                        let previous_solution = BacktrackLog::enabled().then(|| {
+                            state
+                                .pubgrub
+                                .partial_solution
+                                .extract_solution()
+                                .collect::<Vec<_>>()
+                        });
+                        let propagation_start = Instant::now();
                         let result = state.pubgrub.unit_propagation(state.next);
+                        if let Some(decisions) = decisions
+                            && let Ok(conflicts) = &result
+                            && !conflicts.is_empty()
+                        {
+                            stats.conflicts += conflicts.len();
+                            stats.retracted_decisions +=
+                                decisions.saturating_sub(decision_level(&state));
+                            stats.conflict_resolution += propagation_start.elapsed();
+                        }
                         match result {
                             Err(err) => {
-                                // If unit propagation failed, there is no solution.
+                                // If unit propagation failed, there is no solution. Any
+                                // conflicting indexes take precedence, as the resolution
+                                // continued with the first index of each conflicting package.
+                                fork_graph.failed(state.fork_id, &state.fork_indexes);
+                                self.defer_index_conflicts(&state, None)?;
+                                if let Some(err) = self.indexes.priority().deferred_conflicts() {
+                                    return Err(err);
+                                }
                                 return Err(self.convert_no_solution_err(
                                     err,
                                     state.fork_urls,
@@ -374,6 +604,44 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 ));
                             }
                             Ok(conflicts) => {
+                                if let Some(previous_solution) = previous_solution
+                                    && !conflicts.is_empty()
+                                {
+                                    let solution = state
+                                        .pubgrub
+                                        .partial_solution
+                                        .extract_solution()
+                                        .collect::<FxHashMap<_, _>>();
+                                    let retracted = previous_solution
+                                        .into_iter()
+                                        .filter(|(package, version)| {
+                                            solution.get(package) != Some(version)
+                                        })
+                                        .filter(|(package, _)| {
+                                            !state.pubgrub.package_store[*package].is_proxy()
+                                        })
+                                        .map(|(package, version)| {
+                                            format!(
+                                                "{}=={version}",
+                                                state.pubgrub.package_store[package]
+                                            )
+                                        })
+                                        .collect::<Vec<_>>();
+                                    for (affected, incompatibility) in &conflicts {
+                                        let learned = format_incompatibility(
+                                            state.pubgrub.incompatibility_store[*incompatibility]
+                                                .iter()
+                                                .map(|(package, term)| {
+                                                    (&state.pubgrub.package_store[package], term)
+                                                }),
+                                        );
+                                        backtrack_log.conflict(
+                                            &state.pubgrub.package_store[*affected],
+                                            learned,
+                                            &retracted,
+                                        );
+                                    }
+                                }
                                 for (affected, incompatibility) in conflicts {
                                     // Conflict tracking: If there was a conflict, track affected and
                                     // culprit for all root cause incompatibilities
@@ -392,12 +660,19 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                     .map(|(id, range)| (&state.pubgrub.package_store[id], range)),
                                 &self.urls,
                                 &self.indexes,
+                                &state.fork_indexes,
                                 &state.python_requirement,
                                 request_sink,
                             )?;
                         }
 
+                        let decisions = stats.enabled.then(|| decision_level(&state));
                         Self::reprioritize_conflicts(&mut state);
+                        Self::reresolve_repinned(&mut state);
+                        if let Some(decisions) = decisions {
+                            stats.retracted_decisions +=
+                                decisions.saturating_sub(decision_level(&state));
+                        }
 
                         trace!(
                             "Assigned packages: {}",
@@ -418,6 +693,12 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             )
                         else {
                             // All packages have been assigned, the fork has been successfully resolved
+                            let selected = state
+                                .pubgrub
+                                .partial_solution
+                                .extract_solution()
+                                .collect::<Vec<_>>();
+                            self.defer_index_conflicts(&state, Some(&selected))?;
                             if tracing::enabled!(Level::DEBUG) {
                                 state.prefetcher.log_tried_versions();
                             }
@@ -426,8 +707,39 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 state.env,
                                 start.elapsed().as_secs_f32()
                             );
+                            if tracing::enabled!(target: "uv_resolver::indexes", Level::DEBUG)
+                                && !state.fork_indexes.is_empty()
+                            {
+                                log_fork_indexes(&state.fork_indexes, &state.env);
+                            }
+
+                            // Lower versions are selected on purpose with the lowest resolution
+                            // strategies, so packages are only considered held back if the
+                            // highest versions are preferred.
+                            if matches!(
+                                self.selector.resolution_strategy(),
+                                ResolutionStrategy::Highest
+                            ) {
+                                self.held_back.record(
+                                    &state,
+                                    &self.index,
+                                    &self.exclusions,
+                                    &self.preferences,
+                                );
+                            }
 
+                            let fork_id = state.fork_id;
                             let resolution = state.into_resolution();
+                            fork_graph.resolved(
+                                fork_id,
+                                resolution
+                                    .nodes
+                                    .keys()
+                                    .map(|package| &package.name)
+                                    .collect::<FxHashSet<_>>()
+                                    .len(),
+                                &resolution.indexes,
+                            );
 
                             // Walk over the selected versions, and mark them as preferences. We have to
                             // add forks back as to not override the preferences from the lockfile for
@@ -455,7 +767,15 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 }
                             }
 
+                            // The first fork to be resolved serves as the base fork, against
+                            // which the pins of the other forks are compared.
+                            let (pinned, divergent) =
+                                resolution.divergence(resolutions.first().unwrap_or(&resolution));
+                            span.record("pinned", pinned);
+                            span.record("divergent", divergent);
+
                             resolutions.push(resolution);
+                            span.record("resolved", true);
                             continue 'FORK;
                         };
                         trace!(
@@ -482,9 +802,18 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 let url = next_package
                     .name()
                     .and_then(|name| state.fork_urls.get(name));
-                let index = next_package
-                    .name()
-                    .and_then(|name| state.fork_indexes.get(name));
+                let index = next_package.name().and_then(|name| {
+                    match state
+                        .pubgrub
+                        .partial_solution
+                        .term_intersection_for_package(next_id)
+                    {
+                        Some(term) => state
+                            .fork_indexes
+                            .get_for_versions(name, term.unwrap_positive()),
+                        None => state.fork_indexes.get(name),
+                    }
+                });
 
                 // Consider:
                 // ```toml
@@ -565,6 +894,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             continue 'FORK;
                         }
                         ResolverVersion::Unavailable(version, reason) => {
+                            if let Some(name) = next_package.name() {
+                                self.python_exclusions.insert(name, &version, &reason);
+                            }
                             state.add_unavailable_version(version, reason);
                             continue;
                         }
@@ -647,12 +979,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 self.selector.resolution_strategy(),
                             )
                             .map_err(|err| {
+                                fork_graph.failed(state.fork_id, &state.fork_indexes);
                                 enrich_dependency_error(err, next_id, &version, &state.pubgrub)
                             })?;
 
                         // Emit a request to fetch the metadata for each registry package.
                         self.visit_dependencies(&dependencies, &state, request_sink)
                             .map_err(|err| {
+                                fork_graph.failed(state.fork_id, &state.fork_indexes);
                                 enrich_dependency_error(err, next_id, &version, &state.pubgrub)
                             })?;
 
@@ -705,6 +1039,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 }
             }
         }
+        if let Some(err) = self.indexes.priority().deferred_conflicts() {
+            return Err(err);
+        }
         if resolutions.len() > 1 {
             info!(
                 "Solved your requirements for {} environments",
@@ -726,6 +1063,61 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 }
             }
         }
+        if let Some(base) = resolutions.first() {
+            for resolution in &resolutions {
+                let (pinned, divergent) = resolution.divergence(base);
+                let markers = resolution
+                    .env
+                    .simplify_markers_for_display(self.python_requirement.target())
+                    .fork_markers()
+                    .and_then(MarkerTree::try_to_string);
+                let diverging = resolution.diverging_pins(base);
+                let diverging = if diverging.is_empty() {
+                    String::new()
+                } else if diverging.len() > MAX_LOGGED_DIVERGING {
+                    format!(
+                        ": {}, …and {} more",
+                        diverging[..MAX_LOGGED_DIVERGING].join(", "),
+                        diverging.len() - MAX_LOGGED_DIVERGING
+                    )
+                } else {
+                    format!(": {}", diverging.join(", "))
+                };
+                debug!(
+                    target: "uv_resolver::forks",
+                    "Fork {}: {pinned} package(s) pinned, {divergent} diverging from the base fork{diverging}",
+                    markers.as_deref().unwrap_or("(all environments)"),
+                );
+            }
+        }
+        let fallbacks = self.fallbacks(&resolutions);
+        if !fallbacks.is_empty() {
+            debug!(
+                target: "uv_resolver::indexes",
+                "{} served by fallback indexes: {}",
+                if fallbacks.len() == 1 {
+                    "1 package".to_string()
+                } else {
+                    format!("{} packages", fallbacks.len())
+                },
+                fallbacks
+                    .iter()
+                    .map(|(name, url)| format!("{name} ({})", url.without_credentials()))
+                    .join(", ")
+            );
+            Span::current().record(
+                "fallbacks",
+                fallbacks.iter().map(|(name, _)| name).dedup().join(", "),
+            );
+        }
+        let index_conflicts = self.indexes.priority().ignored();
+        if index_conflicts > 0 {
+            debug!(
+                target: "uv_resolver::indexes",
+                "Ignored {index_conflicts} conflicting index pin(s)"
+            );
+            Span::current().record("index_conflicts", index_conflicts);
+        }
         for resolution in &resolutions {
             Self::trace_resolution(resolution);
         }
@@ -741,6 +1133,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             &self.conflicts,
             self.selector.resolution_strategy(),
             self.options.clone(),
+            fork_graph.clone(),
         )
     }
 
@@ -794,6 +1187,75 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         }
     }
 
+    /// Backtrack before the decision on each package whose pin was moved to a different index,
+    /// such that its version is selected again from the index that it's now pinned to.
+    ///
+    /// To be called after unit propagation.
+    fn reresolve_repinned(state: &mut ForkState) {
+        let repinned = state.fork_indexes.take_repinned();
+        if repinned.is_empty() {
+            return;
+        }
+        let decided = state
+            .pubgrub
+            .partial_solution
+            .extract_solution()
+            .map(|(id, _)| id)
+            .filter(|id| {
+                state.pubgrub.package_store[*id]
+                    .name_no_root()
+                    .is_some_and(|name| repinned.contains(name))
+            })
+            .collect::<Vec<_>>();
+        for id in decided {
+            if let Some(backtrack_level) = state.pubgrub.backtrack_package(id) {
+                debug!(
+                    "Backtracked {backtrack_level} decisions to select {} from the index it's now pinned to",
+                    state.pubgrub.package_store[id]
+                );
+            }
+        }
+    }
+
+    /// Defer the conflicts between the indexes that packages are pinned to in a fork, such that
+    /// they're reported together once the resolution completes.
+    ///
+    /// If the fork was resolved, `selected` contains the selected version of each package, and
+    /// only the pins that claim the selected version conflict (see [`ForkIndexes::conflicts`]).
+    fn defer_index_conflicts(
+        &self,
+        state: &ForkState,
+        selected: Option<&[(Id<PubGrubPackage>, Version)]>,
+    ) -> Result<(), ResolveError> {
+        let packages = selected.map(|selected| {
+            selected
+                .iter()
+                .filter(|(id, _)| !state.pubgrub.package_store[*id].is_proxy())
+                .filter_map(|(id, version)| {
+                    Some((
+                        state.pubgrub.package_store[*id].name_no_root()?,
+                        (*id, version),
+                    ))
+                })
+                .collect::<FxHashMap<_, _>>()
+        });
+        let versions = packages.as_ref().map(|packages| {
+            packages
+                .iter()
+                .map(|(name, (_, version))| (*name, *version))
+                .collect::<FxHashMap<_, _>>()
+        });
+        for (err, parent) in state.fork_indexes.conflicts(versions.as_ref(), &state.env) {
+            // Attribute the conflict to the package that requested the later pin, if it's known.
+            let err = match parent.and_then(|parent| packages.as_ref()?.get(parent)) {
+                Some((id, version)) => enrich_dependency_error(err, *id, version, &state.pubgrub),
+                None => err,
+            };
+            self.indexes.priority().defer_conflict(err)?;
+        }
+        Ok(())
+    }
+
     /// When trace level logging is enabled, we dump the final
     /// set of resolutions, including markers, to help with
     /// debugging. Namely, this tells use precisely the state
@@ -883,6 +1345,29 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 (fork, forked_state.with_env(env))
             })
             .map(move |(fork, mut forked_state)| {
+                // Record the requirements that caused the split.
+                let requirements = fork
+                    .dependencies
+                    .iter()
+                    .filter(|dependency| {
+                        dependency
+                            .package
+                            .name()
+                            .is_some_and(|name| diverging_packages.contains(name))
+                    })
+                    .map(|dependency| {
+                        let range = SentinelRange::from(&dependency.version).strip();
+                        PackageRange::dependency(&dependency.package, &range, None).to_string()
+                    })
+                    .join(", ");
+                let parent = &forked_state.pubgrub.package_store[package];
+                let reason = if matches!(&**parent, PubGrubPackageInner::Root(_)) {
+                    requirements
+                } else {
+                    format!("{parent}=={version} depends on {requirements}")
+                };
+                forked_state.fork_edge = Some(ForkEdge::new(forked_state.fork_id, reason));
+
                 // Enrich the state with any URLs, etc.
                 forked_state
                     .visit_package_version_dependencies(
@@ -932,6 +1417,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             if !is_last {
                 cur_state = Some(forked_state.clone());
             }
+            forked_state.fork_edge = Some(ForkEdge::new(
+                forked_state.fork_id,
+                format!(
+                    "{} (Requires-Python)",
+                    forked_state.pubgrub.package_store[fork.id]
+                ),
+            ));
             forked_state.initial_id = Some(fork.id);
             forked_state.initial_version = fork.version;
             forked_state.with_env(fork.env)
@@ -948,12 +1440,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         for dependency in dependencies {
             let PubGrubDependency {
                 package,
-                version: _,
+                version,
                 parent: _,
                 url: _,
             } = dependency;
             let url = package.name().and_then(|name| state.fork_urls.get(name));
-            let index = package.name().and_then(|name| state.fork_indexes.get(name));
+            let index = package
+                .name()
+                .and_then(|name| state.fork_indexes.get_for_versions(name, version));
             self.visit_package(package, url, index, request_sink)?;
         }
         Ok(())
@@ -1028,6 +1522,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         packages: impl Iterator<Item = (&'data PubGrubPackage, &'data Range<Version>)>,
         urls: &Urls,
         indexes: &Indexes,
+        fork_indexes: &ForkIndexes,
         python_requirement: &PythonRequirement,
         request_sink: &Sender<Request>,
     ) -> Result<(), ResolveError> {
@@ -1048,8 +1543,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             if urls.any_url(name) {
                 continue;
             }
-            // Avoid visiting packages that may use an explicit index.
-            if indexes.contains_key(name) {
+            // Avoid visiting packages that may use an explicit index, or that are already pinned
+            // to one in this fork (e.g., via `--package-index`).
+            if indexes.contains_key(name) || fork_indexes.get(name).is_some() {
                 continue;
             }
             request_sink.blocking_send(Request::Prefetch(
@@ -1147,6 +1643,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             url.verbatim
         );
 
+        // If the URL isn't hosted on one of the allowed indexes, fail.
+        if let Some(allowed_indexes) = &self.allowed_indexes
+            && !allowed_indexes.allows_url(url)
+        {
+            return Err(allowed_indexes.url_not_allowed(name, url));
+        }
+
         let dist = PubGrubDistribution::from_url(name, url);
         let response = self
             .index
@@ -1423,6 +1926,19 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             candidate.choice_kind(),
             filename,
         );
+        let reason = match dist {
+            CompatibleDist::InstalledDist(_) => "it is already installed",
+            CompatibleDist::CompatibleWheel { .. } => "it is the most specific compatible wheel",
+            CompatibleDist::SourceDist { .. } => "no wheel is usable",
+            CompatibleDist::IncompatibleWheel { .. } => {
+                "no wheel is usable, but an incompatible wheel provides its metadata"
+            }
+        };
+        debug!(
+            target: "uv_resolver::candidates",
+            "Chose {filename} for {name}=={} because {reason}",
+            candidate.version(),
+        );
         self.visit_candidate(&candidate, dist, package, name, pins, request_sink)?;
 
         let version = candidate.version().clone();
@@ -1673,6 +2189,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         pins: &mut FilePins,
         request_sink: &Sender<Request>,
     ) -> Result<(), ResolveError> {
+        // If the candidate would be installed from an index that isn't allowed, fail.
+        if let Some(allowed_indexes) = &self.allowed_indexes
+            && let Some(index) = dist.for_installation().index()
+            && !allowed_indexes.allows_index(index)
+        {
+            return Err(allowed_indexes.not_allowed(name, index.without_credentials().to_string()));
+        }
+
         // We want to return a package pinned to a specific version; but we _also_ want to
         // store the exact file that we selected to satisfy that version.
         pins.insert(candidate, dist);
@@ -1789,6 +2313,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         let url = package.name().and_then(|name| fork_urls.get(name));
         let dependencies = match &**package {
             PubGrubPackageInner::Root(_) => {
+                Self::log_marker_skips(package, &self.requirements, env);
                 let no_dev_deps = BTreeMap::default();
                 let requirements = self.flatten_requirements(
                     &self.requirements,
@@ -1907,6 +2432,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     })
                     .map(PubGrubDependency::from);
 
+                if extra.is_none() && group.is_none() {
+                    Self::log_marker_skips(
+                        format!("{name}=={version}"),
+                        &metadata.requires_dist,
+                        env,
+                    );
+                }
+
                 let requirements = self.flatten_requirements(
                     &metadata.requires_dist,
                     &metadata.dependency_groups,
@@ -2012,6 +2545,32 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         Ok(Dependencies::Available(dependencies))
     }
 
+    /// Summarize how many of a package's requirements don't apply to the target environment due
+    /// to their markers.
+    ///
+    /// Only applies to resolutions for a specific environment; the individual evaluations are
+    /// logged at trace level by [`Requirement::evaluate_markers`].
+    fn log_marker_skips(
+        package: impl Display,
+        requirements: &[Requirement],
+        env: &ResolverEnvironment,
+    ) {
+        let Some(marker_env) = env.marker_environment() else {
+            return;
+        };
+        let skipped = requirements
+            .iter()
+            .filter(|requirement| !requirement.marker.evaluate(marker_env, &[]))
+            .count();
+        if skipped > 0 {
+            debug!(
+                target: "uv_pep508::eval",
+                "Skipped {skipped} of {} requirements of {package} due to markers",
+                requirements.len()
+            );
+        }
+    }
+
     /// The regular and dev dependencies filtered by Python version and the markers of this fork,
     /// plus the extras dependencies of the current package (e.g., `black` depending on
     /// `black[colorama]`).
@@ -2781,6 +3340,37 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
         }
 
+        // Track the newest versions of each package that were excluded due to their
+        // `requires-python`, if the Python requirement is involved in the failure. Versions are
+        // only reported if an older version that wasn't excluded is available, as the failure
+        // already explains packages for which no versions are compatible.
+        let mut python_exclusions = FxHashMap::default();
+        if self.python_requirement.source() != PythonRequirementSource::RequiresPython
+            && err
+                .packages()
+                .into_iter()
+                .any(|package| matches!(&**package, PubGrubPackageInner::Python(_)))
+        {
+            for package in err.packages() {
+                let Some(name) = package.name() else { continue };
+                let Some(excluded) = self.python_exclusions.get(name) else {
+                    continue;
+                };
+                let Some(newest) = available_versions.get(name).and_then(|versions| {
+                    versions
+                        .iter()
+                        .rev()
+                        .find(|version| !excluded.contains(version))
+                }) else {
+                    continue;
+                };
+                let newer = excluded.newer_than(newest);
+                if !newer.is_empty() {
+                    python_exclusions.insert(name.clone(), newer);
+                }
+            }
+        }
+
         ResolveError::NoSolution(Box::new(NoSolutionError::new(
             err,
             self.index.clone(),
@@ -2792,6 +3382,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             self.capabilities.clone(),
             unavailable_packages,
             incomplete_packages,
+            python_exclusions,
             fork_urls,
             fork_indexes,
             env,
@@ -2907,6 +3498,10 @@ pub(crate) struct ForkState {
     ///
     /// Tracked on the fork state to avoid counting each identical version between forks as new try.
     prefetcher: BatchPrefetcher,
+    /// The index of this fork in the [`ForkGraph`], once the resolver started solving it.
+    fork_id: usize,
+    /// The fork that this fork was split from, to be recorded in the [`ForkGraph`].
+    fork_edge: Option<ForkEdge>,
 }
 
 impl ForkState {
@@ -2930,6 +3525,8 @@ impl ForkState {
             python_requirement,
             conflict_tracker: ConflictTracker::default(),
             prefetcher,
+            fork_id: 0,
+            fork_edge: None,
         }
     }
 
@@ -2965,9 +3562,18 @@ impl ForkState {
                     has_url = true;
                 }
 
-                // If the package is pinned to an exact index, add it to the fork.
-                for index in indexes.get(name, &self.env) {
-                    self.fork_indexes.insert(name, index, &self.env)?;
+                // If the package is pinned to an exact index, add it to the fork. Conflicting pins
+                // are only reported once the fork is resolved, if the selected version is claimed
+                // by more than one index.
+                for (index, versions, provenance) in indexes.get(name, &self.env) {
+                    self.fork_indexes.insert(
+                        name,
+                        index,
+                        versions,
+                        provenance,
+                        &self.env,
+                        indexes.priority(),
+                    );
                 }
             }
 
@@ -3452,6 +4058,7 @@ impl ForkState {
             nodes,
             edges,
             pins: self.pins,
+            indexes: self.fork_indexes,
             env: self.env,
         }
     }
@@ -3466,10 +4073,80 @@ pub(crate) struct Resolution {
     pub(crate) edges: Vec<ResolutionDependencyEdge>,
     /// Map each package name, version tuple from `packages` to a distribution.
     pub(crate) pins: FilePins,
+    /// The indexes that packages were pinned to in this fork.
+    pub(crate) indexes: ForkIndexes,
     /// The environment setting this resolution was found under.
     pub(crate) env: ResolverEnvironment,
 }
 
+/// The version and source that a package is pinned to in a fork.
+type Pin<'a> = (
+    &'a Version,
+    Option<&'a VerbatimParsedUrl>,
+    Option<&'a IndexUrl>,
+);
+
+impl Resolution {
+    /// The number of packages pinned in this fork, along with the number of those that diverge
+    /// from the given base fork, i.e., that are pinned to a different version or source there, or
+    /// not at all.
+    fn divergence(&self, base: &Self) -> (usize, usize) {
+        let ours = self.pins_by_name();
+        let theirs = base.pins_by_name();
+        let divergent = ours
+            .iter()
+            .filter(|(name, pins)| theirs.get(*name) != Some(*pins))
+            .count();
+        (ours.len(), divergent)
+    }
+
+    /// Describe the pins of the packages in this fork that diverge from the given base fork,
+    /// sorted by name, e.g., `numpy==1.26.4`.
+    ///
+    /// The index is included for packages that the base fork pins to a different index.
+    fn diverging_pins(&self, base: &Self) -> Vec<String> {
+        let ours = self.pins_by_name();
+        let theirs = base.pins_by_name();
+        ours.iter()
+            .filter(|(name, pins)| theirs.get(*name) != Some(*pins))
+            .sorted_by_key(|(name, _)| **name)
+            .flat_map(|(name, pins)| {
+                let base = theirs.get(*name);
+                pins.iter().sorted_by_key(|(version, ..)| *version).map(
+                    move |(version, url, index)| {
+                        if let Some(url) = url {
+                            return format!("{name} @ {}", url.verbatim);
+                        }
+                        match index {
+                            Some(index)
+                                if base.is_some_and(|base| {
+                                    base.iter().all(|(.., other)| *other != Some(*index))
+                                }) =>
+                            {
+                                format!("{name}=={version} ({})", index.without_credentials())
+                            }
+                            _ => format!("{name}=={version}"),
+                        }
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// The version and source of each package in this fork, by package name.
+    fn pins_by_name(&self) -> FxHashMap<&PackageName, FxHashSet<Pin<'_>>> {
+        let mut pins: FxHashMap<_, FxHashSet<_>> = FxHashMap::default();
+        for (package, version) in &self.nodes {
+            pins.entry(&package.name).or_default().insert((
+                version,
+                package.url.as_ref(),
+                package.index.as_ref(),
+            ));
+        }
+        pins
+    }
+}
+
 /// Package representation we used during resolution where each extra and also the dev-dependencies
 /// group are their own package.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -4075,6 +4752,34 @@ fn enrich_dependency_error(
     ResolveError::Dependencies(Box::new(error), name.clone(), version.clone(), chain)
 }
 
+/// Log the packages that were pinned to each index in a resolved fork, under the
+/// `uv_resolver::indexes` target.
+fn log_fork_indexes(fork_indexes: &ForkIndexes, env: &ResolverEnvironment) {
+    let mut urls: Vec<&IndexUrl> = Vec::new();
+    for (_, url) in fork_indexes.iter() {
+        if !urls.iter().any(|existing| existing.is_same_index(url)) {
+            urls.push(url);
+        }
+    }
+    debug!(
+        target: "uv_resolver::indexes",
+        "Resolved {env} with {} package(s) pinned to {} index(es)",
+        fork_indexes.len(),
+        urls.len(),
+    );
+    for url in urls {
+        debug!(
+            target: "uv_resolver::indexes",
+            "Resolved {env} with {} pinned to {}",
+            fork_indexes
+                .packages_for_index(url)
+                .map(|package_name| format!("`{package_name}`"))
+                .join(", "),
+            url.without_credentials(),
+        );
+    }
+}
+
 /// Compute the set of markers for which a package is known to be relevant.
 fn find_environments(id: Id<PubGrubPackage>, state: &State<UvDependencyProvider>) -> MarkerTree {
     let package = &state.package_store[id];
@@ -4104,6 +4809,67 @@ fn find_environments(id: Id<PubGrubPackage>, state: &State<UvDependencyProvider>
     marker
 }
 
+/// Returns the decision level of the fork, i.e., the number of packages that have been decided.
+///
+/// Both unit propagation and reprioritization backtrack by lowering the decision level, such that
+/// the difference in the decision level is the number of decisions that were retracted.
+fn decision_level(state: &ForkState) -> usize {
+    state.pubgrub.partial_solution.extract_solution().count()
+}
+
+/// Statistics on the backtracking of a resolution, across all forks.
+///
+/// Once the resolution completes (or fails), the statistics are recorded on the `solve` span and
+/// emitted as a `DEBUG` event with the `uv_resolver::stats` target.
+#[derive(Debug)]
+struct BacktrackingStats {
+    span: Span,
+    /// Whether the statistics are reported, as counting the retracted decisions isn't free.
+    enabled: bool,
+    /// The number of conflicts encountered during unit propagation.
+    conflicts: usize,
+    /// The number of decisions that were retracted by backtracking, whether during unit
+    /// propagation or when reprioritizing conflicting packages.
+    retracted_decisions: usize,
+    /// The time spent in unit propagation that ran into conflicts, i.e., resolving them.
+    conflict_resolution: Duration,
+}
+
+impl BacktrackingStats {
+    fn new(span: Span) -> Self {
+        let enabled =
+            !span.is_disabled() || tracing::enabled!(target: "uv_resolver::stats", Level::DEBUG);
+        Self {
+            span,
+            enabled,
+            conflicts: 0,
+            retracted_decisions: 0,
+            conflict_resolution: Duration::ZERO,
+        }
+    }
+
+    /// Record the statistics on the `solve` span and emit them as an event.
+    fn report(&self) {
+        if !self.enabled {
+            return;
+        }
+        self.span.record("conflicts", self.conflicts);
+        self.span
+            .record("retracted_decisions", self.retracted_decisions);
+        self.span.record(
+            "conflict_resolution",
+            self.conflict_resolution.as_secs_f64(),
+        );
+        debug!(
+            target: "uv_resolver::stats",
+            conflicts = self.conflicts,
+            retracted_decisions = self.retracted_decisions,
+            conflict_resolution = self.conflict_resolution.as_secs_f64(),
+            "Resolution backtracking statistics"
+        );
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 struct ConflictTracker {
     /// How often a decision on the package was discarded due to another package decided earlier.
@@ -0,0 +1,139 @@
+//! Candidate versions that were rejected due to their `requires-python`, summarized per package
+//! once the resolution completes (or fails).
+//!
+//! When the newest versions of a package require a newer Python, the resolver silently falls back
+//! to older versions, and a failure only reports the versions that remain. The exclusions are
+//! tracked across all forks, such that the failure can point out the newer versions instead.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Bound;
+
+use dashmap::DashMap;
+use pubgrub::Ranges;
+use tracing::debug;
+
+use uv_distribution_types::{IncompatibleDist, IncompatibleSource, IncompatibleWheel};
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers, release_specifiers_to_ranges};
+
+use crate::python_requirement::{PythonRequirement, PythonRequirementSource};
+use crate::resolver::UnavailableVersion;
+
+/// The versions of each package that were rejected due to their `requires-python`.
+#[derive(Debug, Default)]
+pub(crate) struct PythonExclusions(DashMap<PackageName, ExcludedVersions>);
+
+impl PythonExclusions {
+    /// Record the version of a package as excluded, if it was unavailable due to its
+    /// `requires-python`.
+    pub(crate) fn insert(
+        &self,
+        name: &PackageName,
+        version: &Version,
+        reason: &UnavailableVersion,
+    ) {
+        if let UnavailableVersion::IncompatibleDist(
+            IncompatibleDist::Source(IncompatibleSource::RequiresPython(requires_python, _))
+            | IncompatibleDist::Wheel(IncompatibleWheel::RequiresPython(requires_python, _)),
+        ) = reason
+        {
+            self.0
+                .entry(name.clone())
+                .or_default()
+                .0
+                .insert(version.clone(), requires_python.clone());
+        }
+    }
+
+    /// Return the versions of the package that were excluded, if any.
+    pub(crate) fn get(&self, name: &PackageName) -> Option<ExcludedVersions> {
+        self.0.get(name).map(|versions| versions.value().clone())
+    }
+
+    /// Log a summary of the excluded versions of each package.
+    pub(crate) fn log(&self, python_requirement: &PythonRequirement) {
+        let python = resolving_for(python_requirement);
+        let mut packages = self.0.iter().collect::<Vec<_>>();
+        packages.sort_by(|a, b| a.key().cmp(b.key()));
+        for entry in packages {
+            let (name, versions) = entry.pair();
+            debug!(
+                "Excluded {} {} of `{name}` ({versions}) due to `requires-python`: Python {} is required, but resolving for Python {python}",
+                versions.len(),
+                if versions.len() == 1 {
+                    "version"
+                } else {
+                    "versions"
+                },
+                versions.requires_python()
+            );
+        }
+    }
+}
+
+/// The versions of a single package that were rejected due to their `requires-python`, along with
+/// the `requires-python` of each.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ExcludedVersions(BTreeMap<Version, VersionSpecifiers>);
+
+impl ExcludedVersions {
+    /// Return the excluded versions that are newer than the given version.
+    pub(crate) fn newer_than(&self, version: &Version) -> Self {
+        Self(
+            self.0
+                .range((Bound::Excluded(version), Bound::Unbounded))
+                .map(|(version, requires_python)| (version.clone(), requires_python.clone()))
+                .collect(),
+        )
+    }
+
+    /// Returns `true` if the given version was excluded.
+    pub(crate) fn contains(&self, version: &Version) -> bool {
+        self.0.contains_key(version)
+    }
+
+    /// Returns `true` if no versions were excluded.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of excluded versions.
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The range of Python versions that would be needed to use any of the excluded versions,
+    /// e.g., `>=3.10`.
+    pub(crate) fn requires_python(&self) -> Ranges<Version> {
+        self.0
+            .values()
+            .fold(Ranges::empty(), |range, requires_python| {
+                range.union(&release_specifiers_to_ranges(requires_python.clone()))
+            })
+    }
+}
+
+/// Display the range of excluded versions, e.g., `2.0.0 to 4.0.0`.
+impl Display for ExcludedVersions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.0.first_key_value(), self.0.last_key_value()) {
+            (Some((first, _)), Some((last, _))) if first != last => {
+                write!(f, "{first} to {last}")
+            }
+            (Some((version, _)), _) => write!(f, "{version}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Describe the Python version that the resolution targets, e.g., `3.9.20` for the current
+/// interpreter or `>=3.9` for a `requires-python` or `--python-version` value.
+pub(crate) fn resolving_for(python_requirement: &PythonRequirement) -> String {
+    match python_requirement.source() {
+        PythonRequirementSource::Interpreter => python_requirement.exact().to_string(),
+        PythonRequirementSource::PythonVersion | PythonRequirementSource::RequiresPython => {
+            python_requirement.target().to_string()
+        }
+    }
+}
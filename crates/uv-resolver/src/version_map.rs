@@ -3,13 +3,14 @@ use std::collections::btree_map::{BTreeMap, Entry};
 use std::ops::RangeBounds;
 use std::sync::OnceLock;
 
+use itertools::Itertools;
 use pubgrub::Ranges;
 use rustc_hash::FxHashMap;
-use tracing::instrument;
+use tracing::{instrument, trace};
 
 use uv_client::{FlatIndexEntry, OwnedArchive, SimpleDetailMetadata, VersionFiles};
 use uv_configuration::BuildOptions;
-use uv_distribution_filename::{DistFilename, WheelFilename};
+use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
     HashComparison, IncompatibleSource, IncompatibleWheel, IndexUrl, PrioritizedDist,
     RegistryBuiltWheel, RegistrySourceDist, RequiresPython, SourceDistCompatibility,
@@ -42,7 +43,7 @@ impl VersionMap {
     /// unyanked distributions _can_ be used.
     ///
     /// PEP 592: <https://peps.python.org/pep-0592/#warehouse-pypi-implementation-notes>
-    #[instrument(skip_all, fields(package_name))]
+    #[instrument(skip_all, fields(%package_name))]
     pub(crate) fn from_simple_metadata(
         simple_metadata: OwnedArchive<SimpleDetailMetadata>,
         package_name: &PackageName,
@@ -477,6 +478,7 @@ impl VersionMapLazy {
                             excluded,
                             upload_time,
                         );
+                        trace_wheel(&filename, &compatibility);
                         let dist = RegistryBuiltWheel {
                             filename,
                             file: Box::new(file),
@@ -493,6 +495,7 @@ impl VersionMapLazy {
                             excluded,
                             upload_time,
                         );
+                        trace_source_dist(&filename, &compatibility);
                         let dist = RegistrySourceDist {
                             name: filename.name.clone(),
                             version: filename.version.clone(),
@@ -630,6 +633,63 @@ impl VersionMapLazy {
     }
 }
 
+/// Log the verdict on a candidate wheel, along with its parsed tags.
+pub(crate) fn trace_wheel(filename: &WheelFilename, compatibility: &WheelCompatibility) {
+    let verdict = match compatibility {
+        WheelCompatibility::Compatible(
+            HashComparison::Mismatched | HashComparison::Missing,
+            ..,
+        ) => "excluded-by-hash",
+        WheelCompatibility::Compatible(..) => "compatible",
+        WheelCompatibility::Incompatible(incompatibility) => match incompatibility {
+            IncompatibleWheel::Tag(IncompatibleTag::Invalid) => "invalid-tag",
+            IncompatibleWheel::Tag(IncompatibleTag::Python) => "incompatible-python-tag",
+            IncompatibleWheel::Tag(IncompatibleTag::Abi | IncompatibleTag::FreethreadedAbi) => {
+                "incompatible-abi-tag"
+            }
+            IncompatibleWheel::Tag(IncompatibleTag::AbiPythonVersion) => {
+                "incompatible-requires-python"
+            }
+            IncompatibleWheel::Tag(IncompatibleTag::Platform) => "incompatible-platform-tag",
+            IncompatibleWheel::RequiresPython(..) => "incompatible-requires-python",
+            IncompatibleWheel::Yanked(..) => "yanked",
+            IncompatibleWheel::ExcludeNewer(..) => "excluded-by-upload-time",
+            IncompatibleWheel::NoBinary => "excluded-by-no-binary",
+            IncompatibleWheel::MissingPlatform(..) => "missing-platform",
+        },
+    };
+    trace!(
+        target: "uv_resolver::candidates",
+        "{filename} (python: {}, abi: {}, platform: {}): {verdict}",
+        filename.python_tags().iter().join("."),
+        filename.abi_tags().iter().join("."),
+        filename.platform_tags().iter().join("."),
+    );
+}
+
+/// Log the verdict on a candidate source distribution.
+pub(crate) fn trace_source_dist(
+    filename: &SourceDistFilename,
+    compatibility: &SourceDistCompatibility,
+) {
+    let verdict = match compatibility {
+        SourceDistCompatibility::Compatible(
+            HashComparison::Mismatched | HashComparison::Missing,
+        ) => "excluded-by-hash",
+        SourceDistCompatibility::Compatible(..) => "compatible",
+        SourceDistCompatibility::Incompatible(incompatibility) => match incompatibility {
+            IncompatibleSource::RequiresPython(..) => "incompatible-requires-python",
+            IncompatibleSource::Yanked(..) => "yanked",
+            IncompatibleSource::ExcludeNewer(..) => "excluded-by-upload-time",
+            IncompatibleSource::NoBuild => "excluded-by-no-build",
+        },
+    };
+    trace!(
+        target: "uv_resolver::candidates",
+        "{filename} (source distribution): {verdict}"
+    );
+}
+
 /// Represents a possibly initialized [`PrioritizedDist`] for
 /// a single version of a package.
 #[derive(Debug)]
@@ -17,13 +17,13 @@ use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerValue, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    IndexConflictStrategy, PrereleaseMode, ResolutionMode,
 };
 use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
-use crate::{FilesystemOptions, Options, PipOptions};
+use crate::{FilesystemOptions, LogLevel, LoggingOptions, Options, PipOptions};
 
 pub trait Combine {
     /// Combine two values, preferring the values in `self`.
@@ -45,9 +45,17 @@ impl Combine for Option<FilesystemOptions> {
     /// Combine the options used in two [`FilesystemOptions`]s. Retains the root of `self`.
     fn combine(self, other: Self) -> Self {
         match (self, other) {
-            (Some(a), Some(b)) => Some(FilesystemOptions(
-                a.into_options().combine(b.into_options()),
-            )),
+            (Some(a), Some(b)) => {
+                let mut index_urls = a.index_urls;
+                index_urls.extend(b.index_urls);
+                let mut layers = a.layers;
+                layers.extend(b.layers);
+                Some(FilesystemOptions {
+                    options: a.options.combine(b.options),
+                    index_urls,
+                    layers,
+                })
+            }
             (a, b) => a.or(b),
         }
     }
@@ -72,6 +80,15 @@ impl Combine for Option<PipOptions> {
     }
 }
 
+impl Combine for Option<LoggingOptions> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 macro_rules! impl_combine_or {
     ($name:ident) => {
         impl Combine for Option<$name> {
@@ -89,6 +106,7 @@ impl_combine_or!(ExcludeNewerValue);
 impl_combine_or!(ExportFormat);
 impl_combine_or!(ForkStrategy);
 impl_combine_or!(Index);
+impl_combine_or!(IndexConflictStrategy);
 impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
@@ -144,6 +162,22 @@ impl<K: Ord, T> Combine for Option<BTreeMap<K, Vec<T>>> {
     }
 }
 
+impl Combine for Option<BTreeMap<String, LogLevel>> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, with the values in
+    /// `self` taking precedence.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (key, value) in b {
+                    a.entry(key).or_insert(value);
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ExcludeNewerPackage> {
     /// Combine two [`ExcludeNewerPackage`] instances by merging them, with the values in `self` taking precedence.
     fn combine(self, other: Self) -> Self {
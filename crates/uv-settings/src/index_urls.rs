@@ -0,0 +1,164 @@
+use std::path::Path;
+
+use uv_configuration::TrustedHost;
+use uv_distribution_types::{Index, IndexConflictError, IndexUrl, Origin, Verbatim, dedup_indexes};
+use uv_warnings::warn_user_once;
+
+use crate::provenance::Scope;
+use crate::{Error, Options};
+
+/// An index URL, along with where it was provided.
+#[derive(Debug, Clone)]
+pub struct SourcedIndexUrl {
+    pub url: IndexUrl,
+    pub origin: Origin,
+}
+
+impl SourcedIndexUrl {
+    pub fn new(url: IndexUrl, origin: Origin) -> Self {
+        Self { url, origin }
+    }
+}
+
+/// Record the file and key that each index in the [`Options`] read from the configuration file at
+/// `path` was configured with, as its [`Origin`], and collect the index URLs.
+///
+/// The `prefix` is prepended to each key, e.g., `tool.uv.` for a `pyproject.toml` file.
+pub(crate) fn collect(
+    options: &mut Options,
+    path: &Path,
+    prefix: &str,
+    scope: Scope,
+) -> Vec<SourcedIndexUrl> {
+    let mut urls = Vec::new();
+    let mut push = |key: &str, index: &mut Index| {
+        let path = path.to_path_buf();
+        let key = format!("{prefix}{key}");
+        // A file passed via `--config-file` takes the place of the project configuration.
+        let origin = match scope {
+            Scope::User | Scope::System => Origin::User { path, key },
+            Scope::Project | Scope::Explicit => Origin::Project { path, key },
+        };
+        index.origin = Some(origin.clone());
+        urls.push(SourcedIndexUrl::new(index.url.clone(), origin));
+    };
+
+    let top_level = &mut options.top_level;
+    for index in top_level.index.iter_mut().flatten() {
+        push("index", index);
+    }
+    if let Some(index_url) = &mut top_level.index_url {
+        push("index-url", index_url.as_mut());
+    }
+    for index in top_level.extra_index_url.iter_mut().flatten() {
+        push("extra-index-url", index.as_mut());
+    }
+    for index in top_level.find_links.iter_mut().flatten() {
+        push("find-links", index.as_mut());
+    }
+
+    if let Some(pip) = &mut options.pip {
+        for index in pip.index.iter_mut().flatten() {
+            push("pip.index", index);
+        }
+        if let Some(index_url) = &mut pip.index_url {
+            push("pip.index-url", index_url.as_mut());
+        }
+        for index in pip.extra_index_url.iter_mut().flatten() {
+            push("pip.extra-index-url", index.as_mut());
+        }
+        for index in pip.find_links.iter_mut().flatten() {
+            push("pip.find-links", index.as_mut());
+        }
+    }
+
+    urls
+}
+
+/// Deduplicate the indexes in the [`Options`], which may have been merged from several
+/// configuration files, returning an error if two definitions conflict.
+pub(crate) fn dedup(options: &mut Options) -> Result<(), IndexConflictError> {
+    if let Some(indexes) = options.top_level.index.take() {
+        options.top_level.index = Some(dedup_indexes(indexes).map_err(locate)?);
+    }
+    if let Some(pip) = &mut options.pip
+        && let Some(indexes) = pip.index.take()
+    {
+        pip.index = Some(dedup_indexes(indexes).map_err(locate)?);
+    }
+    Ok(())
+}
+
+/// Record the line of each conflicting definition, by re-reading the file it was configured in.
+fn locate(err: IndexConflictError) -> IndexConflictError {
+    err.with_lines(|definition| {
+        let Some(Origin::User { path, key } | Origin::Project { path, key }) = &definition.origin
+        else {
+            return Vec::new();
+        };
+        let Ok(content) = fs_err::read_to_string(path) else {
+            return Vec::new();
+        };
+        definition.find_lines(&content, key)
+    })
+}
+
+/// Validate the given index URLs before they're used.
+///
+/// Returns an error for any local index that does not exist, and warns for any index that's served
+/// over plain HTTP from a non-local host that isn't included in `allow_insecure_host`.
+pub fn validate_index_urls<'a>(
+    urls: impl IntoIterator<Item = &'a SourcedIndexUrl>,
+    allow_insecure_host: &[TrustedHost],
+) -> Result<(), Error> {
+    for SourcedIndexUrl { url, origin } in urls {
+        match url {
+            IndexUrl::Path(_) => {
+                let Ok(path) = url.url().to_file_path() else {
+                    continue;
+                };
+                if path.exists() {
+                    continue;
+                }
+                let given = url.verbatim();
+                if looks_like_host(&given) {
+                    return Err(Error::MissingIndexScheme {
+                        url: given.to_string(),
+                        origin: origin.clone(),
+                    });
+                }
+                return Err(Error::MissingIndexPath {
+                    url: given.to_string(),
+                    path,
+                    origin: origin.clone(),
+                });
+            }
+            IndexUrl::Pypi(_) | IndexUrl::Url(_) => {
+                let url = url.url();
+                if url.scheme() != "http"
+                    || matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"))
+                    || allow_insecure_host.iter().any(|host| host.matches(url))
+                {
+                    continue;
+                }
+                warn_user_once!(
+                    "Index URL `{}` from {origin} uses `http://`, so its packages will be downloaded over an insecure connection. Use `https://` instead, or add the host to `allow-insecure-host` to silence this warning.",
+                    url.without_credentials()
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if a relative path looks like a URL that's missing its scheme, as in
+/// `pypi.org/simple`.
+fn looks_like_host(given: &str) -> bool {
+    if given.contains("://") || Path::new(given).is_absolute() {
+        return false;
+    }
+    given
+        .split(['/', '\\'])
+        .next()
+        .is_some_and(|host| !host.starts_with('.') && host.contains('.'))
+}
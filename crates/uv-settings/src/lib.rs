@@ -1,28 +1,82 @@
 use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use uv_configuration::ErrorFormat;
 use uv_dirs::{system_config_file, user_config_dir};
+use uv_distribution_types::Origin;
 use uv_flags::EnvironmentFlags;
 use uv_fs::Simplified;
 use uv_static::{EnvVars, InvalidEnvironmentVariable, parse_boolish_environment_variable};
 use uv_warnings::warn_user;
 
 pub use crate::combine::*;
+pub use crate::index_urls::*;
+pub use crate::provenance::log_config_discovery;
 pub use crate::settings::*;
 
+use crate::provenance::{ConfigLayer, Outcome, Scope};
+
 mod combine;
+mod index_urls;
+mod provenance;
 mod settings;
 
 /// The [`Options`] as loaded from a configuration file on disk.
 #[derive(Debug, Clone)]
-pub struct FilesystemOptions(Options);
+pub struct FilesystemOptions {
+    options: Options,
+    /// The index URLs in the configuration file, along with the file and key they were read from.
+    index_urls: Vec<SourcedIndexUrl>,
+    /// The high-impact settings in each configuration file, in order of precedence.
+    layers: Vec<ConfigLayer>,
+}
 
 impl FilesystemOptions {
+    /// Create a [`FilesystemOptions`] from the [`Options`] read from the file at `path`.
+    fn from_path(mut options: Options, path: &Path, prefix: &str, scope: Scope) -> Self {
+        let index_urls = index_urls::collect(&mut options, path, prefix, scope);
+        let layers = vec![ConfigLayer::new(&options, path, prefix)];
+        Self {
+            options,
+            index_urls,
+            layers,
+        }
+    }
+
     /// Convert the [`FilesystemOptions`] into [`Options`].
     pub fn into_options(self) -> Options {
-        self.0
+        self.options
+    }
+
+    /// Return the index URLs in the configuration, along with where they were configured.
+    pub fn index_urls(&self) -> &[SourcedIndexUrl] {
+        &self.index_urls
+    }
+
+    /// Deduplicate the indexes across the merged configuration files, returning an error if two
+    /// definitions conflict (e.g., the same index name in a `pyproject.toml` and a user-level
+    /// `uv.toml`, with different URLs).
+    pub fn dedup_indexes(mut self) -> Result<Self, Error> {
+        index_urls::dedup(&mut self.options)?;
+        Ok(self)
+    }
+
+    /// Return the configuration file that sets the default log level for the given subcommand, if
+    /// any.
+    pub fn log_level_source(&self, command: &str) -> Option<&Path> {
+        self.layers
+            .iter()
+            .find(|layer| layer.configures_log_level(command))
+            .map(ConfigLayer::path)
+    }
+
+    /// Log the winning value of each high-impact setting (like the default index or resolution
+    /// strategy), along with its source, if it's configured differently across the environment and
+    /// the configuration files.
+    pub fn log_precedence(&self) {
+        provenance::log_precedence(&self.layers);
     }
 }
 
@@ -30,7 +84,7 @@ impl Deref for FilesystemOptions {
     type Target = Options;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.options
     }
 }
 
@@ -43,12 +97,12 @@ impl FilesystemOptions {
         let root = dir.join("uv");
         let file = root.join("uv.toml");
 
-        tracing::debug!("Searching for user configuration in: `{}`", file.display());
+        let start = Instant::now();
         match read_file(&file) {
             Ok(options) => {
-                tracing::debug!("Found user configuration in: `{}`", file.display());
+                provenance::consider(Scope::User, &file, Outcome::Found(start.elapsed()));
                 validate_uv_toml(&file, &options)?;
-                Ok(Some(Self(options)))
+                Ok(Some(Self::from_path(options, &file, "", Scope::User)))
             }
             Err(Error::Io(err))
                 if matches!(
@@ -58,6 +112,7 @@ impl FilesystemOptions {
                         | std::io::ErrorKind::PermissionDenied
                 ) =>
             {
+                provenance::consider(Scope::User, &file, Outcome::Missing);
                 Ok(None)
             }
             Err(err) => Err(err),
@@ -69,10 +124,11 @@ impl FilesystemOptions {
             return Ok(None);
         };
 
-        tracing::debug!("Found system configuration in: `{}`", file.display());
+        let start = Instant::now();
         let options = read_file(&file)?;
+        provenance::consider(Scope::System, &file, Outcome::Found(start.elapsed()));
         validate_uv_toml(&file, &options)?;
-        Ok(Some(Self(options)))
+        Ok(Some(Self::from_path(options, &file, "", Scope::System)))
     }
 
     /// Find the [`FilesystemOptions`] for the given path.
@@ -110,6 +166,7 @@ impl FilesystemOptions {
     pub fn from_directory(dir: &Path) -> Result<Option<Self>, Error> {
         // Read a `uv.toml` file in the current directory.
         let path = dir.join("uv.toml");
+        let start = Instant::now();
         match fs_err::read_to_string(&path) {
             Ok(content) => {
                 let options = toml::from_str::<Options>(&content)
@@ -129,9 +186,9 @@ impl FilesystemOptions {
                     }
                 }
 
-                tracing::debug!("Found workspace configuration at `{}`", path.display());
+                provenance::consider(Scope::Project, &path, Outcome::Found(start.elapsed()));
                 validate_uv_toml(&path, &options)?;
-                return Ok(Some(Self(options)));
+                return Ok(Some(Self::from_path(options, &path, "", Scope::Project)));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -139,30 +196,38 @@ impl FilesystemOptions {
 
         // Read a `pyproject.toml` file in the current directory.
         let path = dir.join("pyproject.toml");
+        let start = Instant::now();
         match fs_err::read_to_string(&path) {
             Ok(content) => {
                 // Parse, but skip any `pyproject.toml` that doesn't have a `[tool.uv]` section.
                 let pyproject: PyProjectToml = toml::from_str(&content)
                     .map_err(|err| Error::PyprojectToml(path.clone(), Box::new(err)))?;
                 let Some(tool) = pyproject.tool else {
-                    tracing::debug!(
-                        "Skipping `pyproject.toml` in `{}` (no `[tool]` section)",
-                        dir.display()
+                    provenance::consider(
+                        Scope::Project,
+                        &path,
+                        Outcome::Skipped("no `[tool]` section"),
                     );
                     return Ok(None);
                 };
                 let Some(options) = tool.uv else {
-                    tracing::debug!(
-                        "Skipping `pyproject.toml` in `{}` (no `[tool.uv]` section)",
-                        dir.display()
+                    provenance::consider(
+                        Scope::Project,
+                        &path,
+                        Outcome::Skipped("no `[tool.uv]` section"),
                     );
                     return Ok(None);
                 };
 
                 let options = options.relative_to(&std::path::absolute(dir)?)?;
 
-                tracing::debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
+                provenance::consider(Scope::Project, &path, Outcome::Found(start.elapsed()));
+                return Ok(Some(Self::from_path(
+                    options,
+                    &path,
+                    "tool.uv.",
+                    Scope::Project,
+                )));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -174,17 +239,21 @@ impl FilesystemOptions {
     /// Load a [`FilesystemOptions`] from a `uv.toml` file.
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
         let path = path.as_ref();
-        tracing::debug!("Reading user configuration from: `{}`", path.display());
-
+        let start = Instant::now();
         let options = read_file(path)?;
+        provenance::consider(Scope::Explicit, path, Outcome::Found(start.elapsed()));
         validate_uv_toml(path, &options)?;
-        Ok(Self(options))
+        Ok(Self::from_path(options, path, "", Scope::Explicit))
     }
 }
 
 impl From<Options> for FilesystemOptions {
     fn from(options: Options) -> Self {
-        Self(options)
+        Self {
+            options,
+            index_urls: Vec::new(),
+            layers: Vec::new(),
+        }
     }
 }
 
@@ -210,6 +279,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         publish: _,
         add: _,
         pip: _,
+        logging: _,
         cache_keys: _,
         override_dependencies: _,
         exclude_dependencies: _,
@@ -319,7 +389,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 resolution,
                 prerelease,
                 fork_strategy,
+                index_conflict_strategy,
                 dependency_metadata,
+                allowed_indexes,
                 config_settings,
                 config_settings_package,
                 no_build_isolation,
@@ -356,6 +428,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
             },
         add: AddOptions { add_bounds },
         pip,
+        logging,
         cache_keys,
         override_dependencies,
         exclude_dependencies,
@@ -451,9 +524,15 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if fork_strategy.is_some() {
         masked_fields.push("fork-strategy");
     }
+    if index_conflict_strategy.is_some() {
+        masked_fields.push("index-conflict-strategy");
+    }
     if dependency_metadata.is_some() {
         masked_fields.push("dependency-metadata");
     }
+    if allowed_indexes.is_some() {
+        masked_fields.push("allowed-indexes");
+    }
     if config_settings.is_some() {
         masked_fields.push("config-settings");
     }
@@ -538,6 +617,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if pip.is_some() {
         masked_fields.push("pip");
     }
+    if logging.is_some() {
+        masked_fields.push("logging");
+    }
     if cache_keys.is_some() {
         masked_fields.push("cache_keys");
     }
@@ -580,8 +662,21 @@ pub enum Error {
     )]
     PyprojectOnlyField(PathBuf, &'static str),
 
+    #[error(transparent)]
+    IndexConflict(#[from] uv_distribution_types::IndexConflictError),
+
     #[error(transparent)]
     InvalidEnvironmentVariable(#[from] InvalidEnvironmentVariable),
+
+    #[error("Index URL `{url}` from {origin} refers to a path that does not exist: `{}`", path.user_display())]
+    MissingIndexPath {
+        url: String,
+        path: PathBuf,
+        origin: Origin,
+    },
+
+    #[error("Index URL `{url}` from {origin} is missing a scheme (did you mean `https://{url}`?)")]
+    MissingIndexScheme { url: String, origin: Origin },
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -622,6 +717,7 @@ pub struct EnvironmentOptions {
     pub python_install_registry: Option<bool>,
     pub install_mirrors: PythonInstallMirrors,
     pub log_context: Option<bool>,
+    pub error_format: ErrorFormat,
     pub lfs: Option<bool>,
     pub http_timeout: Duration,
     pub http_retries: u32,
@@ -687,6 +783,8 @@ impl EnvironmentOptions {
                 )?,
             },
             log_context: parse_boolish_environment_variable(EnvVars::UV_LOG_CONTEXT)?,
+            error_format: parse_enum_environment_variable(EnvVars::UV_ERROR_FORMAT)?
+                .unwrap_or_default(),
             lfs: parse_boolish_environment_variable(EnvVars::UV_GIT_LFS)?,
             upload_http_timeout: parse_integer_environment_variable(
                 EnvVars::UV_UPLOAD_HTTP_TIMEOUT,
@@ -746,6 +844,27 @@ fn parse_string_environment_variable(name: &'static str) -> Result<Option<String
     }
 }
 
+/// Parse an environment variable into a value, e.g., one of the variants of an enum.
+fn parse_enum_environment_variable<T>(name: &'static str) -> Result<Option<T>, Error>
+where
+    T: std::str::FromStr,
+    <T as std::str::FromStr>::Err: std::fmt::Display,
+{
+    let Some(value) = parse_string_environment_variable(name)? else {
+        return Ok(None);
+    };
+    match value.parse::<T>() {
+        Ok(v) => Ok(Some(v)),
+        Err(err) => Err(Error::InvalidEnvironmentVariable(
+            InvalidEnvironmentVariable {
+                name: name.to_string(),
+                value,
+                err: err.to_string(),
+            },
+        )),
+    }
+}
+
 fn parse_integer_environment_variable<T>(name: &'static str) -> Result<Option<T>, Error>
 where
     T: std::str::FromStr + Copy,
@@ -0,0 +1,281 @@
+//! The configuration files considered during settings discovery, along with the file (or
+//! environment variable) that provides each of a few high-impact settings when they disagree.
+//!
+//! Settings are discovered before logging is initialized, so the files that were considered are
+//! recorded process-wide and logged via [`log_config_discovery`] once logging is set up.
+
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tracing::{debug, trace};
+
+use uv_distribution_types::{Index, PipIndex};
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+use crate::Options;
+
+/// The configuration files considered so far, in order.
+static CONSIDERED: Mutex<Vec<ConsideredFile>> = Mutex::new(Vec::new());
+
+/// The scope of a configuration file.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Scope {
+    /// A file passed via `--config-file`.
+    Explicit,
+    /// A `uv.toml` or `pyproject.toml` file in the project or one of its parents.
+    Project,
+    /// The user-level `uv.toml`.
+    User,
+    /// The system-level `uv.toml`.
+    System,
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Explicit => write!(f, "configuration file"),
+            Self::Project => write!(f, "project configuration"),
+            Self::User => write!(f, "user configuration"),
+            Self::System => write!(f, "system configuration"),
+        }
+    }
+}
+
+/// The outcome of considering a configuration file.
+#[derive(Debug)]
+pub(crate) enum Outcome {
+    /// The file was read and parsed in the given time.
+    Found(Duration),
+    /// The file doesn't exist.
+    Missing,
+    /// The file exists, but doesn't configure uv.
+    Skipped(&'static str),
+}
+
+#[derive(Debug)]
+struct ConsideredFile {
+    scope: Scope,
+    path: PathBuf,
+    outcome: Outcome,
+}
+
+/// Record that the configuration file at `path` was considered.
+pub(crate) fn consider(scope: Scope, path: &Path, outcome: Outcome) {
+    CONSIDERED.lock().unwrap().push(ConsideredFile {
+        scope,
+        path: path.to_path_buf(),
+        outcome,
+    });
+}
+
+/// Log the configuration files that were considered during settings discovery.
+///
+/// Files that were read are logged at the debug level, while files that are missing or don't
+/// configure uv are only logged at the trace level.
+pub fn log_config_discovery() {
+    let considered = std::mem::take(&mut *CONSIDERED.lock().unwrap());
+    for ConsideredFile {
+        scope,
+        path,
+        outcome,
+    } in considered
+    {
+        match outcome {
+            Outcome::Found(elapsed) => debug!(
+                "Found {scope} at `{}` (parsed in {:.2}ms)",
+                path.user_display(),
+                elapsed.as_secs_f64() * 1000.0
+            ),
+            Outcome::Missing => trace!("No {scope} at `{}`", path.user_display()),
+            Outcome::Skipped(reason) => {
+                trace!("Skipping {scope} at `{}` ({reason})", path.user_display());
+            }
+        }
+    }
+}
+
+/// A setting whose winning value is logged when it's configured differently across layers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Setting {
+    DefaultIndex,
+    PythonPreference,
+    CacheDir,
+    Resolution,
+}
+
+impl Setting {
+    const ALL: [Self; 4] = [
+        Self::DefaultIndex,
+        Self::PythonPreference,
+        Self::CacheDir,
+        Self::Resolution,
+    ];
+
+    /// The environment variables that configure the setting, in order of precedence.
+    fn env_vars(self) -> &'static [&'static str] {
+        match self {
+            Self::DefaultIndex => &[EnvVars::UV_DEFAULT_INDEX, EnvVars::UV_INDEX_URL],
+            Self::PythonPreference => &[EnvVars::UV_PYTHON_PREFERENCE],
+            Self::CacheDir => &[EnvVars::UV_CACHE_DIR],
+            Self::Resolution => &[EnvVars::UV_RESOLUTION],
+        }
+    }
+}
+
+impl Display for Setting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DefaultIndex => write!(f, "default index"),
+            Self::PythonPreference => write!(f, "Python preference"),
+            Self::CacheDir => write!(f, "cache directory"),
+            Self::Resolution => write!(f, "resolution strategy"),
+        }
+    }
+}
+
+/// The high-impact settings configured in a single configuration file.
+#[derive(Debug, Clone)]
+pub(crate) struct ConfigLayer {
+    path: PathBuf,
+    /// The value of each configured setting, along with the key it was configured with.
+    values: Vec<(Setting, String, String)>,
+    /// The subcommands with a configured default log level.
+    log_levels: Vec<String>,
+}
+
+impl ConfigLayer {
+    /// Collect the high-impact settings in the [`Options`] read from the file at `path`.
+    ///
+    /// The `prefix` is prepended to each key, e.g., `tool.uv.` for a `pyproject.toml` file.
+    pub(crate) fn new(options: &Options, path: &Path, prefix: &str) -> Self {
+        let mut values: Vec<(Setting, String, String)> = Vec::new();
+        let mut push = |setting: Setting, key: &str, value: String| {
+            if !values.iter().any(|(existing, ..)| *existing == setting) {
+                values.push((setting, format!("{prefix}{key}"), value));
+            }
+        };
+
+        let pip = options.pip.as_ref();
+        let default_index = |indexes: Option<&Vec<Index>>| {
+            indexes
+                .into_iter()
+                .flatten()
+                .find(|index| index.default)
+                .map(|index| index.url.without_credentials().to_string())
+        };
+        let index_url = |index_url: &PipIndex| {
+            Index::from(index_url.clone())
+                .url
+                .without_credentials()
+                .to_string()
+        };
+        if let Some(url) = default_index(options.top_level.index.as_ref()) {
+            push(Setting::DefaultIndex, "index", url);
+        }
+        if let Some(url) = options.top_level.index_url.as_ref().map(index_url) {
+            push(Setting::DefaultIndex, "index-url", url);
+        }
+        if let Some(url) = default_index(pip.and_then(|pip| pip.index.as_ref())) {
+            push(Setting::DefaultIndex, "pip.index", url);
+        }
+        if let Some(url) = pip.and_then(|pip| pip.index_url.as_ref()).map(index_url) {
+            push(Setting::DefaultIndex, "pip.index-url", url);
+        }
+        if let Some(preference) = options.globals.python_preference {
+            push(
+                Setting::PythonPreference,
+                "python-preference",
+                preference.to_string(),
+            );
+        }
+        if let Some(cache_dir) = &options.globals.cache_dir {
+            push(
+                Setting::CacheDir,
+                "cache-dir",
+                cache_dir.user_display().to_string(),
+            );
+        }
+        if let Some(resolution) = options.top_level.resolution {
+            push(Setting::Resolution, "resolution", resolution.to_string());
+        }
+        if let Some(resolution) = pip.and_then(|pip| pip.resolution) {
+            push(
+                Setting::Resolution,
+                "pip.resolution",
+                resolution.to_string(),
+            );
+        }
+
+        let log_levels = options
+            .logging
+            .as_ref()
+            .and_then(|logging| logging.commands.as_ref())
+            .map(|commands| commands.keys().cloned().collect())
+            .unwrap_or_default();
+
+        Self {
+            path: path.to_path_buf(),
+            values,
+            log_levels,
+        }
+    }
+
+    /// Returns the path to the configuration file.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns `true` if the file configures a default log level for the given subcommand.
+    pub(crate) fn configures_log_level(&self, command: &str) -> bool {
+        self.log_levels
+            .iter()
+            .any(|configured| configured == command)
+    }
+}
+
+/// Log the winning value of each high-impact setting, along with where it was configured, if the
+/// environment and the configuration files (in order of precedence) disagree on its value.
+///
+/// Command-line arguments take precedence over all of these sources.
+pub(crate) fn log_precedence(layers: &[ConfigLayer]) {
+    for setting in Setting::ALL {
+        let env = setting.env_vars().iter().find_map(|var| {
+            std::env::var(var)
+                .ok()
+                .filter(|value| !value.is_empty())
+                .map(|value| (value, format!("`{var}`")))
+        });
+        let sources = env
+            .into_iter()
+            .chain(layers.iter().flat_map(|layer| {
+                layer
+                    .values
+                    .iter()
+                    .filter(move |(configured, ..)| *configured == setting)
+                    .map(|(_, key, value)| {
+                        (
+                            value.clone(),
+                            format!("`{key}` in `{}`", layer.path.user_display()),
+                        )
+                    })
+            }))
+            .collect::<Vec<_>>();
+        let Some(((value, source), overridden)) = sources.split_first() else {
+            continue;
+        };
+        if overridden.iter().all(|(other, _)| other == value) {
+            continue;
+        }
+        debug!(
+            "Using {setting} `{value}` from {source}, over {}",
+            overridden
+                .iter()
+                .map(|(value, source)| format!("`{value}` from {source}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
+use std::{collections::BTreeMap, fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
@@ -8,8 +8,8 @@ use uv_configuration::{
     RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, PackageConfigSettings,
-    PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
+    AllowedIndex, ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError,
+    PackageConfigSettings, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
 };
 use uv_install_wheel::LinkMode;
 use uv_macros::{CombineOptions, OptionsMetadata};
@@ -20,7 +20,7 @@ use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerValue, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    IndexConflictStrategy, PrereleaseMode, ResolutionMode,
 };
 use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
@@ -65,6 +65,9 @@ pub struct Options {
     #[option_group]
     pub pip: Option<PipOptions>,
 
+    #[option_group]
+    pub logging: Option<LoggingOptions>,
+
     /// The keys to consider when caching builds for the project.
     ///
     /// Cache keys enable you to specify the files or directories that should trigger a rebuild when
@@ -398,7 +401,9 @@ pub struct ResolverOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub index_conflict_strategy: Option<IndexConflictStrategy>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
+    pub allowed_indexes: Option<Vec<AllowedIndex>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
     pub exclude_newer: ExcludeNewer,
@@ -430,7 +435,9 @@ pub struct ResolverInstallerOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub index_conflict_strategy: Option<IndexConflictStrategy>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
+    pub allowed_indexes: Option<Vec<AllowedIndex>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
     pub build_isolation: Option<BuildIsolation>,
@@ -464,7 +471,9 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             resolution,
             prerelease,
             fork_strategy,
+            index_conflict_strategy,
             dependency_metadata,
+            allowed_indexes,
             config_settings,
             config_settings_package,
             no_build_isolation,
@@ -498,7 +507,9 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             resolution,
             prerelease,
             fork_strategy,
+            index_conflict_strategy,
             dependency_metadata,
+            allowed_indexes,
             config_settings,
             config_settings_package,
             build_isolation: BuildIsolation::from_args(
@@ -750,6 +761,30 @@ pub struct ResolverInstallerSchema {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// The strategy to use when a package is pinned to different indexes for the same versions,
+    /// e.g., by requirements with overlapping markers.
+    ///
+    /// By default, uv will fail the resolution (`error`).
+    ///
+    /// Under `prefer-first`, uv will use the index that was defined first, and warn about the
+    /// index that was ignored.
+    ///
+    /// Under `fallback`, uv will use the other index if the index that the package was pinned to
+    /// doesn't offer any of the required versions, and warn about the fallback; otherwise, uv will
+    /// fail the resolution.
+    ///
+    /// Under `warn`, uv will use the index that the package was pinned to first, and warn about the
+    /// index that was ignored. Since that depends on the order in which requirements are resolved,
+    /// the lockfile may change when unrelated requirements change.
+    #[option(
+        default = "\"error\"",
+        value_type = "str",
+        example = r#"
+            index-conflict-strategy = "prefer-first"
+        "#,
+        possible_values = true
+    )]
+    pub index_conflict_strategy: Option<IndexConflictStrategy>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -773,6 +808,25 @@ pub struct ResolverInstallerSchema {
         "#
     )]
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
+    /// The indexes that packages may be installed from, as a list of index names (as defined in
+    /// `[[tool.uv.index]]`) or URLs.
+    ///
+    /// When set, the resolution fails if a package would be installed from any other index,
+    /// including a `--find-links` location, or from a direct URL that isn't hosted on the same
+    /// host as one of the allowed indexes. Local paths and directories are always allowed.
+    ///
+    /// The same checks apply to the packages in an existing lockfile, e.g., with
+    /// `uv sync --frozen`.
+    ///
+    /// A named index also allows its `aliases`.
+    #[option(
+        default = "None",
+        value_type = "list[str]",
+        example = r#"
+            allowed-indexes = ["internal", "https://pypi.org/simple"]
+        "#
+    )]
+    pub allowed_indexes: Option<Vec<AllowedIndex>>,
     /// Settings to pass to the [PEP 517](https://peps.python.org/pep-0517/) build backend,
     /// specified as `KEY=VALUE` pairs.
     #[option(
@@ -1105,6 +1159,55 @@ impl PythonInstallMirrors {
     }
 }
 
+/// Settings that control uv's internal logging.
+#[derive(Debug, Clone, Default, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LoggingOptions {
+    /// The default log level to use for specific commands, keyed by the name of the subcommand
+    /// (e.g., `lock`, `sync`, or `pip`).
+    ///
+    /// The configured level is only applied when neither `--verbose` nor `--quiet` is passed on
+    /// the command-line; an explicit flag always takes precedence.
+    ///
+    /// Supported levels are `default` (no internal logs), `verbose` (equivalent to `-v`), `trace`
+    /// (equivalent to `-vv`), and `trace-all` (equivalent to `-vvv`).
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            commands = { lock = "verbose" }
+        "#
+    )]
+    pub commands: Option<BTreeMap<String, LogLevel>>,
+}
+
+/// The default log level for a command, mirroring the repeatable `--verbose` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum LogLevel {
+    /// Don't show any internal logs.
+    Default,
+    /// Show `DEBUG` messages from uv, equivalent to `-v`.
+    Verbose,
+    /// Show `TRACE` messages from uv, equivalent to `-vv`.
+    Trace,
+    /// Show `TRACE` messages from all crates, equivalent to `-vvv`.
+    TraceAll,
+}
+
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::Verbose => write!(f, "verbose"),
+            Self::Trace => write!(f, "trace"),
+            Self::TraceAll => write!(f, "trace-all"),
+        }
+    }
+}
+
 /// Settings that are specific to the `uv pip` command-line interface.
 ///
 /// These values will be ignored when running commands outside the `uv pip` namespace (e.g.,
@@ -1489,6 +1592,30 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// The strategy to use when a package is pinned to different indexes for the same versions,
+    /// e.g., by requirements with overlapping markers.
+    ///
+    /// By default, uv will fail the resolution (`error`).
+    ///
+    /// Under `prefer-first`, uv will use the index that was defined first, and warn about the
+    /// index that was ignored.
+    ///
+    /// Under `fallback`, uv will use the other index if the index that the package was pinned to
+    /// doesn't offer any of the required versions, and warn about the fallback; otherwise, uv will
+    /// fail the resolution.
+    ///
+    /// Under `warn`, uv will use the index that the package was pinned to first, and warn about the
+    /// index that was ignored. Since that depends on the order in which requirements are resolved,
+    /// the lockfile may change when unrelated requirements change.
+    #[option(
+        default = "\"error\"",
+        value_type = "str",
+        example = r#"
+            index-conflict-strategy = "prefer-first"
+        "#,
+        possible_values = true
+    )]
+    pub index_conflict_strategy: Option<IndexConflictStrategy>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -1512,6 +1639,22 @@ pub struct PipOptions {
         "#
     )]
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
+    /// The indexes that packages may be installed from, as a list of index names (as defined in
+    /// `[[tool.uv.index]]`) or URLs.
+    ///
+    /// When set, the resolution fails if a package would be installed from any other index,
+    /// including a `--find-links` location, or from a direct URL that isn't hosted on the same
+    /// host as one of the allowed indexes. Local paths and directories are always allowed.
+    ///
+    /// A named index also allows its `aliases`.
+    #[option(
+        default = "None",
+        value_type = "list[str]",
+        example = r#"
+            allowed-indexes = ["internal", "https://pypi.org/simple"]
+        "#
+    )]
+    pub allowed_indexes: Option<Vec<AllowedIndex>>,
     /// Write the requirements generated by `uv pip compile` to the given `requirements.txt` file.
     ///
     /// If the file already exists, the existing versions will be preferred when resolving
@@ -1734,6 +1877,18 @@ pub struct PipOptions {
         "#
     )]
     pub emit_index_annotation: Option<bool>,
+    /// Include comment annotations indicating the index used to resolve each package that wasn't
+    /// served by the default index (e.g., `# index: internal (https://pypi.internal/simple)`).
+    ///
+    /// Named indexes are annotated with their name, followed by their URL.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            annotate-index = true
+        "#
+    )]
+    pub annotate_index: Option<bool>,
     /// The style of the annotation comments included in the output file, used to indicate the
     /// source of each package.
     #[option(
@@ -1951,7 +2106,9 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            index_conflict_strategy: value.index_conflict_strategy,
             dependency_metadata: value.dependency_metadata,
+            allowed_indexes: value.allowed_indexes,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
             exclude_newer: ExcludeNewer::from_args(
@@ -2051,7 +2208,9 @@ pub struct ToolOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub index_conflict_strategy: Option<IndexConflictStrategy>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
+    pub allowed_indexes: Option<Vec<AllowedIndex>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
     pub build_isolation: Option<BuildIsolation>,
@@ -2083,7 +2242,9 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            index_conflict_strategy: value.index_conflict_strategy,
             dependency_metadata: value.dependency_metadata,
+            allowed_indexes: value.allowed_indexes,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
             build_isolation: value.build_isolation,
@@ -2117,7 +2278,9 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            index_conflict_strategy: value.index_conflict_strategy,
             dependency_metadata: value.dependency_metadata,
+            allowed_indexes: value.allowed_indexes,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
             build_isolation: value.build_isolation,
@@ -2175,7 +2338,9 @@ pub struct OptionsWire {
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
     fork_strategy: Option<ForkStrategy>,
+    index_conflict_strategy: Option<IndexConflictStrategy>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
+    allowed_indexes: Option<Vec<AllowedIndex>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
     no_build_isolation: Option<bool>,
@@ -2215,6 +2380,7 @@ pub struct OptionsWire {
     add_bounds: Option<AddBoundsKind>,
 
     pip: Option<PipOptions>,
+    logging: Option<LoggingOptions>,
     cache_keys: Option<Vec<CacheKey>>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
@@ -2274,7 +2440,9 @@ impl From<OptionsWire> for Options {
             resolution,
             prerelease,
             fork_strategy,
+            index_conflict_strategy,
             dependency_metadata,
+            allowed_indexes,
             config_settings,
             config_settings_package,
             no_build_isolation,
@@ -2295,6 +2463,7 @@ impl From<OptionsWire> for Options {
             no_binary_package,
             torch_backend,
             pip,
+            logging,
             cache_keys,
             override_dependencies,
             exclude_dependencies,
@@ -2350,7 +2519,9 @@ impl From<OptionsWire> for Options {
                 resolution,
                 prerelease,
                 fork_strategy,
+                index_conflict_strategy,
                 dependency_metadata,
+                allowed_indexes,
                 config_settings,
                 config_settings_package,
                 no_build_isolation,
@@ -2374,6 +2545,7 @@ impl From<OptionsWire> for Options {
                 torch_backend,
             },
             pip,
+            logging,
             cache_keys,
             build_backend,
             override_dependencies,
@@ -84,6 +84,39 @@ impl EnvVars {
     #[attr_added_in("0.5.9")]
     pub const UV_FORK_STRATEGY: &'static str = "UV_FORK_STRATEGY";
 
+    /// Equivalent to the `--index-conflict-strategy` argument. Controls how a package pinned to
+    /// different indexes for the same versions is resolved.
+    #[attr_added_in("next version")]
+    pub const UV_INDEX_CONFLICT_STRATEGY: &'static str = "UV_INDEX_CONFLICT_STRATEGY";
+
+    /// The format in which errors are reported, either `text` (the default) or `json`.
+    ///
+    /// If set to `json`, resolution errors are written to stderr as a JSON object identified by
+    /// a stable `code` (e.g., `no-solution`), rather than as a human-readable message. Errors with
+    /// a stable shape (e.g., a package pinned to conflicting indexes) include their details; other
+    /// errors include their message.
+    #[attr_added_in("next version")]
+    pub const UV_ERROR_FORMAT: &'static str = "UV_ERROR_FORMAT";
+
+    /// If set to a path, uv will write the tree of forks that were solved during a resolution
+    /// to that path in the Graphviz DOT format, for debugging.
+    ///
+    /// Each fork is labeled with its markers, the number of packages in its solution, and the
+    /// indexes that packages were pinned to; each split is labeled with the requirements that
+    /// caused it.
+    #[attr_added_in("next version")]
+    pub const UV_FORK_GRAPH: &'static str = "UV_FORK_GRAPH";
+
+    /// Equivalent to the `--resolver-dump` command-line argument. If set to a path, uv will write
+    /// the state of each fork that was solved during a resolution to that path as JSON, for
+    /// debugging, whether the resolution succeeded or failed.
+    ///
+    /// Each fork includes its markers, the number of packages in its solution, whether it failed,
+    /// and the indexes that packages were pinned to, keyed by package name. Credentials are
+    /// omitted from index URLs.
+    #[attr_added_in("next version")]
+    pub const UV_RESOLVER_DUMP: &'static str = "UV_RESOLVER_DUMP";
+
     /// Equivalent to the `--system` command-line argument. If set to `true`, uv will
     /// use the first Python interpreter found in the system `PATH`.
     ///
@@ -848,6 +881,12 @@ impl EnvVars {
     #[attr_added_in("0.4.16")]
     pub const GITHUB_ACTIONS: &'static str = "GITHUB_ACTIONS";
 
+    /// The path to the Markdown job summary of the current GitHub Actions step.
+    ///
+    /// With `--timings`, uv appends the time spent in each phase to the file.
+    #[attr_added_in("next version")]
+    pub const GITHUB_STEP_SUMMARY: &'static str = "GITHUB_STEP_SUMMARY";
+
     /// Indicates that the current process is running in GitLab CI.
     ///
     /// `uv publish` may attempt trusted publishing flows when set
@@ -940,6 +979,86 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const TRACING_DURATIONS_FILE: &'static str = "TRACING_DURATIONS_FILE";
 
+    /// Omit spans shorter than the given duration from the durations plot, via the
+    /// `tracing-durations-export` feature.
+    ///
+    /// Accepts an integer with a unit of `ns`, `us`, `ms` or `s`, e.g., `50us`. A number without a
+    /// unit is a number of milliseconds, and `0` plots all spans.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_MIN_LENGTH: &'static str = "UV_DURATIONS_MIN_LENGTH";
+
+    /// Whether to draw overlapping spans in separate lanes in the durations plot (the default), via
+    /// the `tracing-durations-export` feature.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_MULTI_LANE: &'static str = "UV_DURATIONS_MULTI_LANE";
+
+    /// The total width of the durations plot in pixels, via the `tracing-durations-export` feature.
+    ///
+    /// Must be between 500 and 100000. Defaults to 1110.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_WIDTH: &'static str = "UV_DURATIONS_WIDTH";
+
+    /// The height of each lane of the durations plot in pixels, via the
+    /// `tracing-durations-export` feature.
+    ///
+    /// Must be between 4 and 200. Defaults to 20.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_LANE_HEIGHT: &'static str = "UV_DURATIONS_LANE_HEIGHT";
+
+    /// The color scheme of the durations plot, either `light` (the default) or `dark`, via the
+    /// `tracing-durations-export` feature.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_THEME: &'static str = "UV_DURATIONS_THEME";
+
+    /// Equivalent to the `--durations-remove` command-line argument. A comma-separated list of
+    /// span names to omit from the durations plot, replacing the default set.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_REMOVE: &'static str = "UV_DURATIONS_REMOVE";
+
+    /// Equivalent to the `--durations-remove-extra` command-line argument. A comma-separated list
+    /// of span names to omit from the durations plot, in addition to the default set.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_REMOVE_EXTRA: &'static str = "UV_DURATIONS_REMOVE_EXTRA";
+
+    /// Equivalent to the `--durations-format` command-line argument. A comma-separated list of
+    /// formats in which to write the span durations (`svg`, `folded`, `perfetto`, or `otlp`).
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_FORMAT: &'static str = "UV_DURATIONS_FORMAT";
+
+    /// Equivalent to the `--durations-fold-fields` command-line argument.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_FOLD_FIELDS: &'static str = "UV_DURATIONS_FOLD_FIELDS";
+
+    /// Equivalent to the `--durations-log` command-line argument.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_LOG: &'static str = "UV_DURATIONS_LOG";
+
+    /// Overwrite the files of a previous run at the location of the durations file, via the
+    /// `tracing-durations-export` feature.
+    ///
+    /// By default, a numeric suffix is appended to the file names instead, e.g., `run-2.jsonl`.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_OVERWRITE: &'static str = "UV_DURATIONS_OVERWRITE";
+
+    /// The spans to record in the durations file, using the same directive syntax as `RUST_LOG`,
+    /// e.g., `uv_resolver=debug,info`.
+    ///
+    /// Defaults to recording all `INFO` spans.
+    #[attr_added_in("next version")]
+    pub const UV_DURATIONS_FILTER: &'static str = "UV_DURATIONS_FILTER";
+
+    /// Equivalent to the `--slow-op-threshold` command-line argument.
+    ///
+    /// If set, uv will warn whenever an operation (e.g., a request to an index) has been running
+    /// for longer than the given duration, e.g., `30s`.
+    #[attr_added_in("next version")]
+    pub const UV_SLOW_OP_THRESHOLD: &'static str = "UV_SLOW_OP_THRESHOLD";
+
+    /// The path to the `.summary.json` file of a previous run with `--durations`, to compare the
+    /// phases against in the GitHub Actions job summary written by `--timings`.
+    #[attr_added_in("next version")]
+    pub const UV_TIMINGS_BASELINE: &'static str = "UV_TIMINGS_BASELINE";
+
     /// Used to set `RUST_HOST_TARGET` at build time via `build.rs`.
     #[attr_hidden]
     #[attr_added_in("0.1.11")]
@@ -18,31 +18,6 @@ pub struct InvalidEnvironmentVariable {
 pub fn parse_boolish_environment_variable(
     name: &'static str,
 ) -> Result<Option<bool>, InvalidEnvironmentVariable> {
-    // See `clap_builder/src/util/str_to_bool.rs`
-    // We want to match Clap's accepted values
-
-    // True values are `y`, `yes`, `t`, `true`, `on`, and `1`.
-    const TRUE_LITERALS: [&str; 6] = ["y", "yes", "t", "true", "on", "1"];
-
-    // False values are `n`, `no`, `f`, `false`, `off`, and `0`.
-    const FALSE_LITERALS: [&str; 6] = ["n", "no", "f", "false", "off", "0"];
-
-    // Converts a string literal representation of truth to true or false.
-    //
-    // `false` values are `n`, `no`, `f`, `false`, `off`, and `0` (case insensitive).
-    //
-    // Any other value will be considered as `true`.
-    fn str_to_bool(val: impl AsRef<str>) -> Option<bool> {
-        let pat: &str = &val.as_ref().to_lowercase();
-        if TRUE_LITERALS.contains(&pat) {
-            Some(true)
-        } else if FALSE_LITERALS.contains(&pat) {
-            Some(false)
-        } else {
-            None
-        }
-    }
-
     let Some(value) = std::env::var_os(name) else {
         return Ok(None);
     };
@@ -55,7 +30,7 @@ pub fn parse_boolish_environment_variable(
         });
     };
 
-    let Some(value) = str_to_bool(value) else {
+    let Some(value) = parse_boolish(value) else {
         return Err(InvalidEnvironmentVariable {
             name: name.to_string(),
             value: value.to_string(),
@@ -65,3 +40,23 @@ pub fn parse_boolish_environment_variable(
 
     Ok(Some(value))
 }
+
+/// Convert a string representation of truth to `true` or `false`, matching Clap's accepted
+/// values.
+///
+/// `true` values are `y`, `yes`, `t`, `true`, `on`, and `1`, and `false` values are `n`, `no`,
+/// `f`, `false`, `off`, and `0` (case insensitive). Any other value is rejected.
+pub fn parse_boolish(value: &str) -> Option<bool> {
+    // See `clap_builder/src/util/str_to_bool.rs`
+    const TRUE_LITERALS: [&str; 6] = ["y", "yes", "t", "true", "on", "1"];
+    const FALSE_LITERALS: [&str; 6] = ["n", "no", "f", "false", "off", "0"];
+
+    let pat: &str = &value.to_lowercase();
+    if TRUE_LITERALS.contains(&pat) {
+        Some(true)
+    } else if FALSE_LITERALS.contains(&pat) {
+        Some(false)
+    } else {
+        None
+    }
+}
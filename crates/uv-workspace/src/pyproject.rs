@@ -16,14 +16,15 @@ use std::str::FromStr;
 
 use glob::Pattern;
 use owo_colors::OwoColorize;
-use rustc_hash::{FxBuildHasher, FxHashSet};
 use serde::de::{IntoDeserializer, SeqAccess};
 use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 
 use uv_build_backend::BuildBackendSettings;
 use uv_configuration::GitLfsSetting;
-use uv_distribution_types::{Index, IndexName, RequirementSource};
+use uv_distribution_types::{
+    Index, IndexConflictError, IndexName, RequirementSource, dedup_indexes,
+};
 use uv_fs::{PortablePathBuf, relative_to};
 use uv_git_types::GitReference;
 use uv_macros::OptionsMetadata;
@@ -42,6 +43,8 @@ pub enum PyprojectTomlError {
     TomlSyntax(#[from] toml_edit::TomlError),
     #[error(transparent)]
     TomlSchema(#[from] toml_edit::de::Error),
+    #[error(transparent)]
+    IndexConflict(#[from] IndexConflictError),
     #[error(
         "`pyproject.toml` is using the `[project]` table, but the required `project.name` field is not set"
     )]
@@ -126,8 +129,18 @@ impl PyProjectToml {
     pub fn from_string(raw: String) -> Result<Self, PyprojectTomlError> {
         let pyproject =
             toml_edit::Document::from_str(&raw).map_err(PyprojectTomlError::TomlSyntax)?;
-        let pyproject = Self::deserialize(pyproject.into_deserializer())
+        let mut pyproject = Self::deserialize(pyproject.into_deserializer())
             .map_err(PyprojectTomlError::TomlSchema)?;
+
+        // Deduplicate the indexes, rejecting any that conflict.
+        if let Some(uv) = pyproject.tool.as_mut().and_then(|tool| tool.uv.as_mut())
+            && let Some(indexes) = uv.index.take()
+        {
+            uv.index = Some(dedup_indexes(indexes).map_err(|err| {
+                err.with_lines(|definition| definition.find_lines(&raw, "tool.uv.index"))
+            })?);
+        }
+
         Ok(Self { raw, ..pyproject })
     }
 
@@ -276,30 +289,6 @@ pub struct Tool {
     pub uv: Option<ToolUv>,
 }
 
-/// Validates that index names in the `tool.uv.index` field are unique.
-///
-/// This custom deserializer function checks for duplicate index names
-/// and returns an error if any duplicates are found.
-fn deserialize_index_vec<'de, D>(deserializer: D) -> Result<Option<Vec<Index>>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let indexes = Option::<Vec<Index>>::deserialize(deserializer)?;
-    if let Some(indexes) = indexes.as_ref() {
-        let mut seen_names = FxHashSet::with_capacity_and_hasher(indexes.len(), FxBuildHasher);
-        for index in indexes {
-            if let Some(name) = index.name.as_ref() {
-                if !seen_names.insert(name) {
-                    return Err(serde::de::Error::custom(format!(
-                        "duplicate index name `{name}`"
-                    )));
-                }
-            }
-        }
-    }
-    Ok(indexes)
-}
-
 // NOTE(charlie): When adding fields to this struct, mark them as ignored on `Options` in
 // `crates/uv-settings/src/settings.rs`.
 #[derive(Deserialize, OptionsMetadata, Debug, Clone, PartialEq, Eq)]
@@ -362,7 +351,6 @@ pub struct ToolUv {
             url = "https://download.pytorch.org/whl/cu121"
         "#
     )]
-    #[serde(deserialize_with = "deserialize_index_vec", default)]
     pub index: Option<Vec<Index>>,
 
     /// The workspace definition for the project, if any.
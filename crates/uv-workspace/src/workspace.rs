@@ -756,8 +756,9 @@ impl Workspace {
         }
 
         // Determine the default value
-        let project_env = from_project_environment_variable(self)
-            .unwrap_or_else(|| self.install_path.join(".venv"));
+        let from_project_env = from_project_environment_variable(self);
+        let is_project_env_variable = from_project_env.is_some();
+        let project_env = from_project_env.unwrap_or_else(|| self.install_path.join(".venv"));
 
         // Warn if it conflicts with `VIRTUAL_ENV`
         if let Some(from_virtual_env) = from_virtual_env_variable() {
@@ -790,6 +791,19 @@ impl Workspace {
             }
         }
 
+        if is_project_env_variable {
+            debug!(
+                "Using project environment `{}` from `{}`",
+                project_env.user_display(),
+                EnvVars::UV_PROJECT_ENVIRONMENT
+            );
+        } else {
+            debug!(
+                "Using project environment `{}` (the default `.venv` in the workspace root)",
+                project_env.user_display()
+            );
+        }
+
         project_env
     }
 
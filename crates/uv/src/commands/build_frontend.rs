@@ -206,6 +206,8 @@ async fn build_impl(
         resolution: _,
         prerelease: _,
         fork_strategy: _,
+        index_conflict_strategy: _,
+        allowed_indexes: _,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -1,16 +1,18 @@
 use std::str::FromStr;
-use std::sync::{Arc, LazyLock};
+use std::sync::{Arc, LazyLock, OnceLock};
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 use version_ranges::Ranges;
 
+use uv_configuration::ErrorFormat;
 use uv_distribution_types::{
     DerivationChain, DerivationStep, Dist, DistErrorKind, Name, RequestedDist,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_resolver::SentinelRange;
+use uv_resolver::{ResolveErrorDetails, ResolveErrorReport, SentinelRange};
 
 use crate::commands::pip;
 
@@ -28,6 +30,9 @@ static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::ne
         .collect()
 });
 
+/// The format in which errors are reported, per [`set_error_format`].
+static ERROR_FORMAT: OnceLock<ErrorFormat> = OnceLock::new();
+
 /// A rich reporter for operational diagnostics, i.e., errors that occur during resolution and
 /// installation.
 #[derive(Debug, Default)]
@@ -38,6 +43,8 @@ pub(crate) struct OperationDiagnostic {
     pub(crate) native_tls: bool,
     /// The context to display to the user upon resolution failure.
     pub(crate) context: Option<&'static str>,
+    /// Whether to report errors with a stable shape as JSON.
+    pub(crate) json: bool,
 }
 
 impl OperationDiagnostic {
@@ -68,10 +75,37 @@ impl OperationDiagnostic {
         }
     }
 
+    /// Report errors with a stable shape as JSON, e.g., for `--output-format json`.
+    #[must_use]
+    pub(crate) fn with_json(self, json: bool) -> Self {
+        Self { json, ..self }
+    }
+
     /// Attempt to report an error with rich diagnostic context.
     ///
+    /// Errors that are handled are followed by their [`pip::operations::Error::code`]. If
+    /// requested, resolution errors are instead reported as JSON, identified by their
+    /// [`uv_resolver::ResolveError::code`].
+    ///
     /// Returns `Some` if the error was not handled.
     pub(crate) fn report(self, err: pip::operations::Error) -> Option<pip::operations::Error> {
+        if (self.json || error_format() == ErrorFormat::Json)
+            && let pip::operations::Error::Resolve(err) = &err
+            && let Ok(json) = serde_json::to_string(&unstyled(err.report()))
+        {
+            anstream::eprintln!("{json}");
+            return None;
+        }
+        let code = err.code();
+        let err = self.render(err);
+        if err.is_none() {
+            error_code(code);
+        }
+        err
+    }
+
+    /// Render an error with rich diagnostic context, returning `Some` if the error was not handled.
+    fn render(self, err: pip::operations::Error) -> Option<pip::operations::Error> {
         match err {
             pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err)) => {
                 if let Some(context) = self.context {
@@ -101,6 +135,12 @@ impl OperationDiagnostic {
                 dependencies_error(error, &name, &version, &chain, self.hint.clone());
                 None
             }
+            pip::operations::Error::Resolve(
+                uv_resolver::ResolveError::ConflictingIndexesForPackages { conflicts },
+            ) => {
+                conflicting_indexes(&conflicts);
+                None
+            }
             pip::operations::Error::Requirements(uv_requirements::Error::Dist(kind, dist, err)) => {
                 dist_error(
                     kind,
@@ -145,6 +185,41 @@ impl OperationDiagnostic {
     }
 }
 
+/// Render the stable code of an error that was reported, dimmed, e.g., `code: no-solution`.
+pub(crate) fn error_code(code: &str) {
+    anstream::eprintln!("  {}", format!("code: {code}").dimmed());
+}
+
+/// Set the format in which errors are reported, e.g., from `UV_ERROR_FORMAT`.
+pub(crate) fn set_error_format(format: ErrorFormat) {
+    let _ = ERROR_FORMAT.set(format);
+}
+
+/// Returns the format in which errors are reported.
+fn error_format() -> ErrorFormat {
+    ERROR_FORMAT.get().copied().unwrap_or_default()
+}
+
+/// Remove the terminal styling from the messages in a [`ResolveErrorReport`].
+fn unstyled(mut report: ResolveErrorReport) -> ResolveErrorReport {
+    match &mut report.details {
+        ResolveErrorDetails::Message { message, causes } => {
+            *message = anstream::adapter::strip_str(message).to_string();
+            for cause in causes {
+                *cause = anstream::adapter::strip_str(cause).to_string();
+            }
+        }
+        ResolveErrorDetails::ConflictingIndexesForPackages { conflicts } => {
+            *conflicts = std::mem::take(conflicts)
+                .into_iter()
+                .map(unstyled)
+                .collect();
+        }
+        ResolveErrorDetails::ConflictingIndexes { .. } => {}
+    }
+    report
+}
+
 /// Render a distribution failure (read, download or build) with a help message.
 // https://github.com/rust-lang/rust/issues/147648
 #[allow(unused_assignments)]
@@ -295,6 +370,16 @@ pub(crate) fn dependencies_error(
     anstream::eprint!("{report:?}");
 }
 
+/// Render the conflicting indexes of several packages, listing the conflict of each package.
+pub(crate) fn conflicting_indexes(conflicts: &[uv_resolver::ResolveError]) {
+    let report = miette::Report::msg(conflicts.iter().map(ToString::to_string).join("\n\n"))
+        .context(format!(
+            "Requirements contain conflicting indexes for {} packages",
+            conflicts.len()
+        ));
+    anstream::eprint!("{report:?}");
+}
+
 /// Render a [`uv_resolver::NoSolutionError`].
 pub(crate) fn no_solution(err: &uv_resolver::NoSolutionError) {
     let report = miette::Report::msg(format!("{err}")).context(err.header());
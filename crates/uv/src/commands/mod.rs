@@ -19,6 +19,7 @@ pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use cache_size::cache_size;
+pub(crate) use diagnostics::{error_code, set_error_format};
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
@@ -121,7 +122,7 @@ impl From<ExitStatus> for ExitCode {
 }
 
 /// Format a duration as a human-readable string, Cargo-style.
-pub(super) fn elapsed(duration: Duration) -> String {
+pub(crate) fn elapsed(duration: Duration) -> String {
     let secs = duration.as_secs();
     let ms = duration.subsec_millis();
 
@@ -21,9 +21,9 @@ use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, HashGeneration, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, RequiresPython,
-    UnresolvedRequirementSpecification, Verbatim,
+    AllowedIndex, ConfigSettings, DependencyMetadata, ExtraBuildVariables, HashGeneration, Index,
+    IndexLocations, NameRequirementSpecification, Origin, PackageConfigSettings, Requirement,
+    RequiresPython, UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::{CWD, Simplified};
 use uv_git::ResolvedRepositoryReference;
@@ -42,8 +42,8 @@ use uv_requirements::{
 };
 use uv_resolver::{
     AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode,
-    ResolverEnvironment,
+    InMemoryIndex, IndexConflictStrategy, OptionsBuilder, PrereleaseMode, PylockToml,
+    PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
@@ -79,6 +79,7 @@ pub(crate) async fn pip_compile(
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
     fork_strategy: ForkStrategy,
+    index_conflict_strategy: IndexConflictStrategy,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
@@ -93,10 +94,12 @@ pub(crate) async fn pip_compile(
     include_build_options: bool,
     include_marker_expression: bool,
     include_index_annotation: bool,
+    annotate_index: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
     dependency_metadata: DependencyMetadata,
+    allowed_indexes: Option<Vec<AllowedIndex>>,
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     config_settings: ConfigSettings,
@@ -557,11 +560,14 @@ pub(crate) async fn pip_compile(
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
         .fork_strategy(fork_strategy)
+        .index_conflict_strategy(index_conflict_strategy)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(index_strategy)
         .torch_backend(torch_backend)
         .build_options(build_options.clone())
+        .allowed_indexes(allowed_indexes)
+        .allow_index_divergence(preview.is_enabled(PreviewFeature::IndexDivergence))
         .build();
 
     // Resolve the requirements.
@@ -716,6 +722,7 @@ pub(crate) async fn pip_compile(
                     include_markers || universal,
                     include_annotations,
                     include_index_annotation,
+                    annotate_index.then_some(&index_locations),
                     annotation_style,
                 )
             )?;
@@ -746,6 +753,11 @@ pub(crate) async fn pip_compile(
                     "The `--emit-index-annotation` option is not supported for `pylock.toml` output"
                 );
             }
+            if annotate_index {
+                warn_user!(
+                    "The `--annotate-index` option is not supported for `pylock.toml` output"
+                );
+            }
 
             // Determine the directory relative to which the output file should be written.
             let output_file = output_file.map(std::path::absolute).transpose()?;
@@ -884,6 +896,19 @@ fn cmd(
                 return Some(None);
             }
 
+            // Always skip the `--resolver-dump` and mark the next item to be skipped
+            if arg == "--resolver-dump" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+
+            // Skip only this argument if option and value are together
+            if arg.starts_with("--resolver-dump=") {
+                // Reset state; skip this iteration.
+                *skip_next = None;
+                return Some(None);
+            }
+
             // Always skip the `--no-progress` flag.
             if arg == "--no-progress" {
                 *skip_next = None;
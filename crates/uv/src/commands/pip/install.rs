@@ -15,9 +15,9 @@ use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
-    UnresolvedRequirementSpecification,
+    AllowedIndex, ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
+    NameRequirementSpecification, Origin, PackageConfigSettings, PackageIndexEntry, Requirement,
+    Resolution, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
@@ -70,7 +70,9 @@ pub(crate) async fn pip_install(
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
+    package_indexes: Vec<PackageIndexEntry>,
     dependency_metadata: DependencyMetadata,
+    allowed_indexes: Option<Vec<AllowedIndex>>,
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     reinstall: Reinstall,
@@ -577,6 +579,8 @@ pub(crate) async fn pip_install(
             .index_strategy(index_strategy)
             .torch_backend(torch_backend)
             .build_options(build_options.clone())
+            .allowed_indexes(allowed_indexes)
+            .package_indexes(package_indexes)
             .build();
 
         // Resolve the requirements.
@@ -473,6 +473,9 @@ pub(crate) trait ResolveLogger {
     /// Log the completion of the operation.
     fn on_complete(&self, count: usize, start: std::time::Instant, printer: Printer)
     -> fmt::Result;
+
+    /// Log the number of packages that weren't moved to their latest version during an upgrade.
+    fn on_held_back(&self, count: usize, printer: Printer) -> fmt::Result;
 }
 
 /// The default logger for resolve operations.
@@ -506,6 +509,18 @@ impl ResolveLogger for DefaultResolveLogger {
             )
         }
     }
+
+    fn on_held_back(&self, count: usize, printer: Printer) -> fmt::Result {
+        if count == 0 {
+            return Ok(());
+        }
+        let s = if count == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("{count} package{s} held back; run with `-v` for details").dimmed()
+        )
+    }
 }
 
 /// A logger that doesn't show any output.
@@ -521,4 +536,8 @@ impl ResolveLogger for SummaryResolveLogger {
     ) -> fmt::Result {
         Ok(())
     }
+
+    fn on_held_back(&self, _count: usize, _printer: Printer) -> fmt::Result {
+        Ok(())
+    }
 }
@@ -2,8 +2,8 @@
 
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 
 use anyhow::{Context, anyhow};
 use itertools::Itertools;
@@ -42,6 +42,7 @@ use uv_resolver::{
     DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
     Preferences, PythonRequirement, Resolver, ResolverEnvironment, ResolverOutput,
 };
+use uv_static::EnvVars;
 use uv_tool::InstalledTools;
 use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
@@ -102,6 +103,16 @@ pub(crate) async fn read_constraints(
     )
 }
 
+/// The path to write the state of each fork to once a resolution completes, as requested via
+/// `--resolver-dump`.
+static RESOLVER_DUMP: OnceLock<PathBuf> = OnceLock::new();
+
+/// Write the state of each fork that was solved to the given path once each resolution of this
+/// process completes, whether it succeeded or failed.
+pub(crate) fn dump_resolver_state(path: &Path) {
+    let _ = RESOLVER_DUMP.set(path.to_path_buf());
+}
+
 /// Resolve a set of requirements, similar to running `pip compile`.
 pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
@@ -360,10 +371,35 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         )?
         .with_reporter(Arc::new(reporter));
 
-        resolver.resolve().await?
+        let (resolution, fork_graph) = resolver.resolve_with_fork_graph().await;
+
+        // Write the state of each fork, if requested, even if the resolution failed.
+        if let Some(path) = RESOLVER_DUMP.get() {
+            let dump = serde_json::to_string_pretty(&fork_graph).map_err(std::io::Error::from)?;
+            fs_err::write(path, dump)?;
+            writeln!(
+                printer.stderr(),
+                "Wrote resolver dump to: {}",
+                path.user_display().cyan()
+            )?;
+        }
+
+        resolution?
     };
 
     logger.on_complete(resolution.len(), start, printer)?;
+    logger.on_held_back(resolution.held_back(), printer)?;
+
+    // Write the tree of forks that were solved, if requested.
+    if let Some(path) = std::env::var_os(EnvVars::UV_FORK_GRAPH) {
+        let path = PathBuf::from(path);
+        fs_err::write(&path, resolution.fork_graph().to_dot())?;
+        writeln!(
+            printer.stderr(),
+            "Wrote fork graph to: {}",
+            path.user_display().cyan()
+        )?;
+    }
 
     Ok(resolution)
 }
@@ -649,7 +685,8 @@ pub(crate) async fn install(
             printer,
             preview,
         )
-        .await?;
+        .await
+        .inspect_err(|err| log_failed_plan(&installs, &uninstalls, err))?;
         installs.extend(isolated_installs);
         uninstalls.extend(isolated_uninstalls);
     }
@@ -678,7 +715,8 @@ pub(crate) async fn install(
             printer,
             preview,
         )
-        .await?;
+        .await
+        .inspect_err(|err| log_failed_plan(&installs, &uninstalls, err))?;
         installs.extend(shared_installs);
         uninstalls.extend(shared_uninstalls);
     }
@@ -687,6 +725,13 @@ pub(crate) async fn install(
         compile_bytecode(venv, &concurrency, cache, printer).await?;
     }
 
+    debug!(
+        target: "uv_installer::plan",
+        installed = %installs.iter().map(ToString::to_string).sorted().join(", "),
+        uninstalled = %uninstalls.iter().map(ToString::to_string).sorted().join(", "),
+        "Executed installation plan"
+    );
+
     // Construct a summary of the changes made to the environment.
     let changelog = Changelog::from_local(installs, uninstalls);
 
@@ -696,6 +741,18 @@ pub(crate) async fn install(
     Ok(changelog)
 }
 
+/// Log the outcome of an installation plan that failed partway through, under the
+/// `uv_installer::plan` target.
+fn log_failed_plan(installs: &[CachedDist], uninstalls: &[InstalledDist], err: &Error) {
+    debug!(
+        target: "uv_installer::plan",
+        installed = %installs.iter().map(ToString::to_string).sorted().join(", "),
+        uninstalled = %uninstalls.iter().map(ToString::to_string).sorted().join(", "),
+        error = %err,
+        "Failed to execute installation plan"
+    );
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum InstallPhase {
     /// A dedicated phase for building and installing packages with build-isolation disabled.
@@ -1084,3 +1141,23 @@ pub(crate) enum Error {
     #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
     OutdatedEnvironment,
 }
+
+impl Error {
+    /// Returns a stable, machine-readable identifier for the kind of this error.
+    ///
+    /// Resolution errors are identified by their [`uv_resolver::ResolveError::code`], and follow
+    /// the same stability policy: once released, a code is never renamed or reused.
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            Self::Prepare(_) => "prepare",
+            Self::Resolve(err) => err.code(),
+            Self::Uninstall(_) => "uninstall",
+            Self::Hash(_) => "hash",
+            Self::Io(_) => "io",
+            Self::Fmt(_) => "fmt",
+            Self::Requirements(_) => "requirements",
+            Self::Anyhow(_) => "other",
+            Self::OutdatedEnvironment => "outdated-environment",
+        }
+    }
+}
@@ -15,8 +15,8 @@ use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, Origin,
-    PackageConfigSettings, Resolution,
+    AllowedIndex, ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
+    Origin, PackageConfigSettings, Resolution,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
@@ -64,6 +64,7 @@ pub(crate) async fn pip_sync(
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
     dependency_metadata: DependencyMetadata,
+    allowed_indexes: Option<Vec<AllowedIndex>>,
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     allow_empty_requirements: bool,
@@ -469,6 +470,7 @@ pub(crate) async fn pip_sync(
             .index_strategy(index_strategy)
             .torch_backend(torch_backend)
             .build_options(build_options.clone())
+            .allowed_indexes(allowed_indexes)
             .build();
 
         let resolution = match operations::resolve(
@@ -55,6 +55,7 @@ use crate::commands::project::{
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{ExitStatus, ScriptPath, diagnostics, project};
+use crate::interrupt;
 use crate::printer::Printer;
 use crate::settings::{FrozenSource, LockCheck, ResolverInstallerSettings};
 
@@ -721,20 +722,13 @@ pub(crate) async fn add(
     // Update the `pypackage.toml` in-memory.
     let target = target.update(&content)?;
 
-    // Set the Ctrl-C handler to revert changes on exit.
-    let _ = ctrlc::set_handler({
+    // Revert changes if interrupted.
+    interrupt::on_interrupt({
         let snapshot = snapshot.clone();
         move || {
             if modified {
                 let _ = snapshot.revert();
             }
-
-            #[expect(clippy::exit, clippy::cast_possible_wrap)]
-            std::process::exit(if cfg!(windows) {
-                0xC000_013A_u32 as i32
-            } else {
-                130
-            });
         }
     });
 
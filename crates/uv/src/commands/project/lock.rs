@@ -21,6 +21,7 @@ use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
     Requirement, RequiresPython, UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_git_types::GitOid;
 use uv_normalize::{GroupName, PackageName};
@@ -346,6 +347,10 @@ impl<'env> LockOperation<'env> {
                             })?;
                     }
                 }
+
+                // Check that the locked packages come from the allowed indexes.
+                check_allowed_indexes(target, &existing, self.settings)?;
+
                 Ok(LockResult::Unchanged(existing))
             }
             LockMode::Locked(interpreter, lock_source) => {
@@ -374,6 +379,9 @@ impl<'env> LockOperation<'env> {
                 ))
                 .await?;
 
+                // Check that the locked packages come from the allowed indexes.
+                check_allowed_indexes(target, result.lock(), self.settings)?;
+
                 // If the lockfile changed, return an error.
                 if let LockResult::Changed(prev, cur) = result {
                     return Err(ProjectError::LockMismatch(
@@ -418,6 +426,34 @@ impl<'env> LockOperation<'env> {
                 ))
                 .await?;
 
+                // Check that the locked packages come from the allowed indexes.
+                check_allowed_indexes(target, result.lock(), self.settings)?;
+
+                match &result {
+                    LockResult::Unchanged(_) => {
+                        debug!(
+                            "Lockfile at `{}` is unchanged",
+                            target.lock_path().user_display()
+                        );
+                    }
+                    LockResult::Changed(previous, lock) => {
+                        let (mut added, mut removed, mut updated) = (0, 0, 0);
+                        for event in
+                            LockEvent::detect_changes(previous.as_ref(), lock, DryRun::Disabled)
+                        {
+                            match event {
+                                LockEvent::Add(..) => added += 1,
+                                LockEvent::Remove(..) => removed += 1,
+                                LockEvent::Update(..) => updated += 1,
+                            }
+                        }
+                        debug!(
+                            "Lockfile at `{}` changed: {added} added, {removed} removed, {updated} updated",
+                            target.lock_path().user_display()
+                        );
+                    }
+                }
+
                 // If the lockfile changed, write it to disk.
                 if !matches!(self.mode, LockMode::DryRun(_)) {
                     if let LockResult::Changed(_, lock) = &result {
@@ -431,6 +467,24 @@ impl<'env> LockOperation<'env> {
     }
 }
 
+/// Returns an error if the lockfile includes a package from a source that isn't allowed by the
+/// `allowed-indexes` setting.
+///
+/// A resolution already enforces the allowlist, but a lockfile that's read from disk (e.g., with
+/// `--frozen`, or when it's already up-to-date) may predate it.
+#[expect(clippy::result_large_err)]
+fn check_allowed_indexes(
+    target: LockTarget<'_>,
+    lock: &Lock,
+    settings: &ResolverSettings,
+) -> Result<(), ProjectError> {
+    let Some(entries) = settings.allowed_indexes.as_deref() else {
+        return Ok(());
+    };
+    lock.check_allowed_indexes(target.install_path(), entries, &settings.index_locations)
+        .map_err(|err| ProjectError::Operation(err.into()))
+}
+
 /// Lock the project requirements into a lockfile.
 async fn do_lock(
     target: LockTarget<'_>,
@@ -458,7 +512,9 @@ async fn do_lock(
         resolution,
         prerelease,
         fork_strategy,
+        index_conflict_strategy,
         dependency_metadata,
+        allowed_indexes,
         config_setting,
         config_settings_package,
         build_isolation,
@@ -678,11 +734,11 @@ async fn do_lock(
     let client_builder = client_builder.clone().keyring(*keyring_provider);
 
     for index in target.indexes() {
-        if let Some(credentials) = index.credentials() {
+        if let Some((credentials, source)) = index.credentials() {
             if let Some(root_url) = index.root_url() {
-                client_builder.store_credentials(&root_url, credentials.clone());
+                client_builder.store_credentials(&root_url, credentials.clone(), source.clone());
             }
-            client_builder.store_credentials(index.raw_url(), credentials);
+            client_builder.store_credentials(index.raw_url(), credentials, source);
         }
     }
 
@@ -712,10 +768,13 @@ async fn do_lock(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .index_conflict_strategy(*index_conflict_strategy)
+        .allowed_indexes(allowed_indexes.clone())
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
         .required_environments(required_environments.cloned().unwrap_or_default())
+        .allow_index_divergence(preview.is_enabled(PreviewFeature::IndexDivergence))
         .build();
     let hasher = HashStrategy::Generate(HashGeneration::Url);
 
@@ -2,11 +2,14 @@ use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use itertools::Either;
+use tracing::debug;
 
 use uv_auth::CredentialsCache;
+use uv_cache_key::hash_digest;
 use uv_configuration::{DependencyGroupsWithDefaults, NoSources};
 use uv_distribution::LoweredRequirement;
 use uv_distribution_types::{Index, IndexLocations, Requirement, RequiresPython};
+use uv_fs::Simplified;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep508::RequirementOrigin;
 use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
@@ -288,7 +291,8 @@ impl<'lock> LockTarget<'lock> {
     ///
     /// Returns `Ok(None)` if the lockfile does not exist.
     pub(crate) async fn read(self) -> Result<Option<Lock>, ProjectError> {
-        match fs_err::tokio::read_to_string(self.lock_path()).await {
+        let path = self.lock_path();
+        match fs_err::tokio::read_to_string(&path).await {
             Ok(encoded) => {
                 match toml::from_str::<Lock>(&encoded) {
                     Ok(lock) => {
@@ -299,6 +303,14 @@ impl<'lock> LockTarget<'lock> {
                                 lock.version(),
                             ));
                         }
+                        debug!(
+                            "Read lockfile at `{}` (version {}, revision {}, {} packages, fingerprint {})",
+                            path.user_display(),
+                            lock.version(),
+                            lock.revision(),
+                            lock.packages().len(),
+                            hash_digest(&encoded)
+                        );
                         Ok(Some(lock))
                     }
                     Err(err) => {
@@ -317,7 +329,10 @@ impl<'lock> LockTarget<'lock> {
                     }
                 }
             }
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                debug!("No lockfile found at `{}`", path.user_display());
+                Ok(None)
+            }
             Err(err) => Err(err.into()),
         }
     }
@@ -333,8 +348,15 @@ impl<'lock> LockTarget<'lock> {
 
     /// Write the lockfile to disk.
     pub(crate) async fn commit(self, lock: &Lock) -> Result<(), ProjectError> {
+        let path = self.lock_path();
         let encoded = lock.to_toml()?;
-        fs_err::tokio::write(self.lock_path(), encoded).await?;
+        let fingerprint = hash_digest(&encoded);
+        fs_err::tokio::write(&path, encoded).await?;
+        debug!(
+            "Wrote lockfile to `{}` ({} packages, fingerprint {fingerprint})",
+            path.user_display(),
+            lock.packages().len()
+        );
         Ok(())
     }
 
@@ -982,6 +982,12 @@ impl ProjectInterpreter {
         let root = workspace.venv(active);
         match PythonEnvironment::from_root(&root, cache) {
             Ok(venv) => {
+                debug!(
+                    "Found existing project environment at `{}` linked to Python {} at `{}`",
+                    root.user_display(),
+                    venv.interpreter().python_version(),
+                    venv.interpreter().sys_base_prefix().user_display()
+                );
                 match environment_is_usable(
                     &venv,
                     EnvironmentKind::Project,
@@ -990,7 +996,13 @@ impl ProjectInterpreter {
                     requires_python.as_ref(),
                     cache,
                 ) {
-                    Ok(()) => return Ok(Self::Environment(venv)),
+                    Ok(()) => {
+                        debug!(
+                            "Reusing existing project environment at `{}`",
+                            root.user_display()
+                        );
+                        return Ok(Self::Environment(venv));
+                    }
                     Err(err) if keep_incompatible => {
                         warn_user!(
                             "Using incompatible environment (`{}`) due to `--no-sync` ({err})",
@@ -999,12 +1011,18 @@ impl ProjectInterpreter {
                         return Ok(Self::Environment(venv));
                     }
                     Err(err) => {
-                        debug!("{err}");
+                        debug!(
+                            "Ignoring existing project environment at `{}`: {err}",
+                            root.user_display()
+                        );
                     }
                 }
             }
-            Err(uv_python::Error::MissingEnvironment(_)) => {}
+            Err(uv_python::Error::MissingEnvironment(_)) => {
+                debug!("No project environment found at `{}`", root.user_display());
+            }
             Err(uv_python::Error::InvalidEnvironment(inner)) => {
+                debug!("{inner}");
                 // If there's an invalid environment with existing content, we error instead of
                 // deleting it later on
                 match inner.kind {
@@ -1454,6 +1472,10 @@ impl ProjectEnvironment {
 
                 // Remove the existing virtual environment if it doesn't meet the requirements.
                 if replace {
+                    debug!(
+                        "Recreating project environment at `{}`",
+                        root.user_display()
+                    );
                     match remove_virtualenv(&root) {
                         Ok(()) => {
                             writeln!(
@@ -1466,6 +1488,8 @@ impl ProjectEnvironment {
                             if err.kind() == std::io::ErrorKind::NotFound => {}
                         Err(err) => return Err(err.into()),
                     }
+                } else {
+                    debug!("Creating project environment at `{}`", root.user_display());
                 }
 
                 writeln!(
@@ -1772,6 +1796,8 @@ pub(crate) async fn resolve_names(
                 dependency_metadata,
                 exclude_newer,
                 fork_strategy: _,
+                index_conflict_strategy: _,
+                allowed_indexes: _,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -1946,7 +1972,9 @@ pub(crate) async fn resolve_environment(
         resolution,
         prerelease,
         fork_strategy,
+        index_conflict_strategy,
         dependency_metadata,
+        allowed_indexes,
         config_setting,
         config_settings_package,
         build_isolation,
@@ -2027,6 +2055,8 @@ pub(crate) async fn resolve_environment(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .index_conflict_strategy(*index_conflict_strategy)
+        .allowed_indexes(allowed_indexes.clone())
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -2323,6 +2353,8 @@ pub(crate) async fn update_environment(
                 dependency_metadata,
                 exclude_newer,
                 fork_strategy,
+                index_conflict_strategy,
+                allowed_indexes,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -2448,6 +2480,8 @@ pub(crate) async fn update_environment(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .index_conflict_strategy(*index_conflict_strategy)
+        .allowed_indexes(allowed_indexes.clone())
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -28,7 +28,10 @@ use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
 use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_resolver::{FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode};
+use uv_resolver::{
+    FlatIndex, ForkStrategy, IndexConflictStrategy, Installable, Lock, PrereleaseMode,
+    ResolutionMode,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
@@ -316,6 +319,7 @@ pub(crate) async fn sync(
                     return diagnostics::OperationDiagnostic::native_tls(
                         client_builder.is_native_tls(),
                     )
+                    .with_json(matches!(output_format, SyncFormat::Json))
                     .report(err)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
                 }
@@ -363,6 +367,7 @@ pub(crate) async fn sync(
         Ok(result) => Outcome::Success(result),
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .with_json(matches!(output_format, SyncFormat::Json))
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
@@ -422,6 +427,7 @@ pub(crate) async fn sync(
         Ok(changelog) => changelog,
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .with_json(matches!(output_format, SyncFormat::Json))
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
@@ -665,12 +671,14 @@ pub(super) async fn do_sync(
         InstallTarget::Script { script, .. } => {
             // Try to get extra build dependencies from the script metadata
             let resolver_settings = ResolverSettings {
+                allowed_indexes: None,
                 build_options: build_options.clone(),
                 config_setting: config_setting.clone(),
                 config_settings_package: config_settings_package.clone(),
                 dependency_metadata: dependency_metadata.clone(),
                 exclude_newer: exclude_newer.clone(),
                 fork_strategy: ForkStrategy::default(),
+                index_conflict_strategy: IndexConflictStrategy::default(),
                 index_locations: index_locations.clone(),
                 index_strategy,
                 keyring_provider,
@@ -951,11 +959,11 @@ fn apply_editable_mode(resolution: Resolution, editable: Option<EditableMode>) -
 fn store_credentials_from_target(target: InstallTarget<'_>, client_builder: &BaseClientBuilder) {
     // Iterate over any indexes in the target.
     for index in target.indexes() {
-        if let Some(credentials) = index.credentials() {
+        if let Some((credentials, source)) = index.credentials() {
             if let Some(root_url) = index.root_url() {
-                client_builder.store_credentials(&root_url, credentials.clone());
+                client_builder.store_credentials(&root_url, credentials.clone(), source.clone());
             }
-            client_builder.store_credentials(index.raw_url(), credentials);
+            client_builder.store_credentials(index.raw_url(), credentials, source);
         }
     }
 
@@ -202,6 +202,8 @@ pub(crate) async fn tree(
                 resolution: _,
                 prerelease: _,
                 fork_strategy: _,
+                index_conflict_strategy: _,
+                allowed_indexes: _,
                 dependency_metadata: _,
                 config_setting: _,
                 config_settings_package: _,
@@ -0,0 +1,184 @@
+//! Folded stacks of the recorded spans, as consumed by `inferno` and `flamegraph.pl`.
+//!
+//! Each line is a semicolon-separated stack of span names, from the root to the leaf, followed by
+//! the time spent in the leaf span itself (excluding its children) in microseconds, e.g.:
+//!
+//! ```text
+//! resolve;build_sdist 1500
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+use itertools::Itertools;
+use tracing_durations_export::plot::OwnedSpanInfo;
+
+/// A span, merged across all of its entries.
+struct Frame<'a> {
+    /// The name of the frame, i.e., the span name and (optionally) its fields.
+    name: String,
+    /// The ids of the parent spans, from the nearest to the root.
+    parents: &'a [u64],
+    /// The total time during which the span was entered.
+    active: Duration,
+}
+
+/// Render the given span records as folded stacks.
+///
+/// If `fold_fields` is set, the values of the span fields are included in the frame names, e.g.,
+/// `build_sdist(numpy)`.
+pub(crate) fn folded_stacks(spans: &[OwnedSpanInfo], fold_fields: bool) -> String {
+    let mut frames: BTreeMap<u64, Frame> = BTreeMap::new();
+    for span in spans {
+        frames
+            .entry(span.id)
+            .or_insert_with(|| Frame {
+                name: frame_name(span, fold_fields),
+                parents: span.parents.as_deref().unwrap_or_default(),
+                active: Duration::ZERO,
+            })
+            .active += span.end.saturating_sub(span.start);
+    }
+
+    // Parents that were never recorded (e.g., because they were still entered when the file was
+    // written) are omitted from the stacks.
+    let recorded_parents = |frame: &Frame| -> Vec<u64> {
+        frame
+            .parents
+            .iter()
+            .copied()
+            .filter(|parent| frames.contains_key(parent))
+            .collect()
+    };
+
+    // The time spent in each span's children, to determine the time spent in the span itself.
+    let mut children = BTreeMap::<u64, Duration>::new();
+    for frame in frames.values() {
+        if let Some(parent) = recorded_parents(frame).first() {
+            *children.entry(*parent).or_default() += frame.active;
+        }
+    }
+
+    let mut stacks = BTreeMap::<String, u128>::new();
+    for (id, frame) in &frames {
+        let own = frame
+            .active
+            .saturating_sub(children.get(id).copied().unwrap_or_default())
+            .as_micros();
+        if own == 0 {
+            continue;
+        }
+        let stack = recorded_parents(frame)
+            .iter()
+            .rev()
+            .filter_map(|parent| frames.get(parent))
+            .map(|parent| parent.name.as_str())
+            .chain(std::iter::once(frame.name.as_str()))
+            .join(";");
+        *stacks.entry(stack).or_default() += own;
+    }
+
+    let mut output = String::new();
+    for (stack, micros) in stacks {
+        let _ = writeln!(output, "{stack} {micros}");
+    }
+    output
+}
+
+/// Determine the frame name of a span.
+fn frame_name(span: &OwnedSpanInfo, fold_fields: bool) -> String {
+    let name = match span
+        .fields
+        .as_ref()
+        .filter(|fields| fold_fields && !fields.is_empty())
+    {
+        Some(fields) => {
+            let values = fields
+                .iter()
+                .sorted_by_key(|(key, _)| key.as_str())
+                .map(|(_, value)| value)
+                .join(",");
+            format!("{}({values})", span.name)
+        }
+        None => span.name.clone(),
+    };
+    // Semicolons separate frames and newlines separate stacks.
+    name.replace(';', ",").replace(['\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use tracing_durations_export::plot::OwnedSpanInfo;
+
+    use super::folded_stacks;
+
+    fn span(
+        id: u64,
+        name: &str,
+        (start, end): (u64, u64),
+        parents: &[u64],
+        fields: &[(&str, &str)],
+    ) -> OwnedSpanInfo {
+        OwnedSpanInfo {
+            id,
+            name: name.to_string(),
+            start: Duration::from_micros(start),
+            end: Duration::from_micros(end),
+            parents: Some(parents.to_vec()),
+            is_main_thread: true,
+            fields: Some(
+                fields
+                    .iter()
+                    .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+                    .collect::<HashMap<_, _>>(),
+            ),
+        }
+    }
+
+    /// ```text
+    /// resolve (0-1000)
+    /// ├── build_sdist(numpy) (100-400)
+    /// │   └── run_python_script (150-350)
+    /// └── build_sdist(scipy) (500-600, entered twice: 500-550 and 560-600)
+    /// ```
+    fn spans() -> Vec<OwnedSpanInfo> {
+        // Records are written when a span exits, i.e., children before their parents.
+        vec![
+            span(3, "run_python_script", (150, 350), &[2, 1], &[]),
+            span(2, "build_sdist", (100, 400), &[1], &[("package", "numpy")]),
+            span(4, "build_sdist", (500, 550), &[1], &[("package", "scipy")]),
+            span(4, "build_sdist", (560, 600), &[1], &[("package", "scipy")]),
+            span(1, "resolve", (0, 1000), &[], &[]),
+        ]
+    }
+
+    #[test]
+    fn folded() {
+        insta::assert_snapshot!(folded_stacks(&spans(), false), @r"
+        resolve 610
+        resolve;build_sdist 190
+        resolve;build_sdist;run_python_script 200
+        ");
+    }
+
+    #[test]
+    fn folded_with_fields() {
+        insta::assert_snapshot!(folded_stacks(&spans(), true), @r"
+        resolve 610
+        resolve;build_sdist(numpy) 100
+        resolve;build_sdist(numpy);run_python_script 200
+        resolve;build_sdist(scipy) 90
+        ");
+    }
+
+    /// Parents that were never recorded are omitted from the stacks.
+    #[test]
+    fn folded_missing_parent() {
+        let spans = [span(2, "build_sdist", (0, 100), &[1], &[])];
+        insta::assert_snapshot!(folded_stacks(&spans, false), @"build_sdist 100");
+    }
+}
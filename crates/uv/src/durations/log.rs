@@ -0,0 +1,68 @@
+//! Log each recorded span as it closes, such that the log output can be correlated with the
+//! durations file.
+
+use std::time::Instant;
+
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Subscriber, debug};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::commands::elapsed;
+use crate::logging::SpanFields;
+
+/// The target of the messages, which can be used to filter them, e.g., via `RUST_LOG`.
+const TARGET: &str = "uv::durations";
+
+/// The state of an open span, stored in its extensions.
+struct OpenSpan {
+    fields: String,
+    opened: Instant,
+}
+
+/// A [`Layer`] that logs the name, fields and duration of each span as it closes.
+pub(crate) struct SpanLogLayer;
+
+impl<S> Layer<S> for SpanLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = SpanFields::default();
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(OpenSpan {
+            fields: fields.into_string(),
+            opened: Instant::now(),
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        if let Some(open) = span.extensions_mut().get_mut::<OpenSpan>() {
+            let mut fields = SpanFields::from(std::mem::take(&mut open.fields));
+            values.record(&mut fields);
+            open.fields = fields.into_string();
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(OpenSpan { fields, opened }) = span.extensions_mut().remove::<OpenSpan>() else {
+            return;
+        };
+        let duration = elapsed(opened.elapsed());
+        if fields.is_empty() {
+            debug!(target: TARGET, "Span `{}` closed after {duration}", span.name());
+        } else {
+            debug!(target: TARGET, "Span `{}` ({fields}) closed after {duration}", span.name());
+        }
+    }
+}
@@ -0,0 +1,1148 @@
+//! Export the durations of uv's internal spans, via the `tracing-durations-export` feature.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
+
+use anyhow::Context;
+use clap::ValueEnum;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+use tracing_durations_export::plot::{OwnedSpanInfo, PlotConfig, PlotLayout};
+use tracing_durations_export::{DurationsLayer, DurationsLayerBuilder, DurationsLayerDropGuard};
+use tracing_subscriber::filter::Filtered;
+use tracing_subscriber::layer::Layered;
+use tracing_subscriber::{EnvFilter, Layer, Registry};
+
+use uv_cli::DurationsFormat;
+use uv_fs::Simplified;
+use uv_static::{EnvVars, InvalidEnvironmentVariable, parse_boolish};
+use uv_warnings::warn_user;
+
+use crate::interrupt;
+use crate::printer::Printer;
+
+mod folded;
+mod log;
+mod otlp;
+mod perfetto;
+mod plot;
+mod summary;
+
+/// The spans omitted from the plot by default.
+const DEFAULT_REMOVE: &[&str] = &["get_cached_with_callback"];
+
+/// The bounds of `UV_DURATIONS_WIDTH`, such that the bars retain a usable width next to the labels.
+const WIDTH_RANGE: RangeInclusive<usize> = 500..=100_000;
+
+/// The bounds of `UV_DURATIONS_LANE_HEIGHT`.
+const LANE_HEIGHT_RANGE: RangeInclusive<usize> = 4..=200;
+
+/// The number of runs whose files are retained in the cache directory with `--durations`.
+const RETAINED_RUNS: usize = 20;
+
+/// The durations layer (and, optionally, the layers logging each recorded span and streaming the
+/// Perfetto trace), filtered to the spans that should be recorded.
+pub(crate) type FilteredDurationsLayer = Filtered<
+    Layered<
+        Option<perfetto::PerfettoLayer>,
+        Layered<Option<log::SpanLogLayer>, DurationsLayer<Registry>, Registry>,
+        Registry,
+    >,
+    EnvFilter,
+    Registry,
+>;
+
+/// Where the location of the durations file was provided, used to attribute errors.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum DurationsSource {
+    /// The `--durations-file` command-line flag.
+    Flag,
+    /// The `TRACING_DURATIONS_FILE` environment variable.
+    EnvVar,
+    /// A location in the cache directory, generated for the `--durations` flag.
+    Cache,
+}
+
+impl std::fmt::Display for DurationsSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Flag => write!(f, "`--durations-file`"),
+            Self::EnvVar => write!(f, "`{}`", EnvVars::TRACING_DURATIONS_FILE),
+            Self::Cache => write!(f, "`--durations`"),
+        }
+    }
+}
+
+/// The options used to render the durations plot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PlotOptions {
+    /// Draw overlapping spans in separate lanes.
+    multi_lane: bool,
+    /// Omit spans shorter than this.
+    min_length: Option<Duration>,
+    /// Omit spans with these names.
+    remove: BTreeSet<String>,
+    /// The total width of the plot, in pixels.
+    width: usize,
+    /// The height of each lane, in pixels.
+    lane_height: usize,
+    /// The color scheme of the plot.
+    theme: PlotTheme,
+}
+
+impl Default for PlotOptions {
+    fn default() -> Self {
+        let layout = PlotLayout::default();
+        Self {
+            multi_lane: true,
+            min_length: None,
+            remove: DEFAULT_REMOVE.iter().map(ToString::to_string).collect(),
+            width: layout.padding_left
+                + layout.text_col_width
+                + layout.content_col_width
+                + layout.padding_right,
+            lane_height: layout.bar_height,
+            theme: PlotTheme::Light,
+        }
+    }
+}
+
+/// The color scheme of the durations plot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PlotTheme {
+    /// Dark text on a transparent background.
+    Light,
+    /// Light text on a dark background.
+    Dark,
+}
+
+impl std::fmt::Display for PlotTheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Light => write!(f, "light"),
+            Self::Dark => write!(f, "dark"),
+        }
+    }
+}
+
+impl PlotOptions {
+    /// Resolve the [`PlotOptions`] from the command-line arguments and the `UV_DURATIONS_*`
+    /// environment variables, falling back to the defaults for any that are unset.
+    ///
+    /// Command-line arguments take precedence over the corresponding environment variables.
+    pub(crate) fn resolve(args: &DurationsArgs) -> Result<Self, InvalidEnvironmentVariable> {
+        let mut options = Self::default();
+        if let Some(value) = read_environment_variable(EnvVars::UV_DURATIONS_MULTI_LANE)? {
+            options.multi_lane = parse_multi_lane(&value)?;
+        }
+        if let Some(value) = read_environment_variable(EnvVars::UV_DURATIONS_MIN_LENGTH)? {
+            options.min_length = parse_min_length(&value)?;
+        }
+        if let Some(value) = read_environment_variable(EnvVars::UV_DURATIONS_WIDTH)? {
+            options.width = parse_pixels(EnvVars::UV_DURATIONS_WIDTH, &value, WIDTH_RANGE)?;
+        }
+        if let Some(value) = read_environment_variable(EnvVars::UV_DURATIONS_LANE_HEIGHT)? {
+            options.lane_height =
+                parse_pixels(EnvVars::UV_DURATIONS_LANE_HEIGHT, &value, LANE_HEIGHT_RANGE)?;
+        }
+        if let Some(value) = read_environment_variable(EnvVars::UV_DURATIONS_THEME)? {
+            options.theme = parse_theme(&value)?;
+        }
+
+        let remove = match &args.remove {
+            Some(remove) => Some(remove.iter().cloned().collect()),
+            None => read_environment_variable(EnvVars::UV_DURATIONS_REMOVE)?
+                .map(|value| parse_remove(EnvVars::UV_DURATIONS_REMOVE, &value))
+                .transpose()?,
+        };
+        let remove_extra = match &args.remove_extra {
+            Some(remove_extra) => Some(remove_extra.iter().cloned().collect()),
+            None => read_environment_variable(EnvVars::UV_DURATIONS_REMOVE_EXTRA)?
+                .map(|value| parse_remove(EnvVars::UV_DURATIONS_REMOVE_EXTRA, &value))
+                .transpose()?,
+        };
+        options.apply_remove(remove, remove_extra, args.keep_all);
+
+        Ok(options)
+    }
+
+    /// Determine the spans to omit from the plot.
+    ///
+    /// `remove` replaces the default set, `remove_extra` extends it, and `keep_all` empties it.
+    fn apply_remove(
+        &mut self,
+        remove: Option<BTreeSet<String>>,
+        remove_extra: Option<BTreeSet<String>>,
+        keep_all: bool,
+    ) {
+        if keep_all {
+            self.remove.clear();
+            return;
+        }
+        if let Some(remove) = remove {
+            self.remove = remove;
+        }
+        if let Some(remove_extra) = remove_extra {
+            self.remove.extend(remove_extra);
+        }
+    }
+}
+
+/// The command-line arguments for the durations export.
+#[derive(Debug, Default)]
+pub(crate) struct DurationsArgs {
+    /// The spans to omit from the plot, replacing the default set (`--durations-remove`).
+    pub(crate) remove: Option<Vec<String>>,
+    /// The spans to omit from the plot, in addition to the default set
+    /// (`--durations-remove-extra`).
+    pub(crate) remove_extra: Option<Vec<String>>,
+    /// Omit no spans from the plot at all (`--durations-keep-all`).
+    pub(crate) keep_all: bool,
+    /// The formats to write, in addition to the durations file (`--durations-format`).
+    pub(crate) formats: Option<Vec<DurationsFormat>>,
+    /// Include the span fields in the frame names of folded stacks (`--durations-fold-fields`).
+    pub(crate) fold_fields: bool,
+    /// Log each recorded span as it closes (`--durations-log`).
+    pub(crate) log: bool,
+}
+
+impl From<PlotOptions> for PlotConfig {
+    fn from(options: PlotOptions) -> Self {
+        Self {
+            multi_lane: options.multi_lane,
+            min_length: options.min_length,
+            remove: Some(options.remove.into_iter().collect()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Read an environment variable as a string, if set.
+fn read_environment_variable(
+    name: &'static str,
+) -> Result<Option<String>, InvalidEnvironmentVariable> {
+    match std::env::var(name) {
+        Ok(value) => Ok(Some(value)),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(std::env::VarError::NotUnicode(value)) => Err(InvalidEnvironmentVariable {
+            name: name.to_string(),
+            value: value.to_string_lossy().to_string(),
+            err: "expected a valid UTF-8 string".to_string(),
+        }),
+    }
+}
+
+/// Parse the value of `UV_DURATIONS_MULTI_LANE`.
+fn parse_multi_lane(value: &str) -> Result<bool, InvalidEnvironmentVariable> {
+    parse_boolish(value).ok_or_else(|| InvalidEnvironmentVariable {
+        name: EnvVars::UV_DURATIONS_MULTI_LANE.to_string(),
+        value: value.to_string(),
+        err: "expected a boolish value".to_string(),
+    })
+}
+
+/// Parse the value of `UV_DURATIONS_OVERWRITE`.
+fn parse_overwrite(value: &str) -> Result<bool, InvalidEnvironmentVariable> {
+    parse_boolish(value).ok_or_else(|| InvalidEnvironmentVariable {
+        name: EnvVars::UV_DURATIONS_OVERWRITE.to_string(),
+        value: value.to_string(),
+        err: "expected a boolish value".to_string(),
+    })
+}
+
+/// Parse the value of `UV_DURATIONS_FOLD_FIELDS`.
+fn parse_fold_fields(value: &str) -> Result<bool, InvalidEnvironmentVariable> {
+    parse_boolish(value).ok_or_else(|| InvalidEnvironmentVariable {
+        name: EnvVars::UV_DURATIONS_FOLD_FIELDS.to_string(),
+        value: value.to_string(),
+        err: "expected a boolish value".to_string(),
+    })
+}
+
+/// Parse the value of `UV_DURATIONS_LOG`.
+fn parse_log(value: &str) -> Result<bool, InvalidEnvironmentVariable> {
+    parse_boolish(value).ok_or_else(|| InvalidEnvironmentVariable {
+        name: EnvVars::UV_DURATIONS_LOG.to_string(),
+        value: value.to_string(),
+        err: "expected a boolish value".to_string(),
+    })
+}
+
+/// Parse the value of `UV_DURATIONS_FORMAT`, e.g., `svg,folded`.
+fn parse_formats(value: &str) -> Result<Vec<DurationsFormat>, InvalidEnvironmentVariable> {
+    value
+        .split(',')
+        .map(|format| {
+            DurationsFormat::from_str(format.trim(), true).map_err(|_| InvalidEnvironmentVariable {
+                name: EnvVars::UV_DURATIONS_FORMAT.to_string(),
+                value: value.to_string(),
+                err: "expected a comma-separated list of `svg`, `folded`, `perfetto` or `otlp`"
+                    .to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Parse the value of `UV_DURATIONS_MIN_LENGTH`, e.g., `0`, `50us`, `1ms` or `2s`.
+///
+/// A number without a unit is a number of milliseconds. Zero disables the minimum length, such
+/// that every recorded span is plotted.
+fn parse_min_length(value: &str) -> Result<Option<Duration>, InvalidEnvironmentVariable> {
+    let err = |reason: String| InvalidEnvironmentVariable {
+        name: EnvVars::UV_DURATIONS_MIN_LENGTH.to_string(),
+        value: value.to_string(),
+        err: format!(
+            "expected a non-negative integer with an optional unit of `ns`, `us`, `ms` (the default) or `s`, e.g., `0`, `50us` or `1ms` ({reason})"
+        ),
+    };
+    let trimmed = value.trim();
+    let (number, unit) = trimmed.split_at(
+        trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(trimmed.len()),
+    );
+    let number = number
+        .parse::<u64>()
+        .map_err(|parse_err| err(parse_err.to_string()))?;
+    let min_length = match unit.trim_start() {
+        "ns" => Duration::from_nanos(number),
+        "us" | "µs" => Duration::from_micros(number),
+        "" | "ms" => Duration::from_millis(number),
+        "s" => Duration::from_secs(number),
+        unit => return Err(err(format!("unknown unit `{unit}`"))),
+    };
+    Ok(Some(min_length).filter(|min_length| !min_length.is_zero()))
+}
+
+/// Parse the value of `UV_DURATIONS_FILTER`, e.g., `uv_resolver=debug,info`.
+///
+/// The value uses the same directive syntax as `RUST_LOG`, including span names and fields, e.g.,
+/// `uv_resolver[solve]=debug`.
+fn parse_filter(value: &str) -> Result<EnvFilter, InvalidEnvironmentVariable> {
+    EnvFilter::builder()
+        .parse(value)
+        .map_err(|err| InvalidEnvironmentVariable {
+            name: EnvVars::UV_DURATIONS_FILTER.to_string(),
+            value: value.to_string(),
+            err: format!("expected a comma-separated list of `RUST_LOG` directives ({err})"),
+        })
+}
+
+/// Determine the spans to record in the durations file.
+///
+/// By default, all `INFO` spans are recorded, which omits the more granular `DEBUG` spans.
+fn resolve_filter() -> Result<EnvFilter, InvalidEnvironmentVariable> {
+    match read_environment_variable(EnvVars::UV_DURATIONS_FILTER)? {
+        Some(value) => parse_filter(&value),
+        None => Ok(default_filter()),
+    }
+}
+
+/// Record all `INFO` spans.
+fn default_filter() -> EnvFilter {
+    EnvFilter::new("info")
+}
+
+/// Parse a size in pixels, e.g., the value of `UV_DURATIONS_WIDTH`, within the given bounds.
+fn parse_pixels(
+    name: &'static str,
+    value: &str,
+    range: RangeInclusive<usize>,
+) -> Result<usize, InvalidEnvironmentVariable> {
+    let err = |reason: String| InvalidEnvironmentVariable {
+        name: name.to_string(),
+        value: value.to_string(),
+        err: format!(
+            "expected a number of pixels between {} and {} ({reason})",
+            range.start(),
+            range.end()
+        ),
+    };
+    let pixels = value
+        .trim()
+        .parse::<usize>()
+        .map_err(|parse_err| err(parse_err.to_string()))?;
+    if !range.contains(&pixels) {
+        return Err(err("out of bounds".to_string()));
+    }
+    Ok(pixels)
+}
+
+/// Parse the value of `UV_DURATIONS_THEME`.
+fn parse_theme(value: &str) -> Result<PlotTheme, InvalidEnvironmentVariable> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "light" => Ok(PlotTheme::Light),
+        "dark" => Ok(PlotTheme::Dark),
+        _ => Err(InvalidEnvironmentVariable {
+            name: EnvVars::UV_DURATIONS_THEME.to_string(),
+            value: value.to_string(),
+            err: "expected `light` or `dark`".to_string(),
+        }),
+    }
+}
+
+/// Parse a comma-separated list of span names, e.g., the value of `UV_DURATIONS_REMOVE`.
+///
+/// An empty value is an empty list.
+fn parse_remove(
+    name: &'static str,
+    value: &str,
+) -> Result<BTreeSet<String>, InvalidEnvironmentVariable> {
+    if value.trim().is_empty() {
+        return Ok(BTreeSet::new());
+    }
+    value
+        .split(',')
+        .map(str::trim)
+        .map(|span| {
+            if span.is_empty() {
+                Err(InvalidEnvironmentVariable {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                    err: "expected a comma-separated list of span names, but found an empty entry"
+                        .to_string(),
+                })
+            } else {
+                Ok(span.to_string())
+            }
+        })
+        .collect()
+}
+
+/// The durations files, which are written once the command completes or is interrupted.
+struct DurationsOutput {
+    guard: Option<DurationsLayerDropGuard>,
+    plot_options: PlotOptions,
+    /// The requested location of the durations file, if the files of a previous run exist there.
+    previous_run: Option<PathBuf>,
+    durations_file: PathBuf,
+    plot_file: Option<PathBuf>,
+    summary_file: PathBuf,
+    folded_file: Option<PathBuf>,
+    perfetto: Option<(PathBuf, perfetto::PerfettoTrace)>,
+    otlp_file: Option<PathBuf>,
+    /// The wall time at which the recording started, to anchor the spans of the OTLP export.
+    started: SystemTime,
+    fold_fields: bool,
+    command: Vec<String>,
+    printer: Printer,
+}
+
+impl DurationsOutput {
+    /// Log the location of the files, if it differs from the requested location.
+    fn log_location(&self) {
+        if let Some(previous_run) = &self.previous_run {
+            debug!(
+                "Found span durations of a previous run at `{}`, writing to `{}` instead",
+                previous_run.user_display(),
+                self.durations_file.user_display()
+            );
+        }
+    }
+
+    /// Log the effective plot options, such that the plot can be reproduced.
+    fn log_plot_options(&self) {
+        let PlotOptions {
+            multi_lane,
+            min_length,
+            remove,
+            width,
+            lane_height,
+            theme,
+        } = &self.plot_options;
+        debug!(
+            "Plotting span durations with multi-lane: {multi_lane}, minimum length: {}, removed spans: {}, width: {width}px, lane height: {lane_height}px, theme: {theme}",
+            min_length.map_or_else(
+                || "none".to_string(),
+                |min_length| format!("{min_length:?}")
+            ),
+            if remove.is_empty() {
+                "none".to_string()
+            } else {
+                remove.iter().map(|span| format!("`{span}`")).join(", ")
+            }
+        );
+    }
+
+    /// Write an output file derived from the span durations, reporting its location.
+    fn write_output(
+        &self,
+        kind: &str,
+        path: &Path,
+        contents: impl FnOnce() -> anyhow::Result<String>,
+    ) {
+        match contents().and_then(|contents| Ok(fs_err::write(path, contents)?)) {
+            Ok(()) => {
+                let _ = writeln!(
+                    self.printer.stderr(),
+                    "Wrote span {kind} to: {}",
+                    path.user_display().cyan()
+                );
+            }
+            Err(err) => {
+                warn_user!(
+                    "Failed to write span {kind} to `{}`: {err:#}",
+                    path.user_display()
+                );
+            }
+        }
+    }
+
+    /// Write out the durations files, and report their location.
+    fn write(mut self) {
+        // Dropping the inner guard flushes the durations file.
+        drop(self.guard.take());
+
+        let _ = writeln!(
+            self.printer.stderr(),
+            "Wrote span durations to: {}",
+            self.durations_file.user_display().cyan()
+        );
+
+        if let Some((perfetto_file, trace)) = &self.perfetto {
+            match trace.finish() {
+                Ok(()) => {
+                    let _ = writeln!(
+                        self.printer.stderr(),
+                        "Wrote span trace to: {}",
+                        perfetto_file.user_display().cyan()
+                    );
+                }
+                Err(err) => {
+                    warn_user!(
+                        "Failed to write span trace to `{}`: {err}",
+                        perfetto_file.user_display()
+                    );
+                }
+            }
+        }
+
+        let spans = match read_spans(&self.durations_file) {
+            Ok(spans) => spans,
+            Err(err) => {
+                warn_user!(
+                    "Failed to read span durations from `{}`: {err:#}",
+                    self.durations_file.user_display()
+                );
+                return;
+            }
+        };
+
+        // The plot is only rendered if any spans were recorded.
+        if let Some(plot_file) = &self.plot_file
+            && let Some(svg) = plot::render_plot(&spans, &self.plot_options)
+        {
+            self.write_output("plot", plot_file, || Ok(svg));
+        }
+
+        let summary = summary::Summary::from_spans(std::mem::take(&mut self.command), &spans);
+        self.write_output("summary", &self.summary_file, || {
+            Ok(serde_json::to_string_pretty(&summary)?)
+        });
+
+        if let Some(folded_file) = &self.folded_file {
+            self.write_output("folded stacks", folded_file, || {
+                Ok(folded::folded_stacks(&spans, self.fold_fields))
+            });
+        }
+
+        if let Some(otlp_file) = &self.otlp_file {
+            self.write_output("OpenTelemetry trace", otlp_file, || {
+                Ok(otlp::otlp_json(&spans, self.started)?)
+            });
+        }
+    }
+}
+
+/// A guard that writes out the durations files when dropped, or, if requested, when the process
+/// is interrupted via Ctrl-C, whichever happens first.
+pub(crate) struct DurationsGuard(Arc<Mutex<Option<DurationsOutput>>>);
+
+impl DurationsGuard {
+    fn new(output: DurationsOutput, write_on_interrupt: bool) -> Self {
+        let output = Arc::new(Mutex::new(Some(output)));
+        if write_on_interrupt {
+            interrupt::on_interrupt({
+                let output = output.clone();
+                move || write_once(&output)
+            });
+        }
+        Self(output)
+    }
+
+    /// Log the location of the files and the effective plot options, such that the plot can be
+    /// reproduced.
+    ///
+    /// This must be called after `tracing` has been initialized.
+    pub(crate) fn log_options(&self) {
+        if let Some(output) = &*self.0.lock().unwrap_or_else(PoisonError::into_inner) {
+            output.log_location();
+            output.log_plot_options();
+        }
+    }
+}
+
+impl Drop for DurationsGuard {
+    fn drop(&mut self) {
+        write_once(&self.0);
+    }
+}
+
+/// Write out the durations files, unless they were already written.
+fn write_once(output: &Mutex<Option<DurationsOutput>>) {
+    let output = output.lock().unwrap_or_else(PoisonError::into_inner).take();
+    if let Some(output) = output {
+        output.write();
+    }
+}
+
+/// Read the span records from a durations file.
+fn read_spans(path: &Path) -> anyhow::Result<Vec<OwnedSpanInfo>> {
+    let file = fs_err::File::open(path)?;
+    let mut spans = Vec::new();
+    for line in std::io::BufReader::new(file).lines() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+        let span = serde_json::from_str(&line)
+            .with_context(|| format!("Invalid span record: `{line}`"))?;
+        spans.push(span);
+    }
+    Ok(spans)
+}
+
+/// Generate the location of the durations file for `--durations`, in the `durations` directory of
+/// the cache, e.g., `durations/20250101T120000123-lock.jsonl`.
+///
+/// The files of all but the most recent runs are removed.
+pub(crate) fn cache_location(cache_root: &Path, command: &str) -> PathBuf {
+    let directory = cache_root.join("durations");
+    if let Err(err) = remove_old_runs(&directory, RETAINED_RUNS.saturating_sub(1)) {
+        debug!(
+            "Failed to remove old span durations from `{}`: {err}",
+            directory.user_display()
+        );
+    }
+    let now = jiff::Timestamp::now();
+    let timestamp = format!(
+        "{}{:03}",
+        now.strftime("%Y%m%dT%H%M%S"),
+        now.subsec_millisecond()
+    );
+    directory.join(format!("{timestamp}-{command}.jsonl"))
+}
+
+/// Remove the files of all but the `retain` most recent runs from the directory.
+///
+/// The files of a run share the same name up to the first dot, which starts with the time of the
+/// run, such that the runs sort chronologically.
+fn remove_old_runs(directory: &Path, retain: usize) -> std::io::Result<()> {
+    let entries = match fs_err::read_dir(directory) {
+        Ok(entries) => entries.collect::<Result<Vec<_>, _>>()?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    let mut runs: Vec<(String, Vec<PathBuf>)> = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let (run, _) = name.split_once('.')?;
+            Some((run.to_string(), entry.path()))
+        })
+        .into_group_map()
+        .into_iter()
+        .collect();
+    runs.sort_unstable_by(|(a, _), (b, _)| b.cmp(a));
+    for (run, files) in runs.into_iter().skip(retain) {
+        debug!("Removing span durations of run `{run}`");
+        for file in files {
+            fs_err::remove_file(file)?;
+        }
+    }
+    Ok(())
+}
+
+/// The extensions of the files written for a run, next to the durations file.
+const OUTPUT_EXTENSIONS: &[&str] = &[
+    "jsonl",
+    "svg",
+    "summary.json",
+    "folded",
+    "pftrace",
+    "otlp.json",
+];
+
+/// Avoid overwriting the files of a previous run at the same location, by appending a numeric
+/// suffix to the name of the files, e.g., `run-2.jsonl` and `run-2.svg` for `run.jsonl`.
+fn unique_location(location: &Path) -> PathBuf {
+    let exists = |location: &Path| {
+        location.exists()
+            || OUTPUT_EXTENSIONS
+                .iter()
+                .any(|extension| location.with_extension(extension).exists())
+    };
+    if !exists(location) {
+        return location.to_path_buf();
+    }
+
+    let stem = location
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let extension = location
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy()))
+        .unwrap_or_default();
+    (2..=u32::MAX)
+        .map(|suffix| location.with_file_name(format!("{stem}-{suffix}{extension}")))
+        .find(|candidate| !exists(candidate))
+        .unwrap_or_else(|| location.to_path_buf())
+}
+
+/// Setup the durations layer, writing to the file provided via `--durations-file` or the
+/// `TRACING_DURATIONS_FILE` environment variable.
+///
+/// If the files of a previous run exist at that location, a numeric suffix is appended to the
+/// file names, unless `UV_DURATIONS_OVERWRITE` is set.
+///
+/// The layer is filtered according to `UV_DURATIONS_FILTER`.
+///
+/// If `write_on_interrupt` is set, the files are written before the process exits on Ctrl-C. It
+/// must be unset for commands that forward Ctrl-C to a child process (e.g., `uv run`), which exit
+/// (and write the files) once the child does.
+pub(crate) fn setup_durations(
+    durations_file: Option<(&Path, DurationsSource)>,
+    args: DurationsArgs,
+    write_on_interrupt: bool,
+    printer: Printer,
+) -> anyhow::Result<(Option<FilteredDurationsLayer>, Option<DurationsGuard>)> {
+    let Some((location, source)) = durations_file else {
+        return Ok((None, None));
+    };
+    let overwrite = read_environment_variable(EnvVars::UV_DURATIONS_OVERWRITE)?
+        .map(|value| parse_overwrite(&value))
+        .transpose()?
+        .unwrap_or(false);
+    let requested = location;
+    let location = if overwrite {
+        location.to_path_buf()
+    } else {
+        unique_location(location)
+    };
+    let location = location.as_path();
+    if let Some(parent) = location.parent() {
+        fs_err::create_dir_all(parent)
+            .with_context(|| format!("Failed to create parent of {source}"))?;
+    }
+    let filter = resolve_filter()?;
+    let plot_options = PlotOptions::resolve(&args)?;
+    let formats = match args.formats {
+        Some(formats) => formats,
+        None => read_environment_variable(EnvVars::UV_DURATIONS_FORMAT)?
+            .map(|value| parse_formats(&value))
+            .transpose()?
+            .unwrap_or_else(|| vec![DurationsFormat::Svg]),
+    };
+    let fold_fields = args.fold_fields
+        || read_environment_variable(EnvVars::UV_DURATIONS_FOLD_FIELDS)?
+            .map(|value| parse_fold_fields(&value))
+            .transpose()?
+            .unwrap_or(false);
+    let log_spans = args.log
+        || read_environment_variable(EnvVars::UV_DURATIONS_LOG)?
+            .map(|value| parse_log(&value))
+            .transpose()?
+            .unwrap_or(false);
+    let plot_file = formats
+        .contains(&DurationsFormat::Svg)
+        .then(|| location.with_extension("svg"));
+    let folded_file = formats
+        .contains(&DurationsFormat::Folded)
+        .then(|| location.with_extension("folded"));
+    let otlp_file = formats
+        .contains(&DurationsFormat::Otlp)
+        .then(|| location.with_extension("otlp.json"));
+
+    let started = SystemTime::now();
+    let (layer, guard) = DurationsLayerBuilder::default()
+        .durations_file(location)
+        .build()
+        .with_context(|| format!("Couldn't create {source} files"))?;
+    let (perfetto_layer, perfetto) = if formats.contains(&DurationsFormat::Perfetto) {
+        let perfetto_file = location.with_extension("pftrace");
+        let (layer, trace) = perfetto::perfetto_trace(&perfetto_file)
+            .with_context(|| format!("Couldn't create {source} files"))?;
+        (Some(layer), Some((perfetto_file, trace)))
+    } else {
+        (None, None)
+    };
+    let output = DurationsOutput {
+        guard: Some(guard),
+        plot_options,
+        previous_run: (location != requested).then(|| requested.to_path_buf()),
+        durations_file: location.to_path_buf(),
+        plot_file,
+        summary_file: location.with_extension("summary.json"),
+        folded_file,
+        perfetto,
+        otlp_file,
+        started,
+        fold_fields,
+        command: std::env::args_os()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect(),
+        printer,
+    };
+    Ok((
+        Some(
+            layer
+                .and_then(log_spans.then_some(log::SpanLogLayer))
+                .and_then(perfetto_layer)
+                .with_filter(filter),
+        ),
+        Some(DurationsGuard::new(output, write_on_interrupt)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::time::Duration;
+
+    use tracing_durations_export::DurationsLayerBuilder;
+    use tracing_durations_export::plot::OwnedSpanInfo;
+    use tracing_subscriber::EnvFilter;
+    use tracing_subscriber::Layer;
+    use tracing_subscriber::layer::SubscriberExt;
+    use uv_static::EnvVars;
+
+    use uv_cli::DurationsFormat;
+
+    use super::plot::render_plot;
+    use super::{
+        LANE_HEIGHT_RANGE, PlotOptions, PlotTheme, WIDTH_RANGE, default_filter, parse_filter,
+        parse_fold_fields, parse_formats, parse_min_length, parse_multi_lane, parse_overwrite,
+        parse_pixels, parse_remove, parse_theme, read_spans, remove_old_runs, unique_location,
+    };
+
+    fn names(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn default_plot_options() {
+        let options = PlotOptions::default();
+        assert!(options.multi_lane);
+        assert_eq!(options.min_length, None);
+        assert_eq!(
+            options.remove,
+            BTreeSet::from(["get_cached_with_callback".to_string()])
+        );
+        assert_eq!(options.width, 1110);
+        assert_eq!(options.lane_height, 20);
+        assert_eq!(options.theme, PlotTheme::Light);
+    }
+
+    #[test]
+    fn pixels() {
+        assert_eq!(
+            parse_pixels(EnvVars::UV_DURATIONS_WIDTH, "2000", WIDTH_RANGE).unwrap(),
+            2000
+        );
+        assert_eq!(
+            parse_pixels(EnvVars::UV_DURATIONS_LANE_HEIGHT, " 4 ", LANE_HEIGHT_RANGE).unwrap(),
+            4
+        );
+
+        let err = parse_pixels(EnvVars::UV_DURATIONS_WIDTH, "100", WIDTH_RANGE).unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_WIDTH` with invalid value `100`: expected a number of pixels between 500 and 100000 (out of bounds)");
+
+        let err =
+            parse_pixels(EnvVars::UV_DURATIONS_LANE_HEIGHT, "tall", LANE_HEIGHT_RANGE).unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_LANE_HEIGHT` with invalid value `tall`: expected a number of pixels between 4 and 200 (invalid digit found in string)");
+    }
+
+    #[test]
+    fn theme() {
+        assert_eq!(parse_theme("light").unwrap(), PlotTheme::Light);
+        assert_eq!(parse_theme("Dark").unwrap(), PlotTheme::Dark);
+
+        let err = parse_theme("solarized").unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_THEME` with invalid value `solarized`: expected `light` or `dark`");
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(parse_formats("svg").unwrap(), vec![DurationsFormat::Svg]);
+        assert_eq!(
+            parse_formats("Folded, perfetto").unwrap(),
+            vec![DurationsFormat::Folded, DurationsFormat::Perfetto]
+        );
+
+        let err = parse_formats("svg,png").unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_FORMAT` with invalid value `svg,png`: expected a comma-separated list of `svg`, `folded`, `perfetto` or `otlp`");
+    }
+
+    #[test]
+    fn fold_fields() {
+        assert!(parse_fold_fields("1").unwrap());
+        assert!(!parse_fold_fields("false").unwrap());
+
+        let err = parse_fold_fields("sometimes").unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_FOLD_FIELDS` with invalid value `sometimes`: expected a boolish value");
+    }
+
+    #[test]
+    fn multi_lane() {
+        assert!(parse_multi_lane("1").unwrap());
+        assert!(parse_multi_lane("TRUE").unwrap());
+        assert!(!parse_multi_lane("off").unwrap());
+        assert!(!parse_multi_lane("0").unwrap());
+
+        let err = parse_multi_lane("sometimes").unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_MULTI_LANE` with invalid value `sometimes`: expected a boolish value");
+    }
+
+    #[test]
+    fn min_length() {
+        assert_eq!(
+            parse_min_length("250").unwrap(),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(
+            parse_min_length(" 5 ").unwrap(),
+            Some(Duration::from_millis(5))
+        );
+        assert_eq!(
+            parse_min_length("1ms").unwrap(),
+            Some(Duration::from_millis(1))
+        );
+        assert_eq!(
+            parse_min_length("50us").unwrap(),
+            Some(Duration::from_micros(50))
+        );
+        assert_eq!(
+            parse_min_length("50µs").unwrap(),
+            Some(Duration::from_micros(50))
+        );
+        assert_eq!(
+            parse_min_length("100ns").unwrap(),
+            Some(Duration::from_nanos(100))
+        );
+        assert_eq!(
+            parse_min_length("2s").unwrap(),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            parse_min_length("2 s").unwrap(),
+            Some(Duration::from_secs(2))
+        );
+
+        // Zero keeps all spans, regardless of the unit.
+        assert_eq!(parse_min_length("0").unwrap(), None);
+        assert_eq!(parse_min_length("0us").unwrap(), None);
+
+        let err = parse_min_length("-1").unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_MIN_LENGTH` with invalid value `-1`: expected a non-negative integer with an optional unit of `ns`, `us`, `ms` (the default) or `s`, e.g., `0`, `50us` or `1ms` (cannot parse integer from empty string)");
+
+        let err = parse_min_length("1.5s").unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_MIN_LENGTH` with invalid value `1.5s`: expected a non-negative integer with an optional unit of `ns`, `us`, `ms` (the default) or `s`, e.g., `0`, `50us` or `1ms` (unknown unit `.5s`)");
+
+        let err = parse_min_length("5m").unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_MIN_LENGTH` with invalid value `5m`: expected a non-negative integer with an optional unit of `ns`, `us`, `ms` (the default) or `s`, e.g., `0`, `50us` or `1ms` (unknown unit `m`)");
+    }
+
+    #[test]
+    fn remove() {
+        assert_eq!(
+            parse_remove(EnvVars::UV_DURATIONS_REMOVE, "").unwrap(),
+            BTreeSet::new()
+        );
+        assert_eq!(
+            parse_remove(EnvVars::UV_DURATIONS_REMOVE, "resolve, build_wheel").unwrap(),
+            BTreeSet::from(["build_wheel".to_string(), "resolve".to_string()])
+        );
+
+        let err = parse_remove(EnvVars::UV_DURATIONS_REMOVE, "resolve,,build_wheel").unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_REMOVE` with invalid value `resolve,,build_wheel`: expected a comma-separated list of span names, but found an empty entry");
+    }
+
+    #[test]
+    fn apply_remove() {
+        // `--durations-remove` replaces the default set.
+        let mut options = PlotOptions::default();
+        options.apply_remove(Some(names(&["resolve"])), None, false);
+        assert_eq!(options.remove, names(&["resolve"]));
+
+        // `--durations-remove-extra` extends the default set.
+        let mut options = PlotOptions::default();
+        options.apply_remove(None, Some(names(&["resolve"])), false);
+        assert_eq!(
+            options.remove,
+            names(&["get_cached_with_callback", "resolve"])
+        );
+
+        // Both can be combined.
+        let mut options = PlotOptions::default();
+        options.apply_remove(
+            Some(names(&["resolve"])),
+            Some(names(&["build_wheel"])),
+            false,
+        );
+        assert_eq!(options.remove, names(&["build_wheel", "resolve"]));
+
+        // `--durations-keep-all` empties the set.
+        let mut options = PlotOptions::default();
+        options.apply_remove(None, Some(names(&["resolve"])), true);
+        assert_eq!(options.remove, BTreeSet::new());
+    }
+
+    /// Spans in the remove set are absent from the plot, while all other spans are retained.
+    #[test]
+    fn removed_spans_are_not_plotted() {
+        let span = |id: u64, name: &str, start: u64, end: u64| OwnedSpanInfo {
+            id,
+            name: name.to_string(),
+            start: Duration::from_millis(start),
+            end: Duration::from_millis(end),
+            parents: None,
+            is_main_thread: true,
+            fields: None,
+        };
+        let spans = [
+            span(1, "get_cached_with_callback", 0, 10),
+            span(2, "resolve", 10, 20),
+            span(3, "build_wheel", 20, 30),
+        ];
+        let render = |options: PlotOptions| render_plot(&spans, &options).unwrap();
+
+        let svg = render(PlotOptions::default());
+        assert!(!svg.contains("get_cached_with_callback"));
+        assert!(svg.contains("resolve"));
+        assert!(svg.contains("build_wheel"));
+
+        let mut options = PlotOptions::default();
+        options.apply_remove(None, Some(names(&["resolve"])), false);
+        let svg = render(options);
+        assert!(!svg.contains("get_cached_with_callback"));
+        assert!(!svg.contains("resolve"));
+        assert!(svg.contains("build_wheel"));
+
+        let mut options = PlotOptions::default();
+        options.apply_remove(None, None, true);
+        let svg = render(options);
+        assert!(svg.contains("get_cached_with_callback"));
+        assert!(svg.contains("resolve"));
+        assert!(svg.contains("build_wheel"));
+    }
+
+    /// Record a resolver `INFO` span and a resolver `DEBUG` span with the given filter, returning
+    /// the names of the recorded spans.
+    fn record_spans(filter: EnvFilter) -> Vec<String> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let durations_file = temp_dir.path().join("durations.jsonl");
+        let (layer, guard) = DurationsLayerBuilder::default()
+            .durations_file(&durations_file)
+            .build()
+            .unwrap();
+        let subscriber = tracing_subscriber::registry().with(layer.with_filter(filter));
+        tracing::subscriber::with_default(subscriber, || {
+            drop(tracing::info_span!(target: "uv_resolver::resolver", "solve").entered());
+            drop(tracing::debug_span!(target: "uv_resolver::resolver", "choose_version").entered());
+        });
+        drop(guard);
+        read_spans(&durations_file)
+            .unwrap()
+            .into_iter()
+            .map(|span| span.name)
+            .collect()
+    }
+
+    #[test]
+    fn filter() {
+        // By default, only `INFO` spans are recorded.
+        assert_eq!(record_spans(default_filter()), ["solve"]);
+
+        // A directive can opt in to `DEBUG` spans.
+        let filter = parse_filter("uv_resolver=debug,info").unwrap();
+        assert_eq!(record_spans(filter), ["solve", "choose_version"]);
+
+        // As in `RUST_LOG`, directives can refer to spans.
+        assert!(parse_filter("uv_resolver[solve]=debug,info").is_ok());
+
+        let err = parse_filter("uv_resolver=loud").unwrap_err();
+        insta::assert_snapshot!(err, @r#"Failed to parse environment variable `UV_DURATIONS_FILTER` with invalid value `uv_resolver=loud`: expected a comma-separated list of `RUST_LOG` directives (error parsing level filter: expected one of "off", "error", "warn", "info", "debug", "trace", or a number 0-5)"#);
+    }
+
+    #[test]
+    fn old_runs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        for run in [
+            "20250101T000000000-lock",
+            "20250102T000000000-sync",
+            "20250103T000000000-lock",
+        ] {
+            for extension in ["jsonl", "svg", "summary.json"] {
+                fs_err::write(temp_dir.path().join(format!("{run}.{extension}")), "").unwrap();
+            }
+        }
+
+        remove_old_runs(temp_dir.path(), 2).unwrap();
+
+        let mut files = fs_err::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(
+            files,
+            [
+                "20250102T000000000-sync.jsonl",
+                "20250102T000000000-sync.summary.json",
+                "20250102T000000000-sync.svg",
+                "20250103T000000000-lock.jsonl",
+                "20250103T000000000-lock.summary.json",
+                "20250103T000000000-lock.svg",
+            ]
+        );
+
+        // A missing directory has no runs to remove.
+        remove_old_runs(&temp_dir.path().join("missing"), 2).unwrap();
+    }
+
+    #[test]
+    fn unique_locations() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let location = temp_dir.path().join("run.jsonl");
+        assert_eq!(unique_location(&location), location);
+
+        // Any file of a previous run counts, not just the durations file.
+        fs_err::write(temp_dir.path().join("run.svg"), "").unwrap();
+        assert_eq!(
+            unique_location(&location),
+            temp_dir.path().join("run-2.jsonl")
+        );
+        fs_err::write(temp_dir.path().join("run-2.summary.json"), "").unwrap();
+        assert_eq!(
+            unique_location(&location),
+            temp_dir.path().join("run-3.jsonl")
+        );
+
+        let location = temp_dir.path().join("durations");
+        fs_err::write(&location, "").unwrap();
+        assert_eq!(
+            unique_location(&location),
+            temp_dir.path().join("durations-2")
+        );
+
+        let err = parse_overwrite("sometimes").unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse environment variable `UV_DURATIONS_OVERWRITE` with invalid value `sometimes`: expected a boolish value");
+    }
+}
@@ -0,0 +1,357 @@
+//! OpenTelemetry spans of the recorded span durations, in the OTLP JSON encoding, such that they
+//! can be imported into a tracing backend after the run (e.g., via the `otlpjsonfile` receiver of
+//! the OpenTelemetry Collector).
+//!
+//! The file contains a single `ExportTraceServiceRequest` on one line. Each recorded span becomes
+//! an OpenTelemetry span, spanning from its first entry to its last exit, with its fields as
+//! attributes. All spans of a run share a trace.
+//!
+//! See <https://opentelemetry.io/docs/specs/otlp/#json-protobuf-encoding> for the encoding.
+
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
+
+use serde::Serialize;
+use tracing_durations_export::plot::OwnedSpanInfo;
+
+/// `SPAN_KIND_INTERNAL`, as all spans are internal operations of uv.
+const SPAN_KIND_INTERNAL: u8 = 1;
+
+/// `ExportTraceServiceRequest`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TracesData {
+    resource_spans: Vec<ResourceSpans>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceSpans {
+    resource: Resource,
+    scope_spans: Vec<ScopeSpans>,
+}
+
+#[derive(Debug, Serialize)]
+struct Resource {
+    attributes: Vec<KeyValue>,
+}
+
+#[derive(Debug, Serialize)]
+struct ScopeSpans {
+    scope: InstrumentationScope,
+    spans: Vec<Span>,
+}
+
+#[derive(Debug, Serialize)]
+struct InstrumentationScope {
+    name: &'static str,
+    version: &'static str,
+}
+
+// The names follow the OTLP protos.
+#[expect(clippy::struct_field_names)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Span {
+    /// The trace ID, as 32 lowercase hex characters.
+    trace_id: String,
+    /// The span ID, as 16 lowercase hex characters.
+    span_id: String,
+    /// The ID of the parent span, omitted for root spans.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_span_id: Option<String>,
+    name: String,
+    kind: u8,
+    /// The timestamps are 64-bit integers, which are encoded as decimal strings.
+    start_time_unix_nano: String,
+    end_time_unix_nano: String,
+    attributes: Vec<KeyValue>,
+}
+
+#[derive(Debug, Serialize)]
+struct KeyValue {
+    key: String,
+    value: AnyValue,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AnyValue {
+    string_value: String,
+}
+
+impl KeyValue {
+    fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            value: AnyValue {
+                string_value: value.into(),
+            },
+        }
+    }
+}
+
+/// A span, spanning from its first entry to its last exit.
+struct FullSpan<'a> {
+    record: &'a OwnedSpanInfo,
+    start: Duration,
+    end: Duration,
+}
+
+/// Render the given span records as OTLP JSON.
+///
+/// The records are relative to the start of the recording, which is anchored at `started`. The
+/// trace ID is derived from `started` and the span IDs from the IDs of the recorded spans, such
+/// that the IDs are stable within a run. Parents that were never recorded (e.g., because they were
+/// still entered when the file was written) are skipped in favor of their nearest recorded parent.
+pub(crate) fn otlp_json(
+    spans: &[OwnedSpanInfo],
+    started: SystemTime,
+) -> serde_json::Result<String> {
+    let mut full_spans: BTreeMap<u64, FullSpan> = BTreeMap::new();
+    for span in spans {
+        full_spans
+            .entry(span.id)
+            .and_modify(|full_span| {
+                full_span.start = full_span.start.min(span.start);
+                full_span.end = full_span.end.max(span.end);
+            })
+            .or_insert(FullSpan {
+                record: span,
+                start: span.start,
+                end: span.end,
+            });
+    }
+
+    let started = started
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    let trace_id = format!("{:032x}", started.as_nanos().max(1));
+    let timestamp = |offset: Duration| (started + offset).as_nanos().to_string();
+
+    let spans = full_spans
+        .iter()
+        .map(|(id, span)| {
+            let parent_span_id = span
+                .record
+                .parents
+                .iter()
+                .flatten()
+                .find(|parent| full_spans.contains_key(parent))
+                .map(|parent| span_id(*parent));
+            let mut attributes = span
+                .record
+                .fields
+                .iter()
+                .flatten()
+                .map(|(key, value)| KeyValue::new(key, value))
+                .collect::<Vec<_>>();
+            attributes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+            Span {
+                trace_id: trace_id.clone(),
+                span_id: span_id(*id),
+                parent_span_id,
+                name: span.record.name.clone(),
+                kind: SPAN_KIND_INTERNAL,
+                start_time_unix_nano: timestamp(span.start),
+                end_time_unix_nano: timestamp(span.end),
+                attributes,
+            }
+        })
+        .collect();
+
+    let data = TracesData {
+        resource_spans: vec![ResourceSpans {
+            resource: Resource {
+                attributes: vec![
+                    KeyValue::new("service.name", "uv"),
+                    KeyValue::new("service.version", uv_version::version()),
+                ],
+            },
+            scope_spans: vec![ScopeSpans {
+                scope: InstrumentationScope {
+                    name: "uv",
+                    version: uv_version::version(),
+                },
+                spans,
+            }],
+        }],
+    };
+    let mut json = serde_json::to_string(&data)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// The span ID of a recorded span, which is never zero, as required for a valid span ID.
+fn span_id(id: u64) -> String {
+    format!("{:016x}", id.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap};
+    use std::time::{Duration, SystemTime};
+
+    use serde_json::Value;
+    use tracing_durations_export::plot::OwnedSpanInfo;
+
+    use super::otlp_json;
+
+    fn span(id: u64, name: &str, parents: &[u64], start: u64, end: u64) -> OwnedSpanInfo {
+        OwnedSpanInfo {
+            id,
+            name: name.to_string(),
+            start: Duration::from_millis(start),
+            end: Duration::from_millis(end),
+            parents: Some(parents.to_vec()),
+            is_main_thread: true,
+            fields: Some(HashMap::from([(
+                "package".to_string(),
+                "numpy".to_string(),
+            )])),
+        }
+    }
+
+    fn started() -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    /// A future polled twice is merged into a single span, and spans whose parent wasn't recorded
+    /// are attached to their nearest recorded parent.
+    #[test]
+    fn spans() {
+        let spans = [
+            span(1, "build_wheel", &[2, 3], 10, 20),
+            span(1, "build_wheel", &[2, 3], 30, 45),
+            span(3, "solve", &[], 0, 50),
+        ];
+        let json = otlp_json(&spans, started()).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        insta::assert_json_snapshot!(value, {
+            ".resourceSpans[].resource.attributes[1].value.stringValue" => "[VERSION]",
+            ".resourceSpans[].scopeSpans[].scope.version" => "[VERSION]",
+        }, @r#"
+        {
+          "resourceSpans": [
+            {
+              "resource": {
+                "attributes": [
+                  {
+                    "key": "service.name",
+                    "value": {
+                      "stringValue": "uv"
+                    }
+                  },
+                  {
+                    "key": "service.version",
+                    "value": {
+                      "stringValue": "[VERSION]"
+                    }
+                  }
+                ]
+              },
+              "scopeSpans": [
+                {
+                  "scope": {
+                    "name": "uv",
+                    "version": "[VERSION]"
+                  },
+                  "spans": [
+                    {
+                      "attributes": [
+                        {
+                          "key": "package",
+                          "value": {
+                            "stringValue": "numpy"
+                          }
+                        }
+                      ],
+                      "endTimeUnixNano": "1700000000045000000",
+                      "kind": 1,
+                      "name": "build_wheel",
+                      "parentSpanId": "0000000000000003",
+                      "spanId": "0000000000000001",
+                      "startTimeUnixNano": "1700000000010000000",
+                      "traceId": "000000000000000017979cfe362a0000"
+                    },
+                    {
+                      "attributes": [
+                        {
+                          "key": "package",
+                          "value": {
+                            "stringValue": "numpy"
+                          }
+                        }
+                      ],
+                      "endTimeUnixNano": "1700000000050000000",
+                      "kind": 1,
+                      "name": "solve",
+                      "spanId": "0000000000000003",
+                      "startTimeUnixNano": "1700000000000000000",
+                      "traceId": "000000000000000017979cfe362a0000"
+                    }
+                  ]
+                }
+              ]
+            }
+          ]
+        }
+        "#);
+    }
+
+    /// Validate the output against the constraints of the OTLP JSON encoding: lowercase hex IDs
+    /// of the right length, integers as decimal strings, a single trace, and parents that refer to
+    /// spans in the trace.
+    #[test]
+    fn encoding() {
+        let spans = (1..=20)
+            .map(|id| span(id << 40, "fetch", &[(id - 1) << 40], id, id + 5))
+            .collect::<Vec<_>>();
+        let json = otlp_json(&spans, started()).unwrap();
+        assert_eq!(json.lines().count(), 1);
+        assert_eq!(json, otlp_json(&spans, started()).unwrap());
+
+        let value: Value = serde_json::from_str(&json).unwrap();
+        let spans = value["resourceSpans"][0]["scopeSpans"][0]["spans"]
+            .as_array()
+            .unwrap();
+        assert_eq!(spans.len(), 20);
+
+        let is_hex_id = |id: &Value, len: usize| {
+            id.as_str().is_some_and(|id| {
+                id.len() == len
+                    && id.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+                    && id.bytes().any(|b| b != b'0')
+            })
+        };
+        let is_u64 = |value: &Value| value.as_str().is_some_and(|s| s.parse::<u64>().is_ok());
+
+        let span_ids = spans
+            .iter()
+            .map(|span| span["spanId"].as_str().unwrap())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(span_ids.len(), spans.len());
+        let trace_ids = spans
+            .iter()
+            .map(|span| span["traceId"].as_str().unwrap())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(trace_ids.len(), 1);
+
+        for span in spans {
+            assert!(is_hex_id(&span["traceId"], 32), "{span}");
+            assert!(is_hex_id(&span["spanId"], 16), "{span}");
+            if let Some(parent) = span.get("parentSpanId") {
+                assert!(is_hex_id(parent, 16), "{span}");
+                assert!(span_ids.contains(parent.as_str().unwrap()), "{span}");
+            }
+            assert!(is_u64(&span["startTimeUnixNano"]), "{span}");
+            assert!(is_u64(&span["endTimeUnixNano"]), "{span}");
+            assert_eq!(span["kind"], 1);
+            assert!(span["name"].is_string());
+            for attribute in span["attributes"].as_array().unwrap() {
+                assert!(attribute["key"].is_string(), "{span}");
+                assert!(attribute["value"]["stringValue"].is_string(), "{span}");
+            }
+        }
+    }
+}
@@ -0,0 +1,477 @@
+//! A Perfetto protobuf trace of the recorded spans, as loaded by <https://ui.perfetto.dev>.
+//!
+//! The trace is streamed to the file as spans are entered and exited, such that large traces are
+//! never held in memory. Each thread has its own track, on which each entry of a span is a slice,
+//! annotated with the span fields.
+//!
+//! See <https://perfetto.dev/docs/reference/trace-packet-proto> for the schema.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::ThreadId;
+use std::time::Instant;
+
+use tracing::Subscriber;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+/// The field numbers of the subset of the Perfetto protos that we write.
+mod proto {
+    /// `Trace.packet`
+    pub(super) const TRACE_PACKET: u32 = 1;
+
+    /// `TracePacket.timestamp`
+    pub(super) const PACKET_TIMESTAMP: u32 = 8;
+    /// `TracePacket.trusted_packet_sequence_id`
+    pub(super) const PACKET_SEQUENCE_ID: u32 = 10;
+    /// `TracePacket.track_event`
+    pub(super) const PACKET_TRACK_EVENT: u32 = 11;
+    /// `TracePacket.sequence_flags`
+    pub(super) const PACKET_SEQUENCE_FLAGS: u32 = 13;
+    /// `TracePacket.track_descriptor`
+    pub(super) const PACKET_TRACK_DESCRIPTOR: u32 = 60;
+
+    /// `TrackDescriptor.uuid`
+    pub(super) const TRACK_UUID: u32 = 1;
+    /// `TrackDescriptor.name`
+    pub(super) const TRACK_NAME: u32 = 2;
+    /// `TrackDescriptor.process`
+    pub(super) const TRACK_PROCESS: u32 = 3;
+    /// `TrackDescriptor.parent_uuid`
+    pub(super) const TRACK_PARENT_UUID: u32 = 5;
+
+    /// `ProcessDescriptor.pid`
+    pub(super) const PROCESS_PID: u32 = 1;
+    /// `ProcessDescriptor.process_name`
+    pub(super) const PROCESS_NAME: u32 = 6;
+
+    /// `TrackEvent.debug_annotations`
+    pub(super) const EVENT_DEBUG_ANNOTATIONS: u32 = 4;
+    /// `TrackEvent.type`
+    pub(super) const EVENT_TYPE: u32 = 9;
+    /// `TrackEvent.track_uuid`
+    pub(super) const EVENT_TRACK_UUID: u32 = 11;
+    /// `TrackEvent.name`
+    pub(super) const EVENT_NAME: u32 = 23;
+
+    /// `DebugAnnotation.string_value`
+    pub(super) const ANNOTATION_STRING_VALUE: u32 = 6;
+    /// `DebugAnnotation.name`
+    pub(super) const ANNOTATION_NAME: u32 = 10;
+
+    /// `TrackEvent.Type.TYPE_SLICE_BEGIN`
+    pub(super) const TYPE_SLICE_BEGIN: u64 = 1;
+    /// `TrackEvent.Type.TYPE_SLICE_END`
+    pub(super) const TYPE_SLICE_END: u64 = 2;
+
+    /// `TracePacket.SequenceFlags.SEQ_INCREMENTAL_STATE_CLEARED`
+    pub(super) const SEQ_INCREMENTAL_STATE_CLEARED: u64 = 1;
+}
+
+/// All packets are written by a single (synchronized) writer, and thus share a sequence.
+const SEQUENCE_ID: u64 = 1;
+
+/// The track of the process, which the thread tracks are nested under.
+const PROCESS_TRACK: u64 = 1;
+
+/// A protobuf message, encoded field by field.
+#[derive(Debug, Default)]
+struct Message(Vec<u8>);
+
+impl Message {
+    fn key(&mut self, field: u32, wire_type: u8) {
+        push_varint(&mut self.0, (u64::from(field) << 3) | u64::from(wire_type));
+    }
+
+    fn varint(mut self, field: u32, value: u64) -> Self {
+        self.key(field, 0);
+        push_varint(&mut self.0, value);
+        self
+    }
+
+    fn bytes(mut self, field: u32, value: &[u8]) -> Self {
+        self.key(field, 2);
+        push_varint(&mut self.0, u64::try_from(value.len()).unwrap_or(u64::MAX));
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    fn string(self, field: u32, value: &str) -> Self {
+        self.bytes(field, value.as_bytes())
+    }
+
+    fn message(self, field: u32, value: &Self) -> Self {
+        self.bytes(field, &value.0)
+    }
+}
+
+fn push_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push(value.to_le_bytes()[0] | 0x80);
+        value >>= 7;
+    }
+    buf.push(value.to_le_bytes()[0]);
+}
+
+/// The fields of a span, recorded as debug annotations.
+#[derive(Debug, Default)]
+struct Annotations(Vec<(&'static str, String)>);
+
+impl Visit for Annotations {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name(), value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push((field.name(), format!("{value:?}")));
+    }
+}
+
+struct State {
+    writer: BufWriter<fs_err::File>,
+    /// The track of each thread that entered a span.
+    threads: HashMap<ThreadId, u64>,
+    /// The UUID of the next track.
+    next_track: u64,
+    /// The first error encountered while writing, after which no further packets are written.
+    error: Option<io::Error>,
+}
+
+impl State {
+    fn write_packet(&mut self, packet: Message) {
+        if self.error.is_some() {
+            return;
+        }
+        // Every packet must have a sequence id.
+        let packet = packet.varint(proto::PACKET_SEQUENCE_ID, SEQUENCE_ID);
+        let trace = Message::default().message(proto::TRACE_PACKET, &packet);
+        if let Err(err) = self.writer.write_all(&trace.0) {
+            self.error = Some(err);
+        }
+    }
+
+    /// Return the track of the current thread, writing its descriptor on first use.
+    ///
+    /// Rust doesn't expose the OS thread ids, so rather than describing the tracks as threads
+    /// (which requires a thread id), each thread gets a named track nested under the process track.
+    fn thread_track(&mut self) -> u64 {
+        let thread = std::thread::current();
+        if let Some(track) = self.threads.get(&thread.id()) {
+            return *track;
+        }
+        let track = self.next_track;
+        self.next_track += 1;
+        self.threads.insert(thread.id(), track);
+
+        let name = thread.name().map_or_else(
+            || format!("thread-{}", self.threads.len()),
+            ToString::to_string,
+        );
+        let descriptor = Message::default()
+            .varint(proto::TRACK_UUID, track)
+            .varint(proto::TRACK_PARENT_UUID, PROCESS_TRACK)
+            .string(proto::TRACK_NAME, &name);
+        self.write_packet(Message::default().message(proto::PACKET_TRACK_DESCRIPTOR, &descriptor));
+        track
+    }
+}
+
+/// A [`Layer`] that streams each entry of a span to a Perfetto trace.
+pub(crate) struct PerfettoLayer {
+    start: Instant,
+    state: Arc<Mutex<State>>,
+}
+
+/// A handle to flush the trace once the command completes.
+pub(crate) struct PerfettoTrace(Arc<Mutex<State>>);
+
+impl PerfettoTrace {
+    /// Flush the trace, returning the first error encountered while writing it, if any.
+    pub(crate) fn finish(&self) -> io::Result<()> {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(err) = state.error.take() {
+            return Err(err);
+        }
+        state.writer.flush()
+    }
+}
+
+/// Create a layer streaming a Perfetto trace to the given file, along with a handle to flush it.
+pub(crate) fn perfetto_trace(path: &Path) -> io::Result<(PerfettoLayer, PerfettoTrace)> {
+    let mut state = State {
+        writer: BufWriter::new(fs_err::File::create(path)?),
+        threads: HashMap::new(),
+        next_track: PROCESS_TRACK + 1,
+        error: None,
+    };
+
+    let process = Message::default()
+        .varint(proto::PROCESS_PID, u64::from(std::process::id()))
+        .string(proto::PROCESS_NAME, "uv");
+    let descriptor = Message::default()
+        .varint(proto::TRACK_UUID, PROCESS_TRACK)
+        .message(proto::TRACK_PROCESS, &process);
+    state.write_packet(
+        Message::default()
+            .varint(
+                proto::PACKET_SEQUENCE_FLAGS,
+                proto::SEQ_INCREMENTAL_STATE_CLEARED,
+            )
+            .message(proto::PACKET_TRACK_DESCRIPTOR, &descriptor),
+    );
+    if let Some(err) = state.error.take() {
+        return Err(err);
+    }
+
+    let state = Arc::new(Mutex::new(state));
+    let layer = PerfettoLayer {
+        start: Instant::now(),
+        state: state.clone(),
+    };
+    Ok((layer, PerfettoTrace(state)))
+}
+
+impl PerfettoLayer {
+    fn write_event(&self, event: Message) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        // Take the timestamp while holding the lock, such that the timestamps of the sequence
+        // are monotonic.
+        let timestamp = u64::try_from(self.start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        let track = state.thread_track();
+        let event = event.varint(proto::EVENT_TRACK_UUID, track);
+        state.write_packet(
+            Message::default()
+                .varint(proto::PACKET_TIMESTAMP, timestamp)
+                .message(proto::PACKET_TRACK_EVENT, &event),
+        );
+    }
+}
+
+impl<S> Layer<S> for PerfettoLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut annotations = Annotations::default();
+        attrs.record(&mut annotations);
+        span.extensions_mut().insert(annotations);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        if let Some(annotations) = span.extensions_mut().get_mut::<Annotations>() {
+            values.record(annotations);
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut event = Message::default()
+            .varint(proto::EVENT_TYPE, proto::TYPE_SLICE_BEGIN)
+            .string(proto::EVENT_NAME, span.name());
+        if let Some(annotations) = span.extensions().get::<Annotations>() {
+            for (name, value) in &annotations.0 {
+                let annotation = Message::default()
+                    .string(proto::ANNOTATION_NAME, name)
+                    .string(proto::ANNOTATION_STRING_VALUE, value);
+                event = event.message(proto::EVENT_DEBUG_ANNOTATIONS, &annotation);
+            }
+        }
+        self.write_event(event);
+    }
+
+    fn on_exit(&self, _id: &Id, _ctx: Context<'_, S>) {
+        self.write_event(Message::default().varint(proto::EVENT_TYPE, proto::TYPE_SLICE_END));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use tracing::Dispatch;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::{Message, perfetto_trace, proto};
+
+    /// Decode the fields of a protobuf message, as `(field, value)` pairs, where the value is
+    /// either a varint or the bytes of a length-delimited field.
+    fn decode(mut buf: &[u8]) -> Vec<(u32, Result<u64, Vec<u8>>)> {
+        fn varint(buf: &mut &[u8]) -> u64 {
+            let mut value = 0;
+            let mut shift = 0;
+            loop {
+                let (byte, rest) = buf.split_first().unwrap();
+                *buf = rest;
+                value |= u64::from(byte & 0x7f) << shift;
+                if byte & 0x80 == 0 {
+                    return value;
+                }
+                shift += 7;
+            }
+        }
+
+        let mut fields = Vec::new();
+        while !buf.is_empty() {
+            let key = varint(&mut buf);
+            let field = u32::try_from(key >> 3).unwrap();
+            match key & 0x7 {
+                0 => fields.push((field, Ok(varint(&mut buf)))),
+                2 => {
+                    let len = usize::try_from(varint(&mut buf)).unwrap();
+                    let (bytes, rest) = buf.split_at(len);
+                    buf = rest;
+                    fields.push((field, Err(bytes.to_vec())));
+                }
+                wire_type => panic!("Unexpected wire type: {wire_type}"),
+            }
+        }
+        fields
+    }
+
+    fn get(fields: &[(u32, Result<u64, Vec<u8>>)], field: u32) -> Option<&Result<u64, Vec<u8>>> {
+        fields
+            .iter()
+            .find(|(number, _)| *number == field)
+            .map(|(_, value)| value)
+    }
+
+    #[test]
+    fn message_round_trip() {
+        let nested = Message::default().string(2, "nested");
+        let mut message = Message::default();
+        for (field, value) in [(1, 0), (2, 127), (3, 128), (4, 300), (16, u64::MAX)] {
+            message = message.varint(field, value);
+        }
+        let message = message
+            .string(5, "")
+            .string(6, &"x".repeat(200))
+            .message(7, &nested);
+
+        assert_eq!(
+            decode(&message.0),
+            [
+                (1, Ok(0)),
+                (2, Ok(127)),
+                (3, Ok(128)),
+                (4, Ok(300)),
+                (16, Ok(u64::MAX)),
+                (5, Err(Vec::new())),
+                (6, Err(vec![b'x'; 200])),
+                (7, Err(nested.0.clone())),
+            ]
+        );
+        assert_eq!(decode(&nested.0), [(2, Err(b"nested".to_vec()))]);
+    }
+
+    #[test]
+    fn slices() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("durations.pftrace");
+        let (layer, trace) = perfetto_trace(&path).unwrap();
+        let dispatch = Dispatch::new(tracing_subscriber::registry().with(layer));
+        tracing::dispatcher::with_default(&dispatch, || {
+            let solve = tracing::info_span!("solve");
+            drop(solve.enter());
+            let build = tracing::info_span!("build_wheel", package = "numpy");
+            // A span that is entered twice (e.g., an instrumented future) produces two slices.
+            drop(build.enter());
+            drop(build.enter());
+        });
+        // Spans entered on another thread are written to the track of that thread.
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                tracing::dispatcher::with_default(&dispatch, || {
+                    drop(tracing::info_span!("solve").entered());
+                });
+            });
+        });
+        trace.finish().unwrap();
+
+        let contents = fs_err::read(&path).unwrap();
+        let packets = decode(&contents);
+        assert!(
+            packets
+                .iter()
+                .all(|(field, _)| *field == proto::TRACE_PACKET)
+        );
+
+        let mut tracks = 0;
+        let mut begins = BTreeMap::<String, usize>::new();
+        let mut ends = 0;
+        let mut annotations = Vec::new();
+        let mut last_timestamp = 0;
+        for (_, packet) in &packets {
+            let packet = decode(packet.as_ref().unwrap_err());
+            assert_eq!(
+                get(&packet, proto::PACKET_SEQUENCE_ID),
+                Some(&Ok(super::SEQUENCE_ID))
+            );
+            if get(&packet, proto::PACKET_TRACK_DESCRIPTOR).is_some() {
+                tracks += 1;
+            }
+            let Some(Err(event)) = get(&packet, proto::PACKET_TRACK_EVENT) else {
+                continue;
+            };
+            let Some(Ok(timestamp)) = get(&packet, proto::PACKET_TIMESTAMP) else {
+                panic!("Track event without a timestamp");
+            };
+            assert!(*timestamp >= last_timestamp);
+            last_timestamp = *timestamp;
+
+            let event = decode(event);
+            match get(&event, proto::EVENT_TYPE) {
+                Some(Ok(proto::TYPE_SLICE_BEGIN)) => {
+                    let Some(Err(name)) = get(&event, proto::EVENT_NAME) else {
+                        panic!("Slice without a name");
+                    };
+                    *begins
+                        .entry(String::from_utf8(name.clone()).unwrap())
+                        .or_default() += 1;
+                    for (field, annotation) in &event {
+                        if *field != proto::EVENT_DEBUG_ANNOTATIONS {
+                            continue;
+                        }
+                        let annotation = decode(annotation.as_ref().unwrap_err());
+                        let Some(Err(name)) = get(&annotation, proto::ANNOTATION_NAME) else {
+                            panic!("Annotation without a name");
+                        };
+                        let Some(Err(value)) = get(&annotation, proto::ANNOTATION_STRING_VALUE)
+                        else {
+                            panic!("Annotation without a value");
+                        };
+                        annotations.push(format!(
+                            "{}={}",
+                            String::from_utf8_lossy(name),
+                            String::from_utf8_lossy(value)
+                        ));
+                    }
+                }
+                Some(Ok(proto::TYPE_SLICE_END)) => ends += 1,
+                other => panic!("Unexpected event type: {other:?}"),
+            }
+        }
+
+        // The process track and the tracks of both threads.
+        assert_eq!(tracks, 3);
+        assert_eq!(
+            begins,
+            BTreeMap::from([("build_wheel".to_string(), 2), ("solve".to_string(), 2)])
+        );
+        assert_eq!(ends, 4);
+        assert_eq!(annotations, ["package=numpy", "package=numpy"]);
+    }
+}
@@ -0,0 +1,172 @@
+//! The SVG plot of the recorded spans.
+//!
+//! The plot has a lane per span name. Since many spans (e.g., building a source distribution) are
+//! repeated for each package, the lanes of such spans are split by package, e.g.,
+//! `run_python_script: numpy`.
+
+use tracing_durations_export::plot::{OwnedSpanInfo, PlotConfig, PlotLayout, plot};
+
+use super::{PlotOptions, PlotTheme};
+use crate::logging::PACKAGE_FIELDS;
+
+/// Render the spans as an SVG plot.
+///
+/// Returns `None` if no spans were recorded.
+pub(crate) fn render_plot(spans: &[OwnedSpanInfo], options: &PlotOptions) -> Option<String> {
+    let end = spans.iter().map(|span| span.end).max()?;
+
+    // Remove spans by their name, before they're labeled with their package.
+    let spans = spans
+        .iter()
+        .filter(|span| !options.remove.contains(&span.name))
+        .map(|span| OwnedSpanInfo {
+            name: lane_label(span),
+            ..span.clone()
+        })
+        .collect::<Vec<_>>();
+    let config = PlotConfig {
+        remove: None,
+        ..PlotConfig::from(options.clone())
+    };
+    let default = PlotLayout::default();
+    let layout = PlotLayout {
+        content_col_width: options
+            .width
+            .saturating_sub(default.padding_left + default.text_col_width + default.padding_right),
+        bar_height: options.lane_height,
+        ..default
+    };
+
+    let svg = plot(&spans, end, &config, &layout);
+    let svg = match options.theme {
+        PlotTheme::Light => svg,
+        // Text is drawn with the inherited fill color, while the bars set their own colors.
+        PlotTheme::Dark => svg
+            .set("style", "background-color: #1e1e1e")
+            .set("fill", "#e6e6e6"),
+    };
+    Some(svg.to_string())
+}
+
+/// Determine the label of the lane of a span, i.e., the span name followed by the package it
+/// belongs to, if any.
+fn lane_label(span: &OwnedSpanInfo) -> String {
+    match package(span) {
+        Some(package) => format!("{}: {package}", span.name),
+        None => span.name.clone(),
+    }
+}
+
+/// Determine the package a span belongs to, if any.
+pub(super) fn package(span: &OwnedSpanInfo) -> Option<&str> {
+    span.fields.as_ref().and_then(|fields| {
+        PACKAGE_FIELDS
+            .iter()
+            .filter_map(|field| fields.get(*field))
+            .find(|value| !value.is_empty())
+            .map(String::as_str)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use tracing_durations_export::plot::OwnedSpanInfo;
+
+    use super::{lane_label, render_plot};
+    use crate::durations::{PlotOptions, PlotTheme};
+
+    fn span(id: u64, name: &str, fields: &[(&str, &str)]) -> OwnedSpanInfo {
+        OwnedSpanInfo {
+            id,
+            name: name.to_string(),
+            start: Duration::from_millis(id * 10),
+            end: Duration::from_millis(id * 10 + 5),
+            parents: None,
+            is_main_thread: true,
+            fields: Some(
+                fields
+                    .iter()
+                    .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+                    .collect::<HashMap<_, _>>(),
+            ),
+        }
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(
+            lane_label(&span(1, "run_python_script", &[("package", "numpy")])),
+            "run_python_script: numpy"
+        );
+        assert_eq!(
+            lane_label(&span(
+                1,
+                "from_simple_metadata",
+                &[("package_name", "scipy")]
+            )),
+            "from_simple_metadata: scipy"
+        );
+        // `package` takes precedence over the other fields.
+        assert_eq!(
+            lane_label(&span(
+                1,
+                "run_python_script",
+                &[("version_id", "numpy-2.0.0"), ("package", "numpy")]
+            )),
+            "run_python_script: numpy"
+        );
+        // Spans without a package fall back to their name.
+        assert_eq!(lane_label(&span(1, "solve", &[])), "solve");
+        assert_eq!(
+            lane_label(&span(1, "fetch", &[("url", "https://pypi.org/simple/")])),
+            "fetch"
+        );
+    }
+
+    #[test]
+    fn plot_lanes_are_labeled() {
+        let spans = [
+            span(1, "run_python_script", &[("package", "numpy")]),
+            span(2, "run_python_script", &[("package", "scipy")]),
+            span(3, "solve", &[]),
+            span(4, "get_cached_with_callback", &[("package", "numpy")]),
+        ];
+        let svg = render_plot(&spans, &PlotOptions::default()).unwrap();
+        assert!(svg.contains("run_python_script: numpy"), "{svg}");
+        assert!(svg.contains("run_python_script: scipy"), "{svg}");
+        assert!(svg.contains("solve"), "{svg}");
+        assert!(!svg.contains("solve:"), "{svg}");
+        // Removed spans are matched by their name, not their label.
+        assert!(!svg.contains("get_cached_with_callback"), "{svg}");
+    }
+
+    /// The configured dimensions are reflected in the attributes of the root element.
+    #[test]
+    fn dimensions() {
+        let spans = [span(1, "solve", &[])];
+
+        let svg = render_plot(&spans, &PlotOptions::default()).unwrap();
+        assert!(
+            svg.starts_with(r#"<svg height="70" viewBox="0 0 1110 70" width="1110""#),
+            "{svg}"
+        );
+
+        let options = PlotOptions {
+            width: 2000,
+            lane_height: 40,
+            theme: PlotTheme::Dark,
+            ..PlotOptions::default()
+        };
+        let svg = render_plot(&spans, &options).unwrap();
+        let root = svg.lines().next().unwrap();
+        insta::assert_snapshot!(root, @r##"<svg fill="#e6e6e6" height="110" style="background-color: #1e1e1e" viewBox="0 0 2000 110" width="2000" xmlns="http://www.w3.org/2000/svg">"##);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(render_plot(&[], &PlotOptions::default()), None);
+    }
+}
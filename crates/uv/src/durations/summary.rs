@@ -0,0 +1,667 @@
+//! A machine-readable summary of the recorded span durations, aggregated by span name, and by
+//! the host and package the spans belong to, along with the wall time of each `--timings` phase
+//! and the kinds of work (e.g., waiting on the network) it was spent on.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::Serialize;
+use tracing_durations_export::plot::OwnedSpanInfo;
+
+use super::plot;
+use crate::timings::{KINDS, PACKAGE_STEPS, PHASES, SpanKind};
+
+/// The summary of a single run, as written to the `.summary.json` file.
+#[derive(Debug, Serialize)]
+pub(crate) struct Summary {
+    /// The version of uv that recorded the spans.
+    uv_version: &'static str,
+    /// The command-line arguments of the run.
+    command: Vec<String>,
+    /// The aggregated durations, keyed by span name.
+    spans: BTreeMap<String, SpanSummary>,
+    /// The aggregated durations of the spans that talk to a remote host (e.g., fetching from an
+    /// index), keyed by host and then by span name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    hosts: BTreeMap<String, BTreeMap<String, SpanSummary>>,
+    /// The aggregated durations of the spans that belong to a package (e.g., building a source
+    /// distribution), keyed by span name and then by package.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    packages: BTreeMap<String, BTreeMap<String, SpanSummary>>,
+    /// The wall time spent in each phase reported by `--timings`, in seconds.
+    ///
+    /// Concurrent (or nested) spans of the same phase are only counted once.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    phases: BTreeMap<String, f64>,
+    /// The wall time spent on each kind of work (e.g., waiting on the network), in seconds, keyed
+    /// by phase and overall (`Total`).
+    ///
+    /// While spans of several kinds are open at once, the time is attributed to the first of
+    /// `network`, `disk` and `compute`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    kinds: BTreeMap<String, BTreeMap<&'static str, f64>>,
+    /// The time spent downloading, building and installing each package, in seconds, keyed by
+    /// package and then by step. Steps that didn't run for a package are omitted.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    package_steps: BTreeMap<String, BTreeMap<String, f64>>,
+}
+
+/// A span, spanning from its first entry to its last exit.
+struct FullSpan<'a> {
+    name: &'a str,
+    host: Option<&'a str>,
+    package: Option<&'a str>,
+    /// The kind of work, as an index into [`KINDS`].
+    kind: Option<usize>,
+    start: Duration,
+    end: Duration,
+}
+
+/// The aggregated durations of all spans with the same name, in seconds.
+#[derive(Debug, Serialize)]
+struct SpanSummary {
+    count: usize,
+    total: f64,
+    mean: f64,
+    p95: f64,
+    max: f64,
+}
+
+impl Summary {
+    /// Aggregate the given span records by name.
+    ///
+    /// A span that is entered multiple times (e.g., an instrumented future that is polled
+    /// repeatedly) produces a record per entry; its duration spans from its first entry to its
+    /// last exit.
+    pub(crate) fn from_spans(command: Vec<String>, spans: &[OwnedSpanInfo]) -> Self {
+        let mut full_spans: BTreeMap<u64, FullSpan> = BTreeMap::new();
+        for span in spans {
+            full_spans
+                .entry(span.id)
+                .and_modify(|full_span| {
+                    full_span.start = full_span.start.min(span.start);
+                    full_span.end = full_span.end.max(span.end);
+                })
+                .or_insert(FullSpan {
+                    name: span.name.as_str(),
+                    host: host(span),
+                    package: plot::package(span),
+                    kind: kind(span),
+                    start: span.start,
+                    end: span.end,
+                });
+        }
+
+        let phases = phases(&full_spans);
+        let kinds = kinds(&full_spans);
+        let package_steps = package_steps(&full_spans);
+
+        let mut durations: BTreeMap<&str, Vec<Duration>> = BTreeMap::new();
+        let mut host_durations: BTreeMap<&str, BTreeMap<&str, Vec<Duration>>> = BTreeMap::new();
+        let mut package_durations: BTreeMap<&str, BTreeMap<&str, Vec<Duration>>> = BTreeMap::new();
+        for span in full_spans.into_values() {
+            let duration = span.end.saturating_sub(span.start);
+            durations.entry(span.name).or_default().push(duration);
+            if let Some(host) = span.host {
+                host_durations
+                    .entry(host)
+                    .or_default()
+                    .entry(span.name)
+                    .or_default()
+                    .push(duration);
+            }
+            if let Some(package) = span.package {
+                package_durations
+                    .entry(span.name)
+                    .or_default()
+                    .entry(package)
+                    .or_default()
+                    .push(duration);
+            }
+        }
+
+        let spans = by_name(durations);
+        let hosts = host_durations
+            .into_iter()
+            .map(|(host, durations)| (host.to_string(), by_name(durations)))
+            .collect();
+        let packages = package_durations
+            .into_iter()
+            .map(|(name, durations)| (name.to_string(), by_name(durations)))
+            .collect();
+
+        Self {
+            uv_version: uv_version::version(),
+            command,
+            spans,
+            hosts,
+            packages,
+            phases,
+            kinds,
+            package_steps,
+        }
+    }
+}
+
+/// Determine the time spent in each step for each package, as reported by `--timings=packages`.
+///
+/// Unlike `--timings`, spans are matched by their name alone, as the target isn't recorded.
+fn package_steps(spans: &BTreeMap<u64, FullSpan>) -> BTreeMap<String, BTreeMap<String, f64>> {
+    let mut durations: BTreeMap<&str, BTreeMap<&str, Duration>> = BTreeMap::new();
+    for span in spans.values() {
+        let Some(package) = span.package else {
+            continue;
+        };
+        let Some((step, _)) = PACKAGE_STEPS
+            .iter()
+            .find(|(_, step_spans)| step_spans.iter().any(|(_, name)| *name == span.name))
+        else {
+            continue;
+        };
+        *durations
+            .entry(package)
+            .or_default()
+            .entry(step)
+            .or_default() += span.end.saturating_sub(span.start);
+    }
+    durations
+        .into_iter()
+        .map(|(package, steps)| {
+            let steps = steps
+                .into_iter()
+                .map(|(step, duration)| (step.to_lowercase(), duration.as_secs_f64()))
+                .collect();
+            (package.to_string(), steps)
+        })
+        .collect()
+}
+
+/// Determine the wall time of each phase, i.e., the time during which any span of the phase was
+/// open.
+///
+/// Unlike `--timings`, spans are matched by their name alone, as the target isn't recorded.
+fn phases(spans: &BTreeMap<u64, FullSpan>) -> BTreeMap<String, f64> {
+    let mut phases = BTreeMap::new();
+    for (phase, phase_spans) in PHASES {
+        let mut intervals = spans
+            .values()
+            .filter(|span| phase_spans.iter().any(|(_, name)| *name == span.name))
+            .map(|span| (span.start, span.end))
+            .collect::<Vec<_>>();
+        if intervals.is_empty() {
+            continue;
+        }
+        intervals.sort_unstable();
+
+        let mut total = Duration::ZERO;
+        let mut current: Option<(Duration, Duration)> = None;
+        for (start, end) in intervals {
+            match &mut current {
+                Some((_, current_end)) if start <= *current_end => {
+                    *current_end = (*current_end).max(end);
+                }
+                _ => {
+                    if let Some((current_start, current_end)) = current {
+                        total += current_end.saturating_sub(current_start);
+                    }
+                    current = Some((start, end));
+                }
+            }
+        }
+        if let Some((current_start, current_end)) = current {
+            total += current_end.saturating_sub(current_start);
+        }
+        phases.insert((*phase).to_string(), total.as_secs_f64());
+    }
+    phases
+}
+
+/// Determine the wall time spent on each kind of work, for each phase and overall.
+///
+/// The spans are swept in order of their start and end, attributing the time between two
+/// consecutive boundaries to the kind of work that was ongoing.
+fn kinds(spans: &BTreeMap<u64, FullSpan>) -> BTreeMap<String, BTreeMap<&'static str, f64>> {
+    /// A change to the open spans.
+    #[derive(Clone, Copy)]
+    enum Boundary {
+        Phase(usize),
+        Kind(usize),
+    }
+
+    let mut boundaries = Vec::new();
+    for span in spans.values() {
+        let phase = PHASES
+            .iter()
+            .position(|(_, phase_spans)| phase_spans.iter().any(|(_, name)| *name == span.name));
+        for boundary in phase
+            .map(Boundary::Phase)
+            .into_iter()
+            .chain(span.kind.map(Boundary::Kind))
+        {
+            boundaries.push((span.start, true, boundary));
+            boundaries.push((span.end, false, boundary));
+        }
+    }
+    if !boundaries
+        .iter()
+        .any(|(.., boundary)| matches!(boundary, Boundary::Kind(_)))
+    {
+        return BTreeMap::new();
+    }
+    boundaries.sort_by_key(|(time, ..)| *time);
+
+    let mut phases_open = [0usize; PHASES.len()];
+    let mut phases_seen = [false; PHASES.len()];
+    let mut kinds_open = [0usize; KINDS.len()];
+    let mut phase_kinds = [[Duration::ZERO; KINDS.len()]; PHASES.len()];
+    let mut total_kinds = [Duration::ZERO; KINDS.len()];
+    let mut since = Duration::ZERO;
+    for (time, open, boundary) in boundaries {
+        if let Some(kind) = kinds_open.iter().position(|open| *open > 0) {
+            let elapsed = time.saturating_sub(since);
+            total_kinds[kind] += elapsed;
+            for (phase_open, kinds) in phases_open.iter().zip(&mut phase_kinds) {
+                if *phase_open > 0 {
+                    kinds[kind] += elapsed;
+                }
+            }
+        }
+        since = time;
+
+        let count = match boundary {
+            Boundary::Phase(phase) => {
+                phases_seen[phase] = true;
+                &mut phases_open[phase]
+            }
+            Boundary::Kind(kind) => &mut kinds_open[kind],
+        };
+        if open {
+            *count += 1;
+        } else {
+            *count = count.saturating_sub(1);
+        }
+    }
+
+    let by_kind = |durations: &[Duration; KINDS.len()]| {
+        KINDS
+            .iter()
+            .zip(durations)
+            .map(|(kind, duration)| (*kind, duration.as_secs_f64()))
+            .collect::<BTreeMap<_, _>>()
+    };
+    PHASES
+        .iter()
+        .zip(phases_seen.iter().zip(&phase_kinds))
+        .filter(|(_, (seen, _))| **seen)
+        .map(|((phase, _), (_, durations))| ((*phase).to_string(), by_kind(durations)))
+        .chain(std::iter::once((
+            "Total".to_string(),
+            by_kind(&total_kinds),
+        )))
+        .collect()
+}
+
+/// The kind of work of a span, as recorded in its `kind` field.
+fn kind(span: &OwnedSpanInfo) -> Option<usize> {
+    span.fields
+        .as_ref()
+        .and_then(|fields| fields.get("kind"))
+        .and_then(|kind| SpanKind::from_str(kind).index())
+}
+
+/// The host a span talks to, if any, as recorded in its `host` field.
+fn host(span: &OwnedSpanInfo) -> Option<&str> {
+    span.fields
+        .as_ref()
+        .and_then(|fields| fields.get("host"))
+        .map(String::as_str)
+        .filter(|host| !host.is_empty())
+}
+
+/// Aggregate the durations of each key, e.g., each span name.
+fn by_name(durations: BTreeMap<&str, Vec<Duration>>) -> BTreeMap<String, SpanSummary> {
+    durations
+        .into_iter()
+        .map(|(name, durations)| (name.to_string(), SpanSummary::from_durations(durations)))
+        .collect()
+}
+
+impl SpanSummary {
+    fn from_durations(mut durations: Vec<Duration>) -> Self {
+        durations.sort_unstable();
+        let count = durations.len();
+        let total = durations.iter().sum::<Duration>();
+        // Use the nearest-rank method, such that the percentile is always a recorded duration.
+        let p95 = durations
+            .get((count * 95).div_ceil(100).saturating_sub(1))
+            .copied()
+            .unwrap_or_default();
+        let max = durations.last().copied().unwrap_or_default();
+        let mean = u32::try_from(count)
+            .ok()
+            .and_then(|count| total.checked_div(count))
+            .unwrap_or_default();
+        Self {
+            count,
+            total: total.as_secs_f64(),
+            mean: mean.as_secs_f64(),
+            p95: p95.as_secs_f64(),
+            max: max.as_secs_f64(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tracing_durations_export::plot::OwnedSpanInfo;
+
+    use super::Summary;
+
+    fn span(id: u64, name: &str, start: u64, end: u64) -> OwnedSpanInfo {
+        OwnedSpanInfo {
+            id,
+            name: name.to_string(),
+            start: Duration::from_millis(start),
+            end: Duration::from_millis(end),
+            parents: None,
+            is_main_thread: true,
+            fields: None,
+        }
+    }
+
+    fn command() -> Vec<String> {
+        vec!["uv".to_string(), "lock".to_string()]
+    }
+
+    #[test]
+    fn summary_schema() {
+        let spans = [
+            span(1, "resolve", 0, 100),
+            // A span entered twice is counted once, from its first entry to its last exit.
+            span(2, "build_wheel", 10, 20),
+            span(2, "build_wheel", 30, 60),
+            span(3, "build_wheel", 60, 70),
+        ];
+        let summary = Summary::from_spans(command(), &spans);
+        insta::with_settings!({
+            filters => vec![(r#""uv_version": "[^"]+""#, r#""uv_version": "[VERSION]""#)]
+        }, {
+            insta::assert_snapshot!(serde_json::to_string_pretty(&summary).unwrap(), @r#"
+            {
+              "uv_version": "[VERSION]",
+              "command": [
+                "uv",
+                "lock"
+              ],
+              "spans": {
+                "build_wheel": {
+                  "count": 2,
+                  "total": 0.06,
+                  "mean": 0.03,
+                  "p95": 0.05,
+                  "max": 0.05
+                },
+                "resolve": {
+                  "count": 1,
+                  "total": 0.1,
+                  "mean": 0.1,
+                  "p95": 0.1,
+                  "max": 0.1
+                }
+              }
+            }
+            "#);
+        });
+    }
+
+    /// Spans with a `host` field are additionally aggregated by host.
+    #[test]
+    fn hosts() {
+        let with_host = |id, name, start, end, host: &str| OwnedSpanInfo {
+            fields: Some(
+                [("host".to_string(), host.to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..span(id, name, start, end)
+        };
+        let spans = [
+            span(1, "resolve", 0, 100),
+            with_host(
+                2,
+                "simple_detail_single_index",
+                0,
+                10,
+                "https://one.example.com",
+            ),
+            with_host(
+                3,
+                "simple_detail_single_index",
+                10,
+                40,
+                "https://two.example.com",
+            ),
+            with_host(4, "wheel", 40, 60, "https://two.example.com"),
+        ];
+        let summary = Summary::from_spans(command(), &spans);
+        let hosts = serde_json::to_value(&summary).unwrap()["hosts"].clone();
+        insta::assert_snapshot!(serde_json::to_string_pretty(&hosts).unwrap(), @r#"
+        {
+          "https://one.example.com": {
+            "simple_detail_single_index": {
+              "count": 1,
+              "max": 0.01,
+              "mean": 0.01,
+              "p95": 0.01,
+              "total": 0.01
+            }
+          },
+          "https://two.example.com": {
+            "simple_detail_single_index": {
+              "count": 1,
+              "max": 0.03,
+              "mean": 0.03,
+              "p95": 0.03,
+              "total": 0.03
+            },
+            "wheel": {
+              "count": 1,
+              "max": 0.02,
+              "mean": 0.02,
+              "p95": 0.02,
+              "total": 0.02
+            }
+          }
+        }
+        "#);
+    }
+
+    /// Spans with a package field are additionally aggregated by span name and package.
+    #[test]
+    fn packages() {
+        let with_package = |id, name, start, end, package: &str| OwnedSpanInfo {
+            fields: Some(
+                [("package".to_string(), package.to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..span(id, name, start, end)
+        };
+        let spans = [
+            span(1, "resolve", 0, 100),
+            with_package(2, "build_wheel", 0, 10, "numpy"),
+            with_package(3, "build_wheel", 10, 40, "numpy"),
+            with_package(4, "build_wheel", 40, 60, "scipy"),
+        ];
+        let summary = Summary::from_spans(command(), &spans);
+        let packages = serde_json::to_value(&summary).unwrap()["packages"].clone();
+        insta::assert_snapshot!(serde_json::to_string_pretty(&packages).unwrap(), @r#"
+        {
+          "build_wheel": {
+            "numpy": {
+              "count": 2,
+              "max": 0.03,
+              "mean": 0.02,
+              "p95": 0.03,
+              "total": 0.04
+            },
+            "scipy": {
+              "count": 1,
+              "max": 0.02,
+              "mean": 0.02,
+              "p95": 0.02,
+              "total": 0.02
+            }
+          }
+        }
+        "#);
+    }
+
+    /// Summaries of different runs share the same structure, such that they can be compared.
+    #[test]
+    fn summaries_are_comparable() {
+        /// Replace all values with their type, retaining the keys of objects.
+        fn structure(value: &serde_json::Value) -> serde_json::Value {
+            match value {
+                serde_json::Value::Object(map) => map
+                    .iter()
+                    .map(|(key, value)| (key.clone(), structure(value)))
+                    .collect(),
+                serde_json::Value::Array(_) => "array".into(),
+                serde_json::Value::Number(_) => "number".into(),
+                serde_json::Value::String(_) => "string".into(),
+                serde_json::Value::Bool(_) => "bool".into(),
+                serde_json::Value::Null => "null".into(),
+            }
+        }
+
+        let first = Summary::from_spans(
+            command(),
+            &[span(1, "resolve", 0, 100), span(2, "download", 5, 15)],
+        );
+        let second = Summary::from_spans(
+            command(),
+            &[
+                span(7, "resolve", 0, 250),
+                span(8, "download", 10, 30),
+                span(9, "download", 30, 40),
+            ],
+        );
+        assert_eq!(
+            structure(&serde_json::to_value(&first).unwrap()),
+            structure(&serde_json::to_value(&second).unwrap())
+        );
+    }
+
+    /// Overlapping spans of a phase are merged, such that nested spans aren't counted twice.
+    #[test]
+    fn phases() {
+        let summary = Summary::from_spans(
+            command(),
+            &[
+                span(1, "solve", 0, 100),
+                span(2, "install", 100, 150),
+                span(3, "install", 110, 140),
+                span(4, "install_blocking", 145, 170),
+                span(5, "install", 200, 210),
+                span(6, "download", 0, 50),
+            ],
+        );
+        insta::assert_snapshot!(serde_json::to_string_pretty(&summary.phases).unwrap(), @r#"
+        {
+          "Installation": 0.08,
+          "Resolution": 0.1
+        }
+        "#);
+    }
+
+    /// The time of each phase is split by the kind of the spans that are open, with network
+    /// requests taking precedence over the solver.
+    #[test]
+    fn kinds() {
+        let with_kind = |id, name, start, end, kind: &str| OwnedSpanInfo {
+            fields: Some(
+                [("kind".to_string(), kind.to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..span(id, name, start, end)
+        };
+        let summary = Summary::from_spans(
+            command(),
+            &[
+                with_kind(1, "solve", 0, 100, "compute"),
+                with_kind(2, "fresh_request", 10, 40, "network"),
+                with_kind(3, "fresh_request", 30, 60, "network"),
+                with_kind(4, "read_and_parse_cache", 70, 80, "disk"),
+                span(5, "install", 100, 150),
+                with_kind(6, "install", 110, 130, "disk"),
+                // Requests outside of any phase only count towards the total.
+                with_kind(7, "fresh_request", 200, 210, "network"),
+            ],
+        );
+        insta::assert_snapshot!(serde_json::to_string_pretty(&summary.kinds).unwrap(), @r#"
+        {
+          "Installation": {
+            "compute": 0.0,
+            "disk": 0.02,
+            "network": 0.0
+          },
+          "Resolution": {
+            "compute": 0.04,
+            "disk": 0.01,
+            "network": 0.05
+          },
+          "Total": {
+            "compute": 0.04,
+            "disk": 0.03,
+            "network": 0.06
+          }
+        }
+        "#);
+
+        // Without any tagged spans, the split is omitted.
+        let summary = Summary::from_spans(command(), &[span(1, "solve", 0, 100)]);
+        assert!(summary.kinds.is_empty());
+    }
+
+    /// The download, build and install spans are aggregated per package.
+    #[test]
+    fn package_steps() {
+        let with_package = |id, name, start, end, package: &str| OwnedSpanInfo {
+            fields: Some(
+                [("package".to_string(), package.to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..span(id, name, start, end)
+        };
+        let summary = Summary::from_spans(
+            command(),
+            &[
+                with_package(1, "wheel", 0, 100, "iniconfig"),
+                with_package(2, "install_wheel", 200, 210, "iniconfig"),
+                with_package(3, "download", 0, 50, "source-distribution"),
+                with_package(4, "build_distribution", 50, 150, "source-distribution"),
+                with_package(5, "install_wheel", 200, 205, "source-distribution"),
+                // Spans of other steps aren't included.
+                with_package(6, "run_python_script", 60, 140, "source-distribution"),
+            ],
+        );
+        insta::assert_snapshot!(serde_json::to_string_pretty(&summary.package_steps).unwrap(), @r#"
+        {
+          "iniconfig": {
+            "download": 0.1,
+            "install": 0.01
+          },
+          "source-distribution": {
+            "build": 0.1,
+            "download": 0.05,
+            "install": 0.005
+          }
+        }
+        "#);
+    }
+}
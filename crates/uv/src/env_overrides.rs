@@ -0,0 +1,217 @@
+//! The uv environment variables that override a default, logged once at startup.
+//!
+//! The variables are read from the documented [`EnvVars`], such that newly added variables are
+//! covered without further changes. Each value is compared against the default of the
+//! command-line argument or setting that the variable configures, where it's known, and
+//! credentials in the values are redacted before logging.
+
+use std::env;
+use std::ffi::OsStr;
+use std::fmt::{Display, Formatter};
+
+use clap::{Arg, Command, CommandFactory};
+use tracing::debug;
+
+use uv_cli::Cli;
+use uv_options_metadata::{OptionEntry, OptionsMetadata};
+use uv_redacted::DisplaySafeUrl;
+use uv_settings::Options;
+use uv_static::EnvVars;
+
+/// Log the uv environment variables that are set to a non-default value, along with the setting
+/// that each of them overrides.
+pub(crate) fn log_env_overrides() {
+    // Building the command-line interface to find the defaults isn't free, so avoid it unless the
+    // overrides are logged.
+    if !tracing::enabled!(tracing::Level::DEBUG) {
+        return;
+    }
+    let mut command = Cli::command();
+    command.build();
+
+    let overrides = EnvVars::metadata()
+        .iter()
+        .filter(|(var, ..)| var.starts_with("UV_"))
+        .filter_map(|(var, doc, _)| {
+            let value = env::var_os(var).filter(|value| !value.is_empty())?;
+            let value = value.to_string_lossy();
+            let setting = Setting::find(&command, var, doc);
+            if setting
+                .default
+                .as_deref()
+                .is_some_and(|default| is_default(&value, default))
+            {
+                return None;
+            }
+            Some(format!("`{var}={}` ({setting})", redact(var, &value)))
+        })
+        .collect::<Vec<_>>();
+    if overrides.is_empty() {
+        return;
+    }
+    debug!(
+        "Environment variables overriding defaults: {}",
+        overrides.join(", ")
+    );
+}
+
+/// The setting that an environment variable configures.
+#[derive(Debug)]
+struct Setting {
+    /// The name of the setting, e.g., `--resolution` or `python-downloads`, or a description of
+    /// what the variable affects if it doesn't correspond to an argument or setting.
+    name: SettingName,
+    /// The default value of the setting, if known.
+    default: Option<String>,
+}
+
+#[derive(Debug)]
+enum SettingName {
+    /// A command-line argument, e.g., `--resolution`.
+    Argument(String),
+    /// A setting in `pyproject.toml` or `uv.toml`, e.g., `python-downloads`.
+    Setting(String),
+    /// The first sentence of the variable's documentation.
+    Description(String),
+}
+
+impl Setting {
+    /// Find the setting configured by the environment variable `var`, with documentation `doc`.
+    ///
+    /// The command-line argument that reads the variable takes precedence, followed by the
+    /// argument or setting that the documentation names as its equivalent.
+    fn find(command: &Command, var: &str, doc: &str) -> Self {
+        if let Some(arg) = find_arg(command, var)
+            && let Some(long) = arg.get_long()
+        {
+            let default = arg
+                .get_default_values()
+                .first()
+                .map(|default| default.to_string_lossy().into_owned())
+                .or_else(|| setting_default(long));
+            return Self {
+                name: SettingName::Argument(format!("--{long}")),
+                default,
+            };
+        }
+        if let Some(rest) = doc.strip_prefix("Equivalent to the `--")
+            && let Some((long, _)) = rest.split_once('`')
+        {
+            return Self {
+                name: SettingName::Argument(format!("--{long}")),
+                default: setting_default(long),
+            };
+        }
+        if let Some((_, rest)) = doc.split_once("[`")
+            && let Some((name, rest)) = rest.split_once('`')
+            && rest.starts_with("](../reference/settings.md")
+        {
+            return Self {
+                name: SettingName::Setting(name.to_string()),
+                default: setting_default(name),
+            };
+        }
+        Self {
+            name: SettingName::Description(description(doc)),
+            default: documented_default(doc),
+        }
+    }
+}
+
+impl Display for Setting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            SettingName::Argument(name) => write!(f, "overrides `{name}`")?,
+            SettingName::Setting(name) => write!(f, "overrides the `{name}` setting")?,
+            SettingName::Description(description) => write!(f, "{description}")?,
+        }
+        if let Some(default) = &self.default {
+            write!(f, ", default `{default}`")?;
+        }
+        Ok(())
+    }
+}
+
+/// Find the command-line argument that reads the environment variable `var`, in any subcommand.
+fn find_arg<'a>(command: &'a Command, var: &str) -> Option<&'a Arg> {
+    command
+        .get_arguments()
+        .find(|arg| arg.get_env() == Some(OsStr::new(var)))
+        .or_else(|| {
+            command
+                .get_subcommands()
+                .find_map(|subcommand| find_arg(subcommand, var))
+        })
+}
+
+/// Returns the default value of the setting with the given name, e.g., `python-downloads`, if
+/// there is one.
+fn setting_default(name: &str) -> Option<String> {
+    let Some(OptionEntry::Field(field)) = Options::metadata().find(name) else {
+        return None;
+    };
+    let default = field.default.trim_matches('"');
+    if matches!(default, "None" | "null" | "[]" | "{}") {
+        return None;
+    }
+    Some(default.to_string())
+}
+
+/// Returns the default value stated in the documentation of an environment variable, e.g.,
+/// `30` for a variable documented with "(default: 30 s)".
+fn documented_default(doc: &str) -> Option<String> {
+    let (_, rest) = doc
+        .split_once("(default: ")
+        .or_else(|| doc.split_once("Defaults to "))?;
+    let default = rest.split_whitespace().next()?;
+    let default = default.trim_end_matches([')', '.', ',']).trim_matches('`');
+    (!default.is_empty()).then(|| default.to_string())
+}
+
+/// Returns `true` if the value of an environment variable matches the default, treating the
+/// various spellings of booleans as equivalent.
+fn is_default(value: &str, default: &str) -> bool {
+    fn normalize(value: &str) -> String {
+        let value = value.trim().to_ascii_lowercase();
+        match value.as_str() {
+            "1" | "true" | "yes" | "y" | "on" => "true".to_string(),
+            "0" | "false" | "no" | "n" | "off" => "false".to_string(),
+            _ => value,
+        }
+    }
+    normalize(value) == normalize(default)
+}
+
+/// Redact the value of an environment variable, hiding secrets entirely and the credentials in any
+/// URLs.
+fn redact(var: &str, value: &str) -> String {
+    if ["PASSWORD", "TOKEN", "SECRET"]
+        .iter()
+        .any(|secret| var.contains(secret))
+    {
+        return "****".to_string();
+    }
+    value
+        .split(' ')
+        .map(|part| match DisplaySafeUrl::parse(part) {
+            Ok(url) if !url.username().is_empty() || url.password().is_some() => url.to_string(),
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Describe what an environment variable affects using the first sentence of its documentation,
+/// e.g., "use to disable line wrapping for diagnostics".
+fn description(doc: &str) -> String {
+    let sentence = doc
+        .split_once(". ")
+        .map_or(doc, |(sentence, _)| sentence)
+        .trim_end_matches('.')
+        .replace('\n', " ");
+    let mut chars = sentence.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,45 @@
+//! A process-wide Ctrl-C handler that runs cleanup hooks before exiting.
+//!
+//! Only a single Ctrl-C handler can be installed per process, so any cleanup that needs to happen
+//! on interrupt (e.g., reverting an edited `pyproject.toml`, or flushing the durations export) is
+//! registered here instead.
+
+use std::sync::{Mutex, Once, PoisonError};
+
+use tracing::debug;
+
+type Hook = Box<dyn FnOnce() + Send>;
+
+static HOOKS: Mutex<Vec<Hook>> = Mutex::new(Vec::new());
+static INSTALL: Once = Once::new();
+
+/// Run the given hook if the process is interrupted via Ctrl-C, before exiting.
+///
+/// Hooks run in the reverse order of their registration. Once a hook has been registered, Ctrl-C
+/// terminates the process after running the hooks, so hooks must not be registered by commands
+/// that forward Ctrl-C to a child process (e.g., `uv run`).
+pub(crate) fn on_interrupt(hook: impl FnOnce() + Send + 'static) {
+    HOOKS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(Box::new(hook));
+    INSTALL.call_once(|| {
+        if let Err(err) = ctrlc::set_handler(interrupted) {
+            debug!("Failed to install Ctrl-C handler: {err}");
+        }
+    });
+}
+
+fn interrupted() {
+    let hooks = std::mem::take(&mut *HOOKS.lock().unwrap_or_else(PoisonError::into_inner));
+    for hook in hooks.into_iter().rev() {
+        hook();
+    }
+
+    #[expect(clippy::exit, clippy::cast_possible_wrap)]
+    std::process::exit(if cfg!(windows) {
+        0xC000_013A_u32 as i32
+    } else {
+        130
+    });
+}
@@ -23,20 +23,21 @@ use tracing::{debug, instrument, trace};
 
 #[cfg(not(feature = "self-update"))]
 use crate::install_source::InstallSource;
-use uv_cache::{Cache, Refresh};
+use uv_cache::{Cache, CacheUsage, Refresh};
 use uv_cache_info::Timestamp;
 #[cfg(feature = "self-update")]
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
     CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
-    PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
-    WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
+    PythonNamespace, SelfCommand, SelfNamespace, TimingsMode, ToolCommand, ToolNamespace,
+    TopLevelArgs, WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
 };
-use uv_client::BaseClientBuilder;
+use uv_client::{BaseClientBuilder, InsecureHosts};
 use uv_configuration::min_stack_size;
 use uv_flags::EnvironmentFlags;
 use uv_fs::{CWD, Simplified};
+use uv_install_wheel::LinkUsage;
 #[cfg(feature = "self-update")]
 use uv_pep440::release_specifiers_to_ranges;
 use uv_pep508::VersionOrUrl;
@@ -46,7 +47,10 @@ use uv_python::PythonRequest;
 use uv_requirements::{GroupsSpecification, RequirementsSource};
 use uv_requirements_txt::RequirementsTxtRequirement;
 use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script};
-use uv_settings::{Combine, EnvironmentOptions, FilesystemOptions, Options};
+use uv_settings::{
+    Combine, EnvironmentOptions, FilesystemOptions, Options, log_config_discovery,
+    validate_index_urls,
+};
 use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
@@ -61,11 +65,17 @@ use crate::settings::{
 
 pub(crate) mod child;
 pub(crate) mod commands;
+#[cfg(feature = "tracing-durations-export")]
+mod durations;
+mod env_overrides;
 #[cfg(not(feature = "self-update"))]
 mod install_source;
+mod interrupt;
 pub(crate) mod logging;
 pub(crate) mod printer;
 pub(crate) mod settings;
+mod timings;
+mod watchdog;
 #[cfg(windows)]
 mod windows_exception;
 
@@ -101,6 +111,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
     // Load environment variables not handled by Clap
     let environment = EnvironmentOptions::new()?;
+    commands::set_error_format(environment.error_format);
 
     // The `--isolated` argument is deprecated on preview APIs, and warns on non-preview APIs.
     let deprecated_isolated = if cli.top_level.global_args.isolated {
@@ -329,7 +340,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .and_then(|tool| tool.uv.as_ref())
         .map(|uv| Options::simple(uv.globals.clone(), uv.top_level.clone()))
         .map(FilesystemOptions::from)
-        .combine(filesystem);
+        .combine(filesystem)
+        .map(FilesystemOptions::dedup_indexes)
+        .transpose()?;
 
     // Resolve the global settings.
     let globals = GlobalSettings::resolve(
@@ -338,6 +351,21 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         &environment,
     );
 
+    // Validate the index URLs for commands that use them, before any requests are made.
+    if let Some(index_urls) = cli.command.index_urls() {
+        validate_index_urls(
+            index_urls
+                .iter()
+                .chain(filesystem.iter().flat_map(FilesystemOptions::index_urls)),
+            &globals.network_settings.allow_insecure_host,
+        )?;
+    }
+
+    // Write the state of each fork once a resolution completes, if requested.
+    if let Some(path) = cli.command.resolver_dump() {
+        commands::pip::operations::dump_resolver_state(path);
+    }
+
     // Adjust open file limits on Unix if the preview feature is enabled.
     #[cfg(unix)]
     if globals.preview.is_enabled(PreviewFeature::AdjustUlimit) {
@@ -395,24 +423,6 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         }
     }
 
-    // Configure the `tracing` crate, which controls internal logging.
-    #[cfg(feature = "tracing-durations-export")]
-    let (durations_layer, _duration_guard) =
-        logging::setup_durations(environment.tracing_durations_file.as_ref())?;
-    #[cfg(not(feature = "tracing-durations-export"))]
-    let durations_layer = None::<tracing_subscriber::layer::Identity>;
-    logging::setup_logging(
-        match globals.verbose {
-            0 => logging::Level::Off,
-            1 => logging::Level::DebugUv,
-            2 => logging::Level::TraceUv,
-            3.. => logging::Level::TraceAll,
-        },
-        durations_layer,
-        globals.color,
-        environment.log_context.unwrap_or_default(),
-    )?;
-
     // Configure the `Printer`, which controls user-facing output in the CLI.
     let printer = if globals.quiet == 1 {
         Printer::Quiet
@@ -426,6 +436,102 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         Printer::Default
     };
 
+    // Configure the `tracing` crate, which controls internal logging.
+    #[cfg(feature = "tracing-durations-export")]
+    let (durations_layer, duration_guard) = {
+        // The `--durations-file` flag takes precedence over the environment variable, either of
+        // which takes precedence over the generated location of `--durations`.
+        let cache_location = if cli.top_level.global_args.durations
+            && cli.top_level.global_args.durations_file.is_none()
+            && environment.tracing_durations_file.is_none()
+        {
+            // Use the persistent cache directory, even with `--no-cache`.
+            let cache = Cache::from_settings(false, cache_settings.cache_dir.clone())?;
+            Some(durations::cache_location(cache.root(), cli.command.name()))
+        } else {
+            None
+        };
+        let durations_file = cli
+            .top_level
+            .global_args
+            .durations_file
+            .as_deref()
+            .map(|path| (path, durations::DurationsSource::Flag))
+            .or_else(|| {
+                environment
+                    .tracing_durations_file
+                    .as_deref()
+                    .map(|path| (path, durations::DurationsSource::EnvVar))
+            })
+            .or_else(|| {
+                cache_location
+                    .as_deref()
+                    .map(|path| (path, durations::DurationsSource::Cache))
+            });
+        let args = durations::DurationsArgs {
+            remove: cli.top_level.global_args.durations_remove.clone(),
+            remove_extra: cli.top_level.global_args.durations_remove_extra.clone(),
+            keep_all: cli.top_level.global_args.durations_keep_all,
+            formats: cli.top_level.global_args.durations_format.clone(),
+            fold_fields: cli.top_level.global_args.durations_fold_fields,
+            log: cli.top_level.global_args.durations_log,
+        };
+        // Commands that run a child process forward Ctrl-C to it and exit once it does, such that
+        // the files are written as usual. Exiting on Ctrl-C would cut the child short.
+        let forwards_interrupt = match &*cli.command {
+            Commands::Tool(ToolNamespace {
+                command: ToolCommand::Uvx(_) | ToolCommand::Run(_),
+            }) => true,
+            Commands::Project(command) => matches!(**command, ProjectCommand::Run(_)),
+            _ => false,
+        };
+        durations::setup_durations(durations_file, args, !forwards_interrupt, printer)?
+    };
+    #[cfg(not(feature = "tracing-durations-export"))]
+    let durations_layer = None::<tracing_subscriber::layer::Identity>;
+    let timings = cli
+        .top_level
+        .global_args
+        .timings
+        .map(|mode| timings::Timings::new(globals.verbose > 0, mode == TimingsMode::Packages));
+    let command_level = filesystem.as_ref().and_then(|filesystem| {
+        let command = cli.command.name();
+        filesystem
+            .logging
+            .as_ref()
+            .and_then(|logging| logging.commands.as_ref())
+            .and_then(|commands| commands.get(command))
+            .map(|&level| logging::CommandLevel {
+                command,
+                level,
+                source: filesystem.log_level_source(command),
+            })
+    });
+    logging::setup_logging(
+        globals.verbose,
+        globals.quiet,
+        command_level,
+        durations_layer,
+        timings.as_ref().map(timings::Timings::layer),
+        cli.top_level
+            .global_args
+            .slow_op_threshold
+            .map(watchdog::WatchdogLayer::new),
+        globals.color,
+        environment.log_context.unwrap_or_default(),
+    )?;
+    let _timings_guard = timings.map(|timings| timings::TimingsGuard::new(timings, printer));
+    // Log the usage of the cache and of each link mode, and warn about any hosts that were
+    // contacted without verifying their certificates, once the command completes, before the
+    // timings are printed.
+    let _cache_usage = CacheUsage::track();
+    let _link_usage = LinkUsage::track();
+    let _insecure_hosts = InsecureHosts::track();
+    #[cfg(feature = "tracing-durations-export")]
+    if let Some(duration_guard) = &duration_guard {
+        duration_guard.log_options();
+    }
+
     // Configure the `warn!` macros, which control user-facing warnings in the CLI.
     if globals.quiet > 0 {
         uv_warnings::disable();
@@ -454,6 +560,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     uv_configuration::RAYON_PARALLELISM.store(globals.concurrency.installs, Ordering::Relaxed);
 
     debug!("uv {}", uv_cli::version::uv_self_version());
+    // Settings are discovered before logging is initialized, so log the configuration files that
+    // were considered (and which of them won) now.
+    log_config_discovery();
+    if let Some(filesystem) = filesystem.as_ref() {
+        filesystem.log_precedence();
+    }
+    env_overrides::log_env_overrides();
 
     // Write out any resolved settings.
     macro_rules! show_settings {
@@ -638,6 +751,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.resolution,
                 args.settings.prerelease,
                 args.settings.fork_strategy,
+                args.settings.index_conflict_strategy,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
@@ -652,10 +766,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.emit_build_options,
                 args.settings.emit_marker_expression,
                 args.settings.emit_index_annotation,
+                args.settings.annotate_index,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
                 args.settings.dependency_metadata,
+                args.settings.allowed_indexes,
                 args.settings.keyring_provider,
                 &client_builder.subcommand(vec!["pip".to_owned(), "compile".to_owned()]),
                 args.settings.config_setting,
@@ -739,6 +855,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.index_strategy,
                 args.settings.torch_backend,
                 args.settings.dependency_metadata,
+                args.settings.allowed_indexes,
                 args.settings.keyring_provider,
                 &client_builder.subcommand(vec!["pip".to_owned(), "sync".to_owned()]),
                 args.settings.allow_empty_requirements,
@@ -892,7 +1009,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
+                args.package_indexes,
                 args.settings.dependency_metadata,
+                args.settings.allowed_indexes,
                 args.settings.keyring_provider,
                 &client_builder.subcommand(vec!["pip".to_owned(), "install".to_owned()]),
                 args.settings.reinstall,
@@ -2658,6 +2777,17 @@ where
             for err in causes {
                 eprintln!("  {}: {}", "Caused by".red().bold(), err.to_string().trim());
             }
+            // Identify resolution and installation failures by their stable code.
+            if let Some(code) = err.chain().find_map(|err| {
+                err.downcast_ref::<commands::pip::operations::Error>()
+                    .map(commands::pip::operations::Error::code)
+                    .or_else(|| {
+                        err.downcast_ref::<uv_resolver::ResolveError>()
+                            .map(uv_resolver::ResolveError::code)
+                    })
+            }) {
+                commands::error_code(code);
+            }
             ExitStatus::Error.into()
         }
     }
@@ -1,19 +1,28 @@
+use std::fmt::{self, Write};
+use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::Context;
-#[cfg(feature = "tracing-durations-export")]
-use tracing_durations_export::{
-    DurationsLayer, DurationsLayerBuilder, DurationsLayerDropGuard, plot::PlotConfig,
-};
+use owo_colors::OwoColorize;
+use tracing::debug;
+use tracing::field::{Field, Visit};
+use tracing::level_filters::LevelFilter;
 use tracing_subscriber::filter::Directive;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{EnvFilter, Layer, Registry};
+
 use tracing_tree::HierarchicalLayer;
 use tracing_tree::time::Uptime;
+use uv_fs::Simplified;
 
 use uv_cli::ColorChoice;
-use uv_logging::UvFormat;
+use uv_logging::{ConsoleWriter, UvFormat};
+use uv_settings::LogLevel;
+use uv_static::EnvVars;
+
+use crate::timings::TimingsLayer;
+use crate::watchdog::WatchdogLayer;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Level {
@@ -24,18 +33,143 @@ pub(crate) enum Level {
     TraceAll,
 }
 
-/// Configure `tracing` based on the given [`Level`], taking into account the `RUST_LOG` environment
-/// variable.
+impl Level {
+    /// Determine the [`Level`] from the number of times `--verbose` was passed.
+    fn from_verbose(verbose: u8) -> Self {
+        match verbose {
+            0 => Self::Off,
+            1 => Self::DebugUv,
+            2 => Self::TraceUv,
+            3.. => Self::TraceAll,
+        }
+    }
+}
+
+impl Level {
+    /// The level of the messages from the `uv` targets that are shown at this [`Level`].
+    fn uv_level(self) -> LevelFilter {
+        match self {
+            Self::Off => LevelFilter::OFF,
+            Self::DebugUv => LevelFilter::DEBUG,
+            Self::TraceUv | Self::TraceAll => LevelFilter::TRACE,
+        }
+    }
+
+    /// The `RUST_LOG` directive that shows the messages of this [`Level`].
+    fn directive(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::DebugUv => "uv=debug",
+            Self::TraceUv => "uv=trace",
+            Self::TraceAll => "trace",
+        }
+    }
+}
+
+impl From<LogLevel> for Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Default => Self::Off,
+            LogLevel::Verbose => Self::DebugUv,
+            LogLevel::Trace => Self::TraceUv,
+            LogLevel::TraceAll => Self::TraceAll,
+        }
+    }
+}
+
+/// The default [`LogLevel`] configured for the running subcommand, e.g., via
+/// `[tool.uv.logging.commands]`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CommandLevel<'a> {
+    pub(crate) command: &'a str,
+    pub(crate) level: LogLevel,
+    /// The configuration file that sets the level.
+    pub(crate) source: Option<&'a Path>,
+}
+
+/// Resolve the [`Level`] from the `--verbose` and `--quiet` flags.
+///
+/// The per-command default is only respected if neither flag was passed explicitly. Returns the
+/// resolved [`Level`] along with whether the per-command default was applied.
+fn resolve_level(verbose: u8, quiet: u8, command_level: Option<LogLevel>) -> (Level, bool) {
+    if verbose > 0 || quiet > 0 {
+        return (Level::from_verbose(verbose), false);
+    }
+    match command_level {
+        Some(level) => (Level::from(level), true),
+        None => (Level::default(), false),
+    }
+}
+
+/// Returns the level of the messages from the `uv` target that pass the `RUST_LOG` directives, if
+/// it's stricter than the requested [`Level`].
+///
+/// Any `RUST_LOG` directives replace the default directive of the [`Level`] entirely, such that,
+/// e.g., `RUST_LOG=warn` hides the messages that `-v` would otherwise show. As in the filter, the
+/// directive with the most specific target that the `uv` target starts with applies, and
+/// directives that only apply within spans are ignored.
+fn narrowed_level(level: Level, rust_log: &str) -> Option<LevelFilter> {
+    let mut directives = rust_log
+        .split(',')
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+        .peekable();
+    // Without any directives, the default directive applies.
+    directives.peek()?;
+
+    let mut effective: Option<(&str, LevelFilter)> = None;
+    for directive in directives {
+        if directive.contains('[') {
+            continue;
+        }
+        let (target, level) = match directive.rsplit_once('=') {
+            Some((target, level)) => match LevelFilter::from_str(level) {
+                Ok(level) => (target, level),
+                Err(_) => continue,
+            },
+            None => match LevelFilter::from_str(directive) {
+                Ok(level) => ("", level),
+                // A bare target enables all of its messages.
+                Err(_) => (directive, LevelFilter::TRACE),
+            },
+        };
+        if !"uv".starts_with(target) {
+            continue;
+        }
+        if effective.is_none_or(|(existing, _)| existing.len() <= target.len()) {
+            effective = Some((target, level));
+        }
+    }
+
+    let effective = effective.map_or(LevelFilter::OFF, |(_, level)| level);
+    (effective < level.uv_level()).then_some(effective)
+}
+
+/// Configure `tracing` based on the requested verbosity, taking into account the `RUST_LOG`
+/// environment variable.
 ///
 /// The [`Level`] is used to dictate the default filters (which can be overridden by the `RUST_LOG`
 /// environment variable) along with the formatting of the output. For example, [`Level::Verbose`]
 /// includes targets and timestamps, along with all `uv=debug` messages by default.
+///
+/// If neither `--verbose` nor `--quiet` was passed, the [`Level`] is derived from the configured
+/// default for the running subcommand, if any.
 pub(crate) fn setup_logging(
-    level: Level,
+    verbose: u8,
+    quiet: u8,
+    command_level: Option<CommandLevel>,
     durations_layer: Option<impl Layer<Registry> + Send + Sync>,
+    timings_layer: Option<TimingsLayer>,
+    watchdog_layer: Option<WatchdogLayer>,
     color: ColorChoice,
     detailed_logging: bool,
 ) -> anyhow::Result<()> {
+    let (level, overridden) = resolve_level(
+        verbose,
+        quiet,
+        command_level.map(|command_level| command_level.level),
+    );
+
     // We use directives here to ensure `RUST_LOG` can override them
     let default_directive = match level {
         Level::Off => {
@@ -56,18 +190,64 @@ pub(crate) fn setup_logging(
         }
     };
 
-    // Avoid setting the default log level to INFO
-    let durations_layer = durations_layer.map(|durations_layer| {
-        durations_layer.with_filter(
-            // Only record our own spans
+    let timings_layer = timings_layer.map(|timings_layer| {
+        timings_layer.with_filter(
+            // Only record our own spans, the usage of the cache and of each link mode, and the
+            // number of files compiled to bytecode
+            tracing_subscriber::filter::Targets::new()
+                .with_target("", tracing::level_filters::LevelFilter::INFO)
+                .with_target(
+                    "uv_cache::usage",
+                    tracing::level_filters::LevelFilter::DEBUG,
+                )
+                .with_target(
+                    "uv_install_wheel::link_usage",
+                    tracing::level_filters::LevelFilter::DEBUG,
+                )
+                .with_target(
+                    "uv_installer::compile",
+                    tracing::level_filters::LevelFilter::DEBUG,
+                ),
+        )
+    });
+    let watchdog = watchdog_layer.is_some();
+    let watchdog_layer = watchdog_layer.map(|watchdog_layer| {
+        watchdog_layer.with_filter(
+            // Only watch our own spans
             tracing_subscriber::filter::Targets::new()
                 .with_target("", tracing::level_filters::LevelFilter::INFO),
         )
     });
-    let filter = EnvFilter::builder()
+    let mut filter = EnvFilter::builder()
         .with_default_directive(default_directive)
         .from_env()
         .context("Invalid RUST_LOG directives")?;
+    // The `RUST_LOG` directives replace the default directive, which is easy to miss when passing
+    // `-v` with a leftover `RUST_LOG` in the environment.
+    if let Ok(rust_log) = std::env::var(EnvVars::RUST_LOG)
+        && narrowed_level(level, &rust_log).is_some()
+    {
+        let requested = match command_level {
+            Some(CommandLevel { command, level, .. }) if overridden => {
+                format!("the `{level}` log level configured for `uv {command}`")
+            }
+            _ => format!("`-{}`", "v".repeat(usize::from(verbose))),
+        };
+        anstream::eprintln!(
+            "{}{} `{}={rust_log}` is overriding {requested}; pass `{}={}` or unset it",
+            "note".bold(),
+            ":".bold(),
+            EnvVars::RUST_LOG,
+            EnvVars::RUST_LOG,
+            level.directive()
+        );
+    }
+
+    if watchdog {
+        // Show the watchdog's warnings regardless of the log level, as they were requested
+        // explicitly.
+        filter = filter.add_directive(Directive::from_str("uv::watchdog=warn").unwrap());
+    }
 
     // Determine our final color settings and create an anstream wrapper based on it.
     //
@@ -85,12 +265,16 @@ pub(crate) fn setup_logging(
             ColorChoice::Never => (false, anstream::ColorChoice::Never),
             ColorChoice::Auto => unreachable!("anstream can't return auto as choice"),
         };
-    let writer = std::sync::Mutex::new(anstream::AutoStream::new(std::io::stderr(), color_choice));
+    // Hold the process-wide console lock while writing each event, such that concurrent writers
+    // (e.g., build backend output) can't interleave with log messages within a line.
+    let writer = ConsoleWriter::new(anstream::AutoStream::new(std::io::stderr(), color_choice));
 
     if detailed_logging {
         // Regardless of the tracing level, include the uptime and target for each message.
         tracing_subscriber::registry()
             .with(durations_layer)
+            .with(timings_layer)
+            .with(watchdog_layer)
             .with(
                 HierarchicalLayer::default()
                     .with_targets(true)
@@ -103,6 +287,8 @@ pub(crate) fn setup_logging(
     } else {
         tracing_subscriber::registry()
             .with(durations_layer)
+            .with(timings_layer)
+            .with(watchdog_layer)
             .with(
                 tracing_subscriber::fmt::layer()
                     .event_format(UvFormat::default())
@@ -113,40 +299,208 @@ pub(crate) fn setup_logging(
             .init();
     }
 
+    if overridden
+        && let Some(CommandLevel {
+            command,
+            level,
+            source,
+        }) = command_level
+    {
+        if let Some(source) = source {
+            debug!(
+                "Using configured log level `{level}` for `uv {command}` from `{}`",
+                source.user_display()
+            );
+        } else {
+            debug!("Using configured log level `{level}` for `uv {command}`");
+        }
+    }
+
     Ok(())
 }
 
-/// Setup the `TRACING_DURATIONS_FILE` environment variable to enable tracing durations.
-#[cfg(feature = "tracing-durations-export")]
-pub(crate) fn setup_durations(
-    tracing_durations_file: Option<&std::path::PathBuf>,
-) -> anyhow::Result<(
-    Option<DurationsLayer<Registry>>,
-    Option<DurationsLayerDropGuard>,
-)> {
-    if let Some(location) = tracing_durations_file {
-        if let Some(parent) = location.parent() {
-            fs_err::create_dir_all(parent)
-                .context("Failed to create parent of TRACING_DURATIONS_FILE")?;
+/// The fields of a span, rendered as comma-separated `key=value` pairs.
+#[derive(Debug, Default)]
+pub(crate) struct SpanFields(String);
+
+impl SpanFields {
+    pub(crate) fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for SpanFields {
+    fn from(fields: String) -> Self {
+        Self(fields)
+    }
+}
+
+impl Visit for SpanFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &format_args!("{value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push_str(", ");
         }
-        let plot_config = PlotConfig {
-            multi_lane: true,
-            min_length: None,
-            remove: Some(
-                ["get_cached_with_callback".to_string()]
-                    .into_iter()
-                    .collect(),
-            ),
-            ..PlotConfig::default()
+        let _ = write!(self.0, "{}={value:?}", field.name());
+    }
+}
+
+/// The span fields that identify the package a span belongs to, in order of precedence.
+pub(crate) const PACKAGE_FIELDS: &[&str] = &["package", "package_name", "name", "dist"];
+
+/// The package a span belongs to, as recorded in the first non-empty field of [`PACKAGE_FIELDS`].
+#[derive(Debug, Default)]
+pub(crate) struct SpanPackage {
+    /// The package, along with the precedence of the field it was recorded in.
+    package: Option<(usize, String)>,
+}
+
+impl SpanPackage {
+    pub(crate) fn into_package(self) -> Option<String> {
+        self.package.map(|(_, package)| package)
+    }
+}
+
+impl Visit for SpanPackage {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        let Some(precedence) = PACKAGE_FIELDS.iter().position(|name| *name == field.name()) else {
+            return;
         };
-        let (layer, guard) = DurationsLayerBuilder::default()
-            .durations_file(location)
-            .plot_file(location.with_extension("svg"))
-            .plot_config(plot_config)
-            .build()
-            .context("Couldn't create TRACING_DURATIONS_FILE files")?;
-        Ok((Some(layer), Some(guard)))
-    } else {
-        Ok((None, None))
+        if value.is_empty()
+            || self
+                .package
+                .as_ref()
+                .is_some_and(|(existing, _)| *existing < precedence)
+        {
+            return;
+        }
+        self.package = Some((precedence, value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if PACKAGE_FIELDS.contains(&field.name()) {
+            self.record_str(field, &format!("{value:?}"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::level_filters::LevelFilter;
+    use uv_settings::LogLevel;
+
+    use super::{Level, narrowed_level, resolve_level};
+
+    #[test]
+    fn resolve_level_without_command_default() {
+        assert_eq!(resolve_level(0, 0, None), (Level::Off, false));
+        assert_eq!(resolve_level(1, 0, None), (Level::DebugUv, false));
+        assert_eq!(resolve_level(2, 0, None), (Level::TraceUv, false));
+        assert_eq!(resolve_level(5, 0, None), (Level::TraceAll, false));
+    }
+
+    #[test]
+    fn resolve_level_with_command_default() {
+        assert_eq!(
+            resolve_level(0, 0, Some(LogLevel::Default)),
+            (Level::Off, true)
+        );
+        assert_eq!(
+            resolve_level(0, 0, Some(LogLevel::Verbose)),
+            (Level::DebugUv, true)
+        );
+        assert_eq!(
+            resolve_level(0, 0, Some(LogLevel::Trace)),
+            (Level::TraceUv, true)
+        );
+        assert_eq!(
+            resolve_level(0, 0, Some(LogLevel::TraceAll)),
+            (Level::TraceAll, true)
+        );
+    }
+
+    #[test]
+    fn resolve_level_explicit_flag_wins() {
+        // An explicit `--verbose` takes precedence over the configured default.
+        assert_eq!(
+            resolve_level(1, 0, Some(LogLevel::TraceAll)),
+            (Level::DebugUv, false)
+        );
+        // An explicit `--quiet` disables the configured default.
+        assert_eq!(
+            resolve_level(0, 1, Some(LogLevel::Verbose)),
+            (Level::Off, false)
+        );
+    }
+
+    #[test]
+    fn narrowed_level_without_directives() {
+        // The default directive applies.
+        assert_eq!(narrowed_level(Level::DebugUv, ""), None);
+        assert_eq!(narrowed_level(Level::TraceAll, " , "), None);
+    }
+
+    #[test]
+    fn narrowed_level_without_verbose() {
+        // Without `-v`, the directives can only widen the output.
+        assert_eq!(narrowed_level(Level::Off, "warn"), None);
+        assert_eq!(narrowed_level(Level::Off, "off"), None);
+    }
+
+    #[test]
+    fn narrowed_level_global_directive() {
+        assert_eq!(
+            narrowed_level(Level::DebugUv, "warn"),
+            Some(LevelFilter::WARN)
+        );
+        assert_eq!(narrowed_level(Level::DebugUv, "debug"), None);
+        assert_eq!(
+            narrowed_level(Level::TraceUv, "debug"),
+            Some(LevelFilter::DEBUG)
+        );
+        assert_eq!(narrowed_level(Level::TraceAll, "trace"), None);
+        assert_eq!(
+            narrowed_level(Level::DebugUv, "INFO"),
+            Some(LevelFilter::INFO)
+        );
+    }
+
+    #[test]
+    fn narrowed_level_target_directive() {
+        assert_eq!(narrowed_level(Level::DebugUv, "uv=debug"), None);
+        assert_eq!(narrowed_level(Level::DebugUv, "uv=trace"), None);
+        assert_eq!(
+            narrowed_level(Level::TraceUv, "uv=info"),
+            Some(LevelFilter::INFO)
+        );
+        // A bare target enables all of its messages.
+        assert_eq!(narrowed_level(Level::TraceAll, "uv"), None);
+        // The most specific target applies.
+        assert_eq!(
+            narrowed_level(Level::DebugUv, "trace,uv=warn"),
+            Some(LevelFilter::WARN)
+        );
+        assert_eq!(narrowed_level(Level::DebugUv, "warn,uv=debug"), None);
+    }
+
+    #[test]
+    fn narrowed_level_unrelated_directive() {
+        // Directives for other targets disable the messages from the `uv` target.
+        assert_eq!(
+            narrowed_level(Level::DebugUv, "uv_resolver=debug"),
+            Some(LevelFilter::OFF)
+        );
+        assert_eq!(
+            narrowed_level(Level::DebugUv, "hyper=trace"),
+            Some(LevelFilter::OFF)
+        );
+        // Directives within spans don't apply to all messages.
+        assert_eq!(
+            narrowed_level(Level::DebugUv, "uv[build]=trace"),
+            Some(LevelFilter::OFF)
+        );
     }
 }
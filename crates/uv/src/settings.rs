@@ -37,10 +37,10 @@ use uv_configuration::{
     TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
-    PackageConfigSettings, Requirement,
+    AllowedIndex, ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
+    IndexUrl, PackageConfigSettings, PackageIndexEntry, Requirement,
 };
-use uv_install_wheel::LinkMode;
+use uv_install_wheel::{LinkMode, LinkUsage};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, RequirementOrigin};
 use uv_preview::Preview;
@@ -49,7 +49,7 @@ use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    IndexConflictStrategy, PrereleaseMode, ResolutionMode,
 };
 use uv_settings::{
     Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
@@ -1003,6 +1003,7 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            index_conflict_strategy,
             config_setting,
             config_setting_package: config_settings_package,
             no_build_isolation,
@@ -1040,6 +1041,7 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            index_conflict_strategy,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -1620,6 +1622,7 @@ impl SyncSettings {
             check,
             no_check,
             output_format,
+            resolver_dump: _,
         } = args;
         let filesystem_install_mirrors = filesystem
             .clone()
@@ -1734,6 +1737,7 @@ impl LockSettings {
             build,
             refresh,
             python,
+            resolver_dump: _,
         } = args;
 
         let filesystem_install_mirrors = filesystem
@@ -2524,8 +2528,11 @@ impl PipCompileSettings {
             no_emit_marker_expression,
             emit_index_annotation,
             no_emit_index_annotation,
+            annotate_index,
+            no_annotate_index,
             torch_backend,
             compat_args: _,
+            resolver_dump: _,
         } = args;
 
         let constraints_from_workspace = if let Some(configuration) = &filesystem {
@@ -2649,6 +2656,7 @@ impl PipCompileSettings {
                         no_emit_index_annotation,
                         "emit-index-annotation",
                     ),
+                    annotate_index: flag(annotate_index, no_annotate_index, "annotate-index"),
                     annotation_style,
                     torch_backend,
                     ..PipOptions::from(resolver)
@@ -2712,6 +2720,7 @@ impl PipSyncSettings {
             dry_run,
             torch_backend,
             compat_args: _,
+            resolver_dump: _,
         } = *args;
 
         Self {
@@ -2778,6 +2787,7 @@ pub(crate) struct PipInstallSettings {
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) excludes_from_workspace: Vec<PackageName>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
+    pub(crate) package_indexes: Vec<PackageIndexEntry>,
     pub(crate) modifications: Modifications,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
@@ -2829,7 +2839,9 @@ impl PipInstallSettings {
             no_strict,
             dry_run,
             torch_backend,
+            package_index,
             compat_args: _,
+            resolver_dump: _,
         } = args;
 
         let constraints_from_workspace = if let Some(configuration) = &filesystem {
@@ -2908,6 +2920,7 @@ impl PipInstallSettings {
             overrides_from_workspace,
             excludes_from_workspace,
             build_constraints_from_workspace,
+            package_indexes: package_index,
             modifications: if flag(exact, inexact, "inexact").unwrap_or(false) {
                 Modifications::Exact
             } else {
@@ -3445,12 +3458,14 @@ pub(crate) struct InstallerSettingsRef<'a> {
 /// ([`ResolverArgs`], represented as [`ResolverOptions`]).
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ResolverSettings {
+    pub(crate) allowed_indexes: Option<Vec<AllowedIndex>>,
     pub(crate) build_options: BuildOptions,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) config_settings_package: PackageConfigSettings,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) exclude_newer: ExcludeNewer,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) index_conflict_strategy: IndexConflictStrategy,
     pub(crate) index_locations: IndexLocations,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
@@ -3504,9 +3519,11 @@ impl From<ResolverOptions> for ResolverSettings {
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
+            index_conflict_strategy: value.index_conflict_strategy.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
+            allowed_indexes: value.allowed_indexes,
             index_strategy: value.index_strategy.unwrap_or_default(),
             keyring_provider: value.keyring_provider.unwrap_or_default(),
             config_setting: value.config_settings.unwrap_or_default(),
@@ -3515,7 +3532,7 @@ impl From<ResolverOptions> for ResolverSettings {
             extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
             extra_build_variables: value.extra_build_variables.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
-            link_mode: value.link_mode.unwrap_or_default(),
+            link_mode: LinkUsage::resolve(value.link_mode),
             torch_backend: value.torch_backend,
             sources: NoSources::from_args(
                 value.no_sources,
@@ -3591,6 +3608,7 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 dependency_metadata: DependencyMetadata::from_entries(
                     value.dependency_metadata.into_iter().flatten(),
                 ),
+                allowed_indexes: value.allowed_indexes,
                 exclude_newer: ExcludeNewer::from_args(
                     value.exclude_newer,
                     value
@@ -3601,10 +3619,11 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                         .collect(),
                 ),
                 fork_strategy: value.fork_strategy.unwrap_or_default(),
+                index_conflict_strategy: value.index_conflict_strategy.unwrap_or_default(),
                 index_locations,
                 index_strategy: value.index_strategy.unwrap_or_default(),
                 keyring_provider: value.keyring_provider.unwrap_or_default(),
-                link_mode: value.link_mode.unwrap_or_default(),
+                link_mode: LinkUsage::resolve(value.link_mode),
                 build_isolation: value.build_isolation.unwrap_or_default(),
                 extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
                 extra_build_variables: value.extra_build_variables.unwrap_or_default(),
@@ -3651,7 +3670,9 @@ pub(crate) struct PipSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) index_conflict_strategy: IndexConflictStrategy,
     pub(crate) dependency_metadata: DependencyMetadata,
+    pub(crate) allowed_indexes: Option<Vec<AllowedIndex>>,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
     pub(crate) no_strip_markers: bool,
@@ -3671,6 +3692,7 @@ pub(crate) struct PipSettings {
     pub(crate) emit_build_options: bool,
     pub(crate) emit_marker_expression: bool,
     pub(crate) emit_index_annotation: bool,
+    pub(crate) annotate_index: bool,
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
@@ -3727,7 +3749,9 @@ impl PipSettings {
             resolution,
             prerelease,
             fork_strategy,
+            index_conflict_strategy,
             dependency_metadata,
+            allowed_indexes,
             output_file,
             no_strip_extras,
             no_strip_markers,
@@ -3747,6 +3771,7 @@ impl PipSettings {
             emit_build_options,
             emit_marker_expression,
             emit_index_annotation,
+            annotate_index,
             annotation_style,
             link_mode,
             compile_bytecode,
@@ -3772,7 +3797,9 @@ impl PipSettings {
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
             fork_strategy: top_level_fork_strategy,
+            index_conflict_strategy: top_level_index_conflict_strategy,
             dependency_metadata: top_level_dependency_metadata,
+            allowed_indexes: top_level_allowed_indexes,
             config_settings: top_level_config_settings,
             config_settings_package: top_level_config_settings_package,
             no_build_isolation: top_level_no_build_isolation,
@@ -3810,7 +3837,10 @@ impl PipSettings {
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
+        let index_conflict_strategy =
+            index_conflict_strategy.combine(top_level_index_conflict_strategy);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
+        let allowed_indexes = allowed_indexes.combine(top_level_allowed_indexes);
         let config_settings = config_settings.combine(top_level_config_settings);
         let config_settings_package =
             config_settings_package.combine(top_level_config_settings_package);
@@ -3880,11 +3910,16 @@ impl PipSettings {
                 .fork_strategy
                 .combine(fork_strategy)
                 .unwrap_or_default(),
+            index_conflict_strategy: args
+                .index_conflict_strategy
+                .combine(index_conflict_strategy)
+                .unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 args.dependency_metadata
                     .combine(dependency_metadata)
                     .unwrap_or_default(),
             ),
+            allowed_indexes: args.allowed_indexes.combine(allowed_indexes),
             output_file: args.output_file.combine(output_file),
             no_strip_extras: args
                 .no_strip_extras
@@ -3974,7 +4009,11 @@ impl PipSettings {
                 .emit_index_annotation
                 .combine(emit_index_annotation)
                 .unwrap_or_default(),
-            link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
+            annotate_index: args
+                .annotate_index
+                .combine(annotate_index)
+                .unwrap_or_default(),
+            link_mode: LinkUsage::resolve(args.link_mode.combine(link_mode)),
             hash_checking: HashCheckingMode::from_args(
                 args.require_hashes.combine(require_hashes),
                 args.verify_hashes.combine(verify_hashes),
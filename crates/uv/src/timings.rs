@@ -0,0 +1,1804 @@
+//! A summary of the wall time spent in each phase of a command, as requested via `--timings`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::io::Write as _;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
+
+use crate::commands::{elapsed, human_readable_bytes};
+use crate::logging::SpanPackage;
+use crate::printer::Printer;
+
+/// The number of spans listed in the verbose summary, i.e., with `--timings --verbose`.
+const TOP_SPANS: usize = 15;
+
+/// The number of packages listed with `--timings=packages`.
+const TOP_PACKAGES: usize = 10;
+
+/// The phases reported by `--timings`, along with the `(target, name)` of the spans that make up
+/// each phase.
+pub(crate) const PHASES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Python discovery",
+        &[("uv_python::discovery", "find_best_python_installation")],
+    ),
+    ("Resolution", &[("uv_resolver::resolver", "solve")]),
+    ("Preparation", &[("uv_installer::preparer", "prepare")]),
+    (
+        "Installation",
+        &[
+            ("uv_installer::installer", "install"),
+            ("uv_installer::installer", "install_blocking"),
+        ],
+    ),
+    (
+        "Bytecode compilation",
+        &[("uv_installer::compile", "compile_tree")],
+    ),
+];
+
+/// The steps reported for each package by `--timings=packages`, along with the `(target, name)` of
+/// the spans that make up each step.
+///
+/// The spans of each step record the package they belong to in their `package` field.
+pub(crate) const PACKAGE_STEPS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Download",
+        &[
+            ("uv_distribution::distribution_database", "wheel"),
+            ("uv_distribution::source", "download"),
+        ],
+    ),
+    (
+        "Build",
+        &[("uv_distribution::source", "build_distribution")],
+    ),
+    ("Install", &[("uv_install_wheel::install", "install_wheel")]),
+];
+
+/// Determine the index of the package step that the given span belongs to, if any.
+fn package_step(metadata: &Metadata) -> Option<usize> {
+    PACKAGE_STEPS.iter().position(|(_, spans)| {
+        spans
+            .iter()
+            .any(|(target, name)| metadata.target() == *target && metadata.name() == *name)
+    })
+}
+
+/// The kinds of work a span can be tagged with via its `kind` field, e.g., `kind = "network"`.
+///
+/// While spans of several kinds are open at once, the time is attributed to the first of them,
+/// e.g., the solver waiting for metadata counts as waiting on the network, not as compute.
+pub(crate) const KINDS: [&str; 3] = ["network", "disk", "compute"];
+
+/// Extracts the kind of a span from its `kind` field, as an index into [`KINDS`].
+#[derive(Debug, Default)]
+pub(crate) struct SpanKind(Option<usize>);
+
+impl SpanKind {
+    pub(crate) fn from_str(kind: &str) -> Self {
+        Self(KINDS.iter().position(|known| *known == kind))
+    }
+
+    pub(crate) fn index(&self) -> Option<usize> {
+        self.0
+    }
+}
+
+impl Visit for SpanKind {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "kind" {
+            *self = Self::from_str(value);
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// Determine the index of the phase that the given span belongs to, if any.
+fn phase(metadata: &Metadata) -> Option<usize> {
+    PHASES.iter().position(|(_, spans)| {
+        spans
+            .iter()
+            .any(|(target, name)| metadata.target() == *target && metadata.name() == *name)
+    })
+}
+
+/// Whether the given span covers the resolution of a single fork.
+fn is_fork(metadata: &Metadata) -> bool {
+    metadata.target() == "uv_resolver::resolver" && metadata.name() == "solve_fork"
+}
+
+/// Whether the given span covers an entire resolution, across all forks.
+fn is_solve(metadata: &Metadata) -> bool {
+    metadata.target() == "uv_resolver::resolver" && metadata.name() == "solve"
+}
+
+/// The wall time spent in a single phase.
+#[derive(Debug, Default, Clone, Copy)]
+struct PhaseTiming {
+    /// The number of spans of this phase that are currently open.
+    open: usize,
+    /// When the first of the currently open spans was opened.
+    since: Option<Instant>,
+    /// The wall time spent in this phase, excluding any currently open spans.
+    total: Duration,
+    /// Whether any span of this phase was opened.
+    seen: bool,
+}
+
+/// A fork that was resolved, along with the time spent resolving it.
+#[derive(Debug, Clone)]
+struct ForkTiming {
+    /// The markers of the fork, or `None` if the resolution didn't fork.
+    markers: Option<String>,
+    duration: Duration,
+    /// The number of packages pinned in the fork, if recorded.
+    pinned: Option<u64>,
+    /// The number of pinned packages that diverge from the base fork, if recorded.
+    divergent: Option<u64>,
+}
+
+/// A fork span that is currently open, stored in the span's extensions.
+#[derive(Debug)]
+struct OpenFork {
+    markers: Option<String>,
+    resolved: bool,
+    pinned: Option<u64>,
+    divergent: Option<u64>,
+    opened: Instant,
+}
+
+impl Visit for OpenFork {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "markers" {
+            self.markers = Some(value.to_string());
+        }
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if field.name() == "resolved" {
+            self.resolved = value;
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "pinned" => self.pinned = Some(value),
+            "divergent" => self.divergent = Some(value),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// The backtracking of the resolver, as recorded on the `solve` span once a resolution completes.
+///
+/// Stored in the extensions of the open span, and summed across all resolutions (e.g., including
+/// those of build environments).
+#[derive(Debug, Default, Clone, Copy)]
+struct Backtracking {
+    /// The number of conflicts encountered.
+    conflicts: u64,
+    /// The number of decisions that were retracted.
+    retracted_decisions: u64,
+    /// The time spent resolving conflicts.
+    conflict_resolution: Duration,
+}
+
+impl Visit for Backtracking {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "conflicts" => self.conflicts = value,
+            "retracted_decisions" => self.retracted_decisions = value,
+            _ => {}
+        }
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "conflict_resolution" {
+            self.conflict_resolution = Duration::try_from_secs_f64(value).unwrap_or_default();
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// The packages that were served by a fallback index, as recorded on the `solve` span once a
+/// resolution completes.
+///
+/// Stored in the extensions of the open span, and merged across all resolutions.
+#[derive(Debug, Default, Clone)]
+struct Fallbacks(BTreeSet<String>);
+
+impl Visit for Fallbacks {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "fallbacks" {
+            self.0.extend(value.split(", ").map(ToString::to_string));
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// The number of conflicting index pins that were ignored with a warning, as recorded on the
+/// `solve` span once a resolution completes.
+///
+/// Stored in the extensions of the open span, and summed across all resolutions.
+#[derive(Debug, Default, Clone, Copy)]
+struct IndexConflicts(u64);
+
+impl Visit for IndexConflicts {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "index_conflicts" {
+            self.0 = value;
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// The usage of a cache bucket, as logged under the `uv_cache::usage` target once the command
+/// completes.
+#[derive(Debug, Default, Clone)]
+struct CacheBucketUsage {
+    /// The name of the bucket.
+    bucket: String,
+    /// The number of entries written to the bucket.
+    entries: u64,
+    /// The number of bytes written to the bucket.
+    bytes: u64,
+}
+
+impl Visit for CacheBucketUsage {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "bucket" {
+            self.bucket = value.to_string();
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "entries" => self.entries = value,
+            "bytes" => self.bytes = value,
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// The number of packages installed with a link mode, as logged under the
+/// `uv_install_wheel::link_usage` target once the command completes.
+#[derive(Debug, Default, Clone)]
+struct LinkModeUsage {
+    /// The link mode, along with the reason for any fallback, e.g., `copy (user requested)`.
+    outcome: String,
+    /// The number of packages installed with the link mode.
+    packages: u64,
+}
+
+impl Visit for LinkModeUsage {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "outcome" {
+            self.outcome = value.to_string();
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "packages" {
+            self.packages = value;
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// The number of files compiled to bytecode, as logged under the `uv_installer::compile` target
+/// once a tree is compiled.
+#[derive(Debug, Default, Clone, Copy)]
+struct BytecodeCompilation {
+    compiled: u64,
+    skipped: u64,
+    failed: u64,
+    /// Whether the event is a summary, i.e., records the number of compiled files.
+    summary: bool,
+}
+
+impl Visit for BytecodeCompilation {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "compiled" => {
+                self.compiled = value;
+                self.summary = true;
+            }
+            "skipped" => self.skipped = value,
+            "failed" => self.failed = value,
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// A span that is currently open, stored in the span's extensions.
+#[derive(Debug)]
+struct OpenSpan {
+    package: SpanPackage,
+    opened: Instant,
+}
+
+/// A span of a package step that is currently open, stored in the span's extensions.
+#[derive(Debug)]
+struct OpenStep {
+    step: usize,
+    package: SpanPackage,
+    opened: Instant,
+}
+
+/// The time spent in each step for a single package, or `None` for the steps that didn't run
+/// (e.g., building a package that's distributed as a wheel).
+type PackageTiming = [Option<Duration>; PACKAGE_STEPS.len()];
+
+/// The aggregated durations of the closed spans with the same name and package.
+#[derive(Debug, Default, Clone, Copy)]
+struct SpanAggregate {
+    count: u32,
+    total: Duration,
+    max: Duration,
+}
+
+/// Whether the span is the outermost span of the command, which is open for the entire run.
+fn is_command_span(metadata: &Metadata) -> bool {
+    metadata.target() == "uv" && metadata.name() == "run"
+}
+
+#[derive(Debug)]
+struct State {
+    start: Instant,
+    phases: [PhaseTiming; PHASES.len()],
+    forks: Vec<ForkTiming>,
+    /// The backtracking of all completed resolutions, or `None` if no resolution completed.
+    backtracking: Option<Backtracking>,
+    /// The packages that were served by a fallback index in any resolution.
+    fallbacks: BTreeSet<String>,
+    /// The number of conflicting index pins that were ignored with a warning in all resolutions.
+    index_conflicts: u64,
+    /// The usage of each cache bucket that was written to, in bucket order.
+    cache: Vec<CacheBucketUsage>,
+    /// The number of packages installed with each link mode.
+    link_modes: Vec<LinkModeUsage>,
+    /// The number of files compiled to bytecode, summed across trees, or `None` if no bytecode
+    /// was compiled.
+    bytecode: Option<BytecodeCompilation>,
+    /// The number of events logged at `WARN`, e.g., for inconsistent source distribution metadata.
+    warnings: u64,
+    /// The number of currently open spans of each kind.
+    kinds_open: [usize; KINDS.len()],
+    /// When the open spans last changed, i.e., since when the time is yet to be attributed.
+    kinds_since: Instant,
+    /// Whether any span with a kind was opened.
+    kinds_seen: bool,
+    /// The wall time attributed to each kind, per phase.
+    phase_kinds: [[Duration; KINDS.len()]; PHASES.len()],
+    /// The wall time attributed to each kind, overall.
+    total_kinds: [Duration; KINDS.len()],
+    /// The aggregated durations of each span name and package, if requested.
+    ///
+    /// Spans are aggregated as they close, such that only the currently open spans are retained.
+    spans: Option<BTreeMap<(&'static str, Option<String>), SpanAggregate>>,
+    /// The time spent in each step for each package, if requested.
+    packages: Option<BTreeMap<String, PackageTiming>>,
+}
+
+impl State {
+    /// Attribute the time since the last change of the open spans to the kind of work that was
+    /// ongoing, both overall and for each open phase.
+    fn advance(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.kinds_since);
+        self.kinds_since = now;
+        let Some(kind) = self.kinds_open.iter().position(|open| *open > 0) else {
+            return;
+        };
+        self.total_kinds[kind] += elapsed;
+        for (timing, kinds) in self.phases.iter().zip(&mut self.phase_kinds) {
+            if timing.open > 0 {
+                kinds[kind] += elapsed;
+            }
+        }
+    }
+
+    /// The wall time spent in each phase that ran, followed by the time not accounted for by any
+    /// phase (`Other`) and the total wall time (`Total`).
+    fn phases(&self, now: Instant) -> Vec<(&'static str, Duration)> {
+        let total = now.duration_since(self.start);
+        let mut rows = Vec::new();
+        let mut accounted = Duration::ZERO;
+        for ((name, _), timing) in PHASES.iter().zip(self.phases) {
+            if !timing.seen {
+                continue;
+            }
+            // Include any spans that are still open.
+            let duration = timing.total
+                + timing
+                    .since
+                    .map(|since| now.duration_since(since))
+                    .unwrap_or_default();
+            accounted += duration;
+            rows.push((*name, duration));
+        }
+        rows.push(("Other", total.saturating_sub(accounted)));
+        rows.push(("Total", total));
+        rows
+    }
+}
+
+/// The accumulated wall time of each phase.
+///
+/// Concurrent spans of the same phase (e.g., installing in parallel) are only counted once.
+#[derive(Debug, Clone)]
+pub(crate) struct Timings(Arc<Mutex<State>>);
+
+impl Timings {
+    /// Create the timings, optionally aggregating the durations of all spans to list the spans
+    /// that took the most time in total, and recording the time spent on each package.
+    pub(crate) fn new(top_spans: bool, packages: bool) -> Self {
+        Self(Arc::new(Mutex::new(State {
+            start: Instant::now(),
+            phases: [PhaseTiming::default(); PHASES.len()],
+            forks: Vec::new(),
+            backtracking: None,
+            fallbacks: BTreeSet::new(),
+            index_conflicts: 0,
+            cache: Vec::new(),
+            link_modes: Vec::new(),
+            bytecode: None,
+            warnings: 0,
+            kinds_open: [0; KINDS.len()],
+            kinds_since: Instant::now(),
+            kinds_seen: false,
+            phase_kinds: [[Duration::ZERO; KINDS.len()]; PHASES.len()],
+            total_kinds: [Duration::ZERO; KINDS.len()],
+            spans: top_spans.then(BTreeMap::new),
+            packages: packages.then(BTreeMap::new),
+        })))
+    }
+
+    fn aggregates_spans(&self) -> bool {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .spans
+            .is_some()
+    }
+
+    /// Return a [`Layer`] that records the spans of each phase.
+    pub(crate) fn layer(&self) -> TimingsLayer {
+        TimingsLayer(self.clone())
+    }
+
+    fn open(&self, phase: usize) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.advance(Instant::now());
+        let Some(timing) = state.phases.get_mut(phase) else {
+            return;
+        };
+        if timing.open == 0 {
+            timing.since = Some(Instant::now());
+        }
+        timing.open += 1;
+        timing.seen = true;
+    }
+
+    fn close(&self, phase: usize) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.advance(Instant::now());
+        let Some(timing) = state.phases.get_mut(phase) else {
+            return;
+        };
+        timing.open = timing.open.saturating_sub(1);
+        if timing.open == 0
+            && let Some(since) = timing.since.take()
+        {
+            timing.total += since.elapsed();
+        }
+    }
+
+    fn open_kind(&self, kind: usize) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.advance(Instant::now());
+        if let Some(open) = state.kinds_open.get_mut(kind) {
+            *open += 1;
+            state.kinds_seen = true;
+        }
+    }
+
+    fn close_kind(&self, kind: usize) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.advance(Instant::now());
+        if let Some(open) = state.kinds_open.get_mut(kind) {
+            *open = open.saturating_sub(1);
+        }
+    }
+
+    fn records_packages(&self) -> bool {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .packages
+            .is_some()
+    }
+
+    fn closed_step(&self, step: usize, package: String, duration: Duration) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(packages) = &mut state.packages else {
+            return;
+        };
+        if let Some(timing) = packages.entry(package).or_default().get_mut(step) {
+            *timing = Some(timing.unwrap_or_default() + duration);
+        }
+    }
+
+    fn closed_span(&self, name: &'static str, package: Option<String>, duration: Duration) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(spans) = &mut state.spans else {
+            return;
+        };
+        let aggregate = spans.entry((name, package)).or_default();
+        aggregate.count = aggregate.count.saturating_add(1);
+        aggregate.total += duration;
+        aggregate.max = aggregate.max.max(duration);
+    }
+
+    fn resolved_fork(&self, fork: ForkTiming) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.forks.push(fork);
+    }
+
+    fn completed_resolution(&self, backtracking: Backtracking) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let total = state.backtracking.get_or_insert_default();
+        total.conflicts += backtracking.conflicts;
+        total.retracted_decisions += backtracking.retracted_decisions;
+        total.conflict_resolution += backtracking.conflict_resolution;
+    }
+
+    fn served_by_fallbacks(&self, fallbacks: Fallbacks) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.fallbacks.extend(fallbacks.0);
+    }
+
+    fn ignored_index_conflicts(&self, conflicts: IndexConflicts) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.index_conflicts += conflicts.0;
+    }
+
+    fn used_cache_bucket(&self, usage: CacheBucketUsage) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.cache.push(usage);
+    }
+
+    fn used_link_mode(&self, usage: LinkModeUsage) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.link_modes.push(usage);
+    }
+
+    fn compiled_bytecode(&self, compilation: BytecodeCompilation) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let total = state.bytecode.get_or_insert_default();
+        total.compiled += compilation.compiled;
+        total.skipped += compilation.skipped;
+        total.failed += compilation.failed;
+    }
+
+    fn logged_warning(&self) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.warnings += 1;
+    }
+
+    /// Render the timings as a Markdown table for the GitHub Actions job summary, with the change
+    /// of each phase relative to the baseline, if any.
+    fn step_summary(&self, baseline: Option<&Baseline>) -> String {
+        let state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let rows = state.phases(Instant::now());
+
+        let mut markdown = String::from("### uv timings\n\n");
+        if let Some(baseline) = baseline {
+            markdown.push_str("| Phase | Duration | Baseline | Change |\n");
+            markdown.push_str("| :--- | ---: | ---: | ---: |\n");
+            for (name, duration) in rows {
+                let (previous, change) = baseline
+                    .phase(name)
+                    .map(|previous| (elapsed(previous), change(duration, previous)))
+                    .unwrap_or_default();
+                let _ = writeln!(
+                    markdown,
+                    "| {name} | {} | {previous} | {change} |",
+                    elapsed(duration)
+                );
+            }
+        } else {
+            markdown.push_str("| Phase | Duration |\n");
+            markdown.push_str("| :--- | ---: |\n");
+            for (name, duration) in rows {
+                let _ = writeln!(markdown, "| {name} | {} |", elapsed(duration));
+            }
+        }
+        markdown.push('\n');
+        markdown
+    }
+
+    /// Render the timings as a table, with the duration and share of the total for each phase that
+    /// ran, along with the time not accounted for by any phase.
+    ///
+    /// If any spans were tagged with a kind of work, the table is followed by the time spent waiting
+    /// on the network, on the disk, and computing in each phase. If a resolution ran, the table is
+    /// followed by the number of forks and the time spent resolving each of them, the packages
+    /// pinned across forks, the conflicts the resolver backtracked on, the packages that were
+    /// served by a fallback index, and the conflicting index pins that were ignored. The table then
+    /// lists the entries added to the cache, the number of packages installed with each link
+    /// mode, the number of files compiled to bytecode, and the number of warnings that were logged.
+    /// If requested, the packages and the spans that took the most time in total are listed last.
+    fn table(&self) -> String {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        state.advance(now);
+        let total = now.duration_since(state.start);
+
+        let rows = state
+            .phases(now)
+            .into_iter()
+            .map(|(name, duration)| {
+                let percent = if total.is_zero() {
+                    0.0
+                } else {
+                    duration.as_secs_f64() / total.as_secs_f64() * 100.0
+                };
+                (name, elapsed(duration), format!("{percent:.1}%"))
+            })
+            .collect::<Vec<_>>();
+
+        let name_width = rows
+            .iter()
+            .map(|(name, ..)| name.len())
+            .chain(std::iter::once("Phase".len()))
+            .max()
+            .unwrap_or_default();
+        let duration_width = rows
+            .iter()
+            .map(|(_, duration, _)| duration.len())
+            .chain(std::iter::once("Duration".len()))
+            .max()
+            .unwrap_or_default();
+        let percent_width = rows
+            .iter()
+            .map(|(.., percent)| percent.len())
+            .chain(std::iter::once("Percent".len()))
+            .max()
+            .unwrap_or_default();
+
+        let mut table = String::new();
+        let _ = writeln!(
+            table,
+            "{:<name_width$}  {:>duration_width$}  {:>percent_width$}",
+            "Phase", "Duration", "Percent"
+        );
+        for (name, duration, percent) in rows {
+            let _ = writeln!(
+                table,
+                "{name:<name_width$}  {duration:>duration_width$}  {percent:>percent_width$}"
+            );
+        }
+
+        if state.kinds_seen {
+            table.push_str(&kinds_table(&state));
+        }
+
+        match state.forks.as_slice() {
+            [] => {}
+            [ForkTiming { markers: None, .. }] => {
+                let _ = writeln!(table, "1 fork (no markers)");
+            }
+            forks => {
+                let _ = writeln!(
+                    table,
+                    "{} {}",
+                    forks.len(),
+                    if forks.len() == 1 { "fork" } else { "forks" }
+                );
+                let rows = forks
+                    .iter()
+                    .map(|fork| {
+                        (
+                            fork.markers.as_deref().unwrap_or("(no markers)"),
+                            elapsed(fork.duration),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let markers_width = rows
+                    .iter()
+                    .map(|(markers, _)| markers.len())
+                    .max()
+                    .unwrap_or_default();
+                let duration_width = rows
+                    .iter()
+                    .map(|(_, duration)| duration.len())
+                    .max()
+                    .unwrap_or_default();
+                for (markers, duration) in rows {
+                    let _ = writeln!(
+                        table,
+                        "  {markers:<markers_width$}  {duration:>duration_width$}"
+                    );
+                }
+                if forks.iter().any(|fork| fork.pinned.is_some()) {
+                    let pinned = forks.iter().filter_map(|fork| fork.pinned).sum::<u64>();
+                    let divergent = forks.iter().filter_map(|fork| fork.divergent).sum::<u64>();
+                    let _ = writeln!(
+                        table,
+                        "Pins: {pinned} {} across forks, {divergent} diverging from the base fork",
+                        if pinned == 1 { "package" } else { "packages" },
+                    );
+                }
+            }
+        }
+
+        if let Some(backtracking) = state.backtracking {
+            let _ = writeln!(
+                table,
+                "Backtracking: {} {}, {} {} retracted, {} resolving conflicts",
+                backtracking.conflicts,
+                if backtracking.conflicts == 1 {
+                    "conflict"
+                } else {
+                    "conflicts"
+                },
+                backtracking.retracted_decisions,
+                if backtracking.retracted_decisions == 1 {
+                    "decision"
+                } else {
+                    "decisions"
+                },
+                elapsed(backtracking.conflict_resolution)
+            );
+        }
+
+        if !state.fallbacks.is_empty() {
+            let _ = writeln!(
+                table,
+                "Fallbacks: {} {} served by fallback indexes: {}",
+                state.fallbacks.len(),
+                if state.fallbacks.len() == 1 {
+                    "package"
+                } else {
+                    "packages"
+                },
+                state
+                    .fallbacks
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        if state.index_conflicts > 0 {
+            let _ = writeln!(
+                table,
+                "Index conflicts: {} conflicting index {} ignored",
+                state.index_conflicts,
+                if state.index_conflicts == 1 {
+                    "pin"
+                } else {
+                    "pins"
+                },
+            );
+        }
+
+        let written = state
+            .cache
+            .iter()
+            .filter(|usage| usage.entries > 0)
+            .collect::<Vec<_>>();
+        if !written.is_empty() {
+            let entries = written.iter().map(|usage| usage.entries).sum::<u64>();
+            let bytes = written.iter().map(|usage| usage.bytes).sum::<u64>();
+            let _ = writeln!(
+                table,
+                "Cache: {entries} {} created, {} added ({})",
+                if entries == 1 { "entry" } else { "entries" },
+                format_bytes(bytes),
+                written
+                    .iter()
+                    .map(|usage| format!("{}: {}", usage.bucket, format_bytes(usage.bytes)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        if !state.link_modes.is_empty() {
+            let _ = writeln!(
+                table,
+                "Link modes: {}",
+                state
+                    .link_modes
+                    .iter()
+                    .map(|usage| format!("{} {}", usage.packages, usage.outcome))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        if let Some(bytecode) = state.bytecode {
+            let _ = writeln!(
+                table,
+                "Bytecode: {} {} compiled, {} skipped, {} failed",
+                bytecode.compiled,
+                if bytecode.compiled == 1 {
+                    "file"
+                } else {
+                    "files"
+                },
+                bytecode.skipped,
+                bytecode.failed
+            );
+        }
+
+        if state.warnings > 0 {
+            let _ = writeln!(
+                table,
+                "Warnings: {} logged; run with `-v` for details",
+                state.warnings
+            );
+        }
+
+        if let Some(packages) = &state.packages {
+            table.push_str(&top_packages(packages));
+        }
+        if let Some(spans) = &state.spans {
+            table.push_str(&top_spans(spans));
+        }
+        table
+    }
+}
+
+/// The wall time of each phase in a previous run, to compare against.
+#[derive(Debug, Default, Deserialize)]
+struct Baseline {
+    /// The wall time of each phase, in seconds.
+    #[serde(default)]
+    phases: BTreeMap<String, f64>,
+}
+
+impl Baseline {
+    /// Read the phases from the `.summary.json` file of a previous run with `--durations`.
+    fn read(path: &Path) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(&fs_err::read_to_string(path)?)?)
+    }
+
+    fn phase(&self, name: &str) -> Option<Duration> {
+        self.phases
+            .get(name)
+            .and_then(|seconds| Duration::try_from_secs_f64(*seconds).ok())
+    }
+}
+
+/// Format the change of a duration relative to the baseline, e.g., `+200ms (+20.0%)`.
+fn change(duration: Duration, baseline: Duration) -> String {
+    let (sign, difference) = if duration >= baseline {
+        ('+', duration.saturating_sub(baseline))
+    } else {
+        ('-', baseline.saturating_sub(duration))
+    };
+    if baseline.is_zero() {
+        return format!("{sign}{}", elapsed(difference));
+    }
+    let percent = difference.as_secs_f64() / baseline.as_secs_f64() * 100.0;
+    format!("{sign}{} ({sign}{percent:.1}%)", elapsed(difference))
+}
+
+/// Render the wall time attributed to each kind of work, for each phase that ran and overall.
+fn kinds_table(state: &State) -> String {
+    let rows = PHASES
+        .iter()
+        .zip(state.phases.iter().zip(&state.phase_kinds))
+        .filter(|(_, (timing, _))| timing.seen)
+        .map(|((name, _), (_, kinds))| (*name, kinds))
+        .chain(std::iter::once(("Total", &state.total_kinds)))
+        .map(|(name, kinds)| (name, kinds.map(elapsed)))
+        .collect::<Vec<_>>();
+
+    let name_width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain(std::iter::once("Phase".len()))
+        .max()
+        .unwrap_or_default();
+    let widths = rows
+        .iter()
+        .fold(KINDS.map(str::len), |mut widths, (_, kinds)| {
+            for (width, duration) in widths.iter_mut().zip(kinds) {
+                *width = (*width).max(duration.len());
+            }
+            widths
+        });
+
+    let mut table = String::new();
+    let _ = write!(table, "{:<name_width$}", "Phase");
+    for (kind, width) in KINDS.iter().zip(widths) {
+        let mut kind = kind.to_string();
+        if let Some(first) = kind.get_mut(..1) {
+            first.make_ascii_uppercase();
+        }
+        let _ = write!(table, "  {kind:>width$}");
+    }
+    table.push('\n');
+    for (name, kinds) in rows {
+        let _ = write!(table, "{name:<name_width$}");
+        for (duration, width) in kinds.iter().zip(widths) {
+            let _ = write!(table, "  {duration:>width$}");
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Render the packages that took the most time in total, along with the time spent in each step.
+///
+/// Packages are sorted by their total duration, with ties broken by their name.
+fn top_packages(packages: &BTreeMap<String, PackageTiming>) -> String {
+    let mut packages = packages
+        .iter()
+        .map(|(name, steps)| (name, steps, steps.iter().flatten().sum::<Duration>()))
+        .collect::<Vec<_>>();
+    packages.sort_by(|(a_name, _, a_total), (b_name, _, b_total)| {
+        b_total.cmp(a_total).then_with(|| a_name.cmp(b_name))
+    });
+
+    let rows = packages
+        .into_iter()
+        .take(TOP_PACKAGES)
+        .map(|(name, steps, total)| {
+            std::iter::once(name.clone())
+                .chain(
+                    steps
+                        .iter()
+                        .map(|step| step.map(elapsed).unwrap_or_else(|| "-".to_string())),
+                )
+                .chain(std::iter::once(elapsed(total)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        return String::new();
+    }
+
+    let header = std::iter::once("Package")
+        .chain(PACKAGE_STEPS.iter().map(|(step, _)| *step))
+        .chain(std::iter::once("Total"))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let widths = rows.iter().fold(
+        header.iter().map(String::len).collect::<Vec<_>>(),
+        |mut widths, row| {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+            widths
+        },
+    );
+
+    let mut table = String::new();
+    for row in std::iter::once(header).chain(rows) {
+        for (index, (cell, width)) in row.iter().zip(&widths).enumerate() {
+            if index == 0 {
+                let _ = write!(table, "{cell:<width$}");
+            } else {
+                let _ = write!(table, "  {cell:>width$}");
+            }
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Render the spans that took the most time in total, along with their count, mean and maximum
+/// duration.
+///
+/// Spans are sorted by their total duration, with ties broken by their label.
+fn top_spans(spans: &BTreeMap<(&str, Option<String>), SpanAggregate>) -> String {
+    let mut spans = spans
+        .iter()
+        .map(|((name, package), aggregate)| {
+            let label = match package {
+                Some(package) => format!("{name}: {package}"),
+                None => (*name).to_string(),
+            };
+            (label, *aggregate)
+        })
+        .collect::<Vec<_>>();
+    spans.sort_by(|(a_label, a), (b_label, b)| {
+        b.total.cmp(&a.total).then_with(|| a_label.cmp(b_label))
+    });
+
+    let rows = spans
+        .into_iter()
+        .take(TOP_SPANS)
+        .map(|(label, aggregate)| {
+            let mean = aggregate
+                .total
+                .checked_div(aggregate.count)
+                .unwrap_or_default();
+            [
+                label,
+                aggregate.count.to_string(),
+                elapsed(aggregate.total),
+                elapsed(mean),
+                elapsed(aggregate.max),
+            ]
+        })
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        return String::new();
+    }
+
+    let header = ["Span", "Count", "Total", "Mean", "Max"];
+    let widths = header.map(str::len);
+    let widths = rows.iter().fold(widths, |mut widths, row| {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+        widths
+    });
+
+    let mut table = String::new();
+    for row in std::iter::once(header.map(ToString::to_string)).chain(rows) {
+        let [label, cells @ ..] = row;
+        let [label_width, cell_widths @ ..] = widths;
+        let _ = write!(table, "{label:<label_width$}");
+        for (cell, width) in cells.iter().zip(cell_widths) {
+            let _ = write!(table, "  {cell:>width$}");
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Format a number of bytes for display, e.g., `512B` or `1.5MiB`.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
+
+/// A [`Layer`] that records the wall time of the spans of each phase.
+pub(crate) struct TimingsLayer(Timings);
+
+impl<S> Layer<S> for TimingsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if let Some(phase) = phase(attrs.metadata()) {
+            self.0.open(phase);
+        }
+        let mut kind = SpanKind::default();
+        attrs.record(&mut kind);
+        if let Some(index) = kind.index()
+            && let Some(span) = ctx.span(id)
+        {
+            self.0.open_kind(index);
+            span.extensions_mut().insert(kind);
+        }
+        if is_fork(attrs.metadata())
+            && let Some(span) = ctx.span(id)
+        {
+            let mut fork = OpenFork {
+                markers: None,
+                resolved: false,
+                pinned: None,
+                divergent: None,
+                opened: Instant::now(),
+            };
+            attrs.record(&mut fork);
+            span.extensions_mut().insert(fork);
+        }
+        if is_solve(attrs.metadata())
+            && let Some(span) = ctx.span(id)
+        {
+            let mut backtracking = Backtracking::default();
+            attrs.record(&mut backtracking);
+            span.extensions_mut().insert(backtracking);
+            span.extensions_mut().insert(Fallbacks::default());
+            span.extensions_mut().insert(IndexConflicts::default());
+        }
+        if let Some(step) = package_step(attrs.metadata())
+            && self.0.records_packages()
+            && let Some(span) = ctx.span(id)
+        {
+            let mut package = SpanPackage::default();
+            attrs.record(&mut package);
+            span.extensions_mut().insert(OpenStep {
+                step,
+                package,
+                opened: Instant::now(),
+            });
+        }
+        if !is_command_span(attrs.metadata())
+            && self.0.aggregates_spans()
+            && let Some(span) = ctx.span(id)
+        {
+            let mut package = SpanPackage::default();
+            attrs.record(&mut package);
+            span.extensions_mut().insert(OpenSpan {
+                package,
+                opened: Instant::now(),
+            });
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() == tracing::Level::WARN {
+            self.0.logged_warning();
+        }
+        if event.metadata().target() == "uv_cache::usage" {
+            let mut usage = CacheBucketUsage::default();
+            event.record(&mut usage);
+            self.0.used_cache_bucket(usage);
+        } else if event.metadata().target() == "uv_install_wheel::link_usage" {
+            let mut usage = LinkModeUsage::default();
+            event.record(&mut usage);
+            // Only the summary, not the events for each package, records the number of packages.
+            if usage.packages > 0 {
+                self.0.used_link_mode(usage);
+            }
+        } else if event.metadata().target() == "uv_installer::compile" {
+            let mut compilation = BytecodeCompilation::default();
+            event.record(&mut compilation);
+            // Only the summary, not the events for each failure, records the number of files.
+            if compilation.summary {
+                self.0.compiled_bytecode(compilation);
+            }
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(fork) = extensions.get_mut::<OpenFork>() {
+            values.record(fork);
+        }
+        if let Some(backtracking) = extensions.get_mut::<Backtracking>() {
+            values.record(backtracking);
+        }
+        if let Some(fallbacks) = extensions.get_mut::<Fallbacks>() {
+            values.record(fallbacks);
+        }
+        if let Some(conflicts) = extensions.get_mut::<IndexConflicts>() {
+            values.record(conflicts);
+        }
+        if let Some(open) = extensions.get_mut::<OpenSpan>() {
+            values.record(&mut open.package);
+        }
+        if let Some(open) = extensions.get_mut::<OpenStep>() {
+            values.record(&mut open.package);
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        if let Some(phase) = phase(span.metadata()) {
+            self.0.close(phase);
+        }
+        if let Some(SpanKind(Some(index))) = span.extensions_mut().remove::<SpanKind>() {
+            self.0.close_kind(index);
+        }
+        if let Some(OpenFork {
+            markers,
+            resolved: true,
+            pinned,
+            divergent,
+            opened,
+        }) = span.extensions_mut().remove::<OpenFork>()
+        {
+            self.0.resolved_fork(ForkTiming {
+                markers,
+                duration: opened.elapsed(),
+                pinned,
+                divergent,
+            });
+        }
+        if let Some(backtracking) = span.extensions_mut().remove::<Backtracking>() {
+            self.0.completed_resolution(backtracking);
+        }
+        if let Some(fallbacks) = span.extensions_mut().remove::<Fallbacks>() {
+            self.0.served_by_fallbacks(fallbacks);
+        }
+        if let Some(conflicts) = span.extensions_mut().remove::<IndexConflicts>() {
+            self.0.ignored_index_conflicts(conflicts);
+        }
+        if let Some(OpenStep {
+            step,
+            package,
+            opened,
+        }) = span.extensions_mut().remove::<OpenStep>()
+            && let Some(package) = package.into_package()
+        {
+            self.0.closed_step(step, package, opened.elapsed());
+        }
+        if let Some(OpenSpan { package, opened }) = span.extensions_mut().remove::<OpenSpan>() {
+            self.0
+                .closed_span(span.name(), package.into_package(), opened.elapsed());
+        }
+    }
+}
+
+/// A guard that prints the timings table when dropped, i.e., after the command has completed.
+pub(crate) struct TimingsGuard {
+    timings: Timings,
+    printer: Printer,
+}
+
+impl TimingsGuard {
+    pub(crate) fn new(timings: Timings, printer: Printer) -> Self {
+        Self { timings, printer }
+    }
+}
+
+impl TimingsGuard {
+    /// Append the timings to the GitHub Actions job summary, if running in GitHub Actions.
+    ///
+    /// Other steps write to the same file, so it's only ever appended to. Failures are reported as
+    /// warnings, without failing the command.
+    fn write_step_summary(&self) {
+        let Some(path) =
+            std::env::var_os(EnvVars::GITHUB_STEP_SUMMARY).filter(|path| !path.is_empty())
+        else {
+            return;
+        };
+
+        let baseline = std::env::var_os(EnvVars::UV_TIMINGS_BASELINE)
+            .filter(|path| !path.is_empty())
+            .and_then(|path| match Baseline::read(Path::new(&path)) {
+                Ok(baseline) => Some(baseline),
+                Err(err) => {
+                    warn_user!(
+                        "Failed to read the timings baseline from `{}`: {err}",
+                        Path::new(&path).display()
+                    );
+                    None
+                }
+            });
+
+        let markdown = self.timings.step_summary(baseline.as_ref());
+        let result = fs_err::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(markdown.as_bytes()));
+        if let Err(err) = result {
+            warn_user!("Failed to write the timings to the GitHub Actions job summary: {err}");
+        }
+    }
+}
+
+impl Drop for TimingsGuard {
+    fn drop(&mut self) {
+        // The table was explicitly requested, so it's shown even with `--quiet`.
+        let _ = write!(self.printer.stderr_important(), "{}", self.timings.table());
+        self.write_step_summary();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    use tracing::info_span;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::{Baseline, SpanAggregate, Timings, change, top_spans};
+
+    /// Render the table after running `f`, omitting the phases.
+    fn forks(f: impl FnOnce()) -> Vec<String> {
+        let timings = Timings::new(false, false);
+        let subscriber = tracing_subscriber::registry().with(timings.layer());
+        tracing::subscriber::with_default(subscriber, f);
+        timings
+            .table()
+            .lines()
+            .skip_while(|line| !line.starts_with("Total"))
+            .skip(1)
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect()
+    }
+
+    fn solve_fork(markers: Option<&str>, resolved: bool) {
+        let span = info_span!(
+            target: "uv_resolver::resolver",
+            "solve_fork",
+            markers = tracing::field::Empty,
+            resolved = tracing::field::Empty
+        );
+        if let Some(markers) = markers {
+            span.record("markers", markers);
+        }
+        let _enter = span.enter();
+        if resolved {
+            span.record("resolved", true);
+        }
+    }
+
+    #[test]
+    fn no_forks() {
+        assert_eq!(forks(|| solve_fork(None, true)), ["1 fork (no markers)"]);
+        assert!(forks(|| {}).is_empty());
+    }
+
+    #[test]
+    fn forks_with_markers() {
+        let forks = forks(|| {
+            // The fork that is split further isn't counted.
+            solve_fork(None, false);
+            solve_fork(Some("sys_platform == 'linux'"), true);
+            solve_fork(Some("sys_platform != 'linux'"), true);
+        });
+        assert_eq!(forks.len(), 3, "{forks:?}");
+        assert_eq!(forks[0], "2 forks");
+        assert!(
+            forks[1].starts_with("sys_platform == 'linux' "),
+            "{forks:?}"
+        );
+        assert!(
+            forks[2].starts_with("sys_platform != 'linux' "),
+            "{forks:?}"
+        );
+    }
+
+    /// The packages pinned in each fork are totaled across forks.
+    #[test]
+    fn forks_with_pins() {
+        let forks = forks(|| {
+            for (markers, pinned, divergent) in [
+                ("sys_platform == 'linux'", 12u64, 0u64),
+                ("sys_platform != 'linux'", 11, 2),
+            ] {
+                let span = info_span!(
+                    target: "uv_resolver::resolver",
+                    "solve_fork",
+                    markers,
+                    resolved = tracing::field::Empty,
+                    pinned = tracing::field::Empty,
+                    divergent = tracing::field::Empty
+                );
+                let _enter = span.enter();
+                span.record("pinned", pinned);
+                span.record("divergent", divergent);
+                span.record("resolved", true);
+            }
+        });
+        assert_eq!(forks.len(), 4, "{forks:?}");
+        assert_eq!(
+            forks[3],
+            "Pins: 23 packages across forks, 2 diverging from the base fork"
+        );
+    }
+
+    fn solve(conflicts: u64, retracted_decisions: u64, conflict_resolution: f64) {
+        let span = info_span!(
+            target: "uv_resolver::resolver",
+            "solve",
+            conflicts = tracing::field::Empty,
+            retracted_decisions = tracing::field::Empty,
+            conflict_resolution = tracing::field::Empty
+        );
+        let _enter = span.enter();
+        span.record("conflicts", conflicts);
+        span.record("retracted_decisions", retracted_decisions);
+        span.record("conflict_resolution", conflict_resolution);
+    }
+
+    /// The backtracking is summed across resolutions, and shown even if there was none.
+    #[test]
+    fn backtracking() {
+        assert_eq!(
+            forks(|| solve(0, 0, 0.0)),
+            ["Backtracking: 0 conflicts, 0 decisions retracted, 0.00ms resolving conflicts"]
+        );
+        assert_eq!(
+            forks(|| {
+                solve(1, 1, 0.25);
+                solve(2, 5, 0.5);
+            }),
+            ["Backtracking: 3 conflicts, 6 decisions retracted, 750ms resolving conflicts"]
+        );
+    }
+
+    /// The packages served by a fallback index are merged across resolutions.
+    #[test]
+    fn fallbacks() {
+        let forks = forks(|| {
+            for fallbacks in ["idna, sniffio", "anyio, idna"] {
+                let span = info_span!(
+                    target: "uv_resolver::resolver",
+                    "solve",
+                    fallbacks = tracing::field::Empty
+                );
+                let _enter = span.enter();
+                span.record("fallbacks", fallbacks);
+            }
+        });
+        assert_eq!(
+            forks[1],
+            "Fallbacks: 3 packages served by fallback indexes: anyio, idna, sniffio"
+        );
+    }
+
+    /// The conflicting index pins that were ignored are summed across resolutions.
+    #[test]
+    fn index_conflicts() {
+        let forks = forks(|| {
+            for index_conflicts in [1_u64, 2] {
+                let span = info_span!(
+                    target: "uv_resolver::resolver",
+                    "solve",
+                    index_conflicts = tracing::field::Empty
+                );
+                let _enter = span.enter();
+                span.record("index_conflicts", index_conflicts);
+            }
+        });
+        assert_eq!(
+            forks[1],
+            "Index conflicts: 3 conflicting index pins ignored"
+        );
+    }
+
+    /// The entries written to the cache are summed across buckets.
+    #[test]
+    fn cache_usage() {
+        let forks = forks(|| {
+            for (bucket, entries, bytes) in [
+                ("wheels-v5", 2_u64, 2048_u64),
+                ("archive-v0", 1, 3 * 1024 * 1024),
+                ("simple-v16", 0, 0),
+            ] {
+                tracing::debug!(
+                    target: "uv_cache::usage",
+                    bucket,
+                    reads = 1_u64,
+                    entries,
+                    bytes,
+                    "Used cache bucket"
+                );
+            }
+        });
+        assert_eq!(
+            forks,
+            ["Cache: 3 entries created, 3.0MiB added (wheels-v5: 2.0KiB, archive-v0: 3.0MiB)"]
+        );
+    }
+
+    /// The events logged for each package are ignored, only the summary is counted.
+    #[test]
+    fn link_modes() {
+        let forks = forks(|| {
+            tracing::debug!(
+                target: "uv_install_wheel::link_usage",
+                "Linked iniconfig-2.0.0-py3-none-any.whl via hardlink"
+            );
+            for (outcome, packages) in [
+                ("copy (hardlink failed: cross-device)", 1_u64),
+                ("hardlink", 3),
+            ] {
+                tracing::debug!(
+                    target: "uv_install_wheel::link_usage",
+                    outcome,
+                    packages,
+                    "Linked packages"
+                );
+            }
+        });
+        assert_eq!(
+            forks,
+            ["Link modes: 1 copy (hardlink failed: cross-device), 3 hardlink"]
+        );
+    }
+
+    /// The files compiled to bytecode are summed across trees, ignoring the events for failures.
+    #[test]
+    fn bytecode() {
+        let forks = forks(|| {
+            for (compiled, skipped, failed) in [(120_u64, 0_u64, 1_u64), (3, 40, 0)] {
+                tracing::debug!(
+                    target: "uv_installer::compile",
+                    workers = 4_u64,
+                    compiled,
+                    skipped,
+                    failed,
+                    "Bytecode compilation"
+                );
+            }
+            tracing::debug!(
+                target: "uv_installer::compile",
+                "Failed to compile `broken.py`: SyntaxError: invalid syntax"
+            );
+        });
+        assert_eq!(
+            forks,
+            ["Bytecode: 123 files compiled, 40 skipped, 1 failed"]
+        );
+    }
+
+    /// The events logged at `WARN` are counted, regardless of their target.
+    #[test]
+    fn warnings() {
+        let forks = forks(|| {
+            tracing::warn!(
+                target: "uv_distribution::source::consistency",
+                "`Requires-Dist` of `child` differs"
+            );
+            tracing::warn!(target: "uv_fs", "Directory already exists");
+            tracing::debug!(target: "uv_fs", "Not a warning");
+        });
+        assert_eq!(forks, ["Warnings: 2 logged; run with `-v` for details"]);
+    }
+
+    #[test]
+    fn spans_by_package() {
+        let timings = Timings::new(true, false);
+        let subscriber = tracing_subscriber::registry().with(timings.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            let _command = info_span!(target: "uv", "run").entered();
+            for package in ["numpy", "numpy", "scipy"] {
+                drop(info_span!("build_wheel", package).entered());
+            }
+            let span = info_span!("build_wheel", package = tracing::field::Empty);
+            span.record("package", "pandas");
+            drop(span.entered());
+            drop(info_span!("solve").entered());
+        });
+
+        let state = timings.0.lock().unwrap();
+        let spans = state.spans.as_ref().unwrap();
+        let counts = spans
+            .iter()
+            .map(|((name, package), aggregate)| (*name, package.as_deref(), aggregate.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            counts,
+            [
+                ("build_wheel", Some("numpy"), 2),
+                ("build_wheel", Some("pandas"), 1),
+                ("build_wheel", Some("scipy"), 1),
+                ("solve", None, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn top() {
+        let aggregate = |count: u32, total: u64, max: u64| SpanAggregate {
+            count,
+            total: Duration::from_millis(total),
+            max: Duration::from_millis(max),
+        };
+        let mut spans = BTreeMap::new();
+        spans.insert(("solve", None), aggregate(1, 1200, 1200));
+        spans.insert(
+            ("build_wheel", Some("numpy".to_string())),
+            aggregate(2, 3000, 2000),
+        );
+        // Ties are broken by the label.
+        spans.insert(
+            ("build_wheel", Some("scipy".to_string())),
+            aggregate(1, 500, 500),
+        );
+        spans.insert(
+            ("build_wheel", Some("pandas".to_string())),
+            aggregate(1, 500, 500),
+        );
+        for i in 0..20 {
+            spans.insert(
+                ("fetch", Some(format!("package-{i:02}"))),
+                aggregate(1, 10, 10),
+            );
+        }
+
+        let table = top_spans(&spans);
+        assert_eq!(table.lines().count(), 16, "{table}");
+        insta::assert_snapshot!(table.lines().take(6).collect::<Vec<_>>().join("\n"), @"
+        Span                 Count  Total   Mean    Max
+        build_wheel: numpy       2  3.00s  1.50s  2.00s
+        solve                    1  1.20s  1.20s  1.20s
+        build_wheel: pandas      1  500ms  500ms  500ms
+        build_wheel: scipy       1  500ms  500ms  500ms
+        fetch: package-00        1   10ms   10ms   10ms
+        ");
+    }
+
+    #[test]
+    fn changes() {
+        let ms = Duration::from_millis;
+        assert_eq!(change(ms(1200), ms(1000)), "+200ms (+20.0%)");
+        assert_eq!(change(ms(500), ms(1000)), "-500ms (-50.0%)");
+        assert_eq!(change(ms(1000), ms(1000)), "+0.00ms (+0.0%)");
+        assert_eq!(change(ms(10), Duration::ZERO), "+10ms");
+    }
+
+    /// The job summary compares the phases that are in the baseline, leaving the others empty.
+    #[test]
+    fn step_summary() {
+        let timings = Timings::new(false, false);
+        let subscriber = tracing_subscriber::registry().with(timings.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            drop(info_span!(target: "uv_resolver::resolver", "solve").entered());
+        });
+
+        // Replace the durations, retaining the structure of the table.
+        let structure = |markdown: String| {
+            markdown
+                .lines()
+                .map(|line| {
+                    if !line.starts_with('|') {
+                        return line.to_string();
+                    }
+                    line.split('|')
+                        .map(|cell| {
+                            if cell.trim().ends_with('s') || cell.contains('%') {
+                                " [TIME] "
+                            } else {
+                                cell
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join("|")
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        insta::assert_snapshot!(structure(timings.step_summary(None)), @"
+        ### uv timings
+
+        | Phase | Duration |
+        | :--- | ---: |
+        | Resolution | [TIME] |
+        | Other | [TIME] |
+        | Total | [TIME] |
+        ");
+
+        let baseline: Baseline =
+            serde_json::from_str(r#"{"phases": {"Resolution": 1.5, "Installation": 2.0}}"#)
+                .unwrap();
+        insta::assert_snapshot!(structure(timings.step_summary(Some(&baseline))), @"
+        ### uv timings
+
+        | Phase | Duration | Baseline | Change |
+        | :--- | ---: | ---: | ---: |
+        | Resolution | [TIME] | [TIME] | [TIME] |
+        | Other | [TIME] |  |  |
+        | Total | [TIME] |  |  |
+        ");
+    }
+
+    /// Time during which a network request is in flight is attributed to the network, even while
+    /// the solver is running.
+    #[test]
+    fn kinds() {
+        let timings = Timings::new(false, false);
+        let subscriber = tracing_subscriber::registry().with(timings.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            let _solve =
+                info_span!(target: "uv_resolver::resolver", "solve", kind = "compute").entered();
+            info_span!("fresh_request", kind = "network").in_scope(|| {
+                std::thread::sleep(Duration::from_millis(50));
+            });
+            std::thread::sleep(Duration::from_millis(20));
+        });
+
+        let table = timings.table();
+        let state = timings.0.lock().unwrap();
+        let [network, disk, compute] = state.phase_kinds[1];
+        assert!(network >= Duration::from_millis(50), "{table}");
+        assert!(compute >= Duration::from_millis(20), "{table}");
+        assert!(compute < Duration::from_millis(50), "{table}");
+        assert_eq!(disk, Duration::ZERO, "{table}");
+        assert_eq!(state.total_kinds[0], network);
+
+        let header = table
+            .lines()
+            .find(|line| line.starts_with("Phase") && line.contains("Network"))
+            .unwrap()
+            .split_whitespace()
+            .collect::<Vec<_>>();
+        assert_eq!(header, ["Phase", "Network", "Disk", "Compute"]);
+        assert!(
+            table
+                .lines()
+                .any(|line| line.starts_with("Resolution") && line.split_whitespace().count() == 4),
+            "{table}"
+        );
+    }
+
+    /// Without any tagged spans, the split isn't shown.
+    #[test]
+    fn no_kinds() {
+        let timings = Timings::new(false, false);
+        assert!(!timings.table().contains("Network"));
+    }
+
+    /// Only packages that were built have a build duration.
+    #[test]
+    fn packages() {
+        let timings = Timings::new(false, true);
+        let subscriber = tracing_subscriber::registry().with(timings.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            let step = |span: tracing::Span, millis: u64| {
+                span.in_scope(|| std::thread::sleep(Duration::from_millis(millis)));
+            };
+            let package = "iniconfig";
+            step(
+                info_span!(target: "uv_distribution::distribution_database", "wheel", package),
+                5,
+            );
+            step(
+                info_span!(target: "uv_install_wheel::install", "install_wheel", package),
+                1,
+            );
+            let package = "source-distribution";
+            step(
+                info_span!(target: "uv_distribution::source", "download", package),
+                5,
+            );
+            step(
+                info_span!(target: "uv_distribution::source", "build_distribution", package),
+                20,
+            );
+            step(
+                info_span!(target: "uv_install_wheel::install", "install_wheel", package),
+                1,
+            );
+        });
+
+        let table = timings.table();
+        let rows = table
+            .lines()
+            .skip_while(|line| !line.starts_with("Package"))
+            .map(|line| line.split_whitespace().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 3, "{table}");
+        assert_eq!(
+            rows[0],
+            ["Package", "Download", "Build", "Install", "Total"]
+        );
+        // The slowest package is listed first.
+        assert_eq!(rows[1][0], "source-distribution", "{table}");
+        assert_ne!(rows[1][2], "-", "{table}");
+        assert_eq!(rows[2][0], "iniconfig", "{table}");
+        assert_eq!(rows[2][2], "-", "{table}");
+    }
+}
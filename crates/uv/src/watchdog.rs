@@ -0,0 +1,238 @@
+//! A watchdog that warns about operations that are taking longer than expected, as requested via
+//! `--slow-op-threshold`.
+//!
+//! For example, a hung index server otherwise manifests as uv "doing nothing" for minutes.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::time::{Duration, Instant};
+
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Subscriber, warn};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+
+use crate::commands::elapsed;
+use crate::logging::SpanFields;
+
+/// An operation that has been running for longer than the threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SlowSpan {
+    /// The name of the span.
+    pub(crate) name: &'static str,
+    /// The fields of the span, e.g., `url=https://pypi.org/simple/numpy/`.
+    pub(crate) fields: String,
+    /// How long the span has been open.
+    pub(crate) elapsed: Duration,
+}
+
+impl fmt::Display for SlowSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.fields.is_empty() {
+            write!(
+                f,
+                "`{}` has been running for {}",
+                self.name,
+                elapsed(self.elapsed)
+            )
+        } else {
+            write!(
+                f,
+                "`{}` ({}) has been running for {}",
+                self.name,
+                self.fields,
+                elapsed(self.elapsed)
+            )
+        }
+    }
+}
+
+type Reporter = Box<dyn Fn(&SlowSpan) + Send + Sync>;
+
+/// A span that is currently open.
+#[derive(Debug)]
+struct OpenSpan {
+    name: &'static str,
+    fields: String,
+    opened: Instant,
+    /// The number of thresholds the span has exceeded so far.
+    reported: u32,
+}
+
+struct State {
+    threshold: Duration,
+    spans: Mutex<HashMap<Id, OpenSpan>>,
+    reporter: Reporter,
+}
+
+impl State {
+    /// Report all spans that exceeded a further multiple of the threshold since the last check.
+    fn check(&self) {
+        let now = Instant::now();
+        let mut slow = Vec::new();
+        {
+            let mut spans = self.spans.lock().unwrap_or_else(PoisonError::into_inner);
+            for span in spans.values_mut() {
+                let elapsed = now.duration_since(span.opened);
+                let multiple = u32::try_from(elapsed.as_nanos() / self.threshold.as_nanos())
+                    .unwrap_or(u32::MAX);
+                if multiple > span.reported {
+                    span.reported = multiple;
+                    slow.push(SlowSpan {
+                        name: span.name,
+                        fields: span.fields.clone(),
+                        elapsed,
+                    });
+                }
+            }
+        }
+        // Report outside of the lock, as the reporter may itself create spans.
+        for span in &slow {
+            (self.reporter)(span);
+        }
+    }
+}
+
+/// A [`Layer`] that tracks the open spans, along with a background thread that reports the spans
+/// that exceed the threshold.
+///
+/// The layer is installed in the global subscriber, so the thread runs until the process exits.
+/// It only holds a weak reference to the state of the layer, and exits if the layer is dropped
+/// (e.g., in tests). If the watchdog is disabled, neither the layer nor the thread exist.
+pub(crate) struct WatchdogLayer(Arc<State>);
+
+impl WatchdogLayer {
+    /// Create a watchdog that emits a `WARN` event for each slow span.
+    pub(crate) fn new(threshold: Duration) -> Self {
+        Self::with_reporter(
+            threshold,
+            Box::new(|span| warn!(target: "uv::watchdog", "{span}")),
+        )
+    }
+
+    fn with_reporter(threshold: Duration, reporter: Reporter) -> Self {
+        let state = Arc::new(State {
+            threshold,
+            spans: Mutex::new(HashMap::new()),
+            reporter,
+        });
+
+        // Check often enough that a report is at most a fraction of the threshold late.
+        let interval = (threshold / 10).clamp(Duration::from_millis(1), Duration::from_secs(1));
+        let weak = Arc::downgrade(&state);
+        let spawned = std::thread::Builder::new()
+            .name("uv-watchdog".to_string())
+            .spawn(move || watch(&weak, interval));
+        if let Err(err) = spawned {
+            warn!("Failed to start the slow operation watchdog: {err}");
+        }
+
+        Self(state)
+    }
+}
+
+fn watch(state: &Weak<State>, interval: Duration) {
+    loop {
+        std::thread::sleep(interval);
+        let Some(state) = state.upgrade() else {
+            return;
+        };
+        state.check();
+    }
+}
+
+impl<S> Layer<S> for WatchdogLayer
+where
+    S: Subscriber,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        let mut fields = SpanFields::default();
+        attrs.record(&mut fields);
+        let span = OpenSpan {
+            name: attrs.metadata().name(),
+            fields: fields.into_string(),
+            opened: Instant::now(),
+            reported: 0,
+        };
+        self.0
+            .spans
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(id.clone(), span);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut spans = self.0.spans.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(span) = spans.get_mut(id) {
+            let mut fields = SpanFields::from(std::mem::take(&mut span.fields));
+            values.record(&mut fields);
+            span.fields = fields.into_string();
+        }
+    }
+
+    fn on_close(&self, id: Id, _ctx: Context<'_, S>) {
+        self.0
+            .spans
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use tracing::info_span;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::{SlowSpan, WatchdogLayer};
+
+    /// Run `f` with a watchdog, returning the reported spans.
+    fn watch(threshold: Duration, f: impl FnOnce()) -> Vec<SlowSpan> {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let layer = WatchdogLayer::with_reporter(threshold, {
+            let reports = reports.clone();
+            Box::new(move |span: &SlowSpan| reports.lock().unwrap().push(span.clone()))
+        });
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, f);
+        reports.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn slow_span() {
+        let reports = watch(Duration::from_millis(20), || {
+            drop(info_span!("fast").entered());
+            let _slow = info_span!("fetch", url = "https://example.com/simple/").entered();
+            std::thread::sleep(Duration::from_millis(70));
+        });
+
+        // The span is reported once per exceeded multiple of the threshold, with its fields.
+        assert!(reports.len() >= 2, "{reports:?}");
+        assert!(reports.iter().all(|report| report.name == "fetch"));
+        assert_eq!(reports[0].fields, "url=https://example.com/simple/");
+        assert!(reports[0].elapsed >= Duration::from_millis(20));
+        assert!(reports[1].elapsed >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn fast_span() {
+        let reports = watch(Duration::from_secs(60), || {
+            let _span = info_span!("fetch").entered();
+        });
+        assert_eq!(reports, []);
+    }
+
+    #[test]
+    fn display() {
+        let span = SlowSpan {
+            name: "fetch",
+            fields: "url=https://example.com/simple/".to_string(),
+            elapsed: Duration::from_secs(90),
+        };
+        insta::assert_snapshot!(span, @"`fetch` (url=https://example.com/simple/) has been running for 1m 30s");
+    }
+}
@@ -66,7 +66,7 @@ fn branching_urls_overlapping() -> Result<()> {
 
     ----- stderr -----
       × Failed to resolve dependencies for `a` (v0.1.0)
-      ╰─▶ Requirements contain conflicting URLs for package `iniconfig` in split `python_full_version == '3.11.*'`:
+      ╰─▶ Requirements contain conflicting URLs for package `iniconfig` in split `python_full_version < '3.12'`:
           - https://files.pythonhosted.org/packages/9b/dd/b3c12c6d707058fa947864b67f0c4e0c39ef8610988d7baea9578f3c48f3/iniconfig-1.1.1-py2.py3-none-any.whl
           - https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl
     "
@@ -735,7 +735,7 @@ fn branching_urls_of_different_sources_conflict() -> Result<()> {
 
     ----- stderr -----
       × Failed to resolve dependencies for `a` (v0.1.0)
-      ╰─▶ Requirements contain conflicting URLs for package `iniconfig` in split `python_full_version == '3.11.*'`:
+      ╰─▶ Requirements contain conflicting URLs for package `iniconfig` in split `python_full_version < '3.12'`:
           - git+https://github.com/pytest-dev/iniconfig@93f5930e668c0d1ddf4597e38dd0dea4e2665e7a
           - https://files.pythonhosted.org/packages/9b/dd/b3c12c6d707058fa947864b67f0c4e0c39ef8610988d7baea9578f3c48f3/iniconfig-1.1.1-py2.py3-none-any.whl
     "
@@ -3,7 +3,7 @@ use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use fs_err::File;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use predicates::prelude::predicate;
 use std::env::current_dir;
@@ -1180,6 +1180,109 @@ fn build_no_build_logs() -> Result<()> {
     Ok(())
 }
 
+/// Test that the output of concurrent builds is written in whole lines, attributed to the package
+/// being built while multiple builds are running at once.
+#[test]
+fn build_concurrent_output() -> Result<()> {
+    const LINES: usize = 200;
+
+    let context = TestContext::new("3.12");
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+            [tool.uv.workspace]
+            members = ["alpha", "beta"]
+    "#})?;
+
+    // Two build backends that write long lines to both `stdout` and `stderr`.
+    for name in ["alpha", "beta"] {
+        let member = context.temp_dir.child(name);
+        member.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = []
+            backend-path = ["."]
+            build-backend = "build_backend"
+        "#})?;
+        member.child("build_backend.py").write_str(&formatdoc! {r#"
+            import os
+            import sys
+            import zipfile
+
+            DIST_INFO = "{name}-0.1.0.dist-info"
+            METADATA = "Metadata-Version: 2.1\nName: {name}\nVersion: 0.1.0\n"
+            WHEEL = "Wheel-Version: 1.0\nGenerator: build_backend\nRoot-Is-Purelib: true\nTag: py3-none-any\n"
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                for i in range({LINES}):
+                    print(f"{name} stdout line {{i}} " + "x" * 200)
+                    print(f"{name} stderr line {{i}} " + "y" * 200, file=sys.stderr)
+                filename = "{name}-0.1.0-py3-none-any.whl"
+                with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                    wheel.writestr(f"{{DIST_INFO}}/METADATA", METADATA)
+                    wheel.writestr(f"{{DIST_INFO}}/WHEEL", WHEEL)
+                    wheel.writestr(
+                        f"{{DIST_INFO}}/RECORD",
+                        f"{{DIST_INFO}}/METADATA,,\n{{DIST_INFO}}/WHEEL,,\n{{DIST_INFO}}/RECORD,,\n",
+                    )
+                return filename
+        "#})?;
+    }
+
+    // Build both packages concurrently, interleaved with uv's own logs.
+    let output = context
+        .build()
+        .arg("--all-packages")
+        .arg("--wheel")
+        .arg("-v")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+
+    let lines = stderr
+        .lines()
+        .filter(|line| line.contains(" stdout line ") || line.contains(" stderr line "))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4 * LINES, "{stderr}");
+    for name in ["alpha", "beta"] {
+        for (stream, padding) in [("stdout", "x"), ("stderr", "y")] {
+            for i in 0..LINES {
+                // Lines are only tagged with the package while both builds are running.
+                let expected = format!("{name} {stream} line {i} {}", padding.repeat(200));
+                let tagged = format!("[{name}] {expected}");
+                assert_eq!(
+                    lines
+                        .iter()
+                        .filter(|line| **line == expected || **line == tagged)
+                        .count(),
+                    1,
+                    "{stderr}"
+                );
+            }
+        }
+    }
+
+    // A single build isn't tagged.
+    let output = context
+        .build()
+        .arg("--package")
+        .arg("alpha")
+        .arg("--wheel")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("\nalpha stdout line 0 "), "{stderr}");
+    assert!(!stderr.contains("[alpha]"), "{stderr}");
+
+    Ok(())
+}
+
 /// Test that `UV_HIDE_BUILD_OUTPUT` suppresses build output.
 #[test]
 fn build_hide_build_output_env_var() -> Result<()> {
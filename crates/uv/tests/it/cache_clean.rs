@@ -29,6 +29,7 @@ fn clean_all() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     Clearing cache at: [CACHE_DIR]/
     DEBUG Released lock at `[CACHE_DIR]/.lock`
@@ -60,6 +61,7 @@ async fn clean_force() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     Clearing cache at: [CACHE_DIR]/
     DEBUG Released lock at `[CACHE_DIR]/.lock`
@@ -84,6 +86,7 @@ async fn clean_force() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Lock is busy for `[CACHE_DIR]/`
     DEBUG Cache is currently in use, proceeding due to `--force`
     Clearing cache at: [CACHE_DIR]/
@@ -140,6 +143,7 @@ fn clean_package_pypi() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     DEBUG Removing dangling cache entry: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed [N] files ([SIZE])
@@ -160,6 +164,7 @@ fn clean_package_pypi() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     Pruning cache at: [CACHE_DIR]/
     No unused entries found
@@ -219,6 +224,7 @@ fn clean_package_index() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     DEBUG Removing dangling cache entry: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed [N] files ([SIZE])
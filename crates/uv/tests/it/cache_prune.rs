@@ -36,6 +36,7 @@ fn prune_no_op() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     Pruning cache at: [CACHE_DIR]/
     No unused entries found
@@ -77,6 +78,7 @@ fn prune_stale_directory() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     Pruning cache at: [CACHE_DIR]/
     DEBUG Removing dangling cache bucket: [CACHE_DIR]/simple-v4
@@ -139,6 +141,7 @@ fn prune_cached_env() {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     Pruning cache at: [CACHE_DIR]/
     DEBUG Removing dangling cache environment: [CACHE_DIR]/environments-v2/[ENTRY]
@@ -186,6 +189,7 @@ fn prune_stale_symlink() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     Pruning cache at: [CACHE_DIR]/
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
@@ -218,6 +222,7 @@ async fn prune_force() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     Pruning cache at: [CACHE_DIR]/
     No unused entries found
@@ -239,6 +244,7 @@ async fn prune_force() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Lock is busy for `[CACHE_DIR]/`
     DEBUG Cache is currently in use, proceeding due to `--force`
     Pruning cache at: [CACHE_DIR]/
@@ -408,6 +414,7 @@ fn prune_stale_revision() -> Result<()> {
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired exclusive lock for `[CACHE_DIR]/`
     Pruning cache at: [CACHE_DIR]/
     DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v9/[ENTRY]
@@ -85,6 +85,8 @@ pub const INSTA_FILTERS: &[(&str, &str)] = &[
         r"uv(-.*)? \d+\.\d+\.\d+(-(alpha|beta|rc)\.\d+)?(\+\d+)?( \([^)]*\))?",
         r"uv [VERSION] ([COMMIT] DATE)",
     ),
+    // Stable error codes, which are covered by dedicated tests.
+    (r"(?m)^  code: [a-z-]+\r?\n", ""),
     // Trim end-of-line whitespaces, to allow removing them on save.
     (r"([^\s])[ \t]+(\r?\n)", "$1$2"),
 ];
@@ -1008,6 +1010,9 @@ impl TestContext {
         command
             // When running the tests in a venv, ignore that venv, otherwise we'll capture warnings.
             .env_remove(EnvVars::VIRTUAL_ENV)
+            // When running the tests in GitHub Actions, don't append to the job summary.
+            .env_remove(EnvVars::GITHUB_STEP_SUMMARY)
+            .env_remove(EnvVars::UV_TIMINGS_BASELINE)
             // Disable wrapping of uv output for readability / determinism in snapshots.
             .env(EnvVars::UV_NO_WRAP, "1")
             // While we disable wrapping in uv above, invoked tools may still wrap their output so
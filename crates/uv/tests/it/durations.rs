@@ -0,0 +1,428 @@
+use std::net::TcpListener;
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, bail};
+use assert_fs::prelude::{FileWriteStr, PathChild};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_static::EnvVars;
+
+use uv_cache::Cache;
+
+use crate::common::TestContext;
+
+/// With `--durations-log`, each span recorded in the durations file has a matching log message.
+#[test]
+fn durations_log() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let durations_file = context.temp_dir.child("durations.jsonl");
+
+    let output = context
+        .python_find()
+        .arg("--durations-file")
+        .arg(durations_file.path())
+        .arg("--durations-log")
+        .env(EnvVars::RUST_LOG, "uv::durations=debug")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+
+    let durations = fs_err::read_to_string(durations_file.path())?;
+    assert!(
+        durations
+            .lines()
+            .any(|line| line.contains(r#""name":"linehaul""#)),
+        "{durations}"
+    );
+    assert!(
+        stderr
+            .lines()
+            .any(|line| line.starts_with("DEBUG Span `linehaul` closed after ")),
+        "{stderr}"
+    );
+
+    // Without `--durations-log`, the spans aren't logged.
+    let output = context
+        .python_find()
+        .arg("--durations-file")
+        .arg(durations_file.path())
+        .env(EnvVars::RUST_LOG, "uv::durations=debug")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(!stderr.contains("Span `linehaul`"), "{stderr}");
+
+    Ok(())
+}
+
+/// With `--durations`, the files are written to the cache directory, unless an explicit location
+/// is provided.
+#[test]
+fn durations_in_cache() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let directory = context.cache_dir.child("durations");
+
+    let output = context.python_find().arg("--durations").output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+
+    let mut files = fs_err::read_dir(directory.path())?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<_>>>()?;
+    files.sort();
+    assert_eq!(files.len(), 3, "{files:?}");
+    for (file, extension) in files.iter().zip([".jsonl", ".summary.json", ".svg"]) {
+        let run = file
+            .strip_suffix(extension)
+            .unwrap_or_else(|| panic!("{files:?}"));
+        assert!(run.ends_with("-python"), "{files:?}");
+        // The locations are reported.
+        assert!(stderr.contains(file.as_str()), "{stderr}");
+    }
+
+    // An explicit location takes precedence.
+    let durations_file = context.temp_dir.child("durations.jsonl");
+    context
+        .python_find()
+        .arg("--durations")
+        .arg("--durations-file")
+        .arg(durations_file.path())
+        .output()?;
+    assert!(durations_file.path().is_file());
+    assert_eq!(fs_err::read_dir(directory.path())?.count(), 3);
+
+    Ok(())
+}
+
+/// With `UV_DURATIONS_FORMAT=otlp`, the spans are exported as a single OpenTelemetry trace.
+#[test]
+fn durations_otlp() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let durations_file = context.temp_dir.child("durations.jsonl");
+
+    let output = context
+        .python_find()
+        .arg("--durations-file")
+        .arg(durations_file.path())
+        .env(EnvVars::UV_DURATIONS_FORMAT, "otlp")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+
+    let otlp_file = context.temp_dir.child("durations.otlp.json");
+    assert!(
+        stderr.contains("Wrote span OpenTelemetry trace to: "),
+        "{stderr}"
+    );
+    // The format replaces the default plot.
+    assert!(!context.temp_dir.child("durations.svg").exists());
+
+    let trace: serde_json::Value =
+        serde_json::from_str(&fs_err::read_to_string(otlp_file.path())?)?;
+    let spans = trace["resourceSpans"][0]["scopeSpans"][0]["spans"]
+        .as_array()
+        .unwrap_or_else(|| panic!("{trace}"));
+    assert!(
+        spans.iter().any(|span| span["name"] == "linehaul"),
+        "{trace}"
+    );
+    assert!(
+        spans
+            .iter()
+            .all(|span| span["traceId"] == spans[0]["traceId"]),
+        "{trace}"
+    );
+
+    Ok(())
+}
+
+/// Running the same command twice doesn't overwrite the files of the first run, unless
+/// requested.
+#[test]
+fn durations_are_not_overwritten() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let durations_file = context.temp_dir.child("run.jsonl");
+
+    let files = || -> Result<Vec<String>> {
+        let mut files = Vec::new();
+        for entry in fs_err::read_dir(context.temp_dir.path())? {
+            let name = entry?.file_name().to_string_lossy().into_owned();
+            if name.starts_with("run") {
+                files.push(name);
+            }
+        }
+        files.sort();
+        Ok(files)
+    };
+
+    for _ in 0..2 {
+        context
+            .python_find()
+            .arg("--durations-file")
+            .arg(durations_file.path())
+            .output()?;
+    }
+    let output = context
+        .python_find()
+        .arg("--durations-file")
+        .arg(durations_file.path())
+        .arg("--verbose")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert_eq!(
+        files()?,
+        [
+            "run-2.jsonl",
+            "run-2.summary.json",
+            "run-2.svg",
+            "run-3.jsonl",
+            "run-3.summary.json",
+            "run-3.svg",
+            "run.jsonl",
+            "run.summary.json",
+            "run.svg",
+        ]
+    );
+    // The chosen location is logged.
+    assert!(
+        stderr.contains("Found span durations of a previous run at"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("run-3.svg"), "{stderr}");
+
+    // With `UV_DURATIONS_OVERWRITE`, the files are overwritten instead.
+    context
+        .python_find()
+        .arg("--durations-file")
+        .arg(durations_file.path())
+        .env(EnvVars::UV_DURATIONS_OVERWRITE, "1")
+        .output()?;
+    assert_eq!(files()?.len(), 9);
+
+    Ok(())
+}
+
+/// Fetches from each index are attributed to the index host in the summary.
+#[tokio::test]
+async fn index_hosts() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let durations_file = context.temp_dir.child("durations.jsonl");
+
+    // Two indexes, neither of which has any distributions for the package.
+    let first = MockServer::start().await;
+    let second = MockServer::start().await;
+    for server in [&first, &second] {
+        Mock::given(method("GET"))
+            .and(path("/iniconfig/"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                "<!DOCTYPE html><html><body><h1>Links for iniconfig</h1></body></html>",
+                "text/html",
+            ))
+            .mount(server)
+            .await;
+    }
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    // The resolution fails, but the index fetches are recorded regardless.
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(first.uri())
+        .arg("--extra-index-url")
+        .arg(second.uri())
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--durations-file")
+        .arg(durations_file.path())
+        .output()?;
+
+    let summary: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        durations_file.path().with_extension("summary.json"),
+    )?)?;
+    for server in [&first, &second] {
+        let host = &summary["hosts"][server.uri()];
+        assert_eq!(
+            host["simple_detail_single_index"]["count"], 1,
+            "{summary:#}"
+        );
+    }
+
+    Ok(())
+}
+
+/// The summary splits the time of each phase into waiting on the network, on the disk, and
+/// computing; a run that's served from the cache doesn't wait on the network.
+#[test]
+fn kinds() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let durations_file = context.temp_dir.child("durations.jsonl");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    let kinds = |offline: bool| -> Result<serde_json::Value> {
+        let mut command = context.pip_compile();
+        command
+            .arg("requirements.in")
+            .arg("--durations-file")
+            .arg(durations_file.path())
+            .env(EnvVars::UV_DURATIONS_OVERWRITE, "1");
+        if offline {
+            command.arg("--offline");
+        }
+        let output = command.output()?;
+        assert!(output.status.success(), "{output:?}");
+        let summary: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+            durations_file.path().with_extension("summary.json"),
+        )?)?;
+        Ok(summary["kinds"].clone())
+    };
+
+    // The first run fetches from the index.
+    let online = kinds(false)?;
+    assert!(
+        online["Resolution"]["network"].as_f64().unwrap() > 0.0,
+        "{online:#}"
+    );
+
+    // The second run is served from the cache.
+    let cached = kinds(true)?;
+    assert!(
+        cached["Total"]["network"].as_f64().unwrap() < 0.01,
+        "{cached:#}"
+    );
+    assert!(
+        cached["Resolution"]["compute"].as_f64().unwrap() > 0.0,
+        "{cached:#}"
+    );
+
+    Ok(())
+}
+
+/// Waiting on a lock held by another process is recorded as a span, along with the holder.
+#[tokio::test]
+async fn lock_wait() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let durations_file = context.temp_dir.child("durations.jsonl");
+
+    // Simulate another uv process holding the cache lock, and release it after a second.
+    let cache = Cache::from_path(context.cache_dir.path())
+        .with_exclusive_lock()
+        .await?;
+    let holder = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_secs(1));
+        drop(cache);
+    });
+
+    let output = context
+        .clean()
+        .arg("--durations-file")
+        .arg(durations_file.path())
+        .arg("--durations-log")
+        .env(EnvVars::RUST_LOG, "uv::durations=debug")
+        .output()?;
+    holder.join().expect("Lock holder panicked");
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+
+    let line = stderr
+        .lines()
+        .find(|line| line.starts_with("DEBUG Span `acquire_lock`"))
+        .unwrap_or_else(|| panic!("Missing `acquire_lock` span: {stderr}"));
+    assert!(line.contains("mode=exclusive"), "{line}");
+    // The holder is only discoverable on Linux.
+    if cfg!(target_os = "linux") {
+        assert!(
+            line.contains(&format!("holder_pid={}", std::process::id())),
+            "{line}"
+        );
+    }
+
+    // The wait is at most as long as the lock was held, minus the startup time of the command.
+    let duration = line
+        .rsplit_once(" closed after ")
+        .and_then(|(_, duration)| {
+            if let Some(ms) = duration.strip_suffix("ms") {
+                ms.parse::<f64>().ok().map(|ms| ms / 1000.0)
+            } else {
+                duration.strip_suffix('s')?.parse::<f64>().ok()
+            }
+        })
+        .unwrap_or_else(|| panic!("Missing duration: {line}"));
+    assert!((0.1..5.0).contains(&duration), "{line}");
+
+    let durations = fs_err::read_to_string(durations_file.path())?;
+    assert!(
+        durations
+            .lines()
+            .any(|line| line.contains(r#""name":"acquire_lock""#)),
+        "{durations}"
+    );
+
+    Ok(())
+}
+
+/// Interrupting a command via Ctrl-C should write out the spans recorded up to that point.
+#[cfg(unix)]
+#[test]
+fn interrupted_command_writes_durations() -> Result<()> {
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+
+    let context = TestContext::new_with_versions(&[]).with_managed_python_dirs();
+    let durations_file = context.temp_dir.child("durations.jsonl");
+
+    // A mirror that accepts connections but never responds, such that the download hangs.
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    listener.set_nonblocking(true)?;
+    let mirror = format!("http://{}", listener.local_addr()?);
+
+    let mut child = context
+        .python_install()
+        .arg("3.12")
+        .arg("--mirror")
+        .arg(&mirror)
+        .arg("--durations-file")
+        .arg(durations_file.path())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // Wait for the download to start.
+    let start = Instant::now();
+    let _connection = loop {
+        match listener.accept() {
+            Ok((connection, _)) => break connection,
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                if start.elapsed() > Duration::from_secs(60) {
+                    child.kill()?;
+                    bail!("uv never connected to the mirror");
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            Err(err) => return Err(err.into()),
+        }
+    };
+
+    kill(Pid::from_raw(i32::try_from(child.id())?), Signal::SIGINT)?;
+    let status = child.wait()?;
+    assert_eq!(status.code(), Some(130));
+
+    // The spans that completed before the interrupt are recorded, e.g., resolving the download.
+    let durations = fs_err::read_to_string(durations_file.path())?;
+    assert!(
+        durations
+            .lines()
+            .any(|line| line.contains(r#""name":"fetch""#)),
+        "{durations}"
+    );
+    assert!(
+        durations_file
+            .path()
+            .with_extension("summary.json")
+            .is_file()
+    );
+
+    Ok(())
+}
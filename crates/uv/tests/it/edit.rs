@@ -11035,7 +11035,7 @@ fn add_index_with_non_existent_relative_path() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Directory not found for index: file://[TEMP_DIR]/test-index
+    error: Index URL `./test-index` from `--index` refers to a path that does not exist: `test-index`
     ");
 
     Ok(())
@@ -11065,7 +11065,7 @@ fn add_index_with_non_existent_relative_path_with_same_name_as_index() -> Result
     ----- stdout -----
 
     ----- stderr -----
-    error: Directory not found for index: file://[TEMP_DIR]/test-index
+    error: Index URL `./test-index` from `--index` refers to a path that does not exist: `test-index`
     ");
 
     Ok(())
@@ -11129,8 +11129,7 @@ fn add_index_with_ambiguous_relative_path() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    warning: Relative paths passed to `--index` or `--default-index` should be disambiguated from index names (use `[PREFIX]test-index`). Support for ambiguous values will be removed in the future
-    error: Directory not found for index: file://[TEMP_DIR]/test-index
+    error: Index URL `test-index` from `--index` refers to a path that does not exist: `test-index`
     ");
 
     #[cfg(windows)]
@@ -11140,8 +11139,7 @@ fn add_index_with_ambiguous_relative_path() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    warning: Relative paths passed to `--index` or `--default-index` should be disambiguated from index names (use `[PREFIX]test-index` or `[PREFIX]test-index`). Support for ambiguous values will be removed in the future
-    error: Directory not found for index: file://[TEMP_DIR]/test-index
+    error: Index URL `test-index` from `--index` refers to a path that does not exist: `test-index`
     ");
 
     Ok(())
@@ -62,6 +62,12 @@ fn help() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --timings[=<MODE>]
+              Print a summary of the time spent in each phase of the command [possible values: phases,
+              packages]
+          --slow-op-threshold <SLOW_OP_THRESHOLD>
+              Warn when an operation has been running for longer than the given duration, e.g., `30s`
+              [env: UV_SLOW_OP_THRESHOLD=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -142,6 +148,12 @@ fn help_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --timings[=<MODE>]
+              Print a summary of the time spent in each phase of the command [possible values: phases,
+              packages]
+          --slow-op-threshold <SLOW_OP_THRESHOLD>
+              Warn when an operation has been running for longer than the given duration, e.g., `30s`
+              [env: UV_SLOW_OP_THRESHOLD=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -221,6 +233,12 @@ fn help_short_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --timings[=<MODE>]
+              Print a summary of the time spent in each phase of the command [possible values: phases,
+              packages]
+          --slow-op-threshold <SLOW_OP_THRESHOLD>
+              Warn when an operation has been running for longer than the given duration, e.g., `30s`
+              [env: UV_SLOW_OP_THRESHOLD=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -399,6 +417,27 @@ fn help_subcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --timings[=<MODE>]
+              Print a summary of the time spent in each phase of the command.
+              
+              The summary is printed to stderr once the command completes, even when `--quiet` is used.
+              
+              With `--timings=packages`, the summary is followed by the packages that took the longest
+              to download, build and install.
+
+              Possible values:
+              - phases:   Print the time spent in each phase of the command
+              - packages: Additionally print the time spent downloading, building and installing each
+                package
+
+          --slow-op-threshold <SLOW_OP_THRESHOLD>
+              Warn when an operation has been running for longer than the given duration, e.g., `30s`.
+              
+              The warning names the operation (e.g., the URL being fetched or the package being built)
+              and is repeated each time the operation exceeds a further multiple of the threshold.
+              
+              [env: UV_SLOW_OP_THRESHOLD=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -685,6 +724,27 @@ fn help_subsubcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --timings[=<MODE>]
+              Print a summary of the time spent in each phase of the command.
+              
+              The summary is printed to stderr once the command completes, even when `--quiet` is used.
+              
+              With `--timings=packages`, the summary is followed by the packages that took the longest
+              to download, build and install.
+
+              Possible values:
+              - phases:   Print the time spent in each phase of the command
+              - packages: Additionally print the time spent downloading, building and installing each
+                package
+
+          --slow-op-threshold <SLOW_OP_THRESHOLD>
+              Warn when an operation has been running for longer than the given duration, e.g., `30s`.
+              
+              The warning names the operation (e.g., the URL being fetched or the package being built)
+              and is repeated each time the operation exceeds a further multiple of the threshold.
+              
+              [env: UV_SLOW_OP_THRESHOLD=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -786,6 +846,12 @@ fn help_flag_subcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --timings[=<MODE>]
+              Print a summary of the time spent in each phase of the command [possible values: phases,
+              packages]
+          --slow-op-threshold <SLOW_OP_THRESHOLD>
+              Warn when an operation has been running for longer than the given duration, e.g., `30s`
+              [env: UV_SLOW_OP_THRESHOLD=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -869,6 +935,12 @@ fn help_flag_subsubcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --timings[=<MODE>]
+              Print a summary of the time spent in each phase of the command [possible values: phases,
+              packages]
+          --slow-op-threshold <SLOW_OP_THRESHOLD>
+              Warn when an operation has been running for longer than the given duration, e.g., `30s`
+              [env: UV_SLOW_OP_THRESHOLD=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1030,6 +1102,12 @@ fn help_with_global_option() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --timings[=<MODE>]
+              Print a summary of the time spent in each phase of the command [possible values: phases,
+              packages]
+          --slow-op-threshold <SLOW_OP_THRESHOLD>
+              Warn when an operation has been running for longer than the given duration, e.g., `30s`
+              [env: UV_SLOW_OP_THRESHOLD=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1152,6 +1230,12 @@ fn help_with_no_pager() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --timings[=<MODE>]
+              Print a summary of the time spent in each phase of the command [possible values: phases,
+              packages]
+          --slow-op-threshold <SLOW_OP_THRESHOLD>
+              Warn when an operation has been running for longer than the given duration, e.g., `30s`
+              [env: UV_SLOW_OP_THRESHOLD=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
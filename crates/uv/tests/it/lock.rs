@@ -4326,6 +4326,7 @@ fn lock_upgrade_log() -> Result<()> {
 
     ----- stderr -----
     Resolved 3 packages in [TIME]
+    1 package held back; run with `-v` for details
     ");
 
     // Modify the `pyproject.toml` to loosen a requirement, drop a requirement, and add a
@@ -4503,6 +4504,7 @@ fn lock_upgrade_log_multi_version() -> Result<()> {
 
     ----- stderr -----
     Resolved 3 packages in [TIME]
+    1 package held back; run with `-v` for details
     ");
 
     // Modify the `pyproject.toml` to loosen the requirement.
@@ -5217,7 +5219,7 @@ fn lock_requires_python() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies for split (markers: python_full_version >= '3.7' and python_full_version < '3.7.9'):
+      × No solution found when resolving dependencies for split (markers: python_full_version < '3.7.9'):
       ╰─▶ Because the requested Python version (>=3.7) does not satisfy Python>=3.7.9 and pygls>=1.1.0,<=1.2.1 depends on Python>=3.7.9,<4, we can conclude that pygls>=1.1.0,<=1.2.1 cannot be used.
           And because only the following versions of pygls are available:
               pygls<=1.1.0
@@ -18835,13 +18837,21 @@ fn lock_explicit_default_index() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock().arg("--verbose"), @r#"
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"fingerprint [0-9a-f]+", "fingerprint [FINGERPRINT]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.lock().arg("--verbose"), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Found project configuration at `pyproject.toml` (parsed in [TIME])
+    DEBUG Environment variables overriding defaults: `UV_EXCLUDE_NEWER=2024-03-25T00:00:00Z` (overrides `--exclude-newer`), `UV_PYTHON_DOWNLOADS=never` (overrides the `python-downloads` setting, default `automatic`), `UV_PYTHON_INSTALL_REGISTRY=0` (whether to install the Python executable into the Windows registry), `UV_NO_WRAP=1` (use to disable line wrapping for diagnostics)
     DEBUG Acquired shared lock for `[CACHE_DIR]/`
     DEBUG Found workspace root: `[TEMP_DIR]/`
     DEBUG Adding root workspace member: `[TEMP_DIR]/`
@@ -18849,6 +18859,9 @@ fn lock_explicit_default_index() -> Result<()> {
     DEBUG Using Python request `>=3.12` from `requires-python` metadata
     DEBUG Checking for Python environment at: `.venv`
     DEBUG The project environment's Python version satisfies the request: `Python >=3.12`
+    DEBUG Read lockfile at `[TEMP_DIR]/uv.lock` (version 1, revision 3, 2 packages, fingerprint [FINGERPRINT])
+    DEBUG Using indexes: `https://test.pypi.org/simple` (default, named `test`, from `tool.uv.index` in `pyproject.toml`)
+    DEBUG No credentials found in the `UV_INDEX_TEST_USERNAME` and `UV_INDEX_TEST_PASSWORD` environment variables for index `test`
     DEBUG Using request timeout of [TIME]
     DEBUG Found static `pyproject.toml` for: project @ file://[TEMP_DIR]/
     DEBUG No workspace root found, using project root
@@ -19239,12 +19252,222 @@ fn lock_repeat_named_index() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to parse: `pyproject.toml`
-      Caused by: TOML parse error at line 8, column 9
-      |
-    8 |         [[tool.uv.index]]
-      |         ^^^^^^^^^^^^^^^^^
-    duplicate index name `pytorch`
+    error: Index `pytorch` is defined with two different URLs: `https://astral-sh.github.io/pytorch-mirror/whl/cu121` (`tool.uv.index` in `pyproject.toml`, line 8) and `https://example.com/` (`tool.uv.index` in `pyproject.toml`, line 12)
+    ");
+
+    Ok(())
+}
+
+/// If a URL is reused under a different name, within a single file, we should raise an error.
+#[test]
+fn lock_repeat_index_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "pytorch"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu121"
+
+        [[tool.uv.index]]
+        name = "pytorch-mirror"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu121/"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index URL `https://astral-sh.github.io/pytorch-mirror/whl/cu121/` is defined under two different names: `pytorch` (`tool.uv.index` in `pyproject.toml`, line 8) and `pytorch-mirror` (`tool.uv.index` in `pyproject.toml`, line 12)
+    ");
+
+    // The same applies to a URL that's defined with conflicting `explicit` flags.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu121"
+
+        [[tool.uv.index]]
+        name = "pytorch"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu121"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index URL `https://astral-sh.github.io/pytorch-mirror/whl/cu121` is defined as both explicit (`tool.uv.index` in `pyproject.toml`, line 11) and not explicit (`tool.uv.index` in `pyproject.toml`, line 8)
+    ");
+
+    Ok(())
+}
+
+/// If an index is repeated verbatim, within a single file, it should be deduplicated.
+#[test]
+fn lock_repeat_index_exact() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple"
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple/"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // An index that's repeated with different settings is not a duplicate, and is rejected.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple"
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple/"
+        authenticate = "always"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index `proxy` is defined twice with different settings (`tool.uv.index` in `pyproject.toml`, line 8; `tool.uv.index` in `pyproject.toml`, line 12)
+    ");
+
+    Ok(())
+}
+
+/// If a name is reused across the project and user configuration with different URLs, we should
+/// raise an error naming both files.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn lock_repeat_named_index_user_configuration() -> Result<()> {
+    let xdg = assert_fs::TempDir::new()?;
+    xdg.child("uv").child("uv.toml").write_str(indoc! {r#"
+        [[index]]
+        name = "other"
+        url = "https://example.com/other"
+
+        [[index]]
+        name = "proxy"
+        url = "https://example.com/simple"
+    "#})?;
+
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple"
+        "#,
+    )?;
+
+    let filters = TestContext::path_patterns(xdg.path())
+        .into_iter()
+        .map(|pattern| (pattern, "[XDG_CONFIG_HOME]/".to_string()))
+        .collect::<Vec<_>>();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain(
+            filters
+                .iter()
+                .map(|(pattern, replacement)| (pattern.as_str(), replacement.as_str())),
+        )
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.lock().env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index `proxy` is defined with two different URLs: `https://pypi-proxy.fly.dev/simple` (`tool.uv.index` in `pyproject.toml`, line 8) and `https://example.com/simple` (`index` in `[XDG_CONFIG_HOME]/uv/uv.toml`, line 5)
+    ");
+
+    // An identical definition in both files is deduplicated.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://example.com/simple/"
+        "#,
+    )?;
+
+    uv_snapshot!(filters, context.lock().env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
     ");
 
     Ok(())
@@ -22064,7 +22287,7 @@ fn lock_keyring_explicit_always() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because iniconfig was not found in the package registry and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
 
-          hint: An index URL (https://pypi-proxy.fly.dev/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized).
+          hint: An index URL (https://pypi-proxy.fly.dev/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized). The credentials were read from the keyring (username: `public`).
     ");
 
     // With valid credentials, we should succeed
@@ -22557,8 +22780,11 @@ fn lock_multiple_sources_index_disjoint_markers() -> Result<()> {
     Ok(())
 }
 
+/// Pin a package to different indexes under disjoint markers, from different packages. By default,
+/// the pins conflict in the universal fork; with the `index-divergence` preview feature, the
+/// resolution forks such that each fork uses its own index.
 #[test]
-fn lock_multiple_sources_index_mixed() -> Result<()> {
+fn lock_multiple_sources_index_divergence() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -22568,49 +22794,157 @@ fn lock_multiple_sources_index_mixed() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["jinja2>=3,<3.1.4"]
+        dependencies = ["jinja2>=3,<3.1.4 ; sys_platform == 'win32'", "child"]
 
         [tool.uv]
         constraint-dependencies = ["markupsafe<3"]
 
+        [tool.uv.workspace]
+        members = ["child"]
+
         [tool.uv.sources]
-        jinja2 = [
-            { index = "torch-cu118", marker = "sys_platform == 'win32'"},
-            { url = "https://files.pythonhosted.org/packages/31/80/3a54838c3fb461f6fec263ebf3a3a41771bd05190238de3486aae8540c36/jinja2-3.1.4-py3-none-any.whl", marker = "sys_platform != 'win32'"},
-        ]
+        jinja2 = { index = "torch-cu118" }
+        child = { workspace = true }
 
         [[tool.uv.index]]
         name = "torch-cu118"
         url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @"
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["jinja2>=3,<3.1.4 ; sys_platform != 'win32'"]
+
+        [tool.uv.sources]
+        jinja2 = { index = "torch-cu124" }
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+    child.child("src/child/__init__.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to resolve dependencies for `child` (v0.1.0)
+      ╰─▶ Requirements contain conflicting indexes for package `jinja2` in all marker environments:
+          - under `sys_platform == 'win32'`, `jinja2` came from the additional index `torch-cu118` (https://astral-sh.github.io/pytorch-mirror/whl/cu118), required by `project` as `jinja2>=3, <3.1.4 ; sys_platform == 'win32'`; versions unknown
+          - under `sys_platform != 'win32'`, `jinja2` came from the additional index `torch-cu124` (https://astral-sh.github.io/pytorch-mirror/whl/cu124), required by `child` as `jinja2>=3, <3.1.4 ; sys_platform != 'win32'`; versions unknown
+
+          hint: Pin `jinja2` to a single index in each `[tool.uv.sources]` table that declares it, e.g.:
+
+              [tool.uv.sources]
+              jinja2 = { index = "torch-cu118" }
+
+              [[tool.uv.index]]
+              name = "torch-cu118"
+              url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+
+          See https://docs.astral.sh/uv/concepts/indexes/#pinning-a-package-to-an-index for details.
+    "#);
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("index-divergence"), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
+    Resolved 5 packages in [TIME]
     ");
 
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
+    assert!(
+        lock.contains(
+            r#"resolution-markers = [
+    "sys_platform == 'win32'",
+    "sys_platform != 'win32'",
+]"#
+        ),
+        "{lock}"
+    );
 
-    insta::with_settings!({
-        filters => context.filters(),
-    }, {
-        assert_snapshot!(
-            lock, @r#"
-        version = 1
-        revision = 3
-        requires-python = ">=3.12"
-        resolution-markers = [
-            "sys_platform == 'win32'",
-            "sys_platform != 'win32'",
-        ]
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--preview-features").arg("index-divergence"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-        [options]
-        exclude-newer = "2025-01-30T00:00:00Z"
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lock_multiple_sources_index_mixed() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["jinja2>=3,<3.1.4"]
+
+        [tool.uv]
+        constraint-dependencies = ["markupsafe<3"]
+
+        [tool.uv.sources]
+        jinja2 = [
+            { index = "torch-cu118", marker = "sys_platform == 'win32'"},
+            { url = "https://files.pythonhosted.org/packages/31/80/3a54838c3fb461f6fec263ebf3a3a41771bd05190238de3486aae8540c36/jinja2-3.1.4-py3-none-any.whl", marker = "sys_platform != 'win32'"},
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+        resolution-markers = [
+            "sys_platform == 'win32'",
+            "sys_platform != 'win32'",
+        ]
+
+        [options]
+        exclude-newer = "2025-01-30T00:00:00Z"
 
         [manifest]
         constraints = [{ name = "markupsafe", specifier = "<3" }]
@@ -23137,84 +23471,962 @@ fn lock_multiple_sources_extra() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["iniconfig"]
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        cpu = []
+
+        [tool.uv.sources]
+        iniconfig = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", marker = "extra == 'cpu'" },
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+
+        [package.optional-dependencies]
+        cpu = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "iniconfig", marker = "extra == 'cpu'", url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" },
+            { name = "iniconfig", marker = "extra != 'cpu'" },
+        ]
+        provides-extras = ["cpu"]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// The extras pin `jinja2` to different indexes, but for disjoint versions, so the indexes don't
+/// conflict. Instead, the extras themselves are incompatible.
+#[test]
+fn lock_multiple_sources_index_overlapping_extras() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cu118 = ["jinja2==3.1.2"]
+        cu124 = ["jinja2==3.1.3"]
+
+        [tool.uv]
+        constraint-dependencies = ["markupsafe<3"]
+
+        [tool.uv.sources]
+        jinja2 = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because project[cu124] depends on jinja2==3.1.3 and project[cu118] depends on jinja2==3.1.2, we can conclude that project[cu118] and project[cu124] are incompatible.
+          And because your project requires project[cu118] and project[cu124], we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
+/// The extras pin `jinja2` to different indexes for overlapping versions, which conflicts, since a
+/// version in both ranges could be claimed by either index.
+#[test]
+fn lock_multiple_sources_index_overlapping_extras_versions() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cu118 = ["jinja2>=3.1.2"]
+        cu124 = ["jinja2<=3.1.3"]
+
+        [tool.uv]
+        constraint-dependencies = ["markupsafe<3"]
+
+        [tool.uv.sources]
+        jinja2 = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to resolve dependencies for `project` (v0.1.0)
+      ╰─▶ Requirements contain conflicting indexes for package `jinja2` in all marker environments:
+          - under `extra == 'cu118'`, `jinja2` came from the explicit index `torch-cu118` (https://astral-sh.github.io/pytorch-mirror/whl/cu118), required by `project` as `jinja2>=3.1.2 ; extra == 'cu118'`; versions unknown
+          - under `extra == 'cu124'`, `jinja2` came from the explicit index `torch-cu124` (https://astral-sh.github.io/pytorch-mirror/whl/cu124), required by `project` as `jinja2<=3.1.3 ; extra == 'cu124'`; versions unknown
+
+          hint: Pin `jinja2` to a single index in each `[tool.uv.sources]` table that declares it, e.g.:
+
+              [tool.uv.sources]
+              jinja2 = { index = "torch-cu118" }
+
+              [[tool.uv.index]]
+              name = "torch-cu118"
+              url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+
+          See https://docs.astral.sh/uv/concepts/indexes/#pinning-a-package-to-an-index for details.
+    "#);
+
+    Ok(())
+}
+
+/// The extras pin `jinja2` to different indexes for overlapping versions. By default, the conflict
+/// is an error; under `--index-conflict-strategy prefer-first`, the first-defined index wins.
+#[test]
+fn lock_multiple_sources_index_conflict_strategy() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cu118 = ["jinja2>=3.1.2"]
+        cu124 = ["jinja2<=3.1.3"]
+
+        [tool.uv]
+        constraint-dependencies = ["markupsafe<3"]
+
+        [tool.uv.sources]
+        jinja2 = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--index-conflict-strategy").arg("error"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to resolve dependencies for `project` (v0.1.0)
+      ╰─▶ Requirements contain conflicting indexes for package `jinja2` in all marker environments:
+          - under `extra == 'cu118'`, `jinja2` came from the explicit index `torch-cu118` (https://astral-sh.github.io/pytorch-mirror/whl/cu118), required by `project` as `jinja2>=3.1.2 ; extra == 'cu118'`; versions unknown
+          - under `extra == 'cu124'`, `jinja2` came from the explicit index `torch-cu124` (https://astral-sh.github.io/pytorch-mirror/whl/cu124), required by `project` as `jinja2<=3.1.3 ; extra == 'cu124'`; versions unknown
+
+          hint: Pin `jinja2` to a single index in each `[tool.uv.sources]` table that declares it, e.g.:
+
+              [tool.uv.sources]
+              jinja2 = { index = "torch-cu118" }
+
+              [[tool.uv.index]]
+              name = "torch-cu118"
+              url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+
+          See https://docs.astral.sh/uv/concepts/indexes/#pinning-a-package-to-an-index for details.
+    "#);
+
+    uv_snapshot!(context.filters(), context.lock().arg("--index-conflict-strategy").arg("prefer-first"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `jinja2` is pinned to conflicting indexes; using https://astral-sh.github.io/pytorch-mirror/whl/cu118 over the lower-priority https://astral-sh.github.io/pytorch-mirror/whl/cu124
+    Resolved 3 packages in [TIME]
+    ");
+
+    // The choice is logged under the `uv_resolver::indexes` target.
+    let output = context
+        .lock()
+        .arg("--index-conflict-strategy")
+        .arg("prefer-first")
+        .env(EnvVars::RUST_LOG, "uv_resolver::indexes=debug")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.lines().any(|line| line == "DEBUG Preferred https://astral-sh.github.io/pytorch-mirror/whl/cu118 over https://astral-sh.github.io/pytorch-mirror/whl/cu124 for `jinja2` in all marker environments, required by `project` as `jinja2<=3.1.3 ; extra == 'cu124'`"));
+
+    uv_snapshot!(context.filters(), context.lock().arg("--index-conflict-strategy").arg("warn"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `jinja2` is pinned to conflicting indexes; using https://astral-sh.github.io/pytorch-mirror/whl/cu118, which it was pinned to first, over https://astral-sh.github.io/pytorch-mirror/whl/cu124. The index that's used depends on the order in which requirements are resolved, so the lockfile may change when unrelated requirements change.
+    Resolved 3 packages in [TIME]
+    ");
+
+    // The number of ignored pins is logged once the resolution completes.
+    let output = context
+        .lock()
+        .arg("--index-conflict-strategy")
+        .arg("warn")
+        .env(EnvVars::RUST_LOG, "uv_resolver::indexes=debug")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(
+        stderr
+            .lines()
+            .any(|line| line == "DEBUG Ignored 1 conflicting index pin(s)")
+    );
+
+    Ok(())
+}
+
+/// A package pinned to an index and to one of its `aliases` isn't pinned to conflicting indexes,
+/// and is fetched from the index that was defined first.
+#[test]
+fn lock_multiple_sources_index_aliases() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cu118 = ["jinja2>=3.1.2"]
+        cu124 = ["jinja2<=3.1.3"]
+
+        [tool.uv]
+        constraint-dependencies = ["markupsafe<3"]
+
+        [tool.uv.sources]
+        jinja2 = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        aliases = ["https://astral-sh.github.io/pytorch-mirror/whl/cu124"]
+        explicit = true
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(
+        r#"source = { registry = "https://astral-sh.github.io/pytorch-mirror/whl/cu118" }"#
+    ));
+    assert!(!lock.contains(
+        r#"source = { registry = "https://astral-sh.github.io/pytorch-mirror/whl/cu124" }"#
+    ));
+
+    // Without the alias, the indexes conflict.
+    pyproject_toml.write_str(&fs_err::read_to_string(&pyproject_toml)?.replace(
+        "aliases = [\"https://astral-sh.github.io/pytorch-mirror/whl/cu124\"]\n",
+        "",
+    ))?;
+
+    let output = context.lock().output()?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains(
+        "Requirements contain conflicting indexes for package `jinja2` in all marker environments"
+    ));
+
+    Ok(())
+}
+
+/// A package pinned to different indexes by two workspace members is pinned to the same index in
+/// every fork under `--index-conflict-strategy warn`, regardless of the order of the requirements.
+#[test]
+fn lock_multiple_sources_index_conflict_strategy_warn_order() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_pyproject = |dependencies: &str| {
+        pyproject_toml.write_str(&format!(
+            r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = [{dependencies}]
+
+            [tool.uv.workspace]
+            members = ["child-a", "child-b"]
+
+            [tool.uv.sources]
+            child-a = {{ workspace = true }}
+            child-b = {{ workspace = true }}
+
+            [[tool.uv.index]]
+            name = "torch-cu118"
+            url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+            explicit = true
+
+            [[tool.uv.index]]
+            name = "torch-cu124"
+            url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+            explicit = true
+            "#
+        ))
+    };
+
+    for (member, index) in [("child-a", "torch-cu118"), ("child-b", "torch-cu124")] {
+        context
+            .temp_dir
+            .child(member)
+            .child("pyproject.toml")
+            .write_str(&format!(
+                r#"
+                [project]
+                name = "{member}"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+                dependencies = [
+                    "jinja2==3.1.3",
+                    "iniconfig==1.1.1 ; sys_platform == 'linux'",
+                    "iniconfig==2.0.0 ; sys_platform != 'linux'",
+                    "markupsafe<3",
+                ]
+
+                [build-system]
+                requires = ["setuptools>=42"]
+                build-backend = "setuptools.build_meta"
+
+                [tool.uv.sources]
+                jinja2 = {{ index = "{index}" }}
+                "#
+            ))?;
+    }
+
+    write_pyproject(r#""child-a", "child-b""#)?;
+    uv_snapshot!(context.filters(), context.lock().arg("--index-conflict-strategy").arg("warn"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `jinja2` is pinned to conflicting indexes; using https://astral-sh.github.io/pytorch-mirror/whl/cu118, which it was pinned to first, over https://astral-sh.github.io/pytorch-mirror/whl/cu124. The index that's used depends on the order in which requirements are resolved, so the lockfile may change when unrelated requirements change.
+    Resolved 7 packages in [TIME]
+    ");
+    let forward = context.read("uv.lock");
+    assert!(forward.contains(
+        r#"source = { registry = "https://astral-sh.github.io/pytorch-mirror/whl/cu118" }"#
+    ));
+    assert!(!forward.contains(
+        r#"source = { registry = "https://astral-sh.github.io/pytorch-mirror/whl/cu124" }"#
+    ));
+
+    // Reversing the order of the requirements doesn't change the index.
+    fs_err::remove_file(context.temp_dir.child("uv.lock"))?;
+    write_pyproject(r#""child-b", "child-a""#)?;
+    context
+        .lock()
+        .arg("--index-conflict-strategy")
+        .arg("warn")
+        .assert()
+        .success();
+    let reversed = context.read("uv.lock");
+    assert_eq!(forward, reversed);
+
+    Ok(())
+}
+
+/// Packages may be installed from any index in `allowed-indexes`, which accepts index names and
+/// URLs.
+#[test]
+fn lock_allowed_indexes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "typing-extensions"]
+
+        [tool.uv]
+        allowed-indexes = ["proxy", "https://pypi.org/simple"]
+
+        [tool.uv.sources]
+        iniconfig = { index = "proxy" }
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// A package that would be installed from an index outside of `allowed-indexes` is rejected.
+#[test]
+fn lock_allowed_indexes_disallowed_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        allowed-indexes = ["proxy"]
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` would be installed from https://pypi.org/simple, which is not one of the allowed indexes: https://pypi-proxy.fly.dev/simple
+    ");
+
+    // Naming an index that isn't defined is an error.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        allowed-indexes = ["internal"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `allowed-indexes` includes `internal`, but no index with that name is defined
+    ");
+
+    Ok(())
+}
+
+/// A direct URL requirement is rejected unless it's hosted on the host of an allowed index.
+#[test]
+fn lock_allowed_indexes_disallowed_direct_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"]
+
+        [tool.uv]
+        allowed-indexes = ["https://pypi.org/simple"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` would be installed from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl, which is not one of the allowed indexes: https://pypi.org/simple
+    ");
+
+    Ok(())
+}
+
+/// The allowlist is also enforced when an existing lockfile is read, e.g., when it's already
+/// up-to-date or when syncing with `--frozen`. Packages from local paths are always allowed.
+#[test]
+fn lock_allowed_indexes_existing_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "iniconfig"]
+
+        [tool.uv]
+        allowed-indexes = ["https://pypi.org/simple"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    context
+        .temp_dir
+        .child("child")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+            [project]
+            name = "child"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [build-system]
+            requires = ["setuptools>=42"]
+            build-backend = "setuptools.build_meta"
+            "#,
+        )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Restrict the allowlist, such that the locked `iniconfig` is no longer allowed.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "iniconfig"]
+
+        [tool.uv]
+        allowed-indexes = ["https://pypi-proxy.fly.dev/simple"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` would be installed from https://pypi.org/simple, which is not one of the allowed indexes: https://pypi-proxy.fly.dev/simple
+    ");
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` would be installed from https://pypi.org/simple, which is not one of the allowed indexes: https://pypi-proxy.fly.dev/simple
+    ");
+
+    Ok(())
+}
+
+/// A package pinned to conflicting indexes is reported as JSON with `UV_ERROR_FORMAT=json` or
+/// `--output-format json`, both outside of a fork and within one.
+#[test]
+fn lock_conflicting_indexes_json() -> Result<()> {
+    /// Validate the documented shape of the report, returning the report.
+    fn report(stderr: &[u8]) -> Result<serde_json::Value> {
+        let stderr = std::str::from_utf8(stderr)?;
+        let line = stderr.lines().last().unwrap_or_default();
+        let report: serde_json::Value = serde_json::from_str(line)?;
+        assert_eq!(report["code"], "conflicting-indexes");
+        assert!(report["package"].is_string());
+        assert!(report["fork_markers"].is_string() || report["fork_markers"].is_null());
+        let indexes = report["indexes"].as_array().unwrap();
+        assert_eq!(indexes.len(), 2);
+        for index in indexes {
+            assert!(index["url"].is_string());
+            assert!(index["requirement"].is_string() || index["requirement"].is_null());
+            assert!(index["parent"].is_string() || index["parent"].is_null());
+            assert!(index["marker"].is_string() || index["marker"].is_null());
+        }
+        Ok(report)
+    }
+
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cu118 = ["jinja2>=3.1.2"]
+        cu124 = ["jinja2<=3.1.3"]
+
+        [tool.uv]
+        constraint-dependencies = ["markupsafe<3"]
+
+        [tool.uv.sources]
+        jinja2 = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+        "#,
+    )?;
+
+    let output = context
+        .lock()
+        .env(EnvVars::UV_ERROR_FORMAT, "json")
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+    insta::assert_json_snapshot!(report(&output.stderr)?, @r#"
+    {
+      "code": "conflicting-indexes",
+      "fork_markers": null,
+      "indexes": [
+        {
+          "marker": "extra == 'cu118'",
+          "parent": "project",
+          "requirement": "jinja2>=3.1.2 ; extra == 'cu118'",
+          "url": "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        },
+        {
+          "marker": "extra == 'cu124'",
+          "parent": "project",
+          "requirement": "jinja2<=3.1.3 ; extra == 'cu124'",
+          "url": "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        }
+      ],
+      "package": "jinja2"
+    }
+    "#);
+
+    // `--output-format json` renders the same report.
+    let output = context.sync().arg("--output-format").arg("json").output()?;
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        report(&output.stderr)?["indexes"][0]["url"],
+        "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+    );
+
+    // Within a fork, the markers of the fork are included.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "markupsafe==2.1.5 ; sys_platform == 'linux'",
+            "markupsafe==2.1.4 ; sys_platform != 'linux'",
+        ]
+
+        [project.optional-dependencies]
+        cu118 = ["jinja2>=3.1.2"]
+        cu124 = ["jinja2<=3.1.3"]
+
+        [tool.uv.sources]
+        jinja2 = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+        "#,
+    )?;
+
+    let output = context
+        .lock()
+        .env(EnvVars::UV_ERROR_FORMAT, "json")
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+    insta::assert_json_snapshot!(report(&output.stderr)?, @r#"
+    {
+      "code": "conflicting-indexes",
+      "fork_markers": "sys_platform == 'linux'",
+      "indexes": [
+        {
+          "marker": "sys_platform == 'linux' and extra == 'cu118'",
+          "parent": "project",
+          "requirement": "jinja2>=3.1.2 ; extra == 'cu118'",
+          "url": "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        },
+        {
+          "marker": "sys_platform == 'linux' and extra == 'cu124'",
+          "parent": "project",
+          "requirement": "jinja2<=3.1.3 ; extra == 'cu124'",
+          "url": "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        }
+      ],
+      "package": "jinja2"
+    }
+    "#);
+
+    // Without opting in, the error remains human-readable.
+    let output = context.lock().output()?;
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8(output.stderr)?
+            .contains("Requirements contain conflicting indexes for package `jinja2`")
+    );
+
+    Ok(())
+}
+
+/// The conflicting indexes of several packages are reported together, in a single error, rather
+/// than one at a time.
+#[test]
+fn lock_conflicting_indexes_multiple_packages() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
 
         [project.optional-dependencies]
-        cpu = []
+        cu118 = ["jinja2", "markupsafe", "filelock"]
+        cu124 = ["jinja2", "markupsafe", "filelock"]
 
         [tool.uv.sources]
-        iniconfig = [
-            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", marker = "extra == 'cpu'" },
+        jinja2 = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
         ]
-        "#,
-    )?;
-
-    uv_snapshot!(context.filters(), context.lock(), @"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-
-    ----- stderr -----
-    Resolved 2 packages in [TIME]
-    ");
-
-    let lock = context.read("uv.lock");
-
-    insta::with_settings!({
-        filters => context.filters(),
-    }, {
-        assert_snapshot!(
-            lock, @r#"
-        version = 1
-        revision = 3
-        requires-python = ">=3.12"
-
-        [options]
-        exclude-newer = "2024-03-25T00:00:00Z"
-
-        [[package]]
-        name = "iniconfig"
-        version = "2.0.0"
-        source = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" },
+        markupsafe = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
+        ]
+        filelock = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
         ]
 
-        [[package]]
-        name = "project"
-        version = "0.1.0"
-        source = { virtual = "." }
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
 
-        [package.optional-dependencies]
-        cpu = [
-            { name = "iniconfig" },
-        ]
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+        "#,
+    )?;
 
-        [package.metadata]
-        requires-dist = [
-            { name = "iniconfig", marker = "extra == 'cpu'", url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" },
-            { name = "iniconfig", marker = "extra != 'cpu'" },
-        ]
-        provides-extras = ["cpu"]
-        "#
+    let output = context.lock().output()?;
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(
+        stderr.contains("Requirements contain conflicting indexes for 3 packages"),
+        "{stderr}"
+    );
+    for package in ["filelock", "jinja2", "markupsafe"] {
+        assert!(
+            stderr.contains(&format!(
+                "Requirements contain conflicting indexes for package `{package}`"
+            )),
+            "{stderr}"
         );
-    });
-
-    // Re-run with `--locked`.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    }
 
-    ----- stderr -----
-    Resolved 2 packages in [TIME]
-    ");
+    // Each package is reported with its own conflict, sorted by package name.
+    let output = context
+        .lock()
+        .env(EnvVars::UV_ERROR_FORMAT, "json")
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr)?;
+    let report: serde_json::Value =
+        serde_json::from_str(stderr.lines().last().unwrap_or_default())?;
+    assert_eq!(report["code"], "conflicting-indexes-for-packages");
+    let conflicts = report["conflicts"].as_array().unwrap();
+    assert_eq!(
+        conflicts
+            .iter()
+            .map(|conflict| conflict["package"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        ["filelock", "jinja2", "markupsafe"]
+    );
+    for conflict in conflicts {
+        assert_eq!(conflict["code"], "conflicting-indexes");
+        assert_eq!(conflict["indexes"].as_array().unwrap().len(), 2);
+    }
 
     Ok(())
 }
 
+/// A package pinned to the default index and to a named index is reported with the role of each
+/// index, along with a hint to drop the pin to the default index.
 #[test]
-fn lock_multiple_sources_index_overlapping_extras() -> Result<()> {
+fn lock_conflicting_indexes_default() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -23227,22 +24439,22 @@ fn lock_multiple_sources_index_overlapping_extras() -> Result<()> {
         dependencies = []
 
         [project.optional-dependencies]
-        cu118 = ["jinja2==3.1.2"]
-        cu124 = ["jinja2==3.1.3"]
+        cu118 = ["jinja2>=3.1.2"]
+        cu124 = ["jinja2<=3.1.3"]
 
         [tool.uv]
         constraint-dependencies = ["markupsafe<3"]
 
         [tool.uv.sources]
         jinja2 = [
-            { index = "torch-cu118", extra = "cu118" },
+            { index = "pypi", extra = "cu118" },
             { index = "torch-cu124", extra = "cu124" },
         ]
 
         [[tool.uv.index]]
-        name = "torch-cu118"
-        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
-        explicit = true
+        name = "pypi"
+        url = "https://pypi.org/simple"
+        default = true
 
         [[tool.uv.index]]
         name = "torch-cu124"
@@ -23251,7 +24463,7 @@ fn lock_multiple_sources_index_overlapping_extras() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -23259,8 +24471,54 @@ fn lock_multiple_sources_index_overlapping_extras() -> Result<()> {
     ----- stderr -----
       × Failed to resolve dependencies for `project` (v0.1.0)
       ╰─▶ Requirements contain conflicting indexes for package `jinja2` in all marker environments:
-          - https://astral-sh.github.io/pytorch-mirror/whl/cu118
-          - https://astral-sh.github.io/pytorch-mirror/whl/cu124
+          - under `extra == 'cu118'`, `jinja2` came from the default index `pypi` (https://pypi.org/simple), required by `project` as `jinja2>=3.1.2 ; extra == 'cu118'`; versions unknown
+          - under `extra == 'cu124'`, `jinja2` came from the explicit index `torch-cu124` (https://astral-sh.github.io/pytorch-mirror/whl/cu124), required by `project` as `jinja2<=3.1.3 ; extra == 'cu124'`; versions unknown
+
+          hint: `jinja2` is pinned to the default index, which is already used for packages that aren't pinned to an index. Remove that pin to use `torch-cu124` wherever `jinja2` is pinned to it, or pin `jinja2` to a single index in each `[tool.uv.sources]` table that declares it.
+
+          See https://docs.astral.sh/uv/concepts/indexes/#pinning-a-package-to-an-index for details.
+    "#);
+
+    Ok(())
+}
+
+/// A package pinned to an index that doesn't provide it includes a hint pointing to the index on
+/// which it was found in another fork.
+#[test]
+fn lock_pinned_index_available_elsewhere() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.sources]
+        iniconfig = [
+            { index = "torch-cu118", marker = "sys_platform == 'linux'" },
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies for split (markers: sys_platform == 'linux'):
+      ╰─▶ Because there are no versions of iniconfig{sys_platform == 'linux'} and your project depends on iniconfig{sys_platform == 'linux'}, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: `iniconfig` is pinned to the index `torch-cu118` (https://astral-sh.github.io/pytorch-mirror/whl/cu118), which doesn't provide a compatible version, but it is available on https://pypi.org/simple
     ");
 
     Ok(())
@@ -23554,6 +24812,7 @@ fn lock_dry_run_noop() -> Result<()> {
 
     ----- stderr -----
     Resolved 5 packages in [TIME]
+    1 package held back; run with `-v` for details
     No lockfile changes detected
     ");
 
@@ -33329,3 +34588,214 @@ fn lock_check_multiple_default_indexes_explicit_assignment_dependency_group() ->
 
     Ok(())
 }
+
+/// Reading and writing the lockfile is logged, along with whether the existing lockfile was
+/// unchanged and, if not, why it was stale and what changed.
+#[test]
+fn lock_read_write_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"fingerprint [0-9a-f]+", "fingerprint [FINGERPRINT]")])
+        .collect::<Vec<_>>();
+    let lockfile_events = |output: std::process::Output| -> Result<String> {
+        let stderr = String::from_utf8(output.stderr)?;
+        Ok(stderr
+            .lines()
+            .filter(|line| {
+                line.starts_with("DEBUG Read lockfile")
+                    || line.starts_with("DEBUG Wrote lockfile")
+                    || line.starts_with("DEBUG Lockfile at")
+                    || line.starts_with("DEBUG Existing `uv.lock`")
+                    || line.starts_with("DEBUG Resolving despite existing lockfile")
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    };
+
+    // A no-op lock reads the lockfile, and leaves it unchanged.
+    let output = context.lock().arg("--verbose").output()?;
+    assert!(output.status.success(), "{output:?}");
+    let events = lockfile_events(output)?;
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(events, @r"
+        DEBUG Read lockfile at `[TEMP_DIR]/uv.lock` (version 1, revision 3, 2 packages, fingerprint [FINGERPRINT])
+        DEBUG Existing `uv.lock` satisfies workspace requirements
+        DEBUG Lockfile at `[TEMP_DIR]/uv.lock` is unchanged
+        ");
+    });
+
+    // Editing a requirement invalidates the lockfile, and logs why.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.1.1"]
+        "#,
+    )?;
+
+    let output = context.lock().arg("--verbose").output()?;
+    assert!(output.status.success(), "{output:?}");
+    let events = lockfile_events(output)?;
+    insta::with_settings!({
+        filters => filters,
+    }, {
+        assert_snapshot!(events, @r"
+        DEBUG Read lockfile at `[TEMP_DIR]/uv.lock` (version 1, revision 3, 2 packages, fingerprint [FINGERPRINT])
+        DEBUG Resolving despite existing lockfile due to mismatched requirements for: `project==0.1.0`
+        DEBUG Lockfile at `[TEMP_DIR]/uv.lock` changed: 0 added, 0 removed, 1 updated
+        DEBUG Wrote lockfile to `[TEMP_DIR]/uv.lock` (2 packages, fingerprint [FINGERPRINT])
+        ");
+    });
+
+    Ok(())
+}
+
+/// When a setting is configured in both the user and the project configuration, the discovered
+/// files are logged, along with the project value taking precedence over the user value.
+#[test]
+fn lock_config_precedence() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let uv = context.user_config_dir.child("uv");
+    uv.create_dir_all()?;
+    uv.child("uv.toml").write_str(indoc! {r#"
+        resolution = "lowest-direct"
+        python-preference = "only-system"
+    "#})?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        resolution = "highest"
+        python-preference = "only-system"
+    "#})?;
+
+    let output = context
+        .lock()
+        .env(EnvVars::RUST_LOG, "uv_settings=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let events = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(events, @r"
+        DEBUG Found project configuration at `pyproject.toml` (parsed in [TIME])
+        DEBUG Found user configuration at `[UV_USER_CONFIG_DIR]/uv.toml` (parsed in [TIME])
+        DEBUG Using resolution strategy `highest` from `tool.uv.resolution` in `pyproject.toml`, over `lowest-direct` from `resolution` in `[UV_USER_CONFIG_DIR]/uv.toml`
+        ");
+    });
+
+    Ok(())
+}
+
+/// The effective index list is logged once, in the order in which the indexes are searched, along
+/// with where each index was configured.
+#[test]
+fn lock_effective_indexes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [[tool.uv.index]]
+        name = "pytorch"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+        explicit = true
+    "#})?;
+
+    let output = context
+        .lock()
+        .arg("--index")
+        .arg("https://test.pypi.org/simple")
+        .env(EnvVars::UV_INDEX_URL, "https://pypi.org/simple")
+        .env(EnvVars::RUST_LOG, "uv_distribution::indexes=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let events = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(events, @"DEBUG Using indexes: `https://test.pypi.org/simple` (additional, from `--index`), `https://pypi.org/simple` (default, from `UV_INDEX_URL`), `https://astral-sh.github.io/pytorch-mirror/whl/cpu` (explicit, named `pytorch`, from `tool.uv.index` in `pyproject.toml`)");
+    });
+
+    Ok(())
+}
+
+/// `--resolver-dump` writes the state of each fork that was solved while locking as JSON.
+#[test]
+fn lock_resolver_dump() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    let dump = context.temp_dir.child("dump.json");
+    uv_snapshot!(context.filters(), context.lock().arg("--resolver-dump").arg(dump.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Wrote resolver dump to: dump.json
+    Resolved 2 packages in [TIME]
+    ");
+
+    let forks: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(dump.path())?)?;
+    let forks = forks["forks"].as_array().unwrap();
+    assert_eq!(forks.len(), 1, "{forks:?}");
+    assert_eq!(forks[0]["failed"], false, "{forks:?}");
+
+    Ok(())
+}
@@ -25,6 +25,9 @@ mod cache_prune;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_size;
 
+#[cfg(feature = "tracing-durations-export")]
+mod durations;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -53,6 +56,8 @@ mod lock_exclude_newer_relative;
 
 mod lock_scenarios;
 
+mod logging;
+
 mod network;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -117,6 +122,8 @@ mod show_settings;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod sync;
 
+mod timings;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_dir;
 
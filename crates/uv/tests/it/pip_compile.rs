@@ -10,6 +10,7 @@ use flate2::write::GzEncoder;
 use fs_err::File;
 use http::StatusCode;
 use indoc::indoc;
+use itertools::Itertools;
 use url::Url;
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -3324,6 +3325,38 @@ dependencies = ["anyio==3.7.0", "anyio==4.0.0"]
     Ok(())
 }
 
+/// A resolution failure is followed by its stable code, which is also included in the JSON report.
+#[test]
+fn compile_unsolvable_requirements_code() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0\nanyio==4.0.0")?;
+
+    let output = context.pip_compile().arg("requirements.in").output()?;
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr)?;
+    assert_eq!(stderr.lines().last(), Some("  code: no-solution"));
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .env(EnvVars::UV_ERROR_FORMAT, "json")
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr)?;
+    let report: serde_json::Value =
+        serde_json::from_str(stderr.lines().last().unwrap_or_default())?;
+    insta::assert_json_snapshot!(report, @r#"
+    {
+      "causes": [],
+      "code": "no-solution",
+      "message": "Because you require anyio==3.7.0 and anyio==4.0.0, we can conclude that your requirements are unsatisfiable."
+    }
+    "#);
+
+    Ok(())
+}
+
 /// Compile requirements in a `pyproject.toml` file that cannot be resolved due to
 /// a requirement with a version that is not available online.
 #[test]
@@ -4671,7 +4704,7 @@ fn error_missing_unnamed_env_var() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Couldn't parse requirement in `requirements.in` at position 0
+    error: Couldn't parse requirement in `requirements.in` on line 1
       Caused by: Expected package name starting with an alphanumeric character, found `$`
     ${URL}
     ^
@@ -5764,6 +5797,7 @@ fn upgrade_all() -> Result<()> {
 
     ----- stderr -----
     Resolved 6 packages in [TIME]
+    1 package held back; run with `-v` for details
     "
     );
 
@@ -5857,6 +5891,7 @@ fn upgrade_constraint() -> Result<()> {
 
     ----- stderr -----
     Resolved 1 package in [TIME]
+    1 package held back; run with `-v` for details
     "
     );
 
@@ -5882,6 +5917,54 @@ fn upgrade_constraint() -> Result<()> {
     Ok(())
 }
 
+/// Upgrade with upper-bounded transitive dependencies, which hold back the packages they bound.
+#[test]
+fn upgrade_held_back() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==1.1.4")?;
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--upgrade")
+        .arg("--verbose")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let held_back = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG Held back "))
+        .collect::<Vec<_>>();
+    assert_eq!(held_back.len(), 5, "{stderr}");
+
+    // The transitive dependencies name the requirement that bounds them.
+    let click = held_back
+        .iter()
+        .find(|line| line.starts_with("DEBUG Held back `click` at 7.1.2 (latest: 8.1.7): "))
+        .expect("`click` should be held back");
+    assert!(click.contains("`flask==1.1.4` requires `click"), "{click}");
+
+    // The direct requirement is held back by its own pin.
+    let flask = held_back
+        .iter()
+        .find(|line| line.starts_with("DEBUG Held back `flask` at 1.1.4 "))
+        .expect("`flask` should be held back");
+    assert!(flask.contains("the requirements include `flask"), "{flask}");
+
+    // Unbounded dependencies aren't held back.
+    assert!(
+        !held_back
+            .iter()
+            .any(|line| line.contains("`markupsafe` at"))
+    );
+
+    assert!(stderr.contains("5 packages held back; run with `-v` for details"));
+
+    Ok(())
+}
+
 /// Attempt to resolve a requirement at a path that doesn't exist.
 #[test]
 fn missing_path_requirement() -> Result<()> {
@@ -7407,7 +7490,7 @@ fn unsupported_scheme() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Couldn't parse requirement in `requirements.in` at position 0
+    error: Couldn't parse requirement in `requirements.in` on line 1
       Caused by: Unsupported URL prefix `bzr` in URL: `bzr+https://example.com/anyio` (Bazaar is not supported)
     anyio @ bzr+https://example.com/anyio
             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
@@ -8171,6 +8254,215 @@ fn universal_platform_fork() -> Result<()> {
     Ok(())
 }
 
+/// `--timings` should report each fork of a universal resolution, labeled by its markers.
+#[test]
+fn universal_fork_timings() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        iniconfig==1.1.1 ; sys_platform == 'linux'
+        iniconfig==2.0.0 ; sys_platform != 'linux'
+    "})?;
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--universal")
+        .arg("--timings")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+
+    let forks = stderr
+        .lines()
+        .skip_while(|line| !line.starts_with("Total"))
+        .skip(1)
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    assert_eq!(forks.len(), 3, "{stderr}");
+    assert_eq!(forks[0], "2 forks");
+    assert!(
+        forks
+            .iter()
+            .any(|fork| fork.starts_with("sys_platform == 'linux' ")),
+        "{stderr}"
+    );
+    assert!(
+        forks
+            .iter()
+            .any(|fork| fork.starts_with("sys_platform != 'linux' ")),
+        "{stderr}"
+    );
+
+    // Without forking, there's a single fork without markers.
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--timings")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.lines().any(|line| line == "1 fork (no markers)"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+/// Each fork of a universal resolution is solved within its own span, labeled by its markers,
+/// such that the events of each fork are attributed to it.
+#[test]
+fn universal_fork_spans() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        iniconfig==1.1.1 ; sys_platform == 'linux'
+        iniconfig==2.0.0 ; sys_platform != 'linux'
+    "})?;
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--universal")
+        .arg("--verbose")
+        .env(EnvVars::UV_LOG_CONTEXT, "1")
+        .env(EnvVars::NO_COLOR, "1")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+
+    // Each fork opens a distinct span, and its events are nested within it.
+    for markers in ["sys_platform == 'linux'", "sys_platform != 'linux'"] {
+        let span = format!("solve_fork markers=\"{markers}\"");
+        let mut lines = stderr.lines().skip_while(|line| !line.ends_with(&span));
+        assert!(lines.next().is_some(), "missing span `{span}`:\n{stderr}");
+        assert!(
+            lines
+                .next()
+                .is_some_and(|line| line.contains(&format!("Solving split (markers: {markers})"))),
+            "missing event in span `{span}`:\n{stderr}"
+        );
+    }
+
+    Ok(())
+}
+
+/// `UV_FORK_GRAPH` writes the tree of forks of a universal resolution in the DOT format.
+#[test]
+fn universal_fork_graph() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        iniconfig==1.1.1 ; sys_platform == 'linux'
+        iniconfig==2.0.0 ; sys_platform != 'linux'
+    "})?;
+
+    let fork_graph = context.temp_dir.child("forks.dot");
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--universal")
+        .env(EnvVars::UV_FORK_GRAPH, fork_graph.path())
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Wrote fork graph to: "), "{stderr}");
+
+    // Parse the node and edge labels from the DOT output.
+    let dot = fs_err::read_to_string(fork_graph.path())?;
+    assert!(dot.starts_with("digraph forks {"), "{dot}");
+    let label = |line: &str| {
+        line.split_once("[label=\"")
+            .and_then(|(_, label)| label.strip_suffix("\"];"))
+            .map(ToString::to_string)
+    };
+    let mut nodes = std::collections::BTreeMap::new();
+    let mut edges = Vec::new();
+    for line in dot.lines().map(str::trim) {
+        let Some(label) = label(line) else {
+            continue;
+        };
+        let (id, _) = line.split_once(' ').unwrap();
+        if let Some((parent, child)) = line
+            .split_once(" [")
+            .and_then(|(edge, _)| edge.split_once(" -> "))
+        {
+            edges.push((parent.to_string(), child.to_string(), label));
+        } else {
+            nodes.insert(id.to_string(), label);
+        }
+    }
+
+    assert_eq!(nodes.len(), 3, "{dot}");
+    assert_eq!(nodes["0"], "(all environments)");
+    assert_eq!(edges.len(), 2, "{dot}");
+    for (markers, requirement) in [
+        (
+            "sys_platform == 'linux'",
+            "iniconfig{sys_platform == 'linux'}==1.1.1",
+        ),
+        (
+            "sys_platform != 'linux'",
+            "iniconfig{sys_platform != 'linux'}==2.0.0",
+        ),
+    ] {
+        let (_, child, _) = edges
+            .iter()
+            .find(|(parent, _, label)| parent == "0" && label == requirement)
+            .unwrap_or_else(|| panic!("missing edge for `{requirement}`:\n{dot}"));
+        assert_eq!(nodes[child], format!("{markers}\\n1 package"), "{dot}");
+    }
+
+    Ok(())
+}
+
+/// `--resolver-dump` writes the state of each fork as JSON, including when the resolution fails.
+#[test]
+fn universal_resolver_dump() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        iniconfig==1.1.1 ; sys_platform == 'linux'
+        iniconfig==2.0.0 ; sys_platform != 'linux'
+    "})?;
+
+    let dump = context.temp_dir.child("dump.json");
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--universal")
+        .arg("--resolver-dump")
+        .arg(dump.path())
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Wrote resolver dump to: "), "{stderr}");
+
+    let forks: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(dump.path())?)?;
+    let forks = forks["forks"].as_array().unwrap();
+    assert_eq!(forks.len(), 3, "{forks:?}");
+    assert!(forks[0]["markers"].is_null(), "{forks:?}");
+    for fork in &forks[1..] {
+        assert_eq!(fork["packages"], 1, "{forks:?}");
+        assert_eq!(fork["failed"], false, "{forks:?}");
+    }
+
+    // The dump is also written if the resolution fails, with the path read from the environment.
+    requirements_in.write_str("iniconfig==0.1")?;
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .env(EnvVars::UV_RESOLVER_DUMP, dump.path())
+        .output()?;
+    assert!(!output.status.success());
+
+    let forks: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(dump.path())?)?;
+    assert_eq!(forks["forks"][0]["failed"], true, "{forks}");
+
+    Ok(())
+}
+
 /// Requested distinct local versions with disjoint markers.
 #[test]
 fn universal_disjoint_locals() -> Result<()> {
@@ -11405,6 +11697,7 @@ fn preserve_hashes_upgrade() -> Result<()> {
 
     ----- stderr -----
     Resolved 1 package in [TIME]
+    1 package held back; run with `-v` for details
     "
     );
 
@@ -12909,6 +13202,105 @@ fn emit_index_annotation_multiple_indexes() -> Result<()> {
     Ok(())
 }
 
+/// `--annotate-index` where every package is served by the default index, so no package is
+/// annotated.
+#[test]
+fn annotate_index_default() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--annotate-index"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --annotate-index
+    certifi==2024.2.2
+        # via requests
+    charset-normalizer==3.3.2
+        # via requests
+    idna==3.6
+        # via requests
+    requests==2.31.0
+        # via -r requirements.in
+    urllib3==2.2.1
+        # via requests
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// `--annotate-index` where packages are pulled from two distinct indexes: the packages served
+/// by the named, non-default index are annotated with its name and URL.
+#[test]
+fn annotate_index_multiple_indexes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("httpcore\nrequests")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index")
+        .arg("test=https://test.pypi.org/simple")
+        .arg("--annotate-index"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --annotate-index
+    certifi==2016.8.8
+        # via httpcore
+        # index: test (https://test.pypi.org/simple)
+    h11==0.14.0
+        # via httpcore
+    httpcore==1.0.4
+        # via -r requirements.in
+    requests==2.5.4.1
+        # via -r requirements.in
+        # index: test (https://test.pypi.org/simple)
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "
+    );
+
+    // With `--annotation-style line`, the index is still annotated on its own line.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index")
+        .arg("test=https://test.pypi.org/simple")
+        .arg("--annotate-index")
+        .arg("--annotation-style")
+        .arg("line"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --annotate-index --annotation-style line
+    certifi==2016.8.8         # via httpcore
+        # index: test (https://test.pypi.org/simple)
+    h11==0.14.0               # via httpcore
+    httpcore==1.0.4           # via -r requirements.in
+    requests==2.5.4.1         # via -r requirements.in
+        # index: test (https://test.pypi.org/simple)
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// Test error message when direct dependency is an empty set.
 #[test]
 fn no_version_for_direct_dependency() -> Result<()> {
@@ -13991,7 +14383,7 @@ fn invalid_extra() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Couldn't parse requirement in `requirements.in` at position 0
+    error: Couldn't parse requirement in `requirements.in` on line 1
       Caused by: Expected an alphanumeric character starting the extra name, found `_`
     .[_anyio]
       ^
@@ -15313,6 +15705,454 @@ fn max_python_requirement() -> Result<()> {
     Ok(())
 }
 
+/// Two local dependencies pin the same package to different indexes. The error should show the
+/// requirement that pinned each index, and the package that requested it.
+#[test]
+fn conflicting_indexes_provenance() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["child-a", "child-b"]
+
+        [tool.uv.sources]
+        child-a = { path = "child-a" }
+        child-b = { path = "child-b" }
+    "#})?;
+
+    let child = context.temp_dir.child("child-a");
+    child.child("pyproject.toml").write_str(indoc::indoc! {r#"
+        [project]
+        name = "child-a"
+        version = "0.1.0"
+        dependencies = ["jinja2>=3"]
+
+        [tool.uv.sources]
+        jinja2 = { index = "torch-cu118" }
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    let child = context.temp_dir.child("child-b");
+    child.child("pyproject.toml").write_str(indoc::indoc! {r#"
+        [project]
+        name = "child-b"
+        version = "0.1.0"
+        dependencies = ["jinja2>=3"]
+
+        [tool.uv.sources]
+        jinja2 = { index = "torch-cu124" }
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("pyproject.toml"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to resolve dependencies for `child-a` (v0.1.0)
+      ╰─▶ Requirements contain conflicting indexes for package `jinja2`:
+          - `jinja2` came from https://astral-sh.github.io/pytorch-mirror/whl/cu118, required by `child-a` as `jinja2>=3`; versions unknown
+          - `jinja2` came from https://astral-sh.github.io/pytorch-mirror/whl/cu124, required by `child-b` as `jinja2>=3`; versions unknown
+
+          hint: Pin `jinja2` to a single index in each `[tool.uv.sources]` table that declares it, e.g.:
+
+              [tool.uv.sources]
+              jinja2 = { index = "jinja2" }
+
+              [[tool.uv.index]]
+              name = "jinja2"
+              url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+
+          See https://docs.astral.sh/uv/concepts/indexes/#pinning-a-package-to-an-index for details.
+    "#);
+
+    Ok(())
+}
+
+/// A direct requirement and a transitive requirement (of a local dependency) pin the same package
+/// to different indexes. The explicit pin of the project takes precedence over the implicit pin of
+/// its dependency.
+#[test]
+fn explicit_index_overrides_implicit() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["jinja2", "child"]
+
+        [tool.uv.sources]
+        jinja2 = { index = "torch-cu118" }
+        child = { path = "child" }
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+    "#})?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc::indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        dependencies = ["jinja2>=3"]
+
+        [tool.uv.sources]
+        jinja2 = { index = "torch-cu124" }
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    let output = context
+        .pip_compile()
+        .arg("pyproject.toml")
+        .env(EnvVars::RUST_LOG, "uv_resolver::indexes=debug")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let assignments = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::assert_snapshot!(assignments, @"
+    DEBUG Pinned `jinja2` to https://astral-sh.github.io/pytorch-mirror/whl/cu124 in marker environment, required by `child` as `jinja2>=3`
+    DEBUG Replaced implicit pin of `jinja2` to https://astral-sh.github.io/pytorch-mirror/whl/cu124 with explicit pin to https://astral-sh.github.io/pytorch-mirror/whl/cu118 in marker environment, directly required as `jinja2`
+    DEBUG Ignored implicit pin of `jinja2` to https://astral-sh.github.io/pytorch-mirror/whl/cu124 in favor of explicit pin to https://astral-sh.github.io/pytorch-mirror/whl/cu118 in marker environment, required by `child` as `jinja2>=3`
+    DEBUG Confirmed pin of `jinja2` to https://astral-sh.github.io/pytorch-mirror/whl/cu118 in marker environment, directly required as `jinja2`
+    ");
+
+    Ok(())
+}
+
+/// Each assignment of a pinned index is logged under the `uv_resolver::indexes` target: first as
+/// a pin, then as a confirmation whenever the same pin is seen again.
+#[test]
+fn index_assignment_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["jinja2", "child"]
+
+        [tool.uv.sources]
+        jinja2 = { index = "torch-cu118" }
+        child = { path = "child" }
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+    "#})?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc::indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        dependencies = ["jinja2>=3"]
+
+        [tool.uv.sources]
+        jinja2 = { index = "torch-cu118" }
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    let output = context
+        .pip_compile()
+        .arg("pyproject.toml")
+        .env(EnvVars::RUST_LOG, "uv_resolver::indexes=debug")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let assignments = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::assert_snapshot!(assignments, @"
+    DEBUG Pinned `jinja2` to https://astral-sh.github.io/pytorch-mirror/whl/cu118 in marker environment, required by `child` as `jinja2>=3`
+    DEBUG Pinned `jinja2` to https://astral-sh.github.io/pytorch-mirror/whl/cu118 in marker environment, directly required as `jinja2`
+    DEBUG Confirmed pin of `jinja2` to https://astral-sh.github.io/pytorch-mirror/whl/cu118 in marker environment, required by `child` as `jinja2>=3`
+    DEBUG Confirmed pin of `jinja2` to https://astral-sh.github.io/pytorch-mirror/whl/cu118 in marker environment, directly required as `jinja2`
+    ");
+
+    Ok(())
+}
+
+/// A universal resolution logs a summary of each fork under the `uv_resolver::forks` target, with
+/// the packages pinned in the fork and the pins diverging from the base fork.
+#[test]
+fn fork_summary_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        iniconfig==1.1.1 ; sys_platform == 'linux'
+        iniconfig==2.0.0 ; sys_platform != 'linux'
+    "})?;
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--universal")
+        .env(EnvVars::RUST_LOG, "uv_resolver::forks=debug")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let summaries = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::assert_snapshot!(summaries, @"
+    DEBUG Fork sys_platform == 'linux': 1 package(s) pinned, 0 diverging from the base fork
+    DEBUG Fork sys_platform != 'linux': 1 package(s) pinned, 1 diverging from the base fork: iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// The verdict on each candidate distribution is logged under the `uv_resolver::candidates`
+/// target, along with a summary of the file that was chosen.
+#[test]
+fn candidate_selection_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("uvloop==0.19.0")?;
+
+    let candidates = |platform: &str| -> Result<String> {
+        let output = context
+            .pip_compile()
+            .arg("requirements.in")
+            .arg("--python-platform")
+            .arg(platform)
+            .env(EnvVars::RUST_LOG, "uv_resolver::candidates=trace")
+            .output()?;
+        assert!(output.status.success(), "{output:?}");
+
+        let stderr = String::from_utf8(output.stderr)?;
+        Ok(stderr
+            .lines()
+            .filter(|line| {
+                line.starts_with("DEBUG")
+                    || line.contains("uvloop-0.19.0-cp312-cp312-manylinux_2_17_x86_64")
+                    || line.contains("uvloop-0.19.0.tar.gz")
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    };
+
+    // On Linux, the `manylinux` wheel is compatible.
+    insta::assert_snapshot!(candidates("linux")?, @"
+    TRACE uvloop-0.19.0-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl (python: cp312, abi: cp312, platform: manylinux_2_17_x86_64.manylinux2014_x86_64): compatible
+    TRACE uvloop-0.19.0.tar.gz (source distribution): compatible
+    DEBUG Chose uvloop-0.19.0-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl for uvloop==0.19.0 because it is the most specific compatible wheel
+    ");
+
+    // On Windows, the `manylinux` wheel is rejected for its platform tag.
+    insta::assert_snapshot!(candidates("windows")?, @"
+    TRACE uvloop-0.19.0-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl (python: cp312, abi: cp312, platform: manylinux_2_17_x86_64.manylinux2014_x86_64): incompatible-platform-tag
+    TRACE uvloop-0.19.0.tar.gz (source distribution): compatible
+    DEBUG Chose uvloop-0.19.0.tar.gz for uvloop==0.19.0 because no wheel is usable, but an incompatible wheel provides its metadata
+    ");
+
+    Ok(())
+}
+
+/// Marker evaluations are logged under the `uv_pep508::eval` target, along with the environment
+/// values they were evaluated against and a summary of the skipped requirements.
+#[test]
+fn marker_evaluation_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        iniconfig
+        sniffio ; sys_platform == 'win32'
+    "})?;
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("linux")
+        .env(EnvVars::RUST_LOG, "uv_pep508::eval=trace")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    // The same requirement can be evaluated at several points during resolution.
+    let stderr = String::from_utf8(output.stderr)?;
+    let evaluations = stderr
+        .lines()
+        .filter(|line| line.starts_with("TRACE") || line.starts_with("DEBUG"))
+        .unique()
+        .join("\n");
+
+    // `sniffio` is skipped on Linux, since `sys_platform` is `linux`.
+    insta::assert_snapshot!(evaluations, @"
+    TRACE Evaluated `sys_platform == 'win32'` for `sniffio` to false (sys_platform = 'linux'; no extras)
+    DEBUG Skipped 1 of 2 requirements of root due to markers
+    ");
+
+    Ok(())
+}
+
+/// Packages that weren't served by the highest-priority index are logged under the
+/// `uv_resolver::indexes` target once the resolution completes.
+#[test]
+fn fallback_index_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A local index that only contains `tqdm`, so `iniconfig` falls back to the default index.
+    let root = context.temp_dir.child("simple-html");
+    let index = root.child("tqdm").child("index.html");
+    index.write_str(&indoc::formatdoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.1" />
+          </head>
+          <body>
+            <h1>Links for tqdm</h1>
+            <a
+              href="{}/tqdm-1000.0.0-py3-none-any.whl"
+              data-requires-python=">=3.8"
+            >
+              tqdm-1000.0.0-py3-none-any.whl
+            </a>
+          </body>
+        </html>
+    "#, Url::from_directory_path(context.workspace_root.join("test/links/")).unwrap().as_str()})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        tqdm
+        iniconfig
+    "})?;
+
+    let output = context
+        .pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.in")
+        .arg("--index")
+        .arg(Url::from_directory_path(root.path()).unwrap().as_str())
+        .env(EnvVars::RUST_LOG, "uv_resolver::indexes=debug")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let fallbacks = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::assert_snapshot!(fallbacks, @"DEBUG 1 package served by fallback indexes: iniconfig (https://pypi.org/simple)");
+
+    Ok(())
+}
+
+/// With `--annotate-index`, packages that are pinned to an index are annotated with that index,
+/// while packages from the default index are not.
+#[test]
+fn pinned_index_annotation() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["iniconfig", "typing-extensions"]
+
+        [tool.uv.sources]
+        iniconfig = { index = "proxy" }
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple"
+        explicit = true
+    "#})?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("pyproject.toml")
+        .arg("--annotate-index"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --annotate-index
+    iniconfig==2.0.0
+        # via project (pyproject.toml)
+        # index: proxy (https://pypi-proxy.fly.dev/simple)
+    typing-extensions==4.10.0
+        # via project (pyproject.toml)
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Without `--annotate-index`, the index is omitted.
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("pyproject.toml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml
+    iniconfig==2.0.0
+        # via project (pyproject.toml)
+    typing-extensions==4.10.0
+        # via project (pyproject.toml)
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// See: <https://github.com/astral-sh/uv/issues/10383>
 #[test]
 fn respect_index_preference() -> Result<()> {
@@ -18321,3 +19161,108 @@ async fn compile_missing_python_download_error_warning() {
       Caused by: tunnel error: unsuccessful
     ");
 }
+
+/// `--timings` should report the conflicts the resolver backtracked on, and none if it didn't need
+/// to backtrack.
+///
+/// `a-2.0.0` requires a different local version of `b` than the root, such that the resolver has to
+/// backtrack to `a-1.0.0`.
+#[test]
+fn compile_timings_backtracking() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let backtracking = |requirements: &str| -> Result<String> {
+        let requirements_in = context.temp_dir.child("requirements.in");
+        requirements_in.write_str(requirements)?;
+        let output = context
+            .pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(packse_index_url())
+            .arg("--timings")
+            .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+            .output()?;
+        assert!(output.status.success(), "{output:?}");
+        let stderr = String::from_utf8(output.stderr)?;
+        Ok(stderr
+            .lines()
+            .find(|line| line.starts_with("Backtracking: "))
+            .unwrap_or_else(|| panic!("No backtracking in: {stderr}"))
+            .to_string())
+    };
+
+    let line = backtracking(indoc! {r"
+        local-transitive-backtrack-a
+        local-transitive-backtrack-b==2.0.0+foo
+    "})?;
+    assert!(!line.starts_with("Backtracking: 0 conflicts"), "{line}");
+    assert!(!line.contains(" 0 decisions retracted"), "{line}");
+
+    let line = backtracking("local-transitive-backtrack-b==2.0.0+foo")?;
+    assert!(
+        line.starts_with("Backtracking: 0 conflicts, 0 decisions retracted, 0.00ms "),
+        "{line}"
+    );
+
+    Ok(())
+}
+
+/// Backtracking decisions are logged under `uv_resolver::backtrack`, followed by a ranking of the
+/// packages involved in conflicts.
+///
+/// `a-2.0.0` requires a different local version of `b` than the root, such that the resolver has to
+/// backtrack to `a-1.0.0`.
+#[test]
+fn backtracking_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        local-transitive-backtrack-a
+        local-transitive-backtrack-b==2.0.0+foo
+    "})?;
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::RUST_LOG, "uv_resolver::backtrack=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let logs = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG"))
+        .collect::<Vec<_>>();
+
+    // Each conflict names the learned incompatibility and the decisions it retracted.
+    let conflicts = logs
+        .iter()
+        .filter(|line| line.starts_with("DEBUG Conflict on "))
+        .collect::<Vec<_>>();
+    assert!(!conflicts.is_empty(), "{stderr}");
+    assert!(
+        conflicts
+            .iter()
+            .all(|line| line.contains(": learned (") && line.contains("), retracting ")),
+        "{stderr}"
+    );
+    assert!(
+        conflicts
+            .iter()
+            .any(|line| line.contains("local-transitive-backtrack-a==2.0.0")),
+        "{stderr}"
+    );
+
+    // The summary ranks the packages involved in conflicts.
+    let summary = logs
+        .last()
+        .unwrap_or_else(|| panic!("No logs in: {stderr}"));
+    assert!(
+        summary.starts_with("DEBUG Conflicts by package: "),
+        "{stderr}"
+    );
+
+    Ok(())
+}
@@ -102,14 +102,334 @@ fn missing_find_links() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to read `--find-links` directory: [TEMP_DIR]/missing
-      Caused by: [OS ERROR 2]
+    error: Index URL `./missing` from `--find-links` refers to a path that does not exist: `missing`
     "
     );
 
     Ok(())
 }
 
+/// Index URLs with embedded whitespace are rejected, naming the environment variable if the value
+/// was read from one.
+#[test]
+fn index_url_whitespace() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.touch()?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg("https://pypi.org/ simple"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'https://pypi.org/ simple' for '--index-url <INDEX_URL>': Index URL contains whitespace: `https://pypi.org/ simple`
+
+    For more information, try '--help'.
+    "
+    );
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .env(EnvVars::UV_INDEX_URL, "https://pypi.org/ simple"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'https://pypi.org/ simple' for '--index-url <INDEX_URL>': Index URL contains whitespace: `https://pypi.org/ simple`
+
+      tip: `https://pypi.org/ simple` was read from the `UV_INDEX_URL` environment variable
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
+/// Index URLs with a scheme other than `https`, `http`, or `file` are rejected.
+#[test]
+fn index_url_unsupported_scheme() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.touch()?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .env(EnvVars::UV_EXTRA_INDEX_URL, "https://example.com/simple htps://pypi.org/simple"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'htps://pypi.org/simple' for '--extra-index-url <EXTRA_INDEX_URL>': Unsupported scheme `htps` in index URL `htps://pypi.org/simple` (expected `https://`, `http://`, or `file://`)
+
+      tip: `htps://pypi.org/simple` was read from the `UV_EXTRA_INDEX_URL` environment variable
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
+/// Index URLs that are missing a scheme are treated as paths, which don't exist.
+#[test]
+fn index_url_missing_scheme() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.touch()?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--find-links")
+        .arg("download.pytorch.org/whl/cpu"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index URL `download.pytorch.org/whl/cpu` from `--find-links` is missing a scheme (did you mean `https://download.pytorch.org/whl/cpu`?)
+    "
+    );
+
+    Ok(())
+}
+
+/// Local indexes that don't exist are reported along with the configuration file and key that
+/// provided them.
+#[test]
+fn index_url_missing_path_in_config() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.touch()?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [pip]
+        find-links = ["./wheels"]
+    "#})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index URL `./wheels` from `pip.find-links` in `uv.toml` refers to a path that does not exist: `wheels`
+    "
+    );
+
+    fs_err::remove_file(uv_toml.path())?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [[tool.uv.index]]
+        name = "local"
+        url = "./wheels"
+    "#})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index URL `./wheels` from `tool.uv.index` in `pyproject.toml` refers to a path that does not exist: `wheels`
+    "
+    );
+
+    Ok(())
+}
+
+/// Index URLs served over plain HTTP warn, unless the host is allowed to be insecure.
+#[test]
+fn index_url_insecure() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.touch()?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg("http://example.com/simple"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Index URL `http://example.com/simple` from `--index-url` uses `http://`, so its packages will be downloaded over an insecure connection. Use `https://` instead, or add the host to `allow-insecure-host` to silence this warning.
+    warning: Requirements file `requirements.txt` does not contain any dependencies
+    Audited in [TIME]
+    "
+    );
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg("http://example.com/simple")
+        .arg("--allow-insecure-host")
+        .arg("example.com"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Requirements file `requirements.txt` does not contain any dependencies
+    Audited in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Hosts that skip certificate verification due to `allow-insecure-host` are logged once each, and
+/// summarized in a single warning once the command completes.
+#[test]
+fn allow_insecure_host_https() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg("https://pypi-proxy.fly.dev/simple")
+        .arg("--allow-insecure-host")
+        .arg("pypi-proxy.fly.dev")
+        .env(EnvVars::RUST_LOG, "uv_client::tls=debug")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+
+    let skipped = stderr
+        .lines()
+        .filter(|line| line.contains("Skipping certificate verification"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::assert_snapshot!(skipped, @"DEBUG Skipping certificate verification for `pypi-proxy.fly.dev` due to the `allow-insecure-host` entry `pypi-proxy.fly.dev`");
+
+    let warnings = stderr
+        .lines()
+        .filter(|line| line.starts_with("warning: Certificate verification"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::assert_snapshot!(warnings, @"warning: Certificate verification was skipped for 1 host due to `allow-insecure-host`: `pypi-proxy.fly.dev`");
+
+    Ok(())
+}
+
+/// A package pinned to an index with `--package-index` is installed from that index.
+#[test]
+fn package_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--package-index")
+        .arg("iniconfig=https://pypi-proxy.fly.dev/simple")
+        .env(EnvVars::RUST_LOG, "uv_resolver::resolution=debug")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let resolved = stderr
+        .lines()
+        .filter(|line| line.contains("from pinned index"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::assert_snapshot!(resolved, @"DEBUG Resolved `iniconfig==2.0.0` from pinned index https://pypi-proxy.fly.dev/simple");
+
+    // The package name is validated.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--package-index")
+        .arg("ini config=https://pypi-proxy.fly.dev/simple"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'ini config=https://pypi-proxy.fly.dev/simple' for '--package-index <PACKAGE=URL>': Invalid package name: Not a valid package or extra name: "ini config". Names must start and end with a letter or digit and may only contain -, _, ., and alphanumeric characters.
+
+    For more information, try '--help'.
+    "#
+    );
+
+    Ok(())
+}
+
+/// A package pinned to an index with `--package-index` conflicts with a requirement that pins it
+/// to a different index.
+#[test]
+fn package_index_conflict() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.sources]
+        iniconfig = { index = "proxy" }
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple"
+        explicit = true
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("pyproject.toml")
+        .arg("--package-index")
+        .arg("iniconfig=https://test.pypi.org/simple"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Requirements contain conflicting indexes for package `iniconfig`:
+    - `iniconfig` came from the explicit index `proxy` (https://pypi-proxy.fly.dev/simple), directly required as `iniconfig`; versions unknown
+    - `iniconfig` came from https://test.pypi.org/simple, directly required as `--package-index iniconfig=https://test.pypi.org/simple`; versions unknown
+
+    hint: Pin `iniconfig` to a single index in each `[tool.uv.sources]` table that declares it, e.g.:
+
+        [tool.uv.sources]
+        iniconfig = { index = "proxy" }
+
+        [[tool.uv.index]]
+        name = "proxy"
+        url = "https://pypi-proxy.fly.dev/simple"
+
+    See https://docs.astral.sh/uv/concepts/indexes/#pinning-a-package-to-an-index for details.
+    "#
+    );
+
+    Ok(())
+}
+
 #[test]
 fn invalid_pyproject_toml_syntax() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -5837,6 +6157,56 @@ anyio
     Ok(())
 }
 
+/// The source of the credentials used for an index is logged along with the username, but never
+/// the password.
+#[test]
+fn install_package_basic_auth_source_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let netrc = context.temp_dir.child(".netrc");
+    netrc.write_str("machine pypi-proxy.fly.dev login public password heron")?;
+
+    let output = context
+        .pip_install()
+        .arg("anyio")
+        .arg("--index-url")
+        .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
+        .arg("--dry-run")
+        .env(EnvVars::NETRC, netrc.to_str().unwrap())
+        .env(EnvVars::RUST_LOG, "uv_auth=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains(
+        "DEBUG Using credentials from the netrc file for https://pypi-proxy.fly.dev (username: `public`)"
+    ));
+    assert!(!stderr.contains("heron"));
+
+    // Credentials for a named index are read from the environment, in preference to the netrc
+    // file.
+    let output = context
+        .pip_install()
+        .arg("anyio")
+        .arg("--default-index")
+        .arg("proxy=https://pypi-proxy.fly.dev/basic-auth/simple")
+        .arg("--dry-run")
+        .env(EnvVars::NETRC, netrc.to_str().unwrap())
+        .env(EnvVars::index_username("PROXY"), "public")
+        .env(EnvVars::index_password("PROXY"), "heron")
+        .env(EnvVars::RUST_LOG, "uv_auth=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains(
+        "DEBUG Using credentials from the `UV_INDEX_PROXY_USERNAME` and `UV_INDEX_PROXY_PASSWORD` environment variables for https://pypi-proxy.fly.dev (username: `public`)"
+    ));
+    assert!(!stderr.contains("netrc file"));
+    assert!(!stderr.contains("heron"));
+
+    Ok(())
+}
+
 /// Install a package from an index that provides relative links
 #[test]
 fn install_index_with_relative_links() {
@@ -5948,7 +6318,7 @@ fn install_package_basic_auth_from_keyring_wrong_password() {
       × No solution found when resolving dependencies:
       ╰─▶ Because anyio was not found in the package registry and you require anyio, we can conclude that your requirements are unsatisfiable.
 
-          hint: An index URL (https://pypi-proxy.fly.dev/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized).
+          hint: An index URL (https://pypi-proxy.fly.dev/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized). The credentials were read from the keyring (username: `public`).
     "
     );
 }
@@ -7045,6 +7415,458 @@ fn require_hashes_mismatch() -> Result<()> {
     Ok(())
 }
 
+/// The progress of each download is logged under the `uv_distribution::progress` target, with a
+/// bounded number of milestones between the start and completion events.
+#[test]
+fn install_download_progress_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .pip_install()
+        .arg("tqdm==4.66.1")
+        .arg("--no-deps")
+        .env(EnvVars::RUST_LOG, "uv_distribution::progress=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let events = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG"))
+        .collect::<Vec<_>>();
+
+    // At least one milestone is logged, and never more than one per quartile.
+    let milestones = events
+        .iter()
+        .filter(|line| line.starts_with("DEBUG Downloaded"))
+        .count();
+    assert!((1..=4).contains(&milestones), "{events:#?}");
+
+    let events = events
+        .into_iter()
+        .filter(|line| !line.starts_with("DEBUG Downloaded"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::with_settings!({
+        filters => vec![
+            (r"\d+ bytes", "[SIZE] bytes"),
+            (r" in \d+(\.\d+)?(ns|µs|ms|s) ", " in [TIME] "),
+            (r"\((\d+\.\d+ MiB/s|unknown throughput)\)", "([THROUGHPUT])"),
+        ]
+    }, {
+        insta::assert_snapshot!(events, @"
+        DEBUG Downloading tqdm-4.66.1-py3-none-any.whl ([SIZE] bytes)
+        DEBUG Finished downloading tqdm-4.66.1-py3-none-any.whl: [SIZE] bytes in [TIME] ([THROUGHPUT])
+        ");
+    });
+
+    Ok(())
+}
+
+/// The inputs to the computation of the compatible tags are logged, along with the tags.
+#[test]
+fn install_platform_tags_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--dry-run")
+        .env(EnvVars::RUST_LOG, "uv_python::tags=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let event = stderr
+        .lines()
+        .find(|line| line.starts_with("DEBUG Computed "))
+        .expect("tags should be logged");
+    assert!(event.contains("compatible tags for"), "{event}");
+    assert!(event.contains("implementation=cpython"), "{event}");
+    assert!(event.contains("python_version=3.12."), "{event}");
+    assert!(
+        event.contains(&format!("arch={}", std::env::consts::ARCH)),
+        "{event}"
+    );
+    if cfg!(target_os = "linux") {
+        assert!(event.contains("os=linux"), "{event}");
+        assert!(
+            event.contains("libc=glibc ") || event.contains("libc=musl "),
+            "{event}"
+        );
+    } else if cfg!(target_os = "macos") {
+        assert!(event.contains("os=macos "), "{event}");
+        assert!(event.contains("libc=none"), "{event}");
+    } else if cfg!(windows) {
+        assert!(event.contains("os=windows"), "{event}");
+        assert!(event.contains("libc=none"), "{event}");
+    }
+
+    // The tags are only logged once per command.
+    assert_eq!(
+        stderr
+            .lines()
+            .filter(|line| line.starts_with("DEBUG Computed "))
+            .count(),
+        1
+    );
+
+    Ok(())
+}
+
+/// Each collected requirement is logged along with its origin, and parse errors point at the
+/// offending line.
+#[test]
+fn install_requirement_origin_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # Pinned for compatibility.
+        anyio==3.7.0
+    "})?;
+
+    let output = context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("iniconfig")
+        .arg("--dry-run")
+        .env(
+            EnvVars::RUST_LOG,
+            "uv_requirements=debug,uv_requirements_txt=debug",
+        )
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let mut collected = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG Collected "))
+        .collect::<Vec<_>>();
+    collected.sort_unstable();
+    insta::assert_snapshot!(collected.join("\n"), @r"
+    DEBUG Collected `anyio==3.7.0` from `requirements.txt:2`
+    DEBUG Collected `iniconfig` from command line
+    ");
+
+    requirements_txt.write_str(indoc! {r"
+        anyio==3.7.0
+        flask==1.0.x
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Couldn't parse requirement in `requirements.txt` on line 2
+      Caused by: after parsing `1.0`, found `.x`, which is not part of a valid version
+    flask==1.0.x
+         ^^^^^^^
+    ");
+
+    Ok(())
+}
+
+/// Each step of a source build is logged with its duration: setting up the build environment,
+/// invoking the build backend hooks, and archiving the built wheel.
+#[test]
+fn install_build_step_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    let output = context
+        .pip_install()
+        .arg("./project")
+        .env(
+            EnvVars::RUST_LOG,
+            "uv_build_frontend=debug,uv_distribution=debug",
+        )
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let steps = stderr
+        .lines()
+        .filter_map(|line| line.strip_prefix("DEBUG Finished `"))
+        .filter_map(|line| {
+            let (step, rest) = line.split_once('`')?;
+            let (_, duration) = rest.rsplit_once(" in ")?;
+            Some((step, duration))
+        })
+        .collect::<Vec<_>>();
+
+    for expected in [
+        "create_build_venv",
+        "resolve_build_requirements",
+        "install_build_requirements",
+        "get_requires_for_build_wheel",
+        "prepare_metadata_for_build_wheel",
+        "build_wheel",
+        "archive_built_wheel",
+    ] {
+        assert!(
+            steps.iter().any(|(step, _)| *step == expected),
+            "Missing `{expected}` in: {steps:#?}"
+        );
+    }
+    assert!(
+        steps
+            .iter()
+            .all(|(_, duration)| !duration.starts_with("0ns") && !duration.starts_with("0.00ns")),
+        "{steps:#?}"
+    );
+
+    Ok(())
+}
+
+/// The usage of each cache bucket is logged under the `uv_cache::usage` target once the command
+/// completes: a cold install adds wheels to the cache, while a warm install only reads them.
+#[test]
+fn install_cache_usage_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The bytes added to the wheel and archive buckets.
+    let bytes_added = || -> Result<u64> {
+        let output = context
+            .pip_install()
+            .arg("iniconfig==2.0.0")
+            .env(EnvVars::RUST_LOG, "uv_cache::usage=debug")
+            .output()?;
+        assert!(output.status.success(), "{output:?}");
+
+        let stderr = String::from_utf8(output.stderr)?;
+        let events = stderr
+            .lines()
+            .filter(|line| line.starts_with("DEBUG Used cache bucket"))
+            .collect::<Vec<_>>();
+        assert!(!events.is_empty(), "{stderr}");
+        Ok(events
+            .iter()
+            .filter(|line| line.contains("wheels-v") || line.contains("archive-v"))
+            .filter_map(|line| {
+                line.split_whitespace()
+                    .find_map(|field| field.strip_prefix("bytes="))
+                    .and_then(|bytes| bytes.parse::<u64>().ok())
+            })
+            .sum())
+    };
+
+    let cold = bytes_added()?;
+    assert!(cold > 0, "{cold}");
+
+    context.reset_venv();
+    let warm = bytes_added()?;
+    assert_eq!(warm, 0);
+
+    Ok(())
+}
+
+/// The link mode used for each package is logged under the `uv_install_wheel::link_usage` target,
+/// and the number of packages installed with each link mode is shown with `--timings`.
+#[test]
+fn install_link_mode_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The link mode of each package, along with the summary shown with `--timings`.
+    let link_modes = |link_mode: Option<&str>| -> Result<(Vec<String>, String)> {
+        let mut command = context.pip_install();
+        command
+            .arg("iniconfig==2.0.0")
+            .arg("--reinstall")
+            .arg("--timings")
+            .env(EnvVars::RUST_LOG, "uv_install_wheel::link_usage=debug");
+        if let Some(link_mode) = link_mode {
+            command.arg("--link-mode").arg(link_mode);
+        }
+        let output = command.output()?;
+        assert!(output.status.success(), "{output:?}");
+
+        let stderr = String::from_utf8(output.stderr)?;
+        let packages = stderr
+            .lines()
+            .filter_map(|line| line.strip_prefix("DEBUG Linked iniconfig-2.0.0-py3-none-any.whl "))
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let summary = stderr
+            .lines()
+            .find(|line| line.starts_with("Link modes: "))
+            .unwrap_or_default()
+            .to_string();
+        Ok((packages, summary))
+    };
+
+    let (packages, summary) = link_modes(Some("copy"))?;
+    assert_eq!(packages, ["via copy (user requested)"]);
+    assert_eq!(summary, "Link modes: 1 copy (user requested)");
+
+    // The cache and the virtual environment are on the same volume, so the default link mode
+    // doesn't fall back to copying.
+    let (packages, summary) = link_modes(None)?;
+    assert!(
+        packages == ["via hardlink"] || packages == ["via clone"],
+        "{packages:?}"
+    );
+    assert!(
+        summary == "Link modes: 1 hardlink" || summary == "Link modes: 1 clone",
+        "{summary}"
+    );
+
+    // An explicit link mode is user requested, even if it matches the default.
+    let link_mode = packages[0].trim_start_matches("via ").to_string();
+    let (packages, summary) = link_modes(Some(&link_mode))?;
+    assert_eq!(packages, [format!("via {link_mode} (user requested)")]);
+    assert_eq!(
+        summary,
+        format!("Link modes: 1 {link_mode} (user requested)")
+    );
+
+    Ok(())
+}
+
+/// Cache entries that exist but go unused are logged under the `uv_cache::invalidation` target,
+/// with a single forced invalidation for each refreshed package.
+#[test]
+fn install_refresh_package_invalidation_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Populate the cache.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("sniffio==1.3.1")
+        .assert()
+        .success();
+
+    context.reset_venv();
+
+    let output = context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("sniffio==1.3.1")
+        .arg("--refresh-package")
+        .arg("iniconfig")
+        .env(EnvVars::RUST_LOG, "uv_cache::invalidation=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let forced = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG Invalidated cache entry"))
+        .filter(|line| line.contains("refresh forced for"))
+        .collect::<Vec<_>>();
+    assert_eq!(forced.len(), 1, "{stderr}");
+    assert!(
+        forced[0].contains("refresh forced for `iniconfig`"),
+        "{stderr}"
+    );
+    assert!(!stderr.contains("`sniffio`"), "{stderr}");
+
+    Ok(())
+}
+
+/// The versions excluded due to their `requires-python` are summarized per package at debug level,
+/// and the failure points out the newer versions that were excluded.
+#[test]
+fn install_requires_python_exclusions() -> Result<()> {
+    let context = TestContext::new("3.9");
+
+    let output = context
+        .pip_install()
+        .arg("python-greater-than-current-excluded-a>=2.0.0")
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .arg("--find-links")
+        .arg(build_vendor_links_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(
+            EnvVars::RUST_LOG,
+            "uv_resolver::resolver::python_exclusions=debug",
+        )
+        .output()?;
+    assert!(!output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let summary = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG Excluded"))
+        .collect::<Vec<_>>();
+    assert_eq!(summary.len(), 1, "{stderr}");
+    assert!(
+        summary[0].contains(
+            "3 versions of `python-greater-than-current-excluded-a` (2.0.0 to 4.0.0) due to `requires-python`: Python >=3.10 is required, but resolving for Python 3.9."
+        ),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains(
+            "hint: 3 newer versions of `python-greater-than-current-excluded-a` (2.0.0 to 4.0.0) require Python >=3.10, but you're resolving for Python 3.9."
+        ),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+/// The outcome of each hash check is logged under the `uv_distribution::hashes` target, with the
+/// digests truncated.
+#[test]
+fn require_hashes_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let hash_checks = |hash: &str| -> Result<String> {
+        let requirements_txt = context.temp_dir.child("requirements.txt");
+        requirements_txt.write_str(&format!("sniffio==1.3.1 --hash=sha256:{hash}"))?;
+
+        let output = context
+            .pip_install()
+            .arg("-r")
+            .arg("requirements.txt")
+            .arg("--require-hashes")
+            .env(EnvVars::RUST_LOG, "uv_distribution::hashes=debug")
+            .output()?;
+
+        let stderr = String::from_utf8(output.stderr)?;
+        Ok(stderr
+            .lines()
+            .filter(|line| line.starts_with("DEBUG"))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    };
+
+    // The hash doesn't match, so the error names the same distribution as the log.
+    insta::assert_snapshot!(hash_checks("3f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2")?, @"
+    DEBUG Hash mismatch for `sniffio==1.3.1` (sha256): expected 3f6da418d1f1..., computed 2f6da418d1f1...
+    ");
+
+    // The hash matches.
+    insta::assert_snapshot!(hash_checks("2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2")?, @"
+    DEBUG Hash match for `sniffio==1.3.1` (sha256): expected 2f6da418d1f1..., computed 2f6da418d1f1...
+    ");
+
+    Ok(())
+}
+
 /// Omit a transitive dependency in `--require-hashes`.
 #[test]
 fn require_hashes_missing_dependency() -> Result<()> {
@@ -13967,6 +14789,75 @@ fn build_backend_wrong_wheel_platform() -> Result<()> {
     Ok(())
 }
 
+/// If the metadata of a built wheel disagrees with the metadata that was prepared via
+/// `prepare_metadata_for_build_wheel`, warn about it with both values.
+#[test]
+fn build_backend_inconsistent_metadata() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A build backend that prepares metadata requiring `iniconfig`, but builds a wheel that
+    // requires `anyio` instead.
+    let child = context.temp_dir.child("child");
+    child.create_dir_all()?;
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [build-system]
+        requires = []
+        backend-path = ["."]
+        build-backend = "build_backend"
+    "#})?;
+    child.child("build_backend.py").write_str(indoc! {r#"
+        import os
+        import zipfile
+
+        DIST_INFO = "child-0.1.0.dist-info"
+        METADATA = "Metadata-Version: 2.1\nName: child\nVersion: 0.1.0\nRequires-Dist: {}\n"
+        WHEEL = "Wheel-Version: 1.0\nGenerator: build_backend\nRoot-Is-Purelib: true\nTag: py3-none-any\n"
+
+
+        def prepare_metadata_for_build_wheel(metadata_directory, config_settings=None):
+            os.makedirs(os.path.join(metadata_directory, DIST_INFO))
+            with open(os.path.join(metadata_directory, DIST_INFO, "METADATA"), "w") as f:
+                f.write(METADATA.format("iniconfig"))
+            return DIST_INFO
+
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            filename = "child-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr(f"{DIST_INFO}/METADATA", METADATA.format("anyio"))
+                wheel.writestr(f"{DIST_INFO}/WHEEL", WHEEL)
+                wheel.writestr(
+                    f"{DIST_INFO}/RECORD",
+                    f"{DIST_INFO}/METADATA,,\n{DIST_INFO}/WHEEL,,\n{DIST_INFO}/RECORD,,\n",
+                )
+            return filename
+    "#})?;
+
+    let output = context
+        .pip_install()
+        .arg("./child")
+        .env(EnvVars::RUST_LOG, "uv_distribution=warn")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+
+    let warnings = stderr
+        .lines()
+        .filter(|line| line.starts_with("WARN "))
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1, "{stderr}");
+    assert!(
+        warnings[0].contains("`Requires-Dist` of `child"),
+        "{stderr}"
+    );
+    assert!(
+        warnings[0].contains("the prepared metadata (`iniconfig`) and the built wheel (`anyio`)"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
 /// Test that RECORD entries use forward slashes on all platforms.
 ///
 /// See <https://github.com/astral-sh/uv/issues/14446>.
@@ -14117,3 +15008,82 @@ fn abi_compatibility_on_freethreaded_python() {
      + multi-abi-package==1.0.0 (from file://[WORKSPACE]/test/links/multi_abi_package-1.0.0-cp314-cp314t.abi3-manylinux_2_17_x86_64.whl)
     ");
 }
+
+/// With `--timings=packages`, the time spent downloading, building and installing each package is
+/// reported, with a build duration only for the packages that were built.
+#[test]
+fn install_timings_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        source-distribution @ https://files.pythonhosted.org/packages/1f/e5/5b016c945d745f8b108e759d428341488a6aee8f51f07c6c4e33498bb91f/source_distribution-0.0.3.tar.gz
+    "})?;
+
+    let output = context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--timings=packages")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr)?;
+
+    let rows = stderr
+        .lines()
+        .skip_while(|line| !line.starts_with("Package "))
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows.first().map(Vec::as_slice),
+        Some(["Package", "Download", "Build", "Install", "Total"].as_slice()),
+        "{stderr}"
+    );
+    let build = |package: &str| {
+        rows.iter()
+            .find(|row| row.first() == Some(&package))
+            .map(|row| row[2])
+    };
+    assert_eq!(build("iniconfig"), Some("-"), "{stderr}");
+    assert!(
+        build("source-distribution").is_some_and(|build| build != "-"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+/// Each HTTP request is logged under the `uv_client::http` target, with its status, size, whether
+/// it was served from the HTTP cache, and how long it took.
+#[test]
+fn install_http_request_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .env(EnvVars::RUST_LOG, "uv_client::http=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let requests = stderr
+        .lines()
+        .filter(|line| line.starts_with("DEBUG"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::with_settings!({
+        filters => vec![
+            (r"\((\d+ bytes|unknown size), ", "([SIZE], "),
+            (r", \d+(\.\d+)?(ns|µs|ms|s)\)", ", [TIME])"),
+        ]
+    }, {
+        insta::assert_snapshot!(requests, @"
+        DEBUG GET https://pypi.org/simple/iniconfig/ -> 200 OK ([SIZE], not cached, [TIME])
+        DEBUG GET https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl.metadata -> 200 OK ([SIZE], not cached, [TIME])
+        DEBUG GET https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl -> 200 OK ([SIZE], not cached, [TIME])
+        ");
+    });
+
+    Ok(())
+}
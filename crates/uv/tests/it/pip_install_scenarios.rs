@@ -3321,6 +3321,8 @@ fn python_greater_than_current_excluded() {
           Because the current Python version (3.9.[X]) does not satisfy Python>=3.12 and package-a==4.0.0 depends on Python>=3.12, we can conclude that package-a==4.0.0 cannot be used.
           And because we know from (2) that package-a>=2.0.0,<4.0.0 cannot be used, we can conclude that package-a>=2.0.0 cannot be used.
           And because you require package-a>=2.0.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: 3 newer versions of `package-a` (2.0.0 to 4.0.0) require Python >=3.10, but you're resolving for Python 3.9.[X]
     ");
 
     context.assert_not_installed("python_greater_than_current_excluded_a");
@@ -3361,6 +3361,43 @@ fn recompile() -> Result<()> {
     Ok(())
 }
 
+/// Bytecode compilation logs the number of compiled and failed files, and a file that fails to
+/// compile doesn't fail the install.
+#[test]
+fn compile_statistics() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    // Add a module with a syntax error to the environment.
+    fs_err::write(context.site_packages().join("broken.py"), "def broken(:\n")?;
+
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--strict")
+        .env(EnvVars::RUST_LOG, "uv_installer::compile=debug")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let summary = stderr
+        .lines()
+        .find(|line| line.starts_with("DEBUG Bytecode compilation with"))
+        .expect("Missing bytecode compilation summary");
+    assert!(!summary.contains(": 0 compiled"), "{summary}");
+    assert!(summary.contains(", 1 failed"), "{summary}");
+    assert!(stderr.lines().any(|line| {
+        line.starts_with("DEBUG Failed to compile") && line.contains("broken.py`: SyntaxError")
+    }));
+
+    context.assert_command("import markupsafe").success();
+
+    Ok(())
+}
+
 /// Raise an error when an editable's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_editable() -> Result<()> {
@@ -5739,7 +5776,7 @@ fn semicolon_no_space() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Couldn't parse requirement in `requirements.txt` at position 0
+    error: Couldn't parse requirement in `requirements.txt` on line 1
       Caused by: Expected direct URL (`https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl;python_version%20%3E%20'3.10'`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
     iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl;python_version > '3.10'
                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
@@ -6214,3 +6251,42 @@ fn sync_with_target_installs_missing_python() -> Result<()> {
     );
     Ok(())
 }
+
+/// The install plan and its outcome are logged under the `uv_installer::plan` target, with the
+/// reason for each planned change.
+#[test]
+fn sync_install_plan_logs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    let plan_logs = |requirements: &str| -> Result<String> {
+        requirements_txt.write_str(requirements)?;
+
+        let output = context
+            .pip_sync()
+            .arg("requirements.txt")
+            .env(EnvVars::RUST_LOG, "uv_installer::plan=debug")
+            .output()?;
+        assert!(output.status.success(), "{output:?}");
+
+        let stderr = String::from_utf8(output.stderr)?;
+        Ok(stderr
+            .lines()
+            .filter(|line| line.starts_with("DEBUG"))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    };
+
+    insta::assert_snapshot!(plan_logs("iniconfig==2.0.0")?, @"
+    DEBUG Planned installation install=iniconfig==2.0.0 (not cached) reinstall= remove= unchanged=
+    DEBUG Executed installation plan installed=iniconfig==2.0.0 uninstalled=
+    ");
+
+    // `iniconfig` is removed, since it's no longer required, and `sniffio` is installed.
+    insta::assert_snapshot!(plan_logs("sniffio==1.3.1")?, @"
+    DEBUG Planned installation install=sniffio==1.3.1 (not cached) reinstall= remove=iniconfig==2.0.0 (not required) unchanged=
+    DEBUG Executed installation plan installed=sniffio==1.3.1 uninstalled=iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
@@ -75,7 +75,7 @@ fn invalid_requirements_txt_requirement() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Couldn't parse requirement in `requirements.txt` at position 0
+    error: Couldn't parse requirement in `requirements.txt` on line 1
       Caused by: after parsing `1.0`, found `.x`, which is not part of a valid version
     flask==1.0.x
          ^^^^^^^
@@ -6,7 +6,7 @@ use indoc::indoc;
 use uv_platform::{Arch, Os};
 use uv_static::EnvVars;
 
-use crate::common::{TestContext, uv_snapshot, venv_bin_path};
+use crate::common::{TestContext, apply_filters, uv_snapshot, venv_bin_path};
 
 #[test]
 fn python_find() {
@@ -150,6 +150,45 @@ fn python_find() {
     ");
 }
 
+/// Each interpreter probed during discovery is logged under the `uv_python::discovery` target,
+/// along with the reason it was skipped and a summary of the selected interpreter.
+#[test]
+fn python_find_discovery_logs() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"])
+        .with_filtered_python_keys()
+        .with_filtered_python_sources();
+
+    let mut filters = context.filters();
+    filters.push((r"(in|after) \d+(\.\d+)?[µmn]?s", "$1 [TIME]"));
+
+    // Python 3.11 comes first on the search path, so it's probed and rejected before Python 3.12
+    // is selected.
+    let output = context
+        .python_find()
+        .arg("3.12")
+        .env(EnvVars::RUST_LOG, "uv_python::discovery=debug")
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let probes = stderr
+        .lines()
+        .filter(|line| {
+            line.starts_with("DEBUG Found")
+                || line.starts_with("DEBUG Skipping interpreter")
+                || line.starts_with("DEBUG Selected")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    insta::assert_snapshot!(apply_filters(probes, filters), @"
+    DEBUG Found `cpython-3.11.[X]-[PLATFORM]` at `[PYTHON-3.11]` (search path) in [TIME]
+    DEBUG Skipping interpreter at `[PYTHON-3.11]` from search path: `cpython-3.11.[X]-[PLATFORM]` does not satisfy request `3.12`
+    DEBUG Found `cpython-3.12.[X]-[PLATFORM]` at `[PYTHON-3.12]` (search path) in [TIME]
+    DEBUG Selected cpython-3.12.[X]-[PLATFORM] at `[PYTHON-3.12]` (search path): first installation to satisfy Python 3.12
+    ");
+}
+
 #[test]
 fn python_find_pin() {
     let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
@@ -144,12 +144,18 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -205,9 +211,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -236,6 +244,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -351,12 +360,18 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -412,9 +427,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -443,6 +460,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -559,12 +577,18 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -620,9 +644,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -651,6 +677,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -799,12 +826,18 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -860,9 +893,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -891,6 +926,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1035,9 +1071,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1066,6 +1104,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1193,12 +1232,18 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -1254,9 +1299,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1287,6 +1334,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1427,12 +1475,18 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.pip.extra-index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -1460,12 +1514,18 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -1521,9 +1581,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1552,6 +1614,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1670,13 +1733,16 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: Some(
-                            Cli,
+                            Flag(
+                                "extra-index-url",
+                            ),
                         ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -1704,12 +1770,18 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.pip.extra-index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -1737,12 +1809,18 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -1798,9 +1876,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1829,6 +1909,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1970,12 +2051,18 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.pip.find-links",
+                            },
+                        ),
                         format: Flat,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 no_index: true,
@@ -2030,9 +2117,11 @@ fn resolve_find_links() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2061,6 +2150,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2227,9 +2317,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2258,6 +2350,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2390,12 +2483,18 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.pip.extra-index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -2423,12 +2522,18 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.extra-index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -2484,9 +2589,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2515,6 +2622,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2630,12 +2738,18 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.pip.extra-index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -2663,12 +2777,18 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/pyproject.toml",
+                                key: "tool.uv.extra-index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -2724,9 +2844,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2755,6 +2877,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2920,9 +3043,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2951,6 +3076,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3100,9 +3226,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3131,6 +3259,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3280,9 +3409,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3311,6 +3442,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3462,9 +3594,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3493,6 +3627,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3618,6 +3753,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             ),
             prerelease: None,
             fork_strategy: None,
+            index_conflict_strategy: None,
             dependency_metadata: None,
             config_settings: None,
             config_settings_package: None,
@@ -3642,6 +3778,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         },
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allowed_indexes: None,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -3662,6 +3799,7 @@ fn resolve_tool() -> anyhow::Result<()> {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                index_conflict_strategy: Error,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -3859,9 +3997,11 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3890,6 +4030,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4046,12 +4187,18 @@ fn resolve_both() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -4107,9 +4254,11 @@ fn resolve_both() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4138,6 +4287,7 @@ fn resolve_both() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4299,12 +4449,18 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -4360,9 +4516,11 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4391,6 +4549,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4631,12 +4790,18 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[CACHE_DIR]/uv.toml",
+                                key: "pip.index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -4692,9 +4857,11 @@ fn resolve_config_file() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4723,6 +4890,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4965,9 +5133,11 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4996,6 +5166,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5148,9 +5319,11 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -5179,6 +5352,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5350,9 +5524,11 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -5381,6 +5557,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5518,13 +5695,16 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: Some(
-                            Cli,
+                            Flag(
+                                "index-url",
+                            ),
                         ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -5552,12 +5732,18 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "index",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -5613,9 +5799,11 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -5644,6 +5832,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5760,13 +5949,16 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: Some(
-                            Cli,
+                            Flag(
+                                "default-index",
+                            ),
                         ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -5794,12 +5986,18 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "index",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -5855,9 +6053,11 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -5886,6 +6086,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6008,13 +6209,16 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: Some(
-                            Cli,
+                            Flag(
+                                "default-index",
+                            ),
                         ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -6042,12 +6246,18 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -6103,9 +6313,11 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6134,6 +6346,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6251,13 +6464,16 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: Some(
-                            Cli,
+                            Flag(
+                                "index",
+                            ),
                         ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -6285,12 +6501,18 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "index-url",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -6346,9 +6568,11 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6377,6 +6601,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6501,13 +6726,16 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: Some(
-                            Cli,
+                            Flag(
+                                "index-url",
+                            ),
                         ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -6535,12 +6763,18 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "index",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -6596,9 +6830,11 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6627,6 +6863,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6744,13 +6981,16 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: Some(
-                            Cli,
+                            Flag(
+                                "extra-index-url",
+                            ),
                         ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                     Index {
                         name: None,
@@ -6778,12 +7018,18 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
-                        origin: None,
+                        origin: Some(
+                            Project {
+                                path: "[TEMP_DIR]/uv.toml",
+                                key: "index",
+                            },
+                        ),
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        aliases: None,
                     },
                 ],
                 flat_index: [],
@@ -6839,9 +7085,11 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6870,6 +7118,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6961,6 +7210,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides_from_workspace: [],
         excludes_from_workspace: [],
         build_constraints_from_workspace: [],
+        package_indexes: [],
         modifications: Sufficient,
         refresh: None(
             Timestamp(
@@ -7026,9 +7276,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -7057,6 +7309,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7134,6 +7387,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides_from_workspace: [],
         excludes_from_workspace: [],
         build_constraints_from_workspace: [],
+        package_indexes: [],
         modifications: Sufficient,
         refresh: None(
             Timestamp(
@@ -7199,9 +7453,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -7230,6 +7486,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7305,6 +7562,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides_from_workspace: [],
         excludes_from_workspace: [],
         build_constraints_from_workspace: [],
+        package_indexes: [],
         modifications: Sufficient,
         refresh: None(
             Timestamp(
@@ -7370,9 +7628,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -7401,6 +7661,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7478,6 +7739,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides_from_workspace: [],
         excludes_from_workspace: [],
         build_constraints_from_workspace: [],
+        package_indexes: [],
         modifications: Sufficient,
         refresh: None(
             Timestamp(
@@ -7543,9 +7805,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -7574,6 +7838,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7649,6 +7914,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides_from_workspace: [],
         excludes_from_workspace: [],
         build_constraints_from_workspace: [],
+        package_indexes: [],
         modifications: Sufficient,
         refresh: None(
             Timestamp(
@@ -7714,9 +7980,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -7745,6 +8013,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7821,6 +8090,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides_from_workspace: [],
         excludes_from_workspace: [],
         build_constraints_from_workspace: [],
+        package_indexes: [],
         modifications: Sufficient,
         refresh: None(
             Timestamp(
@@ -7886,9 +8156,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -7917,6 +8189,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7980,7 +8253,7 @@ fn preview_features() {
         preview: Preview {
             flags: BitFlags<PreviewFeature> {
                 bits: 0b11111111111111111111111,
-                flags: PythonInstallDefault | PythonUpgrade | JsonOutput | Pylock | AddBounds | PackageConflicts | ExtraBuildDependencies | DetectModuleConflicts | Format | NativeAuth | S3Endpoint | CacheSize | InitProjectFlag | WorkspaceMetadata | WorkspaceDir | WorkspaceList | SbomExport | AuthHelper | DirectPublish | TargetWorkspaceDiscovery | MetadataJson | GcsEndpoint | AdjustUlimit,
+                flags: PythonInstallDefault | PythonUpgrade | JsonOutput | Pylock | AddBounds | PackageConflicts | ExtraBuildDependencies | DetectModuleConflicts | Format | NativeAuth | S3Endpoint | CacheSize | InitProjectFlag | WorkspaceMetadata | WorkspaceDir | WorkspaceList | SbomExport | AuthHelper | DirectPublish | TargetWorkspaceDiscovery | MetadataJson | GcsEndpoint | AdjustUlimit | IndexDivergence,
             },
         },
         python_preference: Managed,
@@ -8021,6 +8294,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allowed_indexes: None,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8041,6 +8315,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                index_conflict_strategy: Error,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8140,6 +8415,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allowed_indexes: None,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8160,6 +8436,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                index_conflict_strategy: Error,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8219,7 +8496,7 @@ fn preview_features() {
         preview: Preview {
             flags: BitFlags<PreviewFeature> {
                 bits: 0b11111111111111111111111,
-                flags: PythonInstallDefault | PythonUpgrade | JsonOutput | Pylock | AddBounds | PackageConflicts | ExtraBuildDependencies | DetectModuleConflicts | Format | NativeAuth | S3Endpoint | CacheSize | InitProjectFlag | WorkspaceMetadata | WorkspaceDir | WorkspaceList | SbomExport | AuthHelper | DirectPublish | TargetWorkspaceDiscovery | MetadataJson | GcsEndpoint | AdjustUlimit,
+                flags: PythonInstallDefault | PythonUpgrade | JsonOutput | Pylock | AddBounds | PackageConflicts | ExtraBuildDependencies | DetectModuleConflicts | Format | NativeAuth | S3Endpoint | CacheSize | InitProjectFlag | WorkspaceMetadata | WorkspaceDir | WorkspaceList | SbomExport | AuthHelper | DirectPublish | TargetWorkspaceDiscovery | MetadataJson | GcsEndpoint | AdjustUlimit | IndexDivergence,
             },
         },
         python_preference: Managed,
@@ -8260,6 +8537,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allowed_indexes: None,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8280,6 +8558,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                index_conflict_strategy: Error,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8380,6 +8659,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allowed_indexes: None,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8400,6 +8680,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                index_conflict_strategy: Error,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8500,6 +8781,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allowed_indexes: None,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8520,6 +8802,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                index_conflict_strategy: Error,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8621,6 +8904,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allowed_indexes: None,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8641,6 +8925,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                index_conflict_strategy: Error,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8814,9 +9099,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -8845,6 +9132,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -8995,9 +9283,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -9026,6 +9316,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9199,9 +9490,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -9230,6 +9523,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9378,9 +9672,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -9409,6 +9705,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9551,9 +9848,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -9582,6 +9881,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9725,9 +10025,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -9756,6 +10058,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9904,6 +10207,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allowed_indexes: None,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -9924,6 +10228,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10028,6 +10333,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allowed_indexes: None,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10048,6 +10354,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10175,6 +10482,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allowed_indexes: None,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10195,6 +10503,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10297,6 +10606,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allowed_indexes: None,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10317,6 +10627,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10409,6 +10720,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allowed_indexes: None,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10429,6 +10741,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10522,6 +10835,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allowed_indexes: None,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10542,6 +10856,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10759,9 +11074,11 @@ fn build_isolation_override() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -10790,6 +11107,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -10941,9 +11259,11 @@ fn build_isolation_override() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            index_conflict_strategy: Error,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            allowed_indexes: None,
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -10972,6 +11292,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            annotate_index: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
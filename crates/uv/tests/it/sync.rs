@@ -792,7 +792,7 @@ fn group_requires_python_useful_defaults() -> Result<()> {
     ----- stderr -----
     Using CPython 3.8.[X] interpreter at: [PYTHON-3.8]
     Creating virtual environment at: .venv
-      × No solution found when resolving dependencies for split (markers: python_full_version == '3.8.*'):
+      × No solution found when resolving dependencies for split (markers: python_full_version < '3.9'):
       ╰─▶ Because the requested Python version (>=3.8) does not satisfy Python>=3.9 and sphinx==7.2.6 depends on Python>=3.9, we can conclude that sphinx==7.2.6 cannot be used.
           And because only sphinx<=7.2.6 is available, we can conclude that sphinx>=7.2.6 cannot be used.
           And because pharaohs-tomp:dev depends on sphinx>=7.2.6 and your project requires pharaohs-tomp:dev, we can conclude that your project's requirements are unsatisfiable.
@@ -807,7 +807,7 @@ fn group_requires_python_useful_defaults() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies for split (markers: python_full_version == '3.8.*'):
+      × No solution found when resolving dependencies for split (markers: python_full_version < '3.9'):
       ╰─▶ Because the requested Python version (>=3.8) does not satisfy Python>=3.9 and sphinx==7.2.6 depends on Python>=3.9, we can conclude that sphinx==7.2.6 cannot be used.
           And because only sphinx<=7.2.6 is available, we can conclude that sphinx>=7.2.6 cannot be used.
           And because pharaohs-tomp:dev depends on sphinx>=7.2.6 and your project requires pharaohs-tomp:dev, we can conclude that your project's requirements are unsatisfiable.
@@ -936,7 +936,7 @@ fn group_requires_python_useful_non_defaults() -> Result<()> {
     ----- stderr -----
     Using CPython 3.8.[X] interpreter at: [PYTHON-3.8]
     Creating virtual environment at: .venv
-      × No solution found when resolving dependencies for split (markers: python_full_version == '3.8.*'):
+      × No solution found when resolving dependencies for split (markers: python_full_version < '3.9'):
       ╰─▶ Because the requested Python version (>=3.8) does not satisfy Python>=3.9 and sphinx==7.2.6 depends on Python>=3.9, we can conclude that sphinx==7.2.6 cannot be used.
           And because only sphinx<=7.2.6 is available, we can conclude that sphinx>=7.2.6 cannot be used.
           And because pharaohs-tomp:mygroup depends on sphinx>=7.2.6 and your project requires pharaohs-tomp:mygroup, we can conclude that your project's requirements are unsatisfiable.
@@ -952,7 +952,7 @@ fn group_requires_python_useful_non_defaults() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies for split (markers: python_full_version == '3.8.*'):
+      × No solution found when resolving dependencies for split (markers: python_full_version < '3.9'):
       ╰─▶ Because the requested Python version (>=3.8) does not satisfy Python>=3.9 and sphinx==7.2.6 depends on Python>=3.9, we can conclude that sphinx==7.2.6 cannot be used.
           And because only sphinx<=7.2.6 is available, we can conclude that sphinx>=7.2.6 cannot be used.
           And because pharaohs-tomp:mygroup depends on sphinx>=7.2.6 and your project requires pharaohs-tomp:mygroup, we can conclude that your project's requirements are unsatisfiable.
@@ -15567,3 +15567,65 @@ fn sync_reinstalls_on_version_change() -> Result<()> {
 
     Ok(())
 }
+
+/// Whether the project environment was created, reused, or recreated is logged, along with the
+/// reason for recreating it.
+#[test]
+fn sync_project_environment_logs() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+        "#,
+    )?;
+
+    let environment_logs = |python: &str| -> Result<String> {
+        let output = context
+            .sync()
+            .arg("--python")
+            .arg(python)
+            .env(
+                EnvVars::RUST_LOG,
+                "uv::commands::project=debug,uv_workspace=debug",
+            )
+            .output()?;
+        assert!(output.status.success(), "{output:?}");
+
+        let stderr = String::from_utf8(output.stderr)?;
+        Ok(stderr
+            .lines()
+            .filter(|line| {
+                line.starts_with("DEBUG")
+                    && [
+                        "Creating project environment",
+                        "Reusing existing project environment",
+                        "Ignoring existing project environment",
+                        "Recreating project environment",
+                    ]
+                    .iter()
+                    .any(|message| line.contains(message))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    };
+
+    // There's no environment yet, so it's created.
+    assert_snapshot!(environment_logs("3.12")?, @"DEBUG Creating project environment at `.venv`");
+
+    // The environment satisfies the request, so it's reused.
+    assert_snapshot!(environment_logs("3.12")?, @"DEBUG Reusing existing project environment at `.venv`");
+
+    // The Python version changed, so the environment is recreated.
+    assert_snapshot!(environment_logs("3.11")?, @"
+    DEBUG Ignoring existing project environment at `.venv`: The project environment's Python version does not satisfy the request: `Python 3.11`
+    DEBUG Recreating project environment at `.venv`
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// `--timings` should print a summary of the time spent in each phase, even with `--quiet`.
+#[test]
+fn timings() {
+    let context = TestContext::new_with_versions(&[]).with_filtered_counts();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"(?m)^(\w+)\s+\S+\s+\d+\.\d%$", "$1 [TIME] [PERCENT]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.clean().arg("--timings"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Clearing cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    Phase  Duration  Percent
+    Other [TIME] [PERCENT]
+    Total [TIME] [PERCENT]
+    ");
+
+    uv_snapshot!(filters, context.clean().arg("--timings").arg("--quiet"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Phase  Duration  Percent
+    Other [TIME] [PERCENT]
+    Total [TIME] [PERCENT]
+    ");
+}
+
+/// With `--timings` in GitHub Actions, the phases are appended to the job summary.
+#[test]
+fn timings_step_summary() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]).with_filtered_counts();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"(?m)^(\w+)\s+\S+\s+\d+\.\d%$", "$1 [TIME] [PERCENT]"),
+            (r"(?m)^\| (\w+) \| \d[^|]* \|", "| $1 | [TIME] |"),
+            (r"([+-])\d\S*s \(([+-])\d+\.\d%\)", "$1[TIME] ($2[PERCENT])"),
+        ])
+        .collect::<Vec<_>>();
+
+    // Other steps may have written to the summary already.
+    let step_summary = context.temp_dir.child("step-summary.md");
+    step_summary.write_str("## Tests\n\n")?;
+
+    context
+        .clean()
+        .arg("--timings")
+        .env(EnvVars::GITHUB_STEP_SUMMARY, step_summary.path())
+        .assert()
+        .success();
+
+    // Compare against the phases of a previous, much slower run.
+    let baseline = context.temp_dir.child("baseline.summary.json");
+    baseline.write_str(r#"{"spans": {}, "phases": {"Other": 30.0, "Total": 30.0}}"#)?;
+    context
+        .clean()
+        .arg("--timings")
+        .env(EnvVars::GITHUB_STEP_SUMMARY, step_summary.path())
+        .env(EnvVars::UV_TIMINGS_BASELINE, baseline.path())
+        .assert()
+        .success();
+
+    let markdown = fs_err::read_to_string(step_summary.path())?;
+    let markdown = filters
+        .iter()
+        .fold(markdown, |markdown, (pattern, replacement)| {
+            regex::Regex::new(pattern)
+                .unwrap()
+                .replace_all(&markdown, *replacement)
+                .into_owned()
+        });
+    insta::assert_snapshot!(markdown, @"
+    ## Tests
+
+    ### uv timings
+
+    | Phase | Duration |
+    | :--- | ---: |
+    | Other | [TIME] |
+    | Total | [TIME] |
+
+    ### uv timings
+
+    | Phase | Duration | Baseline | Change |
+    | :--- | ---: | ---: | ---: |
+    | Other | [TIME] | [TIME] | -[TIME] (-[PERCENT]) |
+    | Total | [TIME] | [TIME] | -[TIME] (-[PERCENT]) |
+    ");
+
+    // A summary that can't be written doesn't fail the command.
+    let filters = filters
+        .into_iter()
+        .chain([(r"(failed to open file `.*`): .*", "$1: [ERROR]")])
+        .collect::<Vec<_>>();
+    uv_snapshot!(filters, context.clean().arg("--timings").env(EnvVars::GITHUB_STEP_SUMMARY, context.temp_dir.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache found at: [CACHE_DIR]/
+    Phase  Duration  Percent
+    Other [TIME] [PERCENT]
+    Total [TIME] [PERCENT]
+    warning: Failed to write the timings to the GitHub Actions job summary: failed to open file `[TEMP_DIR]/`: [ERROR]
+    ");
+
+    Ok(())
+}